@@ -53,6 +53,34 @@ fn register_test_ca<T: Config>() -> CaName<T> {
             root_cert: bounded_root_cert,
             revoked_count: 0,
             crl_versions: Default::default(),
+            staleness_policy: Default::default(),
+        },
+    );
+    bounded_name
+}
+
+/// Jan 29 2026 00:00:00 UTC, in milliseconds.
+/// This falls within the validity period of the Intel TCB info (from Jan 20 2026 to Feb 19 2026).
+const TCB_INFO_PRESENT_MS: u64 = 1_769_644_800_000;
+
+fn register_intel_ca<T: Config>() -> CaName<T> {
+    let root_cert = include_bytes!("resources/intel/intel_root_ca.der").to_vec();
+    let bounded_name: CaName<T> = T::IntelCaName::get()
+        .as_bytes()
+        .to_vec()
+        .try_into()
+        .expect("CA name should fit within MaxCaNameLength");
+    let bounded_root_cert: BoundedVec<u8, ConstU32<MAX_ROOT_CERT_LENGTH>> = root_cert
+        .try_into()
+        .expect("Root cert should fit within MAX_ROOT_CERT_LENGTH");
+
+    CertificateAuthorities::<T>::insert(
+        &bounded_name,
+        CaInfo {
+            root_cert: bounded_root_cert,
+            revoked_count: 0,
+            crl_versions: Default::default(),
+            staleness_policy: Default::default(),
         },
     );
     bounded_name
@@ -112,12 +140,19 @@ mod benchmarks {
     fn unregister_ca() {
         let ca_name = register_test_ca::<T>();
         let name = ca_name.to_vec();
+        for i in 0..MAX_ISSUERS_PER_CA {
+            CrlIssuers::<T>::insert(&ca_name, [i as u8; 32], Issuer::default());
+            CrlNumbers::<T>::insert(&ca_name, [i as u8; 32], 1);
+        }
 
         #[extrinsic_call]
         unregister_ca(RawOrigin::Root, name);
 
         // Verify the CA was removed.
         assert!(!CertificateAuthorities::<T>::contains_key(&ca_name));
+        assert_eq!(CrlIssuers::<T>::iter_prefix(&ca_name).count(), 0);
+        assert_eq!(CrlNumbers::<T>::iter_prefix(&ca_name).count(), 0);
+        assert!(PendingRemovals::<T>::contains_key(&ca_name));
     }
 
     /// Benchmark `update_crl` with PEM input, parameterized by the number of revoked certificates.
@@ -173,16 +208,130 @@ mod benchmarks {
         assert!(!ca_info.crl_versions.is_empty());
     }
 
+    /// Benchmark `update_tcb_info` replacing an older TCB info of the same platform.
+    #[benchmark]
+    fn update_tcb_info() {
+        register_intel_ca::<T>();
+        let tcb_response = include_bytes!("resources/intel/valid_tcbinfo.json").to_vec();
+        let cert_chain = include_bytes!("resources/intel/valid_tcbinfo_certs.pem").to_vec();
+        let fmspc = [0xb0, 0xc0, 0x6f, 0x00, 0x00, 0x00];
+        TcbInfos::<T>::insert(
+            fmspc,
+            TcbId::Tdx,
+            TcbInfoEntry {
+                tcb_response: Default::default(),
+                evaluation_data_number: 0,
+                issue_date: 0,
+            },
+        );
+
+        let caller: T::AccountId = whitelisted_caller();
+        set_timestamp::<T>(TCB_INFO_PRESENT_MS);
+
+        #[extrinsic_call]
+        update_tcb_info(RawOrigin::Signed(caller), tcb_response, cert_chain);
+
+        // Verify the TCB info was replaced.
+        let entry = TcbInfos::<T>::get(fmspc, TcbId::Tdx).unwrap();
+        assert_eq!(entry.evaluation_data_number, 18);
+    }
+
+    #[benchmark]
+    fn set_staleness_policy() {
+        let ca_name = register_test_ca::<T>();
+        let name = ca_name.to_vec();
+        let policy = StalenessPolicy::Enforce { grace_period: 3600 };
+
+        #[extrinsic_call]
+        set_staleness_policy(RawOrigin::Root, name, policy);
+
+        // Verify the policy was set.
+        let ca_info = CertificateAuthorities::<T>::get(&ca_name).unwrap();
+        assert_eq!(ca_info.staleness_policy, policy);
+    }
+
     #[benchmark]
-    fn clear_crl() {
+    fn schedule_root_rotation() {
         let ca_name = register_test_ca::<T>();
+        let name = ca_name.to_vec();
+        let new_root_cert = include_bytes!("resources/intel/intel_root_ca.der").to_vec();
+
+        #[extrinsic_call]
+        schedule_root_rotation(RawOrigin::Root, name, new_root_cert, 3600);
+
+        // Verify the rotation was scheduled.
+        assert!(RootRotations::<T>::contains_key(&ca_name));
+    }
+
+    #[benchmark]
+    fn complete_root_rotation() {
+        let ca_name = register_test_ca::<T>();
+        let new_root_cert: BoundedVec<u8, ConstU32<MAX_ROOT_CERT_LENGTH>> =
+            include_bytes!("resources/intel/intel_root_ca.der")
+                .to_vec()
+                .try_into()
+                .unwrap();
+        let rotation = RootRotation {
+            new_root_cert: new_root_cert.clone(),
+            overlap_end: 0,
+        };
+        RootRotations::<T>::insert(&ca_name, rotation.clone());
 
         #[block]
         {
-            Revoked::<T>::remove(&ca_name);
+            Pallet::<T>::complete_root_rotation(ca_name.clone(), rotation);
         }
 
-        assert!(Revoked::<T>::get(&ca_name).is_none());
+        let ca_info = CertificateAuthorities::<T>::get(&ca_name).unwrap();
+        assert_eq!(ca_info.root_cert, new_root_cert);
+        assert!(!RootRotations::<T>::contains_key(&ca_name));
+    }
+
+    #[benchmark]
+    fn set_distribution_points() {
+        let ca_name = register_test_ca::<T>();
+        let name = ca_name.to_vec();
+        let point = DistributionPoint {
+            url: [b'a'; MAX_DISTRIBUTION_POINT_URL_LENGTH as usize]
+                .to_vec()
+                .try_into()
+                .unwrap(),
+            format: CrlFormat::Pem,
+            cert_chain: [0; MAX_CERT_CHAIN_PEM_LENGTH as usize]
+                .to_vec()
+                .try_into()
+                .unwrap(),
+        };
+        let points = alloc::vec![point; MAX_DISTRIBUTION_POINTS_PER_CA as usize];
+
+        #[extrinsic_call]
+        set_distribution_points(RawOrigin::Root, name, points);
+
+        // Verify the distribution points were set.
+        assert_eq!(
+            DistributionPoints::<T>::get(&ca_name).unwrap().len(),
+            MAX_DISTRIBUTION_POINTS_PER_CA as usize
+        );
+    }
+
+    /// Benchmark the removal of the revoked certificates of an unregistered CA, parameterized
+    /// by the number of revoked certificates.
+    #[benchmark]
+    fn clear_crl(n: Linear<0, 1000>) {
+        let ca_name = register_test_ca::<T>();
+        for i in 0..n {
+            let serial: SerialNumber = i.to_be_bytes().to_vec().try_into().unwrap();
+            RevokedCerts::<T>::insert((&ca_name, [0; 32], serial), ());
+        }
+        PendingRemovals::<T>::insert(&ca_name, ());
+
+        #[block]
+        {
+            Pallet::<T>::remove_pending_revocations(Weight::MAX);
+        }
+
+        assert_eq!(RevokedCerts::<T>::iter_key_prefix((&ca_name,)).count(), 0);
+        assert!(!PendingRemovals::<T>::contains_key(&ca_name));
     }
 
     #[cfg(test)]
@@ -219,6 +368,7 @@ mod mock {
 
     parameter_types! {
         pub const MaxCaNameLength: u32 = 64;
+        pub const IntelCaName: &'static str = "Intel_SGX_Processor";
     }
 
     impl crate::Config for Test {
@@ -227,6 +377,26 @@ mod mock {
         type WeightInfo = ();
         type MaxCaNameLength = MaxCaNameLength;
         type UnixTime = Timestamp;
+        type OffchainWorkerInterval = ConstU32<0>;
+        type UnsignedPriority = ConstU64<100>;
+        type IntelCaName = IntelCaName;
+    }
+
+    impl<C> frame_system::offchain::CreateTransactionBase<C> for Test
+    where
+        RuntimeCall: From<C>,
+    {
+        type Extrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+        type RuntimeCall = RuntimeCall;
+    }
+
+    impl<C> frame_system::offchain::CreateInherent<C> for Test
+    where
+        RuntimeCall: From<C>,
+    {
+        fn create_inherent(call: Self::RuntimeCall) -> Self::Extrinsic {
+            frame_system::mocking::MockUncheckedExtrinsic::<Test>::new_bare(call)
+        }
     }
 
     impl pallet_timestamp::Config for Test {
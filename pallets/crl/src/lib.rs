@@ -24,10 +24,36 @@
 //! The CRL is parsed and validated using the `tee-verifier` crate's `parse_crl_pem` or
 //! `parse_crl_der` functions, which verify the CRL signature against a certificate chain
 //! (PEM) or a single signing certificate (DER).
+//!
+//! It also keeps a registry of the Intel TCB info, keyed by platform FMSPC: anyone can
+//! submit a TCB info response signed by a chain rooted in a registered CA, and the
+//! registry only moves forward in `tcbEvaluationDataNumber`.
+//!
+//! The validity period of every stored CRL is tracked, and each CA has a staleness policy
+//! that tells the consumers whether its revocation data can still be relied on once a CRL
+//! is past its `nextUpdate`.
+//!
+//! Every revoked certificate is stored in its own entry, so that verifiers check a
+//! certificate with a single lookup ([`CrlProvider::is_revoked`]) instead of loading the
+//! whole CRL. The revoked certificates of an unregistered CA are removed in idle time.
+//!
+//! Besides complete CRLs, it accepts delta CRLs (RFC 5280, section 5.2.4): they're applied
+//! on top of the stored revocation set of their issuer, provided that it was built from a
+//! complete CRL with a CRL number not lower than the delta base.
+//!
+//! The root certificate of a CA can be rotated: the new one is scheduled together with an
+//! overlap window, during which CRLs and TCB info signed under either root are accepted.
+//! Once the window ends only the new root is trusted, and the rotation is completed in idle
+//! time.
+//!
+//! CRLs can also be kept up to date without anyone submitting them: when the manager configures
+//! the distribution points of a CA, an offchain worker periodically fetches its CRLs and submits
+//! the newer ones as unsigned transactions, which are validated as thoroughly as `update_crl`.
 
 mod weight;
 
 mod benchmarking;
+pub mod migrations;
 
 mod mock;
 mod should;
@@ -39,7 +65,7 @@ use alloc::vec::Vec;
 use frame_support::pallet_prelude::*;
 use frame_support::{dispatch::PostDispatchInfo, traits::UnixTime};
 pub use pallet::*;
-pub use tee_verifier::{Crl, RevokedCertId};
+pub use tee_verifier::{Crl, RevocationList, RevokedCertId};
 pub use weight::WeightInfo;
 
 /// Maximum size in bytes of the CRL PEM data.
@@ -57,8 +83,8 @@ pub const MAX_CRL_DER_LENGTH: u32 = 65536;
 /// Maximum total size in bytes of the DER-encoded certificate chain.
 pub const MAX_CERT_CHAIN_DER_LENGTH: u32 = 16384;
 
-/// Maximum number of revoked certificates that can be stored per CA.
-pub const MAX_REVOKED_CERTS_PER_CA: u32 = 10000;
+/// Maximum number of revoked certificates in a single CRL.
+pub const MAX_REVOKED_CERTS_PER_CRL: u32 = 10000;
 
 /// Maximum number of distinct CRL issuers per CA.
 const MAX_ISSUERS_PER_CA: u32 = 10;
@@ -69,6 +95,87 @@ const MAX_CERT_ISSUER_LENGTH: u32 = 256;
 /// Maximum length in bytes for a certificate serial number.
 const MAX_CERT_SERIAL_LENGTH: u32 = 32;
 
+/// Maximum size in bytes of a signed TCB info response (JSON).
+pub const MAX_TCB_RESPONSE_LENGTH: u32 = 16384;
+
+/// Maximum number of CRL distribution points per CA.
+pub const MAX_DISTRIBUTION_POINTS_PER_CA: u32 = 4;
+
+/// Maximum length in bytes of the URL of a CRL distribution point.
+pub const MAX_DISTRIBUTION_POINT_URL_LENGTH: u32 = 512;
+
+/// How long the offchain worker waits for a CRL distribution point to respond.
+const HTTP_TIMEOUT_MS: u64 = 10_000;
+
+/// FMSPC (Family-Model-Stepping-Platform-CustomSKU) of an Intel platform.
+pub type Fmspc = [u8; 6];
+
+/// The TEE a TCB info applies to.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub enum TcbId {
+    /// Intel SGX.
+    Sgx,
+    /// Intel TDX.
+    Tdx,
+}
+
+/// Validity period of a stored CRL.
+#[derive(
+    Clone, Copy, Default, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug,
+)]
+pub struct CrlValidity {
+    /// The `thisUpdate` timestamp of the CRL (in secs).
+    pub this_update: u64,
+    /// The `nextUpdate` timestamp of the CRL (in secs), if any.
+    pub next_update: Option<u64>,
+}
+
+/// How the revocation data of a CA is treated once a CRL is past its `nextUpdate`.
+#[derive(
+    Clone, Copy, Default, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug,
+)]
+pub enum StalenessPolicy {
+    /// Stale CRLs are still relied on.
+    #[default]
+    Ignore,
+    /// A CRL is stale `grace_period` secs after its `nextUpdate`: a zero grace period means
+    /// failing as soon as the `nextUpdate` is past.
+    Enforce {
+        /// Seconds after `nextUpdate` during which the CRL is still relied on.
+        grace_period: u64,
+    },
+}
+
+impl StalenessPolicy {
+    /// Returns true if a CRL with the given validity is stale at `now` (in secs).
+    pub fn is_stale(&self, validity: &CrlValidity, now: u64) -> bool {
+        match self {
+            StalenessPolicy::Ignore => false,
+            StalenessPolicy::Enforce { grace_period } => validity
+                .next_update
+                .is_some_and(|next_update| now > next_update.saturating_add(*grace_period)),
+        }
+    }
+}
+
+/// A scheduled rotation of the root certificate of a CA.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct RootRotation {
+    /// The new root certificate (DER encoded).
+    pub new_root_cert: BoundedVec<u8, ConstU32<MAX_ROOT_CERT_LENGTH>>,
+    /// The end of the overlap window (in secs): until then, both the current and the new
+    /// root certificates are trusted.
+    pub overlap_end: u64,
+}
+
+impl RootRotation {
+    /// Returns true if the overlap window is over at `now` (in secs), i.e. only the new root
+    /// certificate is trusted.
+    pub fn is_over(&self, now: u64) -> bool {
+        now >= self.overlap_end
+    }
+}
+
 /// Error returned when a CA is not found.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CaNotFoundError;
@@ -78,6 +185,54 @@ pub trait CrlProvider {
     /// Returns the CRL for a specific CA by name.
     /// Returns an error if the CA is not found.
     fn get_crl(ca_name: &str) -> Result<Crl, CaNotFoundError>;
+
+    /// Returns the root certificate (DER encoded) of a specific CA by name: during a root
+    /// rotation, it's the current one until the overlap window ends, then the new one.
+    /// Returns an error if the CA is not found.
+    fn get_root_cert(ca_name: &str) -> Result<Vec<u8>, CaNotFoundError>;
+
+    /// Returns the validity period of the CRL stored for each issuer of a specific CA.
+    /// Returns an error if the CA is not found.
+    fn get_crl_validity(ca_name: &str) -> Result<Vec<CrlValidity>, CaNotFoundError>;
+
+    /// Returns true if, according to the CA staleness policy, any CRL of a specific CA is
+    /// too stale to be relied on at `now` (in secs).
+    /// Returns an error if the CA is not found.
+    fn is_crl_stale(ca_name: &str, now: u64) -> Result<bool, CaNotFoundError>;
+
+    /// Returns true if a specific CA revoked the certificate with the given issuer (DER
+    /// encoded distinguished name) and serial number.
+    fn is_revoked(ca_name: &str, issuer: &[u8], serial_number: &[u8]) -> bool;
+}
+
+/// The certificates revoked by a CA, looked up one at a time by `tee-verifier` while
+/// checking a certificate chain.
+pub struct Revocations<'a, P> {
+    ca_name: &'a str,
+    _provider: core::marker::PhantomData<P>,
+}
+
+impl<'a, P: CrlProvider> Revocations<'a, P> {
+    /// The certificates revoked by the given CA.
+    pub fn new(ca_name: &'a str) -> Self {
+        Self {
+            ca_name,
+            _provider: Default::default(),
+        }
+    }
+}
+
+impl<P: CrlProvider> RevocationList for Revocations<'_, P> {
+    fn is_revoked(&self, issuer: &[u8], serial_number: &[u8]) -> bool {
+        P::is_revoked(self.ca_name, issuer, serial_number)
+    }
+}
+
+/// Trait for accessing the registered TCB info from other pallets.
+pub trait TcbInfoProvider {
+    /// Returns the signed TCB info response registered for the given platform and TEE, if any.
+    /// Its signature has already been checked when it was registered.
+    fn get_tcb_response(fmspc: &Fmspc, id: TcbId) -> Option<Vec<u8>>;
 }
 
 /// Input data for CRL updates, supporting both PEM and DER formats.
@@ -99,17 +254,68 @@ pub enum CrlInput {
     },
 }
 
+/// Encoding of the CRLs served by a distribution point.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub enum CrlFormat {
+    /// PEM-encoded CRL.
+    Pem,
+    /// DER-encoded CRL.
+    Der,
+}
+
+impl CrlFormat {
+    /// Maximum size in bytes of a CRL in this format accepted by `update_crl`.
+    pub fn max_crl_length(&self) -> usize {
+        match self {
+            CrlFormat::Pem => MAX_CRL_PEM_LENGTH as usize,
+            CrlFormat::Der => MAX_CRL_DER_LENGTH as usize,
+        }
+    }
+}
+
+/// A CRL distribution point of a CA, polled by the offchain worker.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct DistributionPoint {
+    /// The (UTF-8 encoded) URL the CRL is served at.
+    pub url: BoundedVec<u8, ConstU32<MAX_DISTRIBUTION_POINT_URL_LENGTH>>,
+    /// The encoding of the served CRL.
+    pub format: CrlFormat,
+    /// The certificate chain for CRL signature verification, encoded as `format`.
+    pub cert_chain: BoundedVec<u8, ConstU32<MAX_CERT_CHAIN_PEM_LENGTH>>,
+}
+
+impl DistributionPoint {
+    /// The input of `update_crl` for a CRL served by this distribution point.
+    pub fn crl_input(&self, crl: Vec<u8>) -> CrlInput {
+        let cert_chain = self.cert_chain.to_vec();
+        match self.format {
+            CrlFormat::Pem => CrlInput::Pem { crl, cert_chain },
+            CrlFormat::Der => CrlInput::Der { crl, cert_chain },
+        }
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
+    use frame_system::offchain::{CreateInherent, SubmitTransaction};
     use frame_system::pallet_prelude::*;
-    use tee_verifier::{parse_crl_der, parse_crl_pem};
+    use sp_runtime::{
+        offchain::{http, Duration},
+        traits::Zero,
+        SaturatedConversion,
+    };
+    use tee_verifier::{intel_parse_tcb_response, parse_crl_der, parse_crl_pem, ParsedCrl};
+
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config: frame_system::Config + CreateInherent<Call<Self>> {
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -125,6 +331,19 @@ pub mod pallet {
 
         /// Provider for UnixTime
         type UnixTime: UnixTime;
+
+        /// How often (in blocks) the offchain worker fetches the CRLs from the distribution
+        /// points. Zero disables the offchain worker.
+        #[pallet::constant]
+        type OffchainWorkerInterval: Get<BlockNumberFor<Self>>;
+
+        /// Priority of the unsigned CRL updates submitted by the offchain worker.
+        #[pallet::constant]
+        type UnsignedPriority: Get<TransactionPriority>;
+
+        /// Name of the Intel SGX CA: the only one trusted to sign TCB infos.
+        #[pallet::constant]
+        type IntelCaName: Get<&'static str>;
     }
 
     /// Type alias for bounded CA name.
@@ -140,43 +359,97 @@ pub mod pallet {
         pub root_cert: BoundedVec<u8, ConstU32<MAX_ROOT_CERT_LENGTH>>,
         /// The total number of revoked certificates currently stored for this CA (across all issuers).
         pub revoked_count: u32,
-        /// Per-issuer CRL version tracking. Maps issuer hash → `thisUpdate` timestamp (in secs).
-        pub crl_versions: BoundedBTreeMap<IssuerHash, u64, ConstU32<MAX_ISSUERS_PER_CA>>,
+        /// Per-issuer CRL version tracking. Maps issuer hash → CRL validity period.
+        pub crl_versions: BoundedBTreeMap<IssuerHash, CrlValidity, ConstU32<MAX_ISSUERS_PER_CA>>,
+        /// How stale CRLs of this CA are treated.
+        pub staleness_policy: StalenessPolicy,
     }
 
-    /// Unique identifiers for revoked certificates.
-    #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
-    pub struct RevokedInfo {
-        /// DER-encoded issuer distinguished name.
-        pub issuer: BoundedVec<u8, ConstU32<MAX_CERT_ISSUER_LENGTH>>,
-        /// Certificate serial number.
-        pub serial: BoundedVec<u8, ConstU32<MAX_CERT_SERIAL_LENGTH>>,
-    }
+    /// DER-encoded distinguished name of a CRL issuer.
+    pub type Issuer = BoundedVec<u8, ConstU32<MAX_CERT_ISSUER_LENGTH>>;
 
-    impl From<RevokedInfo> for RevokedCertId {
-        fn from(info: RevokedInfo) -> Self {
-            RevokedCertId {
-                issuer: info.issuer.into(),
-                serial_number: info.serial.into(),
-            }
-        }
-    }
+    /// Serial number of a revoked certificate.
+    pub type SerialNumber = BoundedVec<u8, ConstU32<MAX_CERT_SERIAL_LENGTH>>;
 
     /// Storage for registered CAs and their metadata.
     #[pallet::storage]
     pub type CertificateAuthorities<T: Config> =
         StorageMap<_, Blake2_128Concat, CaName<T>, CaInfo, OptionQuery>;
 
-    /// Storage for revoked certificate issuers, keyed by (CA name, index).
+    /// A registered TCB info.
+    #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+    pub struct TcbInfoEntry {
+        /// The signed TCB info response (JSON), as returned by Intel PCS.
+        pub tcb_response: BoundedVec<u8, ConstU32<MAX_TCB_RESPONSE_LENGTH>>,
+        /// The `tcbEvaluationDataNumber` of the TCB info.
+        pub evaluation_data_number: u32,
+        /// The `issueDate` of the TCB info (in secs).
+        pub issue_date: u64,
+    }
+
+    /// Storage for the registered TCB info, keyed by (FMSPC, TEE).
     #[pallet::storage]
-    pub type Revoked<T: Config> = StorageMap<
+    pub type TcbInfos<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        Fmspc,
+        Twox64Concat,
+        TcbId,
+        TcbInfoEntry,
+        OptionQuery,
+    >;
+
+    /// Storage for revoked certificates, keyed by (CA name, issuer hash, serial number).
+    #[pallet::storage]
+    pub type RevokedCerts<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, CaName<T>>,
+            NMapKey<Identity, IssuerHash>,
+            NMapKey<Blake2_128Concat, SerialNumber>,
+        ),
+        (),
+        OptionQuery,
+    >;
+
+    /// Storage for the CRL issuers of each CA, keyed by (CA name, issuer hash).
+    #[pallet::storage]
+    pub type CrlIssuers<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, CaName<T>, Identity, IssuerHash, Issuer, OptionQuery>;
+
+    /// Storage for the CRL number of the last CRL (complete or delta) applied for each issuer,
+    /// keyed by (CA name, issuer hash). Issuers whose last complete CRL had no CRL number
+    /// don't accept delta CRLs.
+    #[pallet::storage]
+    pub type CrlNumbers<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, CaName<T>, Identity, IssuerHash, u64, OptionQuery>;
+
+    /// Storage for the number of revoked certificates of each issuer, keyed by (CA name,
+    /// issuer hash). It never exceeds MAX_REVOKED_CERTS_PER_CRL.
+    #[pallet::storage]
+    pub type IssuerRevokedCounts<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, CaName<T>, Identity, IssuerHash, u32, ValueQuery>;
+
+    /// Unregistered CAs whose revoked certificates are still being removed, in idle time.
+    #[pallet::storage]
+    pub type PendingRemovals<T: Config> =
+        StorageMap<_, Blake2_128Concat, CaName<T>, (), OptionQuery>;
+
+    /// Storage for the CRL distribution points of each CA, polled by the offchain worker.
+    #[pallet::storage]
+    pub type DistributionPoints<T: Config> = StorageMap<
         _,
         Blake2_128Concat,
         CaName<T>,
-        BoundedVec<RevokedInfo, ConstU32<MAX_REVOKED_CERTS_PER_CA>>,
+        BoundedVec<DistributionPoint, ConstU32<MAX_DISTRIBUTION_POINTS_PER_CA>>,
         OptionQuery,
     >;
 
+    /// Storage for the scheduled root certificate rotations, keyed by CA name.
+    #[pallet::storage]
+    pub type RootRotations<T: Config> =
+        StorageMap<_, Blake2_128Concat, CaName<T>, RootRotation, OptionQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -198,9 +471,69 @@ pub mod pallet {
             issuer: Vec<u8>,
             /// The `thisUpdate` timestamp of the CRL (in secs).
             last_update: u64,
+            /// The `nextUpdate` timestamp of the CRL (in secs), if any.
+            next_update: Option<u64>,
             /// The total number of revoked certificates stored for this CA (across all issuers).
             revoked_count: u32,
         },
+        /// A delta CRL has been applied to a CA's CRL.
+        DeltaCrlApplied {
+            /// The name of the CA whose CRL was updated.
+            ca_name: CaName<T>,
+            /// The DER-encoded distinguished name of the CRL issuer.
+            issuer: Vec<u8>,
+            /// The CRL number of the delta CRL.
+            crl_number: u64,
+            /// The CRL number of the complete CRL the delta CRL is based on.
+            base_crl_number: u64,
+            /// The `thisUpdate` timestamp of the delta CRL (in secs).
+            last_update: u64,
+            /// The `nextUpdate` timestamp of the delta CRL (in secs), if any.
+            next_update: Option<u64>,
+            /// The number of newly revoked certificates.
+            added: u32,
+            /// The number of certificates removed from the CRL.
+            removed: u32,
+            /// The total number of revoked certificates stored for this CA (across all issuers).
+            revoked_count: u32,
+        },
+        /// The staleness policy of a CA has been set.
+        StalenessPolicySet {
+            /// The name of the CA.
+            ca_name: CaName<T>,
+            /// The new staleness policy.
+            policy: StalenessPolicy,
+        },
+        /// The rotation of the root certificate of a CA has been scheduled.
+        RootRotationScheduled {
+            /// The name of the CA.
+            ca_name: CaName<T>,
+            /// The end of the overlap window (in secs).
+            overlap_end: u64,
+        },
+        /// The root certificate of a CA has been replaced by the scheduled one.
+        RootRotated {
+            /// The name of the CA.
+            ca_name: CaName<T>,
+        },
+        /// The CRL distribution points of a CA have been set.
+        DistributionPointsSet {
+            /// The name of the CA.
+            ca_name: CaName<T>,
+            /// The number of distribution points.
+            count: u32,
+        },
+        /// The TCB info of a platform has been updated.
+        TcbInfoUpdated {
+            /// The FMSPC of the platform.
+            fmspc: Fmspc,
+            /// The TEE the TCB info applies to.
+            id: TcbId,
+            /// The `tcbEvaluationDataNumber` of the TCB info.
+            evaluation_data_number: u32,
+            /// The `issueDate` of the TCB info (in secs).
+            issue_date: u64,
+        },
     }
 
     /// Errors for the CRL pallet.
@@ -228,6 +561,15 @@ pub mod pallet {
         CrlValidationError,
         /// Too many revoked certificates in the CRL.
         TooManyRevokedCerts,
+        /// No complete CRL with a CRL number is stored for the issuer of the delta CRL.
+        MissingBaseCrl,
+        /// The delta CRL is based on a complete CRL newer than the stored one.
+        DeltaCrlBaseMismatch,
+        /// The delta CRL has no CRL number.
+        MissingCrlNumber,
+        /// The revoked certificates of a previously unregistered CA with the same name are
+        /// still being removed.
+        CaRemovalPending,
         /// Issuer data exceeds maximum length.
         IssuerTooLarge,
         /// Serial number data exceeds maximum length.
@@ -238,72 +580,623 @@ pub mod pallet {
         NotNewerCrl,
         /// Too many distinct CRL issuers for this CA.
         TooManyIssuers,
-        /// The CRL contains no revoked certificates.
+        /// Unused: CRLs without revoked certificates are accepted.
         EmptyCrl,
         /// The CRL DER data exceeds the maximum allowed length.
         CrlDerTooLarge,
         /// The DER certificate chain exceeds the maximum allowed length.
         CertChainDerTooLarge,
+        /// The TCB info response exceeds the maximum allowed length.
+        TcbResponseTooLarge,
+        /// Failed to parse or verify the TCB info response.
+        TcbInfoValidationError,
+        /// The TCB info is not newer than the one already registered for this platform.
+        NotNewerTcbInfo,
+        /// Too many CRL distribution points for this CA.
+        TooManyDistributionPoints,
+        /// The URL of a CRL distribution point is not valid UTF-8.
+        InvalidDistributionPointUrl,
     }
 
-    impl<T: Config> Pallet<T> {
-        /// Clear all revoked certificates for a CA.
-        fn clear_revoked_certs_for_ca(ca_name: &CaName<T>) {
-            Revoked::<T>::remove(ca_name);
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let used = Self::complete_root_rotations(remaining_weight);
+            used.saturating_add(Self::remove_pending_revocations(
+                remaining_weight.saturating_sub(used),
+            ))
         }
 
-        /// Merge new CRL entries into the existing revoked set for a CA.
+        fn offchain_worker(n: BlockNumberFor<T>) {
+            let interval = T::OffchainWorkerInterval::get();
+            if interval.is_zero() || !(n % interval).is_zero() {
+                return;
+            }
+            Self::refresh_crls();
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Replace the revoked certificates of a CRL issuer with the CRL entries.
         ///
-        /// Removes all existing entries whose issuer matches the new CRL's issuer,
-        /// then appends the new entries. Returns the total revoked count after merge.
-        fn merge_revoked_certs_for_ca(
+        /// Returns the number of removed and inserted revoked certificates.
+        fn replace_revoked_certs_for_issuer(
             ca_name: &CaName<T>,
-            new_entries: &Crl,
+            issuer_hash: &IssuerHash,
             issuer_bytes: &[u8],
-        ) -> Result<u32, DispatchError> {
-            let mut store = Revoked::<T>::get(ca_name).unwrap_or_default();
-
-            // Remove old entries from the same issuer
-            store.retain(|r| r.issuer.as_slice() != issuer_bytes);
-
-            // Append new entries
-            for r in new_entries {
-                store
-                    .try_push(RevokedInfo {
-                        issuer: r
-                            .issuer
-                            .clone()
-                            .try_into()
-                            .map_err(|_| Error::<T>::IssuerTooLarge)?,
-                        serial: r
-                            .serial_number
-                            .clone()
-                            .try_into()
-                            .map_err(|_| Error::<T>::SerialNumberTooLarge)?,
-                    })
-                    .map_err(|_| Error::<T>::TooManyRevokedCerts)?;
+            new_entries: &Crl,
+        ) -> Result<(u32, u32), DispatchError> {
+            ensure!(
+                new_entries.len() <= MAX_REVOKED_CERTS_PER_CRL as usize,
+                Error::<T>::TooManyRevokedCerts
+            );
+            let issuer: Issuer = issuer_bytes
+                .to_vec()
+                .try_into()
+                .map_err(|_| Error::<T>::IssuerTooLarge)?;
+            let serials = new_entries
+                .iter()
+                .map(|r| SerialNumber::try_from(r.serial_number.clone()))
+                .collect::<Result<alloc::collections::BTreeSet<_>, _>>()
+                .map_err(|_| Error::<T>::SerialNumberTooLarge)?;
+
+            // A CRL issuer never holds more than MAX_REVOKED_CERTS_PER_CRL entries, so the
+            // old ones are removed all at once
+            let removed = RevokedCerts::<T>::clear_prefix(
+                (ca_name, issuer_hash),
+                MAX_REVOKED_CERTS_PER_CRL,
+                None,
+            )
+            .unique;
+            for serial in &serials {
+                RevokedCerts::<T>::insert((ca_name, issuer_hash, serial), ());
+            }
+            CrlIssuers::<T>::insert(ca_name, issuer_hash, issuer);
+            IssuerRevokedCounts::<T>::insert(ca_name, issuer_hash, serials.len() as u32);
+
+            Ok((removed, serials.len() as u32))
+        }
+
+        /// Apply the entries of a delta CRL to the revoked certificates of its issuer. Fails if
+        /// the issuer would end up with more than MAX_REVOKED_CERTS_PER_CRL of them: a later
+        /// complete CRL must be able to replace them all at once.
+        ///
+        /// Returns the number of removed and added revoked certificates.
+        fn apply_delta_for_issuer(
+            ca_name: &CaName<T>,
+            issuer_hash: &IssuerHash,
+            revoked: &Crl,
+            removed: &Crl,
+        ) -> Result<(u32, u32), DispatchError> {
+            ensure!(
+                revoked.len().saturating_add(removed.len()) <= MAX_REVOKED_CERTS_PER_CRL as usize,
+                Error::<T>::TooManyRevokedCerts
+            );
+            let serials = |entries: &Crl| {
+                entries
+                    .iter()
+                    .map(|r| SerialNumber::try_from(r.serial_number.clone()))
+                    .collect::<Result<alloc::collections::BTreeSet<_>, _>>()
+                    .map_err(|_| Error::<T>::SerialNumberTooLarge)
+            };
+            let is_stored = |serial: &SerialNumber| {
+                RevokedCerts::<T>::contains_key((ca_name, issuer_hash, serial))
+            };
+
+            // Removals are applied first: a certificate can be removed and revoked again
+            let to_remove: alloc::collections::BTreeSet<_> =
+                serials(removed)?.into_iter().filter(is_stored).collect();
+            let to_add: Vec<_> = serials(revoked)?
+                .into_iter()
+                .filter(|serial| to_remove.contains(serial) || !is_stored(serial))
+                .collect();
+            let (removed_count, added_count) = (to_remove.len() as u32, to_add.len() as u32);
+
+            let count = IssuerRevokedCounts::<T>::get(ca_name, issuer_hash)
+                .saturating_sub(removed_count)
+                .saturating_add(added_count);
+            ensure!(
+                count <= MAX_REVOKED_CERTS_PER_CRL,
+                Error::<T>::TooManyRevokedCerts
+            );
+
+            for serial in to_remove {
+                RevokedCerts::<T>::remove((ca_name, issuer_hash, serial));
+            }
+            for serial in to_add {
+                RevokedCerts::<T>::insert((ca_name, issuer_hash, serial), ());
+            }
+            IssuerRevokedCounts::<T>::insert(ca_name, issuer_hash, count);
+
+            Ok((removed_count, added_count))
+        }
+
+        /// Remove as many revoked certificates of the unregistered CAs as `remaining_weight`
+        /// allows. Returns the consumed weight.
+        pub(crate) fn remove_pending_revocations(remaining_weight: Weight) -> Weight {
+            let lookup = T::DbWeight::get().reads(1);
+            let base = T::WeightInfo::clear_crl(0);
+            let per_cert = T::WeightInfo::clear_crl(1).saturating_sub(base);
+            if remaining_weight.any_lt(lookup.saturating_add(base).saturating_add(per_cert)) {
+                return Weight::zero();
+            }
+            let Some(ca_name) = PendingRemovals::<T>::iter_keys().next() else {
+                return lookup;
+            };
+
+            let available = remaining_weight.saturating_sub(lookup).saturating_sub(base);
+            let limit = [
+                (available.ref_time(), per_cert.ref_time()),
+                (available.proof_size(), per_cert.proof_size()),
+            ]
+            .into_iter()
+            .filter_map(|(available, per_cert)| available.checked_div(per_cert))
+            .min()
+            .unwrap_or(u64::MAX)
+            .min(MAX_REVOKED_CERTS_PER_CRL as u64) as u32;
+
+            let removed = RevokedCerts::<T>::clear_prefix((&ca_name,), limit, None);
+            if removed.maybe_cursor.is_none() {
+                PendingRemovals::<T>::remove(&ca_name);
+                log::info!("Removed all revoked certificates of unregistered CA {ca_name:?}");
+            }
+
+            lookup.saturating_add(T::WeightInfo::clear_crl(removed.loops))
+        }
+
+        /// Replace the root certificate of the CAs whose rotation overlap window is over, as
+        /// long as `remaining_weight` allows. Returns the consumed weight.
+        pub(crate) fn complete_root_rotations(remaining_weight: Weight) -> Weight {
+            let lookup = T::DbWeight::get().reads(1);
+            let complete = T::WeightInfo::complete_root_rotation();
+            let now = <T as Config>::UnixTime::now().as_secs();
+
+            let mut used = Weight::zero();
+            let mut completed = Vec::new();
+            for (ca_name, rotation) in RootRotations::<T>::iter() {
+                if remaining_weight.any_lt(used.saturating_add(lookup).saturating_add(complete)) {
+                    break;
+                }
+                used.saturating_accrue(lookup);
+                if rotation.is_over(now) {
+                    used.saturating_accrue(complete);
+                    completed.push((ca_name, rotation));
+                }
+            }
+            for (ca_name, rotation) in completed {
+                Self::complete_root_rotation(ca_name, rotation);
+            }
+            used
+        }
+
+        /// Replace the root certificate of a CA with the one of its rotation.
+        pub(crate) fn complete_root_rotation(ca_name: CaName<T>, rotation: RootRotation) {
+            RootRotations::<T>::remove(&ca_name);
+            CertificateAuthorities::<T>::mutate(&ca_name, |ca_info| {
+                if let Some(ca_info) = ca_info {
+                    ca_info.root_cert = rotation.new_root_cert;
+                }
+            });
+
+            log::info!("Root certificate of CA {ca_name:?} rotated");
+            Self::deposit_event(Event::RootRotated { ca_name });
+        }
+
+        /// The root certificates of a CA trusted at `now` (in secs): the current one and,
+        /// during the overlap window of a rotation, the new one. Once the window is over,
+        /// only the new one.
+        fn trusted_root_certs(
+            ca_name: &CaName<T>,
+            ca_info: &CaInfo,
+            now: u64,
+        ) -> Vec<BoundedVec<u8, ConstU32<MAX_ROOT_CERT_LENGTH>>> {
+            match RootRotations::<T>::get(ca_name) {
+                None => alloc::vec![ca_info.root_cert.clone()],
+                Some(rotation) if rotation.is_over(now) => alloc::vec![rotation.new_root_cert],
+                Some(rotation) => alloc::vec![ca_info.root_cert.clone(), rotation.new_root_cert],
+            }
+        }
+
+        /// Run `f` under each of the given root certificates, until it succeeds. Returns the
+        /// last error otherwise, or `None` if there are no root certificates.
+        fn try_root_certs<R, E>(
+            root_certs: &[BoundedVec<u8, ConstU32<MAX_ROOT_CERT_LENGTH>>],
+            mut f: impl FnMut(&[u8]) -> Result<R, E>,
+        ) -> Result<R, Option<E>> {
+            let mut last_error = None;
+            for root_cert in root_certs {
+                match f(root_cert) {
+                    Ok(result) => return Ok(result),
+                    Err(e) => last_error = Some(e),
+                }
+            }
+            Err(last_error)
+        }
+
+        /// Check that a CA would accept a CRL, without storing it: the CRL should be verified
+        /// under a trusted root certificate of the CA, and be newer than the one stored for
+        /// its issuer.
+        fn check_crl(
+            ca_name: &CaName<T>,
+            ca_info: &CaInfo,
+            crl_input: &CrlInput,
+            now: u64,
+        ) -> Result<CheckedCrl, DispatchError> {
+            match crl_input {
+                CrlInput::Pem { crl, cert_chain } => {
+                    if crl.len() > MAX_CRL_PEM_LENGTH as usize {
+                        return Err(Error::<T>::CrlPemTooLarge.into());
+                    }
+                    if cert_chain.len() > MAX_CERT_CHAIN_PEM_LENGTH as usize {
+                        return Err(Error::<T>::CertChainPemTooLarge.into());
+                    }
+                }
+                CrlInput::Der { crl, cert_chain } => {
+                    if crl.len() > MAX_CRL_DER_LENGTH as usize {
+                        return Err(Error::<T>::CrlDerTooLarge.into());
+                    }
+                    if cert_chain.len() > MAX_CERT_CHAIN_DER_LENGTH as usize {
+                        return Err(Error::<T>::CertChainDerTooLarge.into());
+                    }
+                }
             }
 
-            let count = store.len() as u32;
-            Revoked::<T>::insert(ca_name, store);
-            Ok(count)
+            // Parse CRL based on input format, under any trusted root certificate
+            let root_certs = Self::trusted_root_certs(ca_name, ca_info, now);
+            let mut attempts = 0;
+            let parse = |root_cert: &[u8]| {
+                attempts += 1;
+                match crl_input {
+                    CrlInput::Pem { crl, cert_chain } => {
+                        parse_crl_pem(crl, cert_chain, Some(root_cert), now)
+                    }
+                    CrlInput::Der { crl, cert_chain } => {
+                        parse_crl_der(crl, cert_chain, Some(root_cert), now)
+                    }
+                }
+            };
+            let parsed = Self::try_root_certs(&root_certs, parse).map_err(|e| {
+                log::error!("Failed to parse CRL for CA {ca_name:?}: {e:?}");
+                Error::<T>::CrlValidationError
+            })?;
+
+            // An empty CRL is still a valid one: it refreshes the validity of its issuer
+            let issuer = parsed.issuer.clone();
+            let issuer_hash: IssuerHash = sp_core::hashing::blake2_256(&issuer);
+
+            let delta = match parsed.base_crl_number {
+                None => {
+                    // Check per-issuer version
+                    if let Some(existing_version) = ca_info.crl_versions.get(&issuer_hash) {
+                        ensure!(
+                            parsed.this_update > existing_version.this_update,
+                            Error::<T>::NotNewerCrl
+                        );
+                    }
+                    None
+                }
+                // Delta CRLs are often issued together with their base one, so they are
+                // ordered by CRL number alone
+                Some(base_crl_number) => {
+                    let stored_number = CrlNumbers::<T>::get(ca_name, issuer_hash)
+                        .ok_or(Error::<T>::MissingBaseCrl)?;
+                    ensure!(
+                        base_crl_number <= stored_number,
+                        Error::<T>::DeltaCrlBaseMismatch
+                    );
+                    let crl_number = parsed.crl_number.ok_or(Error::<T>::MissingCrlNumber)?;
+                    ensure!(crl_number > stored_number, Error::<T>::NotNewerCrl);
+                    Some((base_crl_number, crl_number))
+                }
+            };
+
+            Ok(CheckedCrl {
+                parsed,
+                issuer,
+                issuer_hash,
+                delta,
+                retried: attempts > 1,
+            })
+        }
+
+        /// Store a CRL of a CA, once it passed the checks.
+        fn do_update_crl(
+            bounded_name: CaName<T>,
+            crl_input: CrlInput,
+        ) -> DispatchResultWithPostInfo {
+            // Get CA info
+            let mut ca_info =
+                CertificateAuthorities::<T>::get(&bounded_name).ok_or(Error::<T>::CaNotFound)?;
+
+            let is_pem = matches!(crl_input, CrlInput::Pem { .. });
+            let now = <T as Config>::UnixTime::now().as_secs();
+            let CheckedCrl {
+                parsed:
+                    ParsedCrl {
+                        this_update,
+                        next_update,
+                        crl_number,
+                        revoked,
+                        removed,
+                        ..
+                    },
+                issuer: issuer_bytes,
+                issuer_hash,
+                delta,
+                retried,
+            } = Self::check_crl(&bounded_name, &ca_info, &crl_input, now)?;
+            // A failed attempt under the first root certificate costs another parse
+            let retry_weight = if !retried {
+                Weight::zero()
+            } else if is_pem {
+                T::WeightInfo::update_pem_crl(0)
+            } else {
+                T::WeightInfo::update_der_crl(0)
+            };
+
+            let (removed_count, added_count) = match delta {
+                None => {
+                    // Replace the old entries of the same issuer with the new ones
+                    let counts = Self::replace_revoked_certs_for_issuer(
+                        &bounded_name,
+                        &issuer_hash,
+                        &issuer_bytes,
+                        &revoked,
+                    )?;
+                    CrlNumbers::<T>::set(&bounded_name, issuer_hash, crl_number);
+                    counts
+                }
+                Some((_, crl_number)) => {
+                    let counts = Self::apply_delta_for_issuer(
+                        &bounded_name,
+                        &issuer_hash,
+                        &revoked,
+                        &removed,
+                    )?;
+                    CrlNumbers::<T>::insert(&bounded_name, issuer_hash, crl_number);
+                    counts
+                }
+            };
+            let revoked_count = ca_info
+                .revoked_count
+                .saturating_sub(removed_count)
+                .saturating_add(added_count);
+
+            // Update per-issuer version
+            ca_info
+                .crl_versions
+                .try_insert(
+                    issuer_hash,
+                    CrlValidity {
+                        this_update,
+                        next_update,
+                    },
+                )
+                .map_err(|_| Error::<T>::TooManyIssuers)?;
+            ca_info.revoked_count = revoked_count;
+            CertificateAuthorities::<T>::insert(&bounded_name, ca_info);
+
+            let actual_weight = match delta {
+                None => {
+                    log::info!(
+                        "CRL updated for CA {bounded_name:?} (issuer {issuer_hash:?}) with {revoked_count} total revoked certificates",
+                    );
+                    Self::deposit_event(Event::CrlUpdated {
+                        ca_name: bounded_name,
+                        issuer: issuer_bytes,
+                        last_update: this_update,
+                        next_update,
+                        revoked_count,
+                    });
+
+                    if is_pem {
+                        T::WeightInfo::update_pem_crl(added_count)
+                    } else {
+                        T::WeightInfo::update_der_crl(added_count)
+                    }
+                    .saturating_add(T::WeightInfo::clear_crl(removed_count))
+                }
+                Some((base_crl_number, crl_number)) => {
+                    log::info!(
+                        "Delta CRL applied for CA {bounded_name:?} (issuer {issuer_hash:?}): {added_count} added, {removed_count} removed, {revoked_count} total revoked certificates",
+                    );
+                    Self::deposit_event(Event::DeltaCrlApplied {
+                        ca_name: bounded_name,
+                        issuer: issuer_bytes,
+                        crl_number,
+                        base_crl_number,
+                        last_update: this_update,
+                        next_update,
+                        added: added_count,
+                        removed: removed_count,
+                        revoked_count,
+                    });
+
+                    // Every delta CRL entry costs a lookup, besides the write done by the
+                    // complete CRL ones
+                    let entries = (revoked.len() + removed.len()) as u32;
+                    if is_pem {
+                        T::WeightInfo::update_pem_crl(entries)
+                    } else {
+                        T::WeightInfo::update_der_crl(entries)
+                    }
+                    .saturating_add(T::DbWeight::get().reads(entries as u64))
+                }
+            };
+
+            Ok(PostDispatchInfo {
+                actual_weight: Some(actual_weight.saturating_add(retry_weight)),
+                pays_fee: Pays::Yes,
+            })
+        }
+
+        /// Fetch the CRLs of every CA from its distribution points, and submit the ones that
+        /// would be accepted as unsigned transactions.
+        fn refresh_crls() {
+            let now = <T as Config>::UnixTime::now().as_secs();
+            for (ca_name, points) in DistributionPoints::<T>::iter() {
+                let Some(ca_info) = CertificateAuthorities::<T>::get(&ca_name) else {
+                    continue;
+                };
+                for point in points {
+                    let url = core::str::from_utf8(&point.url).unwrap_or_default();
+                    let crl = match Self::fetch_crl(url, point.format.max_crl_length()) {
+                        Ok(crl) => crl,
+                        Err(e) => {
+                            log::warn!("Failed to fetch CRL of CA {ca_name:?} from {url}: {e:?}");
+                            continue;
+                        }
+                    };
+                    let crl_input = point.crl_input(crl);
+                    if let Err(e) = Self::check_crl(&ca_name, &ca_info, &crl_input, now) {
+                        log::debug!("Skipping CRL of CA {ca_name:?} from {url}: {e:?}");
+                        continue;
+                    }
+
+                    let call = Call::submit_crl_unsigned {
+                        ca_name: ca_name.to_vec(),
+                        crl_input,
+                    };
+                    let xt = <T as CreateInherent<Call<T>>>::create_inherent(call.into());
+                    match SubmitTransaction::<T, Call<T>>::submit_transaction(xt) {
+                        Ok(()) => log::info!("Submitted CRL of CA {ca_name:?} from {url}"),
+                        Err(()) => log::error!("Failed to submit CRL of CA {ca_name:?} from {url}"),
+                    }
+                }
+            }
+        }
+
+        /// Fetch a CRL from a distribution point. The body is read only up to `max_length`
+        /// bytes: a longer CRL would be rejected anyway.
+        fn fetch_crl(url: &str, max_length: usize) -> Result<Vec<u8>, http::Error> {
+            let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(HTTP_TIMEOUT_MS));
+            let pending = http::Request::get(url)
+                .deadline(deadline)
+                .send()
+                .map_err(|_| http::Error::IoError)?;
+            let response = pending
+                .try_wait(deadline)
+                .map_err(|_| http::Error::DeadlineReached)??;
+            if response.code != 200 {
+                log::warn!("Unexpected status code {} from {url}", response.code);
+                return Err(http::Error::Unknown);
+            }
+            let body: Vec<u8> = response.body().take(max_length.saturating_add(1)).collect();
+            if body.len() > max_length {
+                log::warn!("CRL from {url} exceeds {max_length} bytes");
+                return Err(http::Error::Unknown);
+            }
+            Ok(body)
+        }
+
+        /// Returns the root certificate rotation scheduled for the given CA, if any.
+        pub fn pending_root_rotation(ca_name: &[u8]) -> Option<RootRotation> {
+            CaName::<T>::try_from(ca_name.to_vec())
+                .ok()
+                .and_then(RootRotations::<T>::get)
+        }
+
+        /// Get the CA name for a name coming from another pallet.
+        fn bounded_ca_name(ca_name: &str) -> Result<CaName<T>, CaNotFoundError> {
+            ca_name
+                .as_bytes()
+                .to_vec()
+                .try_into()
+                .map_err(|_| CaNotFoundError)
+        }
+
+        /// Returns true if the given CA revoked the certificate with the given issuer and
+        /// serial number.
+        fn is_revoked_for_ca(ca_name: &CaName<T>, issuer: &[u8], serial_number: &[u8]) -> bool {
+            let Ok(serial) = SerialNumber::try_from(serial_number.to_vec()) else {
+                // Longer serial numbers are never stored
+                return false;
+            };
+            let issuer_hash: IssuerHash = sp_core::hashing::blake2_256(issuer);
+            RevokedCerts::<T>::contains_key((ca_name, issuer_hash, serial))
+        }
+
+        /// Get the CA info for a CA name coming from another pallet.
+        fn get_ca_info(ca_name: &str) -> Result<CaInfo, CaNotFoundError> {
+            CertificateAuthorities::<T>::get(Self::bounded_ca_name(ca_name)?).ok_or(CaNotFoundError)
         }
 
         /// Get the CRL for a specific CA.
         fn get_crl_for_ca(ca_name: &CaName<T>) -> Result<Crl, CaNotFoundError> {
-            let crl = Revoked::<T>::get(ca_name).ok_or(CaNotFoundError)?;
-            Ok(crl.into_iter().map(|c| c.into()).collect())
+            ensure!(
+                CertificateAuthorities::<T>::contains_key(ca_name),
+                CaNotFoundError
+            );
+            Ok(CrlIssuers::<T>::iter_prefix(ca_name)
+                .flat_map(|(issuer_hash, issuer)| {
+                    RevokedCerts::<T>::iter_key_prefix((ca_name, issuer_hash)).map(move |serial| {
+                        RevokedCertId {
+                            issuer: issuer.to_vec(),
+                            serial_number: serial.into(),
+                        }
+                    })
+                })
+                .collect())
+        }
+    }
+
+    /// A CRL that passed the checks of `update_crl`.
+    struct CheckedCrl {
+        parsed: ParsedCrl,
+        /// The DER-encoded distinguished name of the CRL issuer.
+        issuer: Vec<u8>,
+        issuer_hash: IssuerHash,
+        /// The base CRL number and the CRL number of a delta CRL.
+        delta: Option<(u64, u64)>,
+        /// Whether the CRL was verified only under the second trusted root certificate.
+        retried: bool,
+    }
+
+    /// The certificates revoked by a registered CA, for the checks done by this pallet.
+    struct CaRevocations<'a, T: Config>(&'a CaName<T>);
+
+    impl<T: Config> RevocationList for CaRevocations<'_, T> {
+        fn is_revoked(&self, issuer: &[u8], serial_number: &[u8]) -> bool {
+            Pallet::<T>::is_revoked_for_ca(self.0, issuer, serial_number)
         }
     }
 
     impl<T: Config> CrlProvider for Pallet<T> {
         fn get_crl(ca_name: &str) -> Result<Crl, CaNotFoundError> {
-            let bounded_name: CaName<T> = ca_name
-                .as_bytes()
-                .to_vec()
-                .try_into()
-                .map_err(|_| CaNotFoundError)?;
-            Self::get_crl_for_ca(&bounded_name)
+            Self::get_crl_for_ca(&Self::bounded_ca_name(ca_name)?)
+        }
+
+        fn get_root_cert(ca_name: &str) -> Result<Vec<u8>, CaNotFoundError> {
+            let bounded_name = Self::bounded_ca_name(ca_name)?;
+            let ca_info = CertificateAuthorities::<T>::get(&bounded_name).ok_or(CaNotFoundError)?;
+            let now = <T as Config>::UnixTime::now().as_secs();
+            Ok(Self::trusted_root_certs(&bounded_name, &ca_info, now)
+                .swap_remove(0)
+                .into_inner())
+        }
+
+        fn get_crl_validity(ca_name: &str) -> Result<Vec<CrlValidity>, CaNotFoundError> {
+            Self::get_ca_info(ca_name)
+                .map(|ca_info| ca_info.crl_versions.values().copied().collect())
+        }
+
+        fn is_crl_stale(ca_name: &str, now: u64) -> Result<bool, CaNotFoundError> {
+            let ca_info = Self::get_ca_info(ca_name)?;
+            Ok(ca_info
+                .crl_versions
+                .values()
+                .any(|validity| ca_info.staleness_policy.is_stale(validity, now)))
+        }
+
+        fn is_revoked(ca_name: &str, issuer: &[u8], serial_number: &[u8]) -> bool {
+            Self::bounded_ca_name(ca_name)
+                .is_ok_and(|ca_name| Self::is_revoked_for_ca(&ca_name, issuer, serial_number))
+        }
+    }
+
+    impl<T: Config> TcbInfoProvider for Pallet<T> {
+        fn get_tcb_response(fmspc: &Fmspc, id: TcbId) -> Option<Vec<u8>> {
+            TcbInfos::<T>::get(fmspc, id).map(|entry| entry.tcb_response.into_inner())
         }
     }
 
@@ -320,6 +1213,8 @@ pub mod pallet {
         /// * `CaNameEmpty` - The CA name is empty.
         /// * `CaNameTooLong` - The CA name exceeds MaxCaNameLength.
         /// * `CaAlreadyRegistered` - A CA with this name already exists.
+        /// * `CaRemovalPending` - A CA with this name was unregistered, but its revoked
+        ///   certificates are still being removed.
         /// * `MaxCasReached` - Maximum number of CAs reached.
         /// * `RootCertTooLarge` - The root certificate exceeds MAX_ROOT_CERT_LENGTH.
         #[pallet::call_index(0)]
@@ -344,6 +1239,9 @@ pub mod pallet {
             if CertificateAuthorities::<T>::contains_key(&bounded_name) {
                 return Err(Error::<T>::CaAlreadyRegistered.into());
             }
+            if PendingRemovals::<T>::contains_key(&bounded_name) {
+                return Err(Error::<T>::CaRemovalPending.into());
+            }
 
             // Validate root cert
             let bounded_root_cert: BoundedVec<u8, ConstU32<MAX_ROOT_CERT_LENGTH>> = root_cert
@@ -355,11 +1253,9 @@ pub mod pallet {
                 root_cert: bounded_root_cert,
                 revoked_count: 0,
                 crl_versions: Default::default(),
+                staleness_policy: Default::default(),
             };
             CertificateAuthorities::<T>::insert(&bounded_name, ca_info);
-            let empty_vec: BoundedVec<RevokedInfo, ConstU32<MAX_REVOKED_CERTS_PER_CA>> =
-                Default::default();
-            Revoked::<T>::insert(&bounded_name, empty_vec);
 
             log::info!("Registered CA: {bounded_name:?}");
             Self::deposit_event(Event::CaRegistered { name: bounded_name });
@@ -372,6 +1268,9 @@ pub mod pallet {
 
         /// Unregister a Certificate Authority and remove all its CRL data.
         ///
+        /// The revoked certificates are removed in the following blocks, in idle time: the
+        /// name cannot be registered again until then.
+        ///
         /// # Arguments
         /// * `origin` - Must be the ManagerOrigin.
         /// * `name` - Name of the CA to unregister.
@@ -385,15 +1284,20 @@ pub mod pallet {
 
             let bounded_name: CaName<T> = name.try_into().map_err(|_| Error::<T>::CaNameTooLong)?;
 
-            // Clear revoked certificates
-            Self::clear_revoked_certs_for_ca(&bounded_name);
-
             // Remove CA info
             if CertificateAuthorities::<T>::take(&bounded_name).is_none() {
                 log::error!("Could not find CA {bounded_name:?}");
                 return Err(Error::<T>::CaNotFound.into());
             }
 
+            // Schedule the removal of the revoked certificates
+            let _ = CrlIssuers::<T>::clear_prefix(&bounded_name, MAX_ISSUERS_PER_CA, None);
+            let _ = CrlNumbers::<T>::clear_prefix(&bounded_name, MAX_ISSUERS_PER_CA, None);
+            let _ = IssuerRevokedCounts::<T>::clear_prefix(&bounded_name, MAX_ISSUERS_PER_CA, None);
+            RootRotations::<T>::remove(&bounded_name);
+            DistributionPoints::<T>::remove(&bounded_name);
+            PendingRemovals::<T>::insert(&bounded_name, ());
+
             log::info!("Unregistered CA: {bounded_name:?}");
             Self::deposit_event(Event::CaUnregistered { name: bounded_name });
 
@@ -410,6 +1314,14 @@ pub mod pallet {
         /// are replaced entirely, while entries from other issuers are preserved.
         /// Per-issuer version tracking ensures only newer CRLs are accepted.
         ///
+        /// A delta CRL instead adds its entries to the ones of the same issuer and removes
+        /// those listed with the `removeFromCRL` reason. It's accepted only if its base CRL
+        /// number is not greater than the CRL number stored for the issuer, and its own CRL
+        /// number is greater.
+        ///
+        /// During the overlap window of a root rotation, the CRL can be signed under either
+        /// the current or the new root certificate.
+        ///
         /// # Arguments
         /// * `origin` - Must be signed.
         /// * `ca_name` - Name of the CA whose CRL to update.
@@ -422,15 +1334,20 @@ pub mod pallet {
         /// * `CrlDerTooLarge` - The CRL DER data exceeds MAX_CRL_DER_LENGTH.
         /// * `CertChainDerTooLarge` - The certificate chain exceeds MAX_CERT_CHAIN_DER_LENGTH.
         /// * `CrlValidationError` - Failed to parse or verify the CRL.
-        /// * `TooManyRevokedCerts` - Total revoked certs across all issuers exceeds MAX_REVOKED_CERTS_PER_CA.
-        /// * `EmptyCrl` - The CRL contains no revoked certificates.
+        /// * `TooManyRevokedCerts` - The CRL has more than MAX_REVOKED_CERTS_PER_CRL revoked certs.
         /// * `TooManyIssuers` - Too many distinct CRL issuers for this CA.
         /// * `NotNewerCrl` - The CRL is not newer than the one already stored for this issuer.
+        /// * `MissingBaseCrl` - No complete CRL with a CRL number is stored for the issuer of
+        ///   the delta CRL.
+        /// * `DeltaCrlBaseMismatch` - The delta CRL is based on a newer complete CRL.
+        /// * `MissingCrlNumber` - The delta CRL has no CRL number.
         #[pallet::call_index(2)]
         #[pallet::weight(match crl_input {
-            CrlInput::Pem { .. } => T::WeightInfo::update_pem_crl(MAX_REVOKED_CERTS_PER_CA),
-            CrlInput::Der { .. } => T::WeightInfo::update_der_crl(MAX_REVOKED_CERTS_PER_CA),
-        })]
+            CrlInput::Pem { .. } => T::WeightInfo::update_pem_crl(MAX_REVOKED_CERTS_PER_CRL)
+                .saturating_add(T::WeightInfo::update_pem_crl(0)),
+            CrlInput::Der { .. } => T::WeightInfo::update_der_crl(MAX_REVOKED_CERTS_PER_CRL)
+                .saturating_add(T::WeightInfo::update_der_crl(0)),
+        }.saturating_add(T::WeightInfo::clear_crl(MAX_REVOKED_CERTS_PER_CRL)))]
         pub fn update_crl(
             origin: OriginFor<T>,
             ca_name: Vec<u8>,
@@ -440,97 +1357,369 @@ pub mod pallet {
 
             let bounded_name: CaName<T> =
                 ca_name.try_into().map_err(|_| Error::<T>::CaNameTooLong)?;
+            Self::do_update_crl(bounded_name, crl_input)
+        }
 
-            // Get CA info
-            let mut ca_info =
-                CertificateAuthorities::<T>::get(&bounded_name).ok_or(Error::<T>::CaNotFound)?;
+        /// Update the TCB info of an Intel platform.
+        ///
+        /// The TCB info response must be signed by a certificate chain rooted in a trusted root
+        /// certificate of the Intel CA (see [`Config::IntelCaName`] and
+        /// [`Pallet::schedule_root_rotation`]), and none of its certificates can be revoked. It
+        /// replaces the one registered for the same FMSPC and TEE only if it has a greater
+        /// `tcbEvaluationDataNumber`, or the same one but a later `issueDate`.
+        ///
+        /// # Arguments
+        /// * `origin` - Must be signed.
+        /// * `tcb_response` - The signed TCB info response (JSON), as returned by Intel PCS.
+        /// * `cert_chain` - PEM-encoded certificate chain of the TCB info signing key.
+        ///
+        /// # Errors
+        /// * `CaNotFound` - The Intel CA is not registered.
+        /// * `TcbResponseTooLarge` - The TCB info response exceeds MAX_TCB_RESPONSE_LENGTH.
+        /// * `CertChainPemTooLarge` - The certificate chain exceeds MAX_CERT_CHAIN_PEM_LENGTH.
+        /// * `TcbInfoValidationError` - Failed to parse or verify the TCB info response.
+        /// * `NotNewerTcbInfo` - The TCB info is not newer than the one already registered.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::update_tcb_info())]
+        pub fn update_tcb_info(
+            origin: OriginFor<T>,
+            tcb_response: Vec<u8>,
+            cert_chain: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let _who = ensure_signed(origin)?;
 
-            let is_pem = matches!(crl_input, CrlInput::Pem { .. });
+            let bounded_name =
+                Self::bounded_ca_name(T::IntelCaName::get()).map_err(|_| Error::<T>::CaNotFound)?;
+            let ca_info =
+                CertificateAuthorities::<T>::get(&bounded_name).ok_or(Error::<T>::CaNotFound)?;
 
-            // Parse CRL based on input format
-            let (this_update, parsed_crl) = match crl_input {
-                CrlInput::Pem { crl, cert_chain } => {
-                    if crl.len() > MAX_CRL_PEM_LENGTH as usize {
-                        return Err(Error::<T>::CrlPemTooLarge.into());
-                    }
-                    if cert_chain.len() > MAX_CERT_CHAIN_PEM_LENGTH as usize {
-                        return Err(Error::<T>::CertChainPemTooLarge.into());
-                    }
-                    parse_crl_pem(
-                        &crl,
-                        &cert_chain,
-                        Some(ca_info.root_cert.as_slice()),
-                        <T as Config>::UnixTime::now().as_secs(),
-                    )
-                }
-                CrlInput::Der { crl, cert_chain } => {
-                    if crl.len() > MAX_CRL_DER_LENGTH as usize {
-                        return Err(Error::<T>::CrlDerTooLarge.into());
-                    }
-                    if cert_chain.len() > MAX_CERT_CHAIN_DER_LENGTH as usize {
-                        return Err(Error::<T>::CertChainDerTooLarge.into());
-                    }
-                    parse_crl_der(
-                        &crl,
-                        &cert_chain,
-                        Some(ca_info.root_cert.as_slice()),
-                        <T as Config>::UnixTime::now().as_secs(),
-                    )
-                }
+            let bounded_tcb_response: BoundedVec<u8, ConstU32<MAX_TCB_RESPONSE_LENGTH>> =
+                tcb_response
+                    .try_into()
+                    .map_err(|_| Error::<T>::TcbResponseTooLarge)?;
+            if cert_chain.len() > MAX_CERT_CHAIN_PEM_LENGTH as usize {
+                return Err(Error::<T>::CertChainPemTooLarge.into());
             }
+
+            let crl = CaRevocations::<T>(&bounded_name);
+            let parsed = intel_parse_tcb_response(&bounded_tcb_response).map_err(|e| {
+                log::error!("Failed to parse TCB info: {e:?}");
+                Error::<T>::TcbInfoValidationError
+            })?;
+            let now = <T as Config>::UnixTime::now().as_secs();
+            let root_certs = Self::trusted_root_certs(&bounded_name, &ca_info, now);
+            Self::try_root_certs(&root_certs, |root_cert| {
+                parsed.verify_with_root(cert_chain.clone(), root_cert, &crl, now)
+            })
             .map_err(|e| {
-                log::error!("Failed to parse CRL for CA {bounded_name:?}: {e:?}");
-                Error::<T>::CrlValidationError
+                log::error!("Failed to verify TCB info for CA {bounded_name:?}: {e:?}");
+                Error::<T>::TcbInfoValidationError
             })?;
 
-            // Extract the issuer from the CRL entries (all entries in a single CRL share the same issuer)
-            let issuer_bytes = parsed_crl
-                .first()
-                .ok_or(Error::<T>::EmptyCrl)?
-                .issuer
-                .clone();
-            let issuer_hash: IssuerHash = sp_core::hashing::blake2_256(&issuer_bytes);
-
-            // Check per-issuer version
-            if let Some(&existing_version) = ca_info.crl_versions.get(&issuer_hash) {
-                if this_update <= existing_version {
-                    return Err(Error::<T>::NotNewerCrl.into());
+            let tcb_info = &parsed.tcb_info;
+            let (fmspc, id, issue_date) = parse_fmspc(&tcb_info.fmspc)
+                .zip(parse_tcb_id(&tcb_info.id))
+                .zip(parse_date(&tcb_info.issue_date))
+                .map(|((fmspc, id), issue_date)| (fmspc, id, issue_date))
+                .ok_or(Error::<T>::TcbInfoValidationError)?;
+            let evaluation_data_number = tcb_info.tcb_evaluation_data_number;
+
+            if let Some(current) = TcbInfos::<T>::get(fmspc, id) {
+                if (evaluation_data_number, issue_date)
+                    <= (current.evaluation_data_number, current.issue_date)
+                {
+                    return Err(Error::<T>::NotNewerTcbInfo.into());
                 }
             }
 
-            // Merge: remove old entries from same issuer, add new ones
-            let revoked_count =
-                Self::merge_revoked_certs_for_ca(&bounded_name, &parsed_crl, &issuer_bytes)?;
-
-            // Update per-issuer version
-            ca_info
-                .crl_versions
-                .try_insert(issuer_hash, this_update)
-                .map_err(|_| Error::<T>::TooManyIssuers)?;
-            ca_info.revoked_count = revoked_count;
-            CertificateAuthorities::<T>::insert(&bounded_name, ca_info);
+            TcbInfos::<T>::insert(
+                fmspc,
+                id,
+                TcbInfoEntry {
+                    tcb_response: bounded_tcb_response,
+                    evaluation_data_number,
+                    issue_date,
+                },
+            );
 
             log::info!(
-                "CRL updated for CA {bounded_name:?} (issuer {issuer_hash:?}) with {revoked_count} total revoked certificates",
+                "TCB info updated for FMSPC {fmspc:?} ({id:?}) to evaluation data number {evaluation_data_number}",
+            );
+
+            Self::deposit_event(Event::TcbInfoUpdated {
+                fmspc,
+                id,
+                evaluation_data_number,
+                issue_date,
+            });
+
+            Ok(PostDispatchInfo {
+                actual_weight: Some(T::WeightInfo::update_tcb_info()),
+                pays_fee: Pays::Yes,
+            })
+        }
+
+        /// Set the staleness policy of a Certificate Authority.
+        ///
+        /// # Arguments
+        /// * `origin` - Must be the ManagerOrigin.
+        /// * `ca_name` - Name of the CA.
+        /// * `policy` - How the CA revocation data is treated once a CRL is past its `nextUpdate`.
+        ///
+        /// # Errors
+        /// * `CaNotFound` - No CA with this name exists.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::set_staleness_policy())]
+        pub fn set_staleness_policy(
+            origin: OriginFor<T>,
+            ca_name: Vec<u8>,
+            policy: StalenessPolicy,
+        ) -> DispatchResultWithPostInfo {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            let bounded_name: CaName<T> =
+                ca_name.try_into().map_err(|_| Error::<T>::CaNameTooLong)?;
+            CertificateAuthorities::<T>::try_mutate(&bounded_name, |ca_info| {
+                let ca_info = ca_info.as_mut().ok_or(Error::<T>::CaNotFound)?;
+                ca_info.staleness_policy = policy;
+                Ok::<_, Error<T>>(())
+            })?;
+
+            log::info!("Staleness policy of CA {bounded_name:?} set to {policy:?}");
+            Self::deposit_event(Event::StalenessPolicySet {
+                ca_name: bounded_name,
+                policy,
+            });
+
+            Ok(PostDispatchInfo {
+                actual_weight: Some(T::WeightInfo::set_staleness_policy()),
+                pays_fee: Pays::Yes,
+            })
+        }
+
+        /// Schedule the rotation of the root certificate of a Certificate Authority.
+        ///
+        /// For `overlap` secs, CRLs and TCB info signed under either the current or the new
+        /// root certificate are accepted; then only the new one is trusted, and it replaces
+        /// the current one in idle time. A rotation already scheduled for the CA is replaced.
+        ///
+        /// # Arguments
+        /// * `origin` - Must be the ManagerOrigin.
+        /// * `ca_name` - Name of the CA.
+        /// * `new_root_cert` - The new root certificate (DER encoded).
+        /// * `overlap` - Length of the overlap window (in secs).
+        ///
+        /// # Errors
+        /// * `CaNotFound` - No CA with this name exists.
+        /// * `RootCertEmpty` - The new root certificate is empty.
+        /// * `RootCertTooLarge` - The new root certificate exceeds MAX_ROOT_CERT_LENGTH.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::schedule_root_rotation())]
+        pub fn schedule_root_rotation(
+            origin: OriginFor<T>,
+            ca_name: Vec<u8>,
+            new_root_cert: Vec<u8>,
+            overlap: u64,
+        ) -> DispatchResultWithPostInfo {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            let bounded_name: CaName<T> =
+                ca_name.try_into().map_err(|_| Error::<T>::CaNameTooLong)?;
+            ensure!(
+                CertificateAuthorities::<T>::contains_key(&bounded_name),
+                Error::<T>::CaNotFound
+            );
+            if new_root_cert.is_empty() {
+                return Err(Error::<T>::RootCertEmpty.into());
+            }
+            let new_root_cert: BoundedVec<u8, ConstU32<MAX_ROOT_CERT_LENGTH>> = new_root_cert
+                .try_into()
+                .map_err(|_| Error::<T>::RootCertTooLarge)?;
+
+            let overlap_end = <T as Config>::UnixTime::now()
+                .as_secs()
+                .saturating_add(overlap);
+            RootRotations::<T>::insert(
+                &bounded_name,
+                RootRotation {
+                    new_root_cert,
+                    overlap_end,
+                },
             );
 
-            Self::deposit_event(Event::CrlUpdated {
+            log::info!(
+                "Root certificate rotation of CA {bounded_name:?} scheduled, overlapping until {overlap_end}"
+            );
+            Self::deposit_event(Event::RootRotationScheduled {
                 ca_name: bounded_name,
-                issuer: issuer_bytes,
-                last_update: this_update,
-                revoked_count,
+                overlap_end,
             });
 
-            let actual_weight = if is_pem {
-                T::WeightInfo::update_pem_crl(revoked_count)
+            Ok(PostDispatchInfo {
+                actual_weight: Some(T::WeightInfo::schedule_root_rotation()),
+                pays_fee: Pays::Yes,
+            })
+        }
+
+        /// Set the CRL distribution points of a Certificate Authority, replacing the previous
+        /// ones. The offchain worker periodically fetches the CRLs from them, and submits the
+        /// newer ones.
+        ///
+        /// # Arguments
+        /// * `origin` - Must be the ManagerOrigin.
+        /// * `ca_name` - Name of the CA.
+        /// * `points` - The distribution points: an empty list disables the CA refresh.
+        ///
+        /// # Errors
+        /// * `CaNotFound` - No CA with this name exists.
+        /// * `TooManyDistributionPoints` - More than MAX_DISTRIBUTION_POINTS_PER_CA points.
+        /// * `InvalidDistributionPointUrl` - A URL is not valid UTF-8.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::set_distribution_points())]
+        pub fn set_distribution_points(
+            origin: OriginFor<T>,
+            ca_name: Vec<u8>,
+            points: Vec<DistributionPoint>,
+        ) -> DispatchResultWithPostInfo {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            let bounded_name: CaName<T> =
+                ca_name.try_into().map_err(|_| Error::<T>::CaNameTooLong)?;
+            ensure!(
+                CertificateAuthorities::<T>::contains_key(&bounded_name),
+                Error::<T>::CaNotFound
+            );
+            ensure!(
+                points
+                    .iter()
+                    .all(|point| core::str::from_utf8(&point.url).is_ok()),
+                Error::<T>::InvalidDistributionPointUrl
+            );
+            let points: BoundedVec<_, ConstU32<MAX_DISTRIBUTION_POINTS_PER_CA>> = points
+                .try_into()
+                .map_err(|_| Error::<T>::TooManyDistributionPoints)?;
+
+            let count = points.len() as u32;
+            if points.is_empty() {
+                DistributionPoints::<T>::remove(&bounded_name);
             } else {
-                T::WeightInfo::update_der_crl(revoked_count)
-            };
+                DistributionPoints::<T>::insert(&bounded_name, points);
+            }
+
+            log::info!("Set {count} CRL distribution points for CA {bounded_name:?}");
+            Self::deposit_event(Event::DistributionPointsSet {
+                ca_name: bounded_name,
+                count,
+            });
 
             Ok(PostDispatchInfo {
-                actual_weight: Some(actual_weight),
+                actual_weight: Some(T::WeightInfo::set_distribution_points()),
                 pays_fee: Pays::Yes,
             })
         }
+
+        /// Update the Certificate Revocation List for a specific CA, from its distribution
+        /// points.
+        ///
+        /// It's submitted by the offchain worker as an unsigned transaction: see `update_crl`
+        /// for the checks.
+        ///
+        /// # Arguments
+        /// * `origin` - Must be none.
+        /// * `ca_name` - Name of the CA whose CRL to update.
+        /// * `crl_input` - CRL data in PEM or DER format (see [`CrlInput`]).
+        #[pallet::call_index(7)]
+        #[pallet::weight(match crl_input {
+            CrlInput::Pem { .. } => T::WeightInfo::update_pem_crl(MAX_REVOKED_CERTS_PER_CRL)
+                .saturating_add(T::WeightInfo::update_pem_crl(0)),
+            CrlInput::Der { .. } => T::WeightInfo::update_der_crl(MAX_REVOKED_CERTS_PER_CRL)
+                .saturating_add(T::WeightInfo::update_der_crl(0)),
+        }.saturating_add(T::WeightInfo::clear_crl(MAX_REVOKED_CERTS_PER_CRL)))]
+        pub fn submit_crl_unsigned(
+            origin: OriginFor<T>,
+            ca_name: Vec<u8>,
+            crl_input: CrlInput,
+        ) -> DispatchResultWithPostInfo {
+            ensure_none(origin)?;
+
+            let bounded_name: CaName<T> =
+                ca_name.try_into().map_err(|_| Error::<T>::CaNameTooLong)?;
+            Self::do_update_crl(bounded_name, crl_input)
+        }
     }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            let Call::submit_crl_unsigned { ca_name, crl_input } = call else {
+                return Err(InvalidTransaction::Call.into());
+            };
+
+            // Only the CAs with distribution points are refreshed without a signed origin
+            let bounded_name: CaName<T> = ca_name
+                .clone()
+                .try_into()
+                .map_err(|_| InvalidTransaction::Call)?;
+            ensure!(
+                DistributionPoints::<T>::contains_key(&bounded_name),
+                InvalidTransaction::Call
+            );
+            let ca_info =
+                CertificateAuthorities::<T>::get(&bounded_name).ok_or(InvalidTransaction::Call)?;
+
+            let now = <T as Config>::UnixTime::now().as_secs();
+            let checked =
+                Self::check_crl(&bounded_name, &ca_info, crl_input, now).map_err(|e| {
+                    if e == DispatchError::from(Error::<T>::NotNewerCrl) {
+                        InvalidTransaction::Stale
+                    } else {
+                        InvalidTransaction::BadProof
+                    }
+                })?;
+
+            ValidTransaction::with_tag_prefix("CrlOffchainWorker")
+                .priority(T::UnsignedPriority::get())
+                .and_provides((bounded_name, checked.issuer_hash))
+                .longevity(
+                    T::OffchainWorkerInterval::get()
+                        .saturated_into::<u64>()
+                        .max(1),
+                )
+                .propagate(true)
+                .build()
+        }
+    }
+}
+
+/// Parse the hex encoded FMSPC of a TCB info.
+fn parse_fmspc(fmspc: &str) -> Option<Fmspc> {
+    let bytes = fmspc.as_bytes();
+    if bytes.len() != 2 * core::mem::size_of::<Fmspc>() || !bytes.iter().all(u8::is_ascii_hexdigit)
+    {
+        return None;
+    }
+    let mut out = Fmspc::default();
+    for (o, pair) in out.iter_mut().zip(bytes.chunks_exact(2)) {
+        let pair = core::str::from_utf8(pair).ok()?;
+        *o = u8::from_str_radix(pair, 16).ok()?;
+    }
+    Some(out)
+}
+
+/// Parse the `id` of a TCB info.
+fn parse_tcb_id(id: &str) -> Option<TcbId> {
+    match id {
+        "SGX" => Some(TcbId::Sgx),
+        "TDX" => Some(TcbId::Tdx),
+        _ => None,
+    }
+}
+
+/// Parse an RFC 3339 date of a TCB info into a unix timestamp (in secs).
+fn parse_date(date: &str) -> Option<u64> {
+    chrono::DateTime::parse_from_rfc3339(date)
+        .ok()
+        .and_then(|date| date.timestamp().try_into().ok())
 }
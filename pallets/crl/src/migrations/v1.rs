@@ -0,0 +1,184 @@
+// Copyright 2026, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Migrate from storage v0 to v1.
+//!
+//! In v0, the CA info tracked just the `thisUpdate` timestamp of each issuer CRL:
+//! ```ignore
+//! struct CaInfo {
+//!     root_cert: BoundedVec<u8, _>,
+//!     revoked_count: u32,
+//!     crl_versions: BoundedBTreeMap<IssuerHash, u64, _>,
+//! }
+//! ```
+//!
+//! In v1, it tracks the whole CRL validity period and a staleness policy. The `nextUpdate`
+//! of the CRLs stored in v0 is unknown, so they never become stale until they are updated,
+//! and every CA gets the default policy (ignore stale CRLs).
+
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+
+use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+use sp_core::Get;
+
+use crate::{CaInfo, CrlValidity};
+
+/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the CA info from v0 to v1.
+pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+mod v0 {
+    use codec::{Decode, Encode};
+    use frame_support::{pallet_prelude::ConstU32, BoundedBTreeMap, BoundedVec};
+
+    use crate::{IssuerHash, MAX_ISSUERS_PER_CA, MAX_ROOT_CERT_LENGTH};
+
+    #[derive(Decode, Encode)]
+    pub struct OldCaInfo {
+        pub root_cert: BoundedVec<u8, ConstU32<MAX_ROOT_CERT_LENGTH>>,
+        pub revoked_count: u32,
+        pub crl_versions: BoundedBTreeMap<IssuerHash, u64, ConstU32<MAX_ISSUERS_PER_CA>>,
+    }
+}
+
+impl From<v0::OldCaInfo> for CaInfo {
+    fn from(
+        v0::OldCaInfo {
+            root_cert,
+            revoked_count,
+            crl_versions,
+        }: v0::OldCaInfo,
+    ) -> Self {
+        CaInfo {
+            root_cert,
+            revoked_count,
+            crl_versions: crl_versions.map(|(_, this_update)| CrlValidity {
+                this_update,
+                next_update: None,
+            }),
+            staleness_policy: Default::default(),
+        }
+    }
+}
+
+impl<T: crate::Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+    fn on_runtime_upgrade() -> frame_support::weights::Weight {
+        let mut count: u64 = 0;
+        crate::CertificateAuthorities::<T>::translate::<v0::OldCaInfo, _>(|_, old| {
+            count += 1;
+            Some(old.into())
+        });
+
+        log::info!(
+            target: "runtime::crl",
+            "Crl migration V0->V1: migrated {count} CAs",
+        );
+
+        T::DbWeight::get().reads_writes(count, count)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        let count = crate::CertificateAuthorities::<T>::iter_keys().count() as u64;
+        Ok(count.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        let pre_count =
+            u64::decode(&mut state.as_slice()).map_err(|_| "Failed to decode pre_upgrade state")?;
+        // Any CA that failed to decode would have been dropped by `translate`
+        let count = crate::CertificateAuthorities::<T>::iter_values().count() as u64;
+        frame_support::ensure!(
+            count == pre_count,
+            "crl post_upgrade v0->v1: expected {pre_count} CAs, got {count}"
+        );
+        Ok(())
+    }
+}
+
+/// [`UncheckedOnRuntimeUpgrade`] implementation [`InnerMigrateV0ToV1`] wrapped in a
+/// [`VersionedMigration`], which ensures that:
+/// - The migration only runs once when the on-chain storage version is 0
+/// - The on-chain storage version is updated to `1` after the migration executes
+/// - Reads/Writes from checking/setting the on-chain storage version are accounted for
+pub type MigrateV0ToV1<T> = VersionedMigration<
+    0,
+    1,
+    InnerMigrateV0ToV1<T>,
+    crate::Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mock::*, pallet::CaName, StalenessPolicy};
+    use frame_support::{storage_alias, Blake2_128Concat, BoundedBTreeMap};
+
+    #[storage_alias]
+    type CertificateAuthorities<T: crate::Config> =
+        StorageMap<crate::Pallet<T>, Blake2_128Concat, CaName<T>, v0::OldCaInfo>;
+
+    #[test]
+    fn successful_migration() {
+        test().execute_with(|| {
+            let name: CaName<Test> = CA_NAME.to_vec().try_into().unwrap();
+            let mut crl_versions = BoundedBTreeMap::new();
+            crl_versions.try_insert([1; 32], 1000).unwrap();
+            crl_versions.try_insert([2; 32], 2000).unwrap();
+            CertificateAuthorities::<Test>::insert(
+                &name,
+                v0::OldCaInfo {
+                    root_cert: root_cert().try_into().unwrap(),
+                    revoked_count: 5,
+                    crl_versions,
+                },
+            );
+
+            let weight = InnerMigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+            let ca_info = crate::CertificateAuthorities::<Test>::get(&name).unwrap();
+            assert_eq!(ca_info.root_cert.to_vec(), root_cert());
+            assert_eq!(ca_info.revoked_count, 5);
+            assert_eq!(
+                ca_info.crl_versions.into_iter().collect::<Vec<_>>(),
+                vec![
+                    (
+                        [1; 32],
+                        CrlValidity {
+                            this_update: 1000,
+                            next_update: None
+                        }
+                    ),
+                    (
+                        [2; 32],
+                        CrlValidity {
+                            this_update: 2000,
+                            next_update: None
+                        }
+                    ),
+                ]
+            );
+            assert_eq!(ca_info.staleness_policy, StalenessPolicy::Ignore);
+            assert_eq!(
+                weight,
+                <Test as frame_system::Config>::DbWeight::get().reads_writes(1, 1)
+            );
+        })
+    }
+}
@@ -0,0 +1,223 @@
+// Copyright 2026, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Migrate from storage v1 to v2.
+//!
+//! In v1, all the certificates revoked by a CA were stored in a single value:
+//! ```ignore
+//! type Revoked = StorageMap<_, Blake2_128Concat, CaName, BoundedVec<RevokedInfo, _>>;
+//!
+//! struct RevokedInfo {
+//!     issuer: BoundedVec<u8, _>,
+//!     serial: BoundedVec<u8, _>,
+//! }
+//! ```
+//!
+//! In v2, each revoked certificate has its own `RevokedCerts` entry, keyed by (CA name,
+//! issuer hash, serial number), and the issuers are stored once in `CrlIssuers`.
+
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+
+use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+use sp_core::Get;
+
+use crate::{CertificateAuthorities, CrlIssuers, IssuerHash, IssuerRevokedCounts, RevokedCerts};
+
+/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the revoked certificates from v1 to v2.
+pub struct InnerMigrateV1ToV2<T>(core::marker::PhantomData<T>);
+
+mod v1 {
+    use codec::{Decode, Encode};
+    use frame_support::{pallet_prelude::ConstU32, storage_alias, Blake2_128Concat, BoundedVec};
+
+    use crate::{pallet::CaName, Issuer, SerialNumber};
+
+    pub const MAX_REVOKED_CERTS_PER_CA: u32 = 10000;
+
+    #[derive(Decode, Encode)]
+    pub struct RevokedInfo {
+        pub issuer: Issuer,
+        pub serial: SerialNumber,
+    }
+
+    #[storage_alias]
+    pub type Revoked<T: crate::Config> = StorageMap<
+        crate::Pallet<T>,
+        Blake2_128Concat,
+        CaName<T>,
+        BoundedVec<RevokedInfo, ConstU32<MAX_REVOKED_CERTS_PER_CA>>,
+    >;
+}
+
+impl<T: crate::Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+    fn on_runtime_upgrade() -> frame_support::weights::Weight {
+        let mut reads: u64 = 0;
+        let mut writes: u64 = 0;
+        for (ca_name, revoked) in v1::Revoked::<T>::drain() {
+            reads += 1;
+            writes += 1;
+            let Some(mut ca_info) = CertificateAuthorities::<T>::get(&ca_name) else {
+                reads += 1;
+                log::warn!(
+                    target: "runtime::crl",
+                    "Crl migration V1->V2: dropping revoked certificates of unknown CA {ca_name:?}",
+                );
+                continue;
+            };
+            let mut count: u32 = 0;
+            for v1::RevokedInfo { issuer, serial } in revoked {
+                let issuer_hash: IssuerHash = sp_core::hashing::blake2_256(&issuer);
+                let key = (&ca_name, issuer_hash, serial);
+                if !RevokedCerts::<T>::contains_key(&key) {
+                    RevokedCerts::<T>::insert(key, ());
+                    IssuerRevokedCounts::<T>::mutate(&ca_name, issuer_hash, |c| *c += 1);
+                    count += 1;
+                    reads += 1;
+                    writes += 2;
+                }
+                CrlIssuers::<T>::insert(&ca_name, issuer_hash, issuer);
+                reads += 1;
+                writes += 1;
+            }
+            ca_info.revoked_count = count;
+            CertificateAuthorities::<T>::insert(&ca_name, ca_info);
+            reads += 1;
+            writes += 1;
+
+            log::info!(
+                target: "runtime::crl",
+                "Crl migration V1->V2: migrated {count} revoked certificates of CA {ca_name:?}",
+            );
+        }
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        let count = v1::Revoked::<T>::iter()
+            .filter(|(ca_name, _)| CertificateAuthorities::<T>::contains_key(ca_name))
+            .map(|(_, revoked)| revoked.len() as u64)
+            .sum::<u64>();
+        Ok(count.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        let pre_count =
+            u64::decode(&mut state.as_slice()).map_err(|_| "Failed to decode pre_upgrade state")?;
+        frame_support::ensure!(
+            v1::Revoked::<T>::iter_keys().next().is_none(),
+            "crl post_upgrade v1->v2: old revoked certificates left"
+        );
+        // Duplicated entries are stored once
+        let count = RevokedCerts::<T>::iter_keys().count() as u64;
+        frame_support::ensure!(
+            count <= pre_count,
+            "crl post_upgrade v1->v2: expected at most {pre_count} revoked certificates, got {count}"
+        );
+        Ok(())
+    }
+}
+
+/// [`UncheckedOnRuntimeUpgrade`] implementation [`InnerMigrateV1ToV2`] wrapped in a
+/// [`VersionedMigration`], which ensures that:
+/// - The migration only runs once when the on-chain storage version is 1
+/// - The on-chain storage version is updated to `2` after the migration executes
+/// - Reads/Writes from checking/setting the on-chain storage version are accounted for
+pub type MigrateV1ToV2<T> = VersionedMigration<
+    1,
+    2,
+    InnerMigrateV1ToV2<T>,
+    crate::Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mock::*, pallet::CaName, CaInfo, CrlProvider};
+
+    fn revoked_info(issuer: &[u8], serial: &[u8]) -> v1::RevokedInfo {
+        v1::RevokedInfo {
+            issuer: issuer.to_vec().try_into().unwrap(),
+            serial: serial.to_vec().try_into().unwrap(),
+        }
+    }
+
+    #[test]
+    fn successful_migration() {
+        test().execute_with(|| {
+            let name: CaName<Test> = CA_NAME.to_vec().try_into().unwrap();
+            CertificateAuthorities::<Test>::insert(
+                &name,
+                CaInfo {
+                    root_cert: root_cert().try_into().unwrap(),
+                    revoked_count: 4,
+                    crl_versions: Default::default(),
+                    staleness_policy: Default::default(),
+                },
+            );
+            v1::Revoked::<Test>::insert(
+                &name,
+                frame_support::BoundedVec::truncate_from(vec![
+                    revoked_info(b"issuer1", &[1]),
+                    revoked_info(b"issuer1", &[2]),
+                    revoked_info(b"issuer2", &[1]),
+                    revoked_info(b"issuer2", &[1]),
+                ]),
+            );
+            let unknown: CaName<Test> = b"Unknown".to_vec().try_into().unwrap();
+            v1::Revoked::<Test>::insert(
+                &unknown,
+                frame_support::BoundedVec::truncate_from(vec![revoked_info(b"issuer1", &[1])]),
+            );
+
+            InnerMigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+            assert_eq!(v1::Revoked::<Test>::iter_keys().count(), 0);
+            let ca_name = core::str::from_utf8(CA_NAME).unwrap();
+            for (issuer, serial) in [(b"issuer1", [1]), (b"issuer1", [2]), (b"issuer2", [1])] {
+                assert!(crate::Pallet::<Test>::is_revoked(ca_name, issuer, &serial));
+            }
+            assert!(!crate::Pallet::<Test>::is_revoked(
+                ca_name,
+                b"issuer2",
+                &[2]
+            ));
+            assert_eq!(
+                CertificateAuthorities::<Test>::get(&name)
+                    .unwrap()
+                    .revoked_count,
+                3
+            );
+            assert_eq!(crate::Pallet::<Test>::get_crl(ca_name).unwrap().len(), 3);
+            assert_eq!(CrlIssuers::<Test>::iter_prefix(&name).count(), 2);
+            for (issuer, count) in [(b"issuer1", 2), (b"issuer2", 1)] {
+                assert_eq!(
+                    IssuerRevokedCounts::<Test>::get(&name, sp_core::hashing::blake2_256(issuer)),
+                    count
+                );
+            }
+            assert_eq!(
+                RevokedCerts::<Test>::iter_key_prefix((&unknown,)).count(),
+                0
+            );
+        })
+    }
+}
@@ -49,6 +49,8 @@ impl frame_system::Config for Test {
 
 parameter_types! {
     pub const MaxCaNameLength: u32 = 64;
+    pub const OffchainWorkerInterval: u32 = 10;
+    pub const IntelCaName: &'static str = "Intel_SGX_Processor";
 }
 
 impl crate::Config for Test {
@@ -57,6 +59,26 @@ impl crate::Config for Test {
     type WeightInfo = ();
     type MaxCaNameLength = MaxCaNameLength;
     type UnixTime = Timestamp;
+    type OffchainWorkerInterval = OffchainWorkerInterval;
+    type UnsignedPriority = sp_core::ConstU64<100>;
+    type IntelCaName = IntelCaName;
+}
+
+impl<C> frame_system::offchain::CreateTransactionBase<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+    type RuntimeCall = RuntimeCall;
+}
+
+impl<C> frame_system::offchain::CreateInherent<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    fn create_inherent(call: Self::RuntimeCall) -> Self::Extrinsic {
+        frame_system::mocking::MockUncheckedExtrinsic::<Test>::new_bare(call)
+    }
 }
 
 impl pallet_timestamp::Config for Test {
@@ -75,6 +97,15 @@ impl pallet_timestamp::Config for Test {
 //   └── Test Intermediate CA 2 (in chain2.pem)
 //       └── crl_inter2.pem   — CRL v2, 2 revoked certs, issued 2026-02-11 11:48:42 UTC
 //
+// Test Delta Root CA (delta/root_ca.der)
+// └── Test Delta Intermediate CA (in delta/chain.pem)
+//     ├── crl_base.pem              — complete CRL #1, revokes 1, 2 and 3
+//     ├── crl_delta_2.pem           — delta CRL #2 on base #1, revokes 4 and 5, removes 2
+//     ├── crl_delta_3.pem           — delta CRL #3 on base #1, revokes 4, 5 and 6, removes 2
+//     ├── crl_delta_future_base.pem — delta CRL #4 on base #3, revokes 7
+//     ├── crl_delta_same_update.pem — delta CRL #2 on base #1, revokes 4, issued with the base
+//     └── crl_empty.pem             — complete CRL #4, revokes nothing
+//
 // chain PEM order: [intermediate cert, root cert]
 
 /// Root CA certificate (DER encoded).
@@ -136,6 +167,85 @@ pub fn chain2_der() -> Vec<u8> {
     chain
 }
 
+/// Root CA certificate of the delta CRL hierarchy (DER encoded).
+pub fn delta_root_cert() -> Vec<u8> {
+    include_bytes!("resources/test/delta/root_ca.der").to_vec()
+}
+
+/// Certificate chain of the delta CRL issuer (PEM: intermediate + root).
+pub fn delta_chain() -> Vec<u8> {
+    include_bytes!("resources/test/delta/chain.pem").to_vec()
+}
+
+/// DER-encoded certificate chain of the delta CRL issuer (intermediate + root, concatenated).
+pub fn delta_chain_der() -> Vec<u8> {
+    let mut chain = include_bytes!("resources/test/delta/signing_cert.der").to_vec();
+    chain.extend_from_slice(include_bytes!("resources/test/delta/root_ca.der"));
+    chain
+}
+
+/// Complete CRL #1 — revokes serials 1, 2 and 3, issued 2026-02-11 11:50:00 UTC.
+pub fn crl_delta_base() -> Vec<u8> {
+    include_bytes!("resources/test/delta/crl_base.pem").to_vec()
+}
+
+/// Delta CRL #2 on base #1 — revokes serials 4 and 5, removes serial 2, issued
+/// 2026-02-11 11:51:00 UTC.
+pub fn crl_delta_2() -> Vec<u8> {
+    include_bytes!("resources/test/delta/crl_delta_2.pem").to_vec()
+}
+
+/// DER-encoded delta CRL #2.
+pub fn crl_delta_2_der() -> Vec<u8> {
+    include_bytes!("resources/test/delta/crl_delta_2.der").to_vec()
+}
+
+/// Delta CRL #3 on base #1 — revokes serials 4, 5 and 6, removes serial 2, issued
+/// 2026-02-11 11:52:00 UTC.
+pub fn crl_delta_3() -> Vec<u8> {
+    include_bytes!("resources/test/delta/crl_delta_3.pem").to_vec()
+}
+
+/// Delta CRL #4 on base #3 — revokes serial 7, issued 2026-02-11 11:53:00 UTC.
+pub fn crl_delta_future_base() -> Vec<u8> {
+    include_bytes!("resources/test/delta/crl_delta_future_base.pem").to_vec()
+}
+
+/// Delta CRL #2 on base #1 — revokes serial 4, issued 2026-02-11 11:50:00 UTC like its base.
+pub fn crl_delta_same_update() -> Vec<u8> {
+    include_bytes!("resources/test/delta/crl_delta_same_update.pem").to_vec()
+}
+
+/// Complete CRL #4 — revokes nothing, issued 2026-02-11 11:54:00 UTC.
+pub fn crl_delta_empty() -> Vec<u8> {
+    include_bytes!("resources/test/delta/crl_empty.pem").to_vec()
+}
+
+pub const INTEL_CA_NAME: &[u8] = IntelCaName::get().as_bytes();
+
+/// FMSPC of the platform of the Intel TCB info.
+pub const INTEL_FMSPC: crate::Fmspc = [0xb0, 0xc0, 0x6f, 0x00, 0x00, 0x00];
+
+/// Intel SGX Root CA certificate (DER encoded).
+pub fn intel_root_cert() -> Vec<u8> {
+    include_bytes!("resources/intel/intel_root_ca.der").to_vec()
+}
+
+/// TDX TCB info for `INTEL_FMSPC`, evaluation data number 18, issued 2026-01-20 08:55:31 UTC.
+pub fn intel_tcb_response() -> Vec<u8> {
+    include_bytes!("resources/intel/valid_tcbinfo.json").to_vec()
+}
+
+/// The same TCB info as `intel_tcb_response`, with a tampered signature.
+pub fn intel_invalid_tcb_response() -> Vec<u8> {
+    include_bytes!("resources/intel/invalid_tcbinfo.json").to_vec()
+}
+
+/// Certificate chain of the Intel TCB signing key (PEM: signing cert + root).
+pub fn intel_tcb_cert_chain() -> Vec<u8> {
+    include_bytes!("resources/intel/valid_tcbinfo_certs.pem").to_vec()
+}
+
 pub fn test() -> sp_io::TestExternalities {
     let mut ext = sp_io::TestExternalities::from(
         frame_system::GenesisConfig::<Test>::default()
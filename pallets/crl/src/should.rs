@@ -19,7 +19,14 @@ use frame_support::{assert_noop, assert_ok};
 
 use super::*;
 use mock::*;
-use pallet::{CaName, CertificateAuthorities, Error, Event, Revoked};
+use pallet::{
+    CaName, CertificateAuthorities, CrlIssuers, CrlNumbers, Error, Event, IssuerRevokedCounts,
+    PendingRemovals, RevokedCerts, SerialNumber,
+};
+
+fn revoked_certs(ca_name: &CaName<Test>) -> Vec<(IssuerHash, SerialNumber)> {
+    RevokedCerts::<Test>::iter_key_prefix((ca_name,)).collect()
+}
 
 impl CrlInput {
     fn from_pem(crl: Vec<u8>, cert_chain: Vec<u8>) -> Self {
@@ -121,12 +128,119 @@ fn unregister_ca() {
 
         let bounded_name: CaName<Test> = CA_NAME.to_vec().try_into().unwrap();
         assert!(CertificateAuthorities::<Test>::get(&bounded_name).is_none());
-        assert!(Revoked::<Test>::get(&bounded_name).is_none());
+        assert!(revoked_certs(&bounded_name).is_empty());
 
         System::assert_has_event(Event::CaUnregistered { name: bounded_name }.into());
     })
 }
 
+mod pending_removals {
+    use super::*;
+
+    fn register_ca_with_revocations() -> CaName<Test> {
+        assert_ok!(CrlPallet::register_ca(
+            Origin::Root.into(),
+            CA_NAME.to_vec(),
+            root_cert(),
+        ));
+        assert_ok!(CrlPallet::update_crl(
+            Origin::Signed(ALICE).into(),
+            CA_NAME.to_vec(),
+            CrlInput::from_pem(crl_inter1_v1(), chain1()),
+        ));
+        CA_NAME.to_vec().try_into().unwrap()
+    }
+
+    #[test]
+    fn remove_revoked_certs_in_idle_time() {
+        test().execute_with(|| {
+            let bounded_name = register_ca_with_revocations();
+            assert_ok!(CrlPallet::unregister_ca(
+                Origin::Root.into(),
+                CA_NAME.to_vec()
+            ));
+
+            assert_eq!(CrlIssuers::<Test>::iter_prefix(&bounded_name).count(), 0);
+            assert_eq!(revoked_certs(&bounded_name).len(), 3);
+            assert!(PendingRemovals::<Test>::contains_key(&bounded_name));
+
+            CrlPallet::on_idle(1, Weight::MAX);
+
+            assert!(revoked_certs(&bounded_name).is_empty());
+            assert!(!PendingRemovals::<Test>::contains_key(&bounded_name));
+        })
+    }
+
+    #[test]
+    fn remove_only_the_revoked_certs_that_fit_in_the_remaining_weight() {
+        let mut ext = test();
+        let bounded_name = ext.execute_with(|| {
+            let bounded_name = register_ca_with_revocations();
+            assert_ok!(CrlPallet::unregister_ca(
+                Origin::Root.into(),
+                CA_NAME.to_vec()
+            ));
+            bounded_name
+        });
+        // The removal limit only applies to the committed storage
+        ext.commit_all().unwrap();
+
+        ext.execute_with(|| {
+            let weight = <Test as frame_system::Config>::DbWeight::get()
+                .reads(1)
+                .saturating_add(<() as WeightInfo>::clear_crl(2));
+            assert_eq!(CrlPallet::on_idle(1, weight), weight);
+
+            assert_eq!(revoked_certs(&bounded_name).len(), 1);
+            assert!(PendingRemovals::<Test>::contains_key(&bounded_name));
+
+            CrlPallet::on_idle(2, weight);
+
+            assert!(revoked_certs(&bounded_name).is_empty());
+            assert!(!PendingRemovals::<Test>::contains_key(&bounded_name));
+        })
+    }
+
+    #[test]
+    fn do_nothing_without_enough_weight() {
+        test().execute_with(|| {
+            let bounded_name = register_ca_with_revocations();
+            assert_ok!(CrlPallet::unregister_ca(
+                Origin::Root.into(),
+                CA_NAME.to_vec()
+            ));
+
+            assert_eq!(CrlPallet::on_idle(1, Weight::zero()), Weight::zero());
+
+            assert_eq!(revoked_certs(&bounded_name).len(), 3);
+        })
+    }
+
+    #[test]
+    fn err_on_register_before_removal() {
+        test().execute_with(|| {
+            register_ca_with_revocations();
+            assert_ok!(CrlPallet::unregister_ca(
+                Origin::Root.into(),
+                CA_NAME.to_vec()
+            ));
+
+            assert_noop!(
+                CrlPallet::register_ca(Origin::Root.into(), CA_NAME.to_vec(), root_cert()),
+                Error::<Test>::CaRemovalPending
+            );
+
+            CrlPallet::on_idle(1, Weight::MAX);
+
+            assert_ok!(CrlPallet::register_ca(
+                Origin::Root.into(),
+                CA_NAME.to_vec(),
+                root_cert(),
+            ));
+        })
+    }
+}
+
 #[test]
 fn unregister_ca_wrong_origin() {
     test().execute_with(|| {
@@ -169,7 +283,7 @@ mod pem {
             assert_eq!(ca_info.revoked_count, 3);
             assert_eq!(ca_info.crl_versions.len(), 1);
 
-            let revoked = Revoked::<Test>::get(&bounded_name).unwrap();
+            let revoked = revoked_certs(&bounded_name);
             assert_eq!(revoked.len(), 3);
         })
     }
@@ -252,7 +366,7 @@ mod pem {
             assert_eq!(ca_info.revoked_count, 5); // 3 from issuer1 + 2 from issuer2
             assert_eq!(ca_info.crl_versions.len(), 2); // two distinct issuers tracked
 
-            let revoked = Revoked::<Test>::get(&bounded_name).unwrap();
+            let revoked = revoked_certs(&bounded_name);
             assert_eq!(revoked.len(), 5);
         })
     }
@@ -299,7 +413,7 @@ mod pem {
             assert_eq!(ca_info.revoked_count, 7); // 5 from issuer1 v2 + 2 from issuer2
             assert_eq!(ca_info.crl_versions.len(), 2); // still two issuers
 
-            let revoked = Revoked::<Test>::get(&bounded_name).unwrap();
+            let revoked = revoked_certs(&bounded_name);
             assert_eq!(revoked.len(), 7);
         })
     }
@@ -394,15 +508,17 @@ mod pem {
             ));
 
             let bounded_name: CaName<Test> = CA_NAME.to_vec().try_into().unwrap();
-            assert_eq!(Revoked::<Test>::get(&bounded_name).unwrap().len(), 5);
+            assert_eq!(revoked_certs(&bounded_name).len(), 5);
 
             assert_ok!(CrlPallet::unregister_ca(
                 Origin::Root.into(),
                 CA_NAME.to_vec(),
             ));
+            CrlPallet::on_idle(1, Weight::MAX);
 
             assert!(CertificateAuthorities::<Test>::get(&bounded_name).is_none());
-            assert!(Revoked::<Test>::get(&bounded_name).is_none());
+            assert_eq!(CrlIssuers::<Test>::iter_prefix(&bounded_name).count(), 0);
+            assert!(revoked_certs(&bounded_name).is_empty());
         })
     }
 }
@@ -434,7 +550,7 @@ mod der {
             assert_eq!(ca_info.revoked_count, 3);
             assert_eq!(ca_info.crl_versions.len(), 1);
 
-            let revoked = Revoked::<Test>::get(&bounded_name).unwrap();
+            let revoked = revoked_certs(&bounded_name);
             assert_eq!(revoked.len(), 3);
         })
     }
@@ -588,4 +704,1626 @@ mod der {
             assert!(result.is_err());
         })
     }
+
+    #[test]
+    fn get_root_cert_returns_the_registered_one() {
+        test().execute_with(|| {
+            assert_ok!(CrlPallet::register_ca(
+                Origin::Root.into(),
+                CA_NAME.to_vec(),
+                root_cert(),
+            ));
+
+            let ca_name_str = core::str::from_utf8(CA_NAME).unwrap();
+            assert_eq!(
+                <CrlPallet as CrlProvider>::get_root_cert(ca_name_str),
+                Ok(root_cert())
+            );
+        })
+    }
+
+    #[test]
+    fn get_root_cert_err_on_not_found() {
+        test().execute_with(|| {
+            assert_eq!(
+                <CrlPallet as CrlProvider>::get_root_cert("NonExistent"),
+                Err(CaNotFoundError)
+            );
+        })
+    }
+
+    #[test]
+    fn is_revoked_finds_every_revoked_cert() {
+        test().execute_with(|| {
+            assert_ok!(CrlPallet::register_ca(
+                Origin::Root.into(),
+                CA_NAME.to_vec(),
+                root_cert(),
+            ));
+            assert_ok!(CrlPallet::update_crl(
+                Origin::Signed(ALICE).into(),
+                CA_NAME.to_vec(),
+                CrlInput::from_pem(crl_inter1_v1(), chain1()),
+            ));
+            assert_ok!(CrlPallet::update_crl(
+                Origin::Signed(ALICE).into(),
+                CA_NAME.to_vec(),
+                CrlInput::from_pem(crl_inter2(), chain2()),
+            ));
+
+            let ca_name_str = core::str::from_utf8(CA_NAME).unwrap();
+            let crl = <CrlPallet as CrlProvider>::get_crl(ca_name_str).unwrap();
+            for revoked in &crl {
+                assert!(<CrlPallet as CrlProvider>::is_revoked(
+                    ca_name_str,
+                    &revoked.issuer,
+                    &revoked.serial_number
+                ));
+                assert!(Revocations::<CrlPallet>::new(ca_name_str)
+                    .is_revoked(&revoked.issuer, &revoked.serial_number));
+            }
+        })
+    }
+
+    #[test]
+    fn is_revoked_false_for_other_certs() {
+        test().execute_with(|| {
+            assert_ok!(CrlPallet::register_ca(
+                Origin::Root.into(),
+                CA_NAME.to_vec(),
+                root_cert(),
+            ));
+            assert_ok!(CrlPallet::update_crl(
+                Origin::Signed(ALICE).into(),
+                CA_NAME.to_vec(),
+                CrlInput::from_pem(crl_inter1_v1(), chain1()),
+            ));
+
+            let ca_name_str = core::str::from_utf8(CA_NAME).unwrap();
+            let crl = <CrlPallet as CrlProvider>::get_crl(ca_name_str).unwrap();
+            let revoked = &crl[0];
+            let other_issuer = [revoked.issuer.as_slice(), &[0]].concat();
+            let other_serial = [revoked.serial_number.as_slice(), &[0]].concat();
+
+            assert!(!<CrlPallet as CrlProvider>::is_revoked(
+                ca_name_str,
+                &other_issuer,
+                &revoked.serial_number
+            ));
+            assert!(!<CrlPallet as CrlProvider>::is_revoked(
+                ca_name_str,
+                &revoked.issuer,
+                &other_serial
+            ));
+            assert!(!<CrlPallet as CrlProvider>::is_revoked(
+                "NonExistent",
+                &revoked.issuer,
+                &revoked.serial_number
+            ));
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// TCB info
+// ---------------------------------------------------------------------------
+
+mod tcb_info {
+    use super::*;
+    use pallet::{TcbInfoEntry, TcbInfos};
+
+    fn register_intel_ca() {
+        assert_ok!(CrlPallet::register_ca(
+            Origin::Root.into(),
+            INTEL_CA_NAME.to_vec(),
+            intel_root_cert(),
+        ));
+    }
+
+    fn older_entry() -> TcbInfoEntry {
+        TcbInfoEntry {
+            tcb_response: b"older".to_vec().try_into().unwrap(),
+            evaluation_data_number: 17,
+            issue_date: 0,
+        }
+    }
+
+    #[test]
+    fn update_tcb_info() {
+        test().execute_with(|| {
+            register_intel_ca();
+
+            assert_ok!(CrlPallet::update_tcb_info(
+                Origin::Signed(ALICE).into(),
+                intel_tcb_response(),
+                intel_tcb_cert_chain(),
+            ));
+
+            let entry = TcbInfos::<Test>::get(INTEL_FMSPC, TcbId::Tdx).unwrap();
+            assert_eq!(entry.tcb_response.to_vec(), intel_tcb_response());
+            assert_eq!(entry.evaluation_data_number, 18);
+            assert_eq!(entry.issue_date, 1_768_899_331);
+            assert!(TcbInfos::<Test>::get(INTEL_FMSPC, TcbId::Sgx).is_none());
+
+            System::assert_has_event(
+                Event::TcbInfoUpdated {
+                    fmspc: INTEL_FMSPC,
+                    id: TcbId::Tdx,
+                    evaluation_data_number: 18,
+                    issue_date: 1_768_899_331,
+                }
+                .into(),
+            );
+        })
+    }
+
+    #[test]
+    fn update_tcb_info_replaces_an_older_one() {
+        test().execute_with(|| {
+            register_intel_ca();
+            TcbInfos::<Test>::insert(INTEL_FMSPC, TcbId::Tdx, older_entry());
+
+            assert_ok!(CrlPallet::update_tcb_info(
+                Origin::Signed(ALICE).into(),
+                intel_tcb_response(),
+                intel_tcb_cert_chain(),
+            ));
+
+            let entry = TcbInfos::<Test>::get(INTEL_FMSPC, TcbId::Tdx).unwrap();
+            assert_eq!(entry.evaluation_data_number, 18);
+        })
+    }
+
+    #[test]
+    fn update_tcb_info_replaces_a_previous_issue_of_the_same_evaluation() {
+        test().execute_with(|| {
+            register_intel_ca();
+            TcbInfos::<Test>::insert(
+                INTEL_FMSPC,
+                TcbId::Tdx,
+                TcbInfoEntry {
+                    evaluation_data_number: 18,
+                    ..older_entry()
+                },
+            );
+
+            assert_ok!(CrlPallet::update_tcb_info(
+                Origin::Signed(ALICE).into(),
+                intel_tcb_response(),
+                intel_tcb_cert_chain(),
+            ));
+        })
+    }
+
+    #[test]
+    fn err_on_not_newer() {
+        test().execute_with(|| {
+            register_intel_ca();
+
+            assert_ok!(CrlPallet::update_tcb_info(
+                Origin::Signed(ALICE).into(),
+                intel_tcb_response(),
+                intel_tcb_cert_chain(),
+            ));
+            assert_noop!(
+                CrlPallet::update_tcb_info(
+                    Origin::Signed(ALICE).into(),
+                    intel_tcb_response(),
+                    intel_tcb_cert_chain(),
+                ),
+                Error::<Test>::NotNewerTcbInfo
+            );
+        })
+    }
+
+    #[test]
+    fn err_on_older_evaluation() {
+        test().execute_with(|| {
+            register_intel_ca();
+            TcbInfos::<Test>::insert(
+                INTEL_FMSPC,
+                TcbId::Tdx,
+                TcbInfoEntry {
+                    evaluation_data_number: 19,
+                    ..older_entry()
+                },
+            );
+
+            assert_noop!(
+                CrlPallet::update_tcb_info(
+                    Origin::Signed(ALICE).into(),
+                    intel_tcb_response(),
+                    intel_tcb_cert_chain(),
+                ),
+                Error::<Test>::NotNewerTcbInfo
+            );
+        })
+    }
+
+    #[test]
+    fn err_on_invalid_signature() {
+        test().execute_with(|| {
+            register_intel_ca();
+
+            assert_noop!(
+                CrlPallet::update_tcb_info(
+                    Origin::Signed(ALICE).into(),
+                    intel_invalid_tcb_response(),
+                    intel_tcb_cert_chain(),
+                ),
+                Error::<Test>::TcbInfoValidationError
+            );
+        })
+    }
+
+    #[test]
+    fn err_on_chain_not_rooted_in_the_ca() {
+        test().execute_with(|| {
+            assert_ok!(CrlPallet::register_ca(
+                Origin::Root.into(),
+                INTEL_CA_NAME.to_vec(),
+                root_cert(),
+            ));
+
+            assert_noop!(
+                CrlPallet::update_tcb_info(
+                    Origin::Signed(ALICE).into(),
+                    intel_tcb_response(),
+                    intel_tcb_cert_chain(),
+                ),
+                Error::<Test>::TcbInfoValidationError
+            );
+        })
+    }
+
+    #[test]
+    fn err_on_ca_not_found() {
+        test().execute_with(|| {
+            assert_noop!(
+                CrlPallet::update_tcb_info(
+                    Origin::Signed(ALICE).into(),
+                    intel_tcb_response(),
+                    intel_tcb_cert_chain(),
+                ),
+                Error::<Test>::CaNotFound
+            );
+        })
+    }
+
+    #[test]
+    fn err_on_ca_not_found_even_if_another_ca_has_the_intel_root() {
+        test().execute_with(|| {
+            assert_ok!(CrlPallet::register_ca(
+                Origin::Root.into(),
+                CA_NAME.to_vec(),
+                intel_root_cert(),
+            ));
+
+            assert_noop!(
+                CrlPallet::update_tcb_info(
+                    Origin::Signed(ALICE).into(),
+                    intel_tcb_response(),
+                    intel_tcb_cert_chain(),
+                ),
+                Error::<Test>::CaNotFound
+            );
+        })
+    }
+
+    #[test]
+    fn err_on_too_large_response() {
+        test().execute_with(|| {
+            register_intel_ca();
+
+            assert_noop!(
+                CrlPallet::update_tcb_info(
+                    Origin::Signed(ALICE).into(),
+                    vec![b' '; MAX_TCB_RESPONSE_LENGTH as usize + 1],
+                    intel_tcb_cert_chain(),
+                ),
+                Error::<Test>::TcbResponseTooLarge
+            );
+        })
+    }
+
+    #[test]
+    fn err_on_unsigned_origin() {
+        test().execute_with(|| {
+            register_intel_ca();
+
+            assert_noop!(
+                CrlPallet::update_tcb_info(
+                    Origin::Root.into(),
+                    intel_tcb_response(),
+                    intel_tcb_cert_chain(),
+                ),
+                sp_runtime::traits::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn provider_returns_the_registered_response() {
+        test().execute_with(|| {
+            register_intel_ca();
+            assert_ok!(CrlPallet::update_tcb_info(
+                Origin::Signed(ALICE).into(),
+                intel_tcb_response(),
+                intel_tcb_cert_chain(),
+            ));
+
+            assert_eq!(
+                <CrlPallet as TcbInfoProvider>::get_tcb_response(&INTEL_FMSPC, TcbId::Tdx),
+                Some(intel_tcb_response())
+            );
+            assert_eq!(
+                <CrlPallet as TcbInfoProvider>::get_tcb_response(&INTEL_FMSPC, TcbId::Sgx),
+                None
+            );
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// CRL staleness
+// ---------------------------------------------------------------------------
+
+mod staleness {
+    use super::*;
+
+    /// `thisUpdate` of `crl_inter1_v1`: 2026-02-11 11:48:15 UTC.
+    const THIS_UPDATE: u64 = 1770810495;
+    /// `nextUpdate` of `crl_inter1_v1`: 2046-02-06 11:48:15 UTC.
+    const NEXT_UPDATE: u64 = 2401530495;
+
+    fn ca_name() -> &'static str {
+        core::str::from_utf8(CA_NAME).unwrap()
+    }
+
+    fn register_ca_with_crl() {
+        assert_ok!(CrlPallet::register_ca(
+            Origin::Root.into(),
+            CA_NAME.to_vec(),
+            root_cert(),
+        ));
+        assert_ok!(CrlPallet::update_crl(
+            Origin::Signed(ALICE).into(),
+            CA_NAME.to_vec(),
+            CrlInput::from_pem(crl_inter1_v1(), chain1()),
+        ));
+    }
+
+    fn set_policy(policy: StalenessPolicy) {
+        assert_ok!(CrlPallet::set_staleness_policy(
+            Origin::Root.into(),
+            CA_NAME.to_vec(),
+            policy,
+        ));
+    }
+
+    #[test]
+    fn update_crl_tracks_the_validity_period() {
+        test().execute_with(|| {
+            register_ca_with_crl();
+
+            let validity = CrlValidity {
+                this_update: THIS_UPDATE,
+                next_update: Some(NEXT_UPDATE),
+            };
+            assert_eq!(
+                <CrlPallet as CrlProvider>::get_crl_validity(ca_name()),
+                Ok(vec![validity])
+            );
+            System::assert_has_event(
+                Event::CrlUpdated {
+                    ca_name: CA_NAME.to_vec().try_into().unwrap(),
+                    issuer: CrlPallet::get_crl(ca_name()).unwrap()[0].issuer.clone(),
+                    last_update: THIS_UPDATE,
+                    next_update: Some(NEXT_UPDATE),
+                    revoked_count: 3,
+                }
+                .into(),
+            );
+        })
+    }
+
+    #[test]
+    fn get_crl_validity_err_on_not_found() {
+        test().execute_with(|| {
+            assert_eq!(
+                <CrlPallet as CrlProvider>::get_crl_validity("NonExistent"),
+                Err(CaNotFoundError)
+            );
+            assert_eq!(
+                <CrlPallet as CrlProvider>::is_crl_stale("NonExistent", 0),
+                Err(CaNotFoundError)
+            );
+        })
+    }
+
+    #[test]
+    fn stale_crls_are_ignored_by_default() {
+        test().execute_with(|| {
+            register_ca_with_crl();
+
+            assert_eq!(
+                <CrlPallet as CrlProvider>::is_crl_stale(ca_name(), u64::MAX),
+                Ok(false)
+            );
+        })
+    }
+
+    #[test]
+    fn hard_fail_past_next_update() {
+        test().execute_with(|| {
+            register_ca_with_crl();
+            set_policy(StalenessPolicy::Enforce { grace_period: 0 });
+
+            assert_eq!(
+                <CrlPallet as CrlProvider>::is_crl_stale(ca_name(), NEXT_UPDATE),
+                Ok(false)
+            );
+            assert_eq!(
+                <CrlPallet as CrlProvider>::is_crl_stale(ca_name(), NEXT_UPDATE + 1),
+                Ok(true)
+            );
+        })
+    }
+
+    #[test]
+    fn fail_past_the_grace_period() {
+        test().execute_with(|| {
+            register_ca_with_crl();
+            set_policy(StalenessPolicy::Enforce { grace_period: 3600 });
+
+            assert_eq!(
+                <CrlPallet as CrlProvider>::is_crl_stale(ca_name(), NEXT_UPDATE + 3600),
+                Ok(false)
+            );
+            assert_eq!(
+                <CrlPallet as CrlProvider>::is_crl_stale(ca_name(), NEXT_UPDATE + 3601),
+                Ok(true)
+            );
+        })
+    }
+
+    #[test]
+    fn never_stale_without_crls() {
+        test().execute_with(|| {
+            assert_ok!(CrlPallet::register_ca(
+                Origin::Root.into(),
+                CA_NAME.to_vec(),
+                root_cert(),
+            ));
+            set_policy(StalenessPolicy::Enforce { grace_period: 0 });
+
+            assert_eq!(
+                <CrlPallet as CrlProvider>::is_crl_stale(ca_name(), u64::MAX),
+                Ok(false)
+            );
+        })
+    }
+
+    #[test]
+    fn never_stale_without_next_update() {
+        let policy = StalenessPolicy::Enforce { grace_period: 0 };
+        let validity = CrlValidity {
+            this_update: THIS_UPDATE,
+            next_update: None,
+        };
+        assert!(!policy.is_stale(&validity, u64::MAX));
+    }
+
+    #[test]
+    fn set_staleness_policy() {
+        test().execute_with(|| {
+            register_ca_with_crl();
+            let policy = StalenessPolicy::Enforce { grace_period: 60 };
+            set_policy(policy);
+
+            let bounded_name: CaName<Test> = CA_NAME.to_vec().try_into().unwrap();
+            assert_eq!(
+                CertificateAuthorities::<Test>::get(&bounded_name)
+                    .unwrap()
+                    .staleness_policy,
+                policy
+            );
+            System::assert_last_event(
+                Event::StalenessPolicySet {
+                    ca_name: bounded_name,
+                    policy,
+                }
+                .into(),
+            );
+        })
+    }
+
+    #[test]
+    fn set_staleness_policy_err_on_ca_not_found() {
+        test().execute_with(|| {
+            assert_noop!(
+                CrlPallet::set_staleness_policy(
+                    Origin::Root.into(),
+                    CA_NAME.to_vec(),
+                    StalenessPolicy::Ignore
+                ),
+                Error::<Test>::CaNotFound
+            );
+        })
+    }
+
+    #[test]
+    fn set_staleness_policy_err_on_wrong_origin() {
+        test().execute_with(|| {
+            register_ca_with_crl();
+            assert_noop!(
+                CrlPallet::set_staleness_policy(
+                    Origin::Signed(ALICE).into(),
+                    CA_NAME.to_vec(),
+                    StalenessPolicy::Ignore
+                ),
+                sp_runtime::traits::BadOrigin
+            );
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Delta CRLs
+// ---------------------------------------------------------------------------
+
+mod delta {
+    use super::*;
+
+    /// `thisUpdate` of `crl_delta_2`: 2026-02-11 11:51:00 UTC.
+    const DELTA_2_THIS_UPDATE: u64 = 1770810660;
+    /// `nextUpdate` of `crl_delta_2`: 2046-02-06 11:51:00 UTC.
+    const DELTA_2_NEXT_UPDATE: u64 = 2401530660;
+
+    fn ca_name() -> &'static str {
+        core::str::from_utf8(CA_NAME).unwrap()
+    }
+
+    fn bounded_name() -> CaName<Test> {
+        CA_NAME.to_vec().try_into().unwrap()
+    }
+
+    fn register_ca_with_base_crl() {
+        assert_ok!(CrlPallet::register_ca(
+            Origin::Root.into(),
+            CA_NAME.to_vec(),
+            delta_root_cert(),
+        ));
+        assert_ok!(CrlPallet::update_crl(
+            Origin::Signed(ALICE).into(),
+            CA_NAME.to_vec(),
+            CrlInput::from_pem(crl_delta_base(), delta_chain()),
+        ));
+    }
+
+    fn apply(crl: Vec<u8>) -> DispatchResultWithPostInfo {
+        CrlPallet::update_crl(
+            Origin::Signed(ALICE).into(),
+            CA_NAME.to_vec(),
+            CrlInput::from_pem(crl, delta_chain()),
+        )
+    }
+
+    fn revoked_serials() -> Vec<Vec<u8>> {
+        let mut serials: Vec<_> = CrlPallet::get_crl(ca_name())
+            .unwrap()
+            .into_iter()
+            .map(|r| r.serial_number)
+            .collect();
+        serials.sort();
+        serials
+    }
+
+    fn issuer() -> Vec<u8> {
+        CrlIssuers::<Test>::iter_prefix_values(bounded_name())
+            .next()
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn complete_crl_stores_its_crl_number() {
+        test().execute_with(|| {
+            register_ca_with_base_crl();
+
+            let issuer_hash = sp_core::hashing::blake2_256(&issuer());
+            assert_eq!(
+                CrlNumbers::<Test>::get(bounded_name(), issuer_hash),
+                Some(1)
+            );
+            assert_eq!(revoked_serials(), vec![vec![1], vec![2], vec![3]]);
+            assert_eq!(
+                IssuerRevokedCounts::<Test>::get(bounded_name(), issuer_hash),
+                3
+            );
+        })
+    }
+
+    #[test]
+    fn refresh_the_issuer_validity_with_an_empty_crl() {
+        test().execute_with(|| {
+            register_ca_with_base_crl();
+
+            assert_ok!(apply(crl_delta_empty()));
+
+            assert!(revoked_serials().is_empty());
+            assert_eq!(
+                CrlPallet::get_crl_validity(ca_name()),
+                Ok(vec![CrlValidity {
+                    this_update: DELTA_2_THIS_UPDATE + 180,
+                    next_update: Some(DELTA_2_NEXT_UPDATE + 180),
+                }])
+            );
+            let issuer_hash = sp_core::hashing::blake2_256(&issuer());
+            assert_eq!(
+                CrlNumbers::<Test>::get(bounded_name(), issuer_hash),
+                Some(4)
+            );
+            System::assert_last_event(
+                Event::CrlUpdated {
+                    ca_name: bounded_name(),
+                    issuer: issuer(),
+                    last_update: DELTA_2_THIS_UPDATE + 180,
+                    next_update: Some(DELTA_2_NEXT_UPDATE + 180),
+                    revoked_count: 0,
+                }
+                .into(),
+            );
+        })
+    }
+
+    #[test]
+    fn accept_an_empty_crl_as_the_first_one() {
+        test().execute_with(|| {
+            assert_ok!(CrlPallet::register_ca(
+                Origin::Root.into(),
+                CA_NAME.to_vec(),
+                delta_root_cert(),
+            ));
+
+            assert_ok!(apply(crl_delta_empty()));
+
+            assert!(revoked_serials().is_empty());
+            assert_eq!(
+                CrlPallet::get_crl_validity(ca_name()),
+                Ok(vec![CrlValidity {
+                    this_update: DELTA_2_THIS_UPDATE + 180,
+                    next_update: Some(DELTA_2_NEXT_UPDATE + 180),
+                }])
+            );
+        })
+    }
+
+    #[test]
+    fn apply_delta_crl() {
+        test().execute_with(|| {
+            register_ca_with_base_crl();
+
+            assert_ok!(apply(crl_delta_2()));
+
+            assert_eq!(revoked_serials(), vec![vec![1], vec![3], vec![4], vec![5]]);
+            assert!(!CrlPallet::is_revoked(ca_name(), &issuer(), &[2]));
+            assert!(CrlPallet::is_revoked(ca_name(), &issuer(), &[4]));
+            let ca_info = CertificateAuthorities::<Test>::get(bounded_name()).unwrap();
+            assert_eq!(ca_info.revoked_count, 4);
+            assert_eq!(
+                CrlPallet::get_crl_validity(ca_name()),
+                Ok(vec![CrlValidity {
+                    this_update: DELTA_2_THIS_UPDATE,
+                    next_update: Some(DELTA_2_NEXT_UPDATE),
+                }])
+            );
+            let issuer_hash = sp_core::hashing::blake2_256(&issuer());
+            assert_eq!(
+                CrlNumbers::<Test>::get(bounded_name(), issuer_hash),
+                Some(2)
+            );
+            assert_eq!(
+                IssuerRevokedCounts::<Test>::get(bounded_name(), issuer_hash),
+                4
+            );
+            System::assert_last_event(
+                Event::DeltaCrlApplied {
+                    ca_name: bounded_name(),
+                    issuer: issuer(),
+                    crl_number: 2,
+                    base_crl_number: 1,
+                    last_update: DELTA_2_THIS_UPDATE,
+                    next_update: Some(DELTA_2_NEXT_UPDATE),
+                    added: 2,
+                    removed: 1,
+                    revoked_count: 4,
+                }
+                .into(),
+            );
+        })
+    }
+
+    #[test]
+    fn apply_delta_crl_issued_with_its_base() {
+        test().execute_with(|| {
+            register_ca_with_base_crl();
+
+            assert_ok!(apply(crl_delta_same_update()));
+
+            assert_eq!(revoked_serials(), vec![vec![1], vec![2], vec![3], vec![4]]);
+            let issuer_hash = sp_core::hashing::blake2_256(&issuer());
+            assert_eq!(
+                CrlNumbers::<Test>::get(bounded_name(), issuer_hash),
+                Some(2)
+            );
+            assert_noop!(apply(crl_delta_same_update()), Error::<Test>::NotNewerCrl);
+        })
+    }
+
+    #[test]
+    fn err_on_complete_crl_not_newer_than_a_delta() {
+        test().execute_with(|| {
+            register_ca_with_base_crl();
+            assert_ok!(apply(crl_delta_same_update()));
+
+            assert_noop!(apply(crl_delta_base()), Error::<Test>::NotNewerCrl);
+        })
+    }
+
+    #[test]
+    fn apply_delta_crl_der() {
+        test().execute_with(|| {
+            register_ca_with_base_crl();
+
+            assert_ok!(CrlPallet::update_crl(
+                Origin::Signed(ALICE).into(),
+                CA_NAME.to_vec(),
+                CrlInput::from_der(crl_delta_2_der(), delta_chain_der()),
+            ));
+
+            assert_eq!(revoked_serials(), vec![vec![1], vec![3], vec![4], vec![5]]);
+        })
+    }
+
+    #[test]
+    fn apply_cumulative_delta_crls_on_the_same_base() {
+        test().execute_with(|| {
+            register_ca_with_base_crl();
+
+            assert_ok!(apply(crl_delta_2()));
+            assert_ok!(apply(crl_delta_3()));
+
+            assert_eq!(
+                revoked_serials(),
+                vec![vec![1], vec![3], vec![4], vec![5], vec![6]]
+            );
+            assert_eq!(
+                CertificateAuthorities::<Test>::get(bounded_name())
+                    .unwrap()
+                    .revoked_count,
+                5
+            );
+            System::assert_last_event(
+                Event::DeltaCrlApplied {
+                    ca_name: bounded_name(),
+                    issuer: issuer(),
+                    crl_number: 3,
+                    base_crl_number: 1,
+                    last_update: DELTA_2_THIS_UPDATE + 60,
+                    next_update: Some(DELTA_2_NEXT_UPDATE + 60),
+                    added: 1,
+                    removed: 0,
+                    revoked_count: 5,
+                }
+                .into(),
+            );
+        })
+    }
+
+    #[test]
+    fn skip_intermediate_delta_crls() {
+        test().execute_with(|| {
+            register_ca_with_base_crl();
+
+            assert_ok!(apply(crl_delta_3()));
+
+            assert_eq!(
+                revoked_serials(),
+                vec![vec![1], vec![3], vec![4], vec![5], vec![6]]
+            );
+        })
+    }
+
+    #[test]
+    fn apply_delta_crl_filling_the_issuer_up() {
+        test().execute_with(|| {
+            register_ca_with_base_crl();
+            let issuer_hash = sp_core::hashing::blake2_256(&issuer());
+            // `crl_delta_2` adds 2 revoked certificates and removes 1
+            IssuerRevokedCounts::<Test>::insert(
+                bounded_name(),
+                issuer_hash,
+                MAX_REVOKED_CERTS_PER_CRL - 1,
+            );
+
+            assert_ok!(apply(crl_delta_2()));
+
+            assert_eq!(
+                IssuerRevokedCounts::<Test>::get(bounded_name(), issuer_hash),
+                MAX_REVOKED_CERTS_PER_CRL
+            );
+        })
+    }
+
+    #[test]
+    fn err_on_delta_crl_overflowing_the_issuer() {
+        test().execute_with(|| {
+            register_ca_with_base_crl();
+            let issuer_hash = sp_core::hashing::blake2_256(&issuer());
+            IssuerRevokedCounts::<Test>::insert(
+                bounded_name(),
+                issuer_hash,
+                MAX_REVOKED_CERTS_PER_CRL,
+            );
+
+            assert_noop!(apply(crl_delta_2()), Error::<Test>::TooManyRevokedCerts);
+        })
+    }
+
+    #[test]
+    fn err_on_delta_crl_without_base_crl() {
+        test().execute_with(|| {
+            assert_ok!(CrlPallet::register_ca(
+                Origin::Root.into(),
+                CA_NAME.to_vec(),
+                delta_root_cert(),
+            ));
+
+            assert_noop!(apply(crl_delta_2()), Error::<Test>::MissingBaseCrl);
+        })
+    }
+
+    #[test]
+    fn err_on_delta_crl_based_on_a_newer_crl() {
+        test().execute_with(|| {
+            register_ca_with_base_crl();
+
+            assert_noop!(
+                apply(crl_delta_future_base()),
+                Error::<Test>::DeltaCrlBaseMismatch
+            );
+        })
+    }
+
+    #[test]
+    fn err_on_older_delta_crl() {
+        test().execute_with(|| {
+            register_ca_with_base_crl();
+            assert_ok!(apply(crl_delta_3()));
+
+            assert_noop!(apply(crl_delta_2()), Error::<Test>::NotNewerCrl);
+            assert_noop!(apply(crl_delta_3()), Error::<Test>::NotNewerCrl);
+        })
+    }
+
+    #[test]
+    fn unregister_ca_clears_crl_numbers() {
+        test().execute_with(|| {
+            register_ca_with_base_crl();
+
+            assert_ok!(CrlPallet::unregister_ca(
+                Origin::Root.into(),
+                CA_NAME.to_vec()
+            ));
+
+            assert_eq!(CrlNumbers::<Test>::iter_prefix(bounded_name()).count(), 0);
+            assert_eq!(
+                IssuerRevokedCounts::<Test>::iter_prefix(bounded_name()).count(),
+                0
+            );
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Root certificate rotation
+// ---------------------------------------------------------------------------
+
+mod root_rotation {
+    use super::*;
+    use pallet::RootRotations;
+
+    /// Length of the overlap window used by the tests (in secs).
+    const OVERLAP: u64 = 3600;
+
+    fn ca_name() -> &'static str {
+        core::str::from_utf8(CA_NAME).unwrap()
+    }
+
+    fn bounded_name() -> CaName<Test> {
+        CA_NAME.to_vec().try_into().unwrap()
+    }
+
+    /// Register the CA under `root_cert` and schedule its rotation to `delta_root_cert`.
+    fn register_ca_and_schedule_rotation() {
+        assert_ok!(CrlPallet::register_ca(
+            Origin::Root.into(),
+            CA_NAME.to_vec(),
+            root_cert(),
+        ));
+        assert_ok!(CrlPallet::schedule_root_rotation(
+            Origin::Root.into(),
+            CA_NAME.to_vec(),
+            delta_root_cert(),
+            OVERLAP,
+        ));
+    }
+
+    fn end_overlap() {
+        pallet_timestamp::Now::<Test>::put(PRESENT_MS + OVERLAP * 1000);
+    }
+
+    fn update_under_old_root() -> DispatchResultWithPostInfo {
+        CrlPallet::update_crl(
+            Origin::Signed(ALICE).into(),
+            CA_NAME.to_vec(),
+            CrlInput::from_pem(crl_inter1_v1(), chain1()),
+        )
+    }
+
+    fn update_under_new_root() -> DispatchResultWithPostInfo {
+        CrlPallet::update_crl(
+            Origin::Signed(ALICE).into(),
+            CA_NAME.to_vec(),
+            CrlInput::from_pem(crl_delta_base(), delta_chain()),
+        )
+    }
+
+    #[test]
+    fn schedule_root_rotation() {
+        test().execute_with(|| {
+            register_ca_and_schedule_rotation();
+
+            let overlap_end = PRESENT_MS / 1000 + OVERLAP;
+            let rotation = RootRotation {
+                new_root_cert: delta_root_cert().try_into().unwrap(),
+                overlap_end,
+            };
+            assert_eq!(
+                RootRotations::<Test>::get(bounded_name()),
+                Some(rotation.clone())
+            );
+            assert_eq!(CrlPallet::pending_root_rotation(CA_NAME), Some(rotation));
+            System::assert_last_event(
+                Event::RootRotationScheduled {
+                    ca_name: bounded_name(),
+                    overlap_end,
+                }
+                .into(),
+            );
+        })
+    }
+
+    #[test]
+    fn no_pending_rotation_by_default() {
+        test().execute_with(|| {
+            assert_ok!(CrlPallet::register_ca(
+                Origin::Root.into(),
+                CA_NAME.to_vec(),
+                root_cert(),
+            ));
+
+            assert_eq!(CrlPallet::pending_root_rotation(CA_NAME), None);
+        })
+    }
+
+    #[test]
+    fn a_new_schedule_replaces_the_pending_rotation() {
+        test().execute_with(|| {
+            register_ca_and_schedule_rotation();
+            assert_ok!(CrlPallet::schedule_root_rotation(
+                Origin::Root.into(),
+                CA_NAME.to_vec(),
+                intel_root_cert(),
+                2 * OVERLAP,
+            ));
+
+            let rotation = CrlPallet::pending_root_rotation(CA_NAME).unwrap();
+            assert_eq!(rotation.new_root_cert.to_vec(), intel_root_cert());
+            assert_eq!(rotation.overlap_end, PRESENT_MS / 1000 + 2 * OVERLAP);
+        })
+    }
+
+    #[test]
+    fn accept_crls_under_either_root_during_the_overlap() {
+        test().execute_with(|| {
+            register_ca_and_schedule_rotation();
+
+            assert_ok!(update_under_old_root());
+            assert_ok!(update_under_new_root());
+            assert_eq!(CrlIssuers::<Test>::iter_prefix(bounded_name()).count(), 2);
+        })
+    }
+
+    #[test]
+    fn reject_crls_under_the_old_root_after_the_overlap() {
+        test().execute_with(|| {
+            register_ca_and_schedule_rotation();
+            end_overlap();
+
+            assert_noop!(update_under_old_root(), Error::<Test>::CrlValidationError);
+            assert_ok!(update_under_new_root());
+        })
+    }
+
+    #[test]
+    fn get_root_cert_switches_at_the_end_of_the_overlap() {
+        test().execute_with(|| {
+            register_ca_and_schedule_rotation();
+            assert_eq!(
+                <CrlPallet as CrlProvider>::get_root_cert(ca_name()),
+                Ok(root_cert())
+            );
+
+            end_overlap();
+            assert_eq!(
+                <CrlPallet as CrlProvider>::get_root_cert(ca_name()),
+                Ok(delta_root_cert())
+            );
+        })
+    }
+
+    #[test]
+    fn complete_the_rotation_in_idle_time() {
+        test().execute_with(|| {
+            register_ca_and_schedule_rotation();
+
+            CrlPallet::on_idle(1, Weight::MAX);
+            assert!(RootRotations::<Test>::contains_key(bounded_name()));
+
+            end_overlap();
+            CrlPallet::on_idle(2, Weight::MAX);
+
+            assert!(!RootRotations::<Test>::contains_key(bounded_name()));
+            assert_eq!(
+                CertificateAuthorities::<Test>::get(bounded_name())
+                    .unwrap()
+                    .root_cert
+                    .to_vec(),
+                delta_root_cert()
+            );
+            System::assert_last_event(
+                Event::RootRotated {
+                    ca_name: bounded_name(),
+                }
+                .into(),
+            );
+            assert_noop!(update_under_old_root(), Error::<Test>::CrlValidationError);
+            assert_ok!(update_under_new_root());
+        })
+    }
+
+    #[test]
+    fn do_not_complete_the_rotation_without_enough_weight() {
+        test().execute_with(|| {
+            register_ca_and_schedule_rotation();
+            end_overlap();
+
+            assert_eq!(
+                CrlPallet::complete_root_rotations(Weight::zero()),
+                Weight::zero()
+            );
+
+            assert!(RootRotations::<Test>::contains_key(bounded_name()));
+        })
+    }
+
+    #[test]
+    fn revocations_survive_the_rotation() {
+        test().execute_with(|| {
+            register_ca_and_schedule_rotation();
+            assert_ok!(update_under_old_root());
+            let revoked = revoked_certs(&bounded_name());
+
+            end_overlap();
+            CrlPallet::on_idle(1, Weight::MAX);
+
+            assert_eq!(revoked_certs(&bounded_name()), revoked);
+        })
+    }
+
+    #[test]
+    fn unregister_ca_drops_the_pending_rotation() {
+        test().execute_with(|| {
+            register_ca_and_schedule_rotation();
+
+            assert_ok!(CrlPallet::unregister_ca(
+                Origin::Root.into(),
+                CA_NAME.to_vec()
+            ));
+
+            assert!(!RootRotations::<Test>::contains_key(bounded_name()));
+        })
+    }
+
+    #[test]
+    fn err_on_ca_not_found() {
+        test().execute_with(|| {
+            assert_noop!(
+                CrlPallet::schedule_root_rotation(
+                    Origin::Root.into(),
+                    CA_NAME.to_vec(),
+                    delta_root_cert(),
+                    OVERLAP,
+                ),
+                Error::<Test>::CaNotFound
+            );
+        })
+    }
+
+    #[test]
+    fn err_on_empty_root_cert() {
+        test().execute_with(|| {
+            register_ca_and_schedule_rotation();
+
+            assert_noop!(
+                CrlPallet::schedule_root_rotation(
+                    Origin::Root.into(),
+                    CA_NAME.to_vec(),
+                    vec![],
+                    OVERLAP,
+                ),
+                Error::<Test>::RootCertEmpty
+            );
+        })
+    }
+
+    #[test]
+    fn err_on_too_large_root_cert() {
+        test().execute_with(|| {
+            register_ca_and_schedule_rotation();
+
+            assert_noop!(
+                CrlPallet::schedule_root_rotation(
+                    Origin::Root.into(),
+                    CA_NAME.to_vec(),
+                    vec![0; MAX_ROOT_CERT_LENGTH as usize + 1],
+                    OVERLAP,
+                ),
+                Error::<Test>::RootCertTooLarge
+            );
+        })
+    }
+
+    #[test]
+    fn err_on_wrong_origin() {
+        test().execute_with(|| {
+            assert_ok!(CrlPallet::register_ca(
+                Origin::Root.into(),
+                CA_NAME.to_vec(),
+                root_cert(),
+            ));
+
+            assert_noop!(
+                CrlPallet::schedule_root_rotation(
+                    Origin::Signed(ALICE).into(),
+                    CA_NAME.to_vec(),
+                    delta_root_cert(),
+                    OVERLAP,
+                ),
+                sp_runtime::traits::BadOrigin
+            );
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Offchain worker
+// ---------------------------------------------------------------------------
+
+mod offchain_worker {
+    use super::*;
+    use codec::Decode;
+    use frame_support::traits::Hooks;
+    use pallet::{Call, DistributionPoints};
+    use sp_core::offchain::{
+        testing::{OffchainState, PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+        OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+    };
+    use sp_runtime::transaction_validity::{
+        InvalidTransaction, TransactionSource, TransactionValidityError, ValidateUnsigned,
+    };
+
+    const PEM_URL: &str = "https://crl.example.com/inter1.pem";
+    const DER_URL: &str = "https://crl.example.com/inter1.der";
+
+    fn bounded_name() -> CaName<Test> {
+        CA_NAME.to_vec().try_into().unwrap()
+    }
+
+    fn point(url: &str, format: CrlFormat, cert_chain: Vec<u8>) -> DistributionPoint {
+        DistributionPoint {
+            url: url.as_bytes().to_vec().try_into().unwrap(),
+            format,
+            cert_chain: cert_chain.try_into().unwrap(),
+        }
+    }
+
+    fn register_ca_with_points(points: Vec<DistributionPoint>) {
+        assert_ok!(CrlPallet::register_ca(
+            Origin::Root.into(),
+            CA_NAME.to_vec(),
+            root_cert(),
+        ));
+        assert_ok!(CrlPallet::set_distribution_points(
+            Origin::Root.into(),
+            CA_NAME.to_vec(),
+            points,
+        ));
+    }
+
+    fn expect_get(state: &mut OffchainState, url: &str, response: Vec<u8>) {
+        state.expect_request(PendingRequest {
+            method: "GET".into(),
+            uri: url.into(),
+            response: Some(response),
+            sent: true,
+            ..Default::default()
+        });
+    }
+
+    /// Run `f` with mocked offchain HTTP responses for the given URLs. `f` gets a function
+    /// returning the calls submitted to the transaction pool so far.
+    fn with_offchain(
+        responses: Vec<(&str, Vec<u8>)>,
+        f: impl FnOnce(&dyn Fn() -> Vec<RuntimeCall>),
+    ) {
+        let (offchain, state) = TestOffchainExt::new();
+        let (pool, pool_state) = TestTransactionPoolExt::new();
+        for (url, response) in responses {
+            expect_get(&mut state.write(), url, response);
+        }
+
+        let mut ext = test();
+        ext.register_extension(OffchainDbExt::new(offchain.clone()));
+        ext.register_extension(OffchainWorkerExt::new(offchain));
+        ext.register_extension(TransactionPoolExt::new(pool));
+        ext.execute_with(|| {
+            f(&|| {
+                pool_state
+                    .read()
+                    .transactions
+                    .iter()
+                    .map(|tx| {
+                        frame_system::mocking::MockUncheckedExtrinsic::<Test>::decode(&mut &tx[..])
+                            .unwrap()
+                            .function
+                    })
+                    .collect()
+            })
+        });
+    }
+
+    fn submit_call(crl_input: CrlInput) -> Call<Test> {
+        Call::submit_crl_unsigned {
+            ca_name: CA_NAME.to_vec(),
+            crl_input,
+        }
+    }
+
+    fn run_offchain_worker() {
+        CrlPallet::offchain_worker(OffchainWorkerInterval::get());
+    }
+
+    #[test]
+    fn submit_a_newer_crl() {
+        with_offchain(vec![(PEM_URL, crl_inter1_v1())], |submitted| {
+            register_ca_with_points(vec![point(PEM_URL, CrlFormat::Pem, chain1())]);
+
+            run_offchain_worker();
+
+            let crl_input = CrlInput::from_pem(crl_inter1_v1(), chain1());
+            assert_eq!(
+                submitted(),
+                vec![RuntimeCall::from(submit_call(crl_input.clone()))]
+            );
+
+            assert_ok!(CrlPallet::submit_crl_unsigned(
+                Origin::None.into(),
+                CA_NAME.to_vec(),
+                crl_input,
+            ));
+            assert_eq!(revoked_certs(&bounded_name()).len(), 3);
+        })
+    }
+
+    #[test]
+    fn submit_a_newer_der_crl() {
+        with_offchain(vec![(DER_URL, crl_inter1_v1_der())], |submitted| {
+            register_ca_with_points(vec![point(DER_URL, CrlFormat::Der, chain1_der())]);
+
+            run_offchain_worker();
+
+            assert_eq!(
+                submitted(),
+                vec![RuntimeCall::from(submit_call(CrlInput::from_der(
+                    crl_inter1_v1_der(),
+                    chain1_der()
+                )))]
+            );
+        })
+    }
+
+    #[test]
+    fn skip_the_crl_already_stored() {
+        with_offchain(vec![(PEM_URL, crl_inter1_v1())], |submitted| {
+            register_ca_with_points(vec![point(PEM_URL, CrlFormat::Pem, chain1())]);
+            assert_ok!(CrlPallet::update_crl(
+                Origin::Signed(ALICE).into(),
+                CA_NAME.to_vec(),
+                CrlInput::from_pem(crl_inter1_v1(), chain1()),
+            ));
+
+            run_offchain_worker();
+
+            assert!(submitted().is_empty());
+        })
+    }
+
+    #[test]
+    fn skip_invalid_crls() {
+        with_offchain(
+            vec![(PEM_URL, crl_inter2()), (DER_URL, b"not a CRL".to_vec())],
+            |submitted| {
+                register_ca_with_points(vec![
+                    point(PEM_URL, CrlFormat::Pem, chain1()),
+                    point(DER_URL, CrlFormat::Der, chain1_der()),
+                ]);
+
+                run_offchain_worker();
+
+                assert!(submitted().is_empty());
+            },
+        )
+    }
+
+    #[test]
+    fn skip_oversized_crls() {
+        with_offchain(
+            vec![(DER_URL, vec![0; MAX_CRL_DER_LENGTH as usize + 1])],
+            |submitted| {
+                register_ca_with_points(vec![point(DER_URL, CrlFormat::Der, chain1_der())]);
+
+                run_offchain_worker();
+
+                assert!(submitted().is_empty());
+            },
+        )
+    }
+
+    #[test]
+    fn run_only_every_interval() {
+        // No request is expected: any would fail the test
+        with_offchain(vec![], |submitted| {
+            register_ca_with_points(vec![point(PEM_URL, CrlFormat::Pem, chain1())]);
+
+            CrlPallet::offchain_worker(OffchainWorkerInterval::get() + 1);
+
+            assert!(submitted().is_empty());
+        })
+    }
+
+    #[test]
+    fn validate_a_newer_crl() {
+        test().execute_with(|| {
+            register_ca_with_points(vec![point(PEM_URL, CrlFormat::Pem, chain1())]);
+
+            let call = submit_call(CrlInput::from_pem(crl_inter1_v1(), chain1()));
+            let validity =
+                CrlPallet::validate_unsigned(TransactionSource::External, &call).unwrap();
+
+            assert_eq!(validity.priority, 100);
+            assert_eq!(validity.provides.len(), 1);
+        })
+    }
+
+    #[test]
+    fn reject_a_crl_not_newer() {
+        test().execute_with(|| {
+            register_ca_with_points(vec![point(PEM_URL, CrlFormat::Pem, chain1())]);
+            let crl_input = CrlInput::from_pem(crl_inter1_v1(), chain1());
+            assert_ok!(CrlPallet::submit_crl_unsigned(
+                Origin::None.into(),
+                CA_NAME.to_vec(),
+                crl_input.clone(),
+            ));
+
+            assert_eq!(
+                CrlPallet::validate_unsigned(TransactionSource::External, &submit_call(crl_input)),
+                Err(TransactionValidityError::Invalid(InvalidTransaction::Stale))
+            );
+        })
+    }
+
+    #[test]
+    fn reject_an_invalid_crl() {
+        test().execute_with(|| {
+            register_ca_with_points(vec![point(PEM_URL, CrlFormat::Pem, chain1())]);
+
+            let call = submit_call(CrlInput::from_pem(crl_inter2(), chain1()));
+            assert_eq!(
+                CrlPallet::validate_unsigned(TransactionSource::External, &call),
+                Err(TransactionValidityError::Invalid(
+                    InvalidTransaction::BadProof
+                ))
+            );
+        })
+    }
+
+    #[test]
+    fn reject_crls_of_cas_without_distribution_points() {
+        test().execute_with(|| {
+            assert_ok!(CrlPallet::register_ca(
+                Origin::Root.into(),
+                CA_NAME.to_vec(),
+                root_cert(),
+            ));
+
+            let call = submit_call(CrlInput::from_pem(crl_inter1_v1(), chain1()));
+            assert_eq!(
+                CrlPallet::validate_unsigned(TransactionSource::External, &call),
+                Err(TransactionValidityError::Invalid(InvalidTransaction::Call))
+            );
+        })
+    }
+
+    #[test]
+    fn err_on_signed_submission() {
+        test().execute_with(|| {
+            register_ca_with_points(vec![point(PEM_URL, CrlFormat::Pem, chain1())]);
+
+            assert_noop!(
+                CrlPallet::submit_crl_unsigned(
+                    Origin::Signed(ALICE).into(),
+                    CA_NAME.to_vec(),
+                    CrlInput::from_pem(crl_inter1_v1(), chain1()),
+                ),
+                sp_runtime::traits::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn set_distribution_points() {
+        test().execute_with(|| {
+            let points = vec![
+                point(PEM_URL, CrlFormat::Pem, chain1()),
+                point(DER_URL, CrlFormat::Der, chain1_der()),
+            ];
+            register_ca_with_points(points.clone());
+
+            assert_eq!(
+                DistributionPoints::<Test>::get(bounded_name())
+                    .unwrap()
+                    .to_vec(),
+                points
+            );
+            System::assert_last_event(
+                Event::DistributionPointsSet {
+                    ca_name: bounded_name(),
+                    count: 2,
+                }
+                .into(),
+            );
+        })
+    }
+
+    #[test]
+    fn clear_distribution_points() {
+        test().execute_with(|| {
+            register_ca_with_points(vec![point(PEM_URL, CrlFormat::Pem, chain1())]);
+
+            assert_ok!(CrlPallet::set_distribution_points(
+                Origin::Root.into(),
+                CA_NAME.to_vec(),
+                vec![],
+            ));
+
+            assert!(!DistributionPoints::<Test>::contains_key(bounded_name()));
+        })
+    }
+
+    #[test]
+    fn unregister_ca_clears_distribution_points() {
+        test().execute_with(|| {
+            register_ca_with_points(vec![point(PEM_URL, CrlFormat::Pem, chain1())]);
+
+            assert_ok!(CrlPallet::unregister_ca(
+                Origin::Root.into(),
+                CA_NAME.to_vec()
+            ));
+
+            assert!(!DistributionPoints::<Test>::contains_key(bounded_name()));
+        })
+    }
+
+    #[test]
+    fn err_on_too_many_distribution_points() {
+        test().execute_with(|| {
+            assert_ok!(CrlPallet::register_ca(
+                Origin::Root.into(),
+                CA_NAME.to_vec(),
+                root_cert(),
+            ));
+
+            assert_noop!(
+                CrlPallet::set_distribution_points(
+                    Origin::Root.into(),
+                    CA_NAME.to_vec(),
+                    vec![
+                        point(PEM_URL, CrlFormat::Pem, chain1());
+                        MAX_DISTRIBUTION_POINTS_PER_CA as usize + 1
+                    ],
+                ),
+                Error::<Test>::TooManyDistributionPoints
+            );
+        })
+    }
+
+    #[test]
+    fn err_on_invalid_url() {
+        test().execute_with(|| {
+            assert_ok!(CrlPallet::register_ca(
+                Origin::Root.into(),
+                CA_NAME.to_vec(),
+                root_cert(),
+            ));
+
+            let mut invalid = point(PEM_URL, CrlFormat::Pem, chain1());
+            invalid.url = vec![0xff, 0xfe].try_into().unwrap();
+            assert_noop!(
+                CrlPallet::set_distribution_points(
+                    Origin::Root.into(),
+                    CA_NAME.to_vec(),
+                    vec![invalid],
+                ),
+                Error::<Test>::InvalidDistributionPointUrl
+            );
+        })
+    }
+
+    #[test]
+    fn set_distribution_points_err_on_ca_not_found() {
+        test().execute_with(|| {
+            assert_noop!(
+                CrlPallet::set_distribution_points(
+                    Origin::Root.into(),
+                    CA_NAME.to_vec(),
+                    vec![point(PEM_URL, CrlFormat::Pem, chain1())],
+                ),
+                Error::<Test>::CaNotFound
+            );
+        })
+    }
+
+    #[test]
+    fn set_distribution_points_err_on_wrong_origin() {
+        test().execute_with(|| {
+            assert_ok!(CrlPallet::register_ca(
+                Origin::Root.into(),
+                CA_NAME.to_vec(),
+                root_cert(),
+            ));
+
+            assert_noop!(
+                CrlPallet::set_distribution_points(
+                    Origin::Signed(ALICE).into(),
+                    CA_NAME.to_vec(),
+                    vec![point(PEM_URL, CrlFormat::Pem, chain1())],
+                ),
+                sp_runtime::traits::BadOrigin
+            );
+        })
+    }
 }
@@ -59,40 +59,61 @@ pub trait WeightInfo {
     fn unregister_ca() -> Weight;
     fn update_pem_crl(n: u32, ) -> Weight;
     fn update_der_crl(n: u32, ) -> Weight;
-    fn clear_crl() -> Weight;
+    fn clear_crl(n: u32, ) -> Weight;
+    fn update_tcb_info() -> Weight;
+    fn set_staleness_policy() -> Weight;
+    fn schedule_root_rotation() -> Weight;
+    fn complete_root_rotation() -> Weight;
+    fn set_distribution_points() -> Weight;
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
     /// Storage: `Crl::CertificateAuthorities` (r:1 w:1)
     /// Proof: `Crl::CertificateAuthorities` (`max_values`: None, `max_size`: Some(2144), added: 4619, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::PendingRemovals` (r:1 w:0)
+    /// Proof: `Crl::PendingRemovals` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
     fn register_ca() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `76`
         //  Estimated: `5609`
         // Minimum execution time: 24_428_000 picoseconds.
         Weight::from_parts(25_601_000, 5609)
-            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
             .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
     /// Storage: `Crl::CertificateAuthorities` (r:0 w:1)
     /// Proof: `Crl::CertificateAuthorities` (`max_values`: None, `max_size`: Some(2144), added: 4619, mode: `MaxEncodedLen`)
-    /// Storage: `Crl::Revoked` (r:0 w:1)
-    /// Proof: `Crl::Revoked` (`max_values`: None, `max_size`: Some(3240084), added: 3242559, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::CrlIssuers` (r:0 w:10)
+    /// Proof: `Crl::CrlIssuers` (`max_values`: None, `max_size`: Some(371), added: 2846, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::CrlNumbers` (r:0 w:10)
+    /// Proof: `Crl::CrlNumbers` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::PendingRemovals` (r:0 w:1)
+    /// Proof: `Crl::PendingRemovals` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::RootRotations` (r:0 w:1)
+    /// Proof: `Crl::RootRotations` (`max_values`: None, `max_size`: Some(2139), added: 4614, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::DistributionPoints` (r:0 w:1)
+    /// Proof: `Crl::DistributionPoints` (`max_values`: None, `max_size`: Some(67694), added: 70169, mode: `MaxEncodedLen`)
     fn unregister_ca() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `0`
         //  Estimated: `0`
         // Minimum execution time: 16_860_000 picoseconds.
         Weight::from_parts(17_442_000, 0)
-            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(24_u64))
     }
     /// Storage: `Crl::CertificateAuthorities` (r:1 w:1)
     /// Proof: `Crl::CertificateAuthorities` (`max_values`: None, `max_size`: Some(2144), added: 4619, mode: `MaxEncodedLen`)
     /// Storage: `Timestamp::Now` (r:1 w:0)
     /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-    /// Storage: `Crl::Revoked` (r:0 w:1)
-    /// Proof: `Crl::Revoked` (`max_values`: None, `max_size`: Some(3240084), added: 3242559, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::RevokedCerts` (r:0 w:1000)
+    /// Proof: `Crl::RevokedCerts` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::CrlIssuers` (r:0 w:1)
+    /// Proof: `Crl::CrlIssuers` (`max_values`: None, `max_size`: Some(371), added: 2846, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::CrlNumbers` (r:1 w:1)
+    /// Proof: `Crl::CrlNumbers` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::RootRotations` (r:1 w:0)
+    /// Proof: `Crl::RootRotations` (`max_values`: None, `max_size`: Some(2139), added: 4614, mode: `MaxEncodedLen`)
     /// The range of component `n` is `[1, 1000]`.
     fn update_pem_crl(n: u32, ) -> Weight {
         // Proof Size summary in bytes:
@@ -102,15 +123,22 @@ impl WeightInfo for () {
         Weight::from_parts(5_503_496_899, 5609)
             // Standard Error: 34_643
             .saturating_add(Weight::from_parts(1_891_034, 0).saturating_mul(n.into()))
-            .saturating_add(RocksDbWeight::get().reads(2_u64))
-            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
     }
     /// Storage: `Crl::CertificateAuthorities` (r:1 w:1)
     /// Proof: `Crl::CertificateAuthorities` (`max_values`: None, `max_size`: Some(2144), added: 4619, mode: `MaxEncodedLen`)
     /// Storage: `Timestamp::Now` (r:1 w:0)
     /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-    /// Storage: `Crl::Revoked` (r:0 w:1)
-    /// Proof: `Crl::Revoked` (`max_values`: None, `max_size`: Some(3240084), added: 3242559, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::RevokedCerts` (r:0 w:1000)
+    /// Proof: `Crl::RevokedCerts` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::CrlIssuers` (r:0 w:1)
+    /// Proof: `Crl::CrlIssuers` (`max_values`: None, `max_size`: Some(371), added: 2846, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::CrlNumbers` (r:1 w:1)
+    /// Proof: `Crl::CrlNumbers` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::RootRotations` (r:1 w:0)
+    /// Proof: `Crl::RootRotations` (`max_values`: None, `max_size`: Some(2139), added: 4614, mode: `MaxEncodedLen`)
     /// The range of component `n` is `[1, 1000]`.
     fn update_der_crl(n: u32, ) -> Weight {
         // Proof Size summary in bytes:
@@ -119,17 +147,83 @@ impl WeightInfo for () {
         // Use update_pem_crl weights as conservative upper bound until proper benchmarks are run.
         Weight::from_parts(5_503_496_899, 5609)
             .saturating_add(Weight::from_parts(1_891_034, 0).saturating_mul(n.into()))
-            .saturating_add(RocksDbWeight::get().reads(2_u64))
-            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
     }
-    /// Storage: `Crl::Revoked` (r:0 w:1)
-    /// Proof: `Crl::Revoked` (`max_values`: None, `max_size`: Some(3240084), added: 3242559, mode: `MaxEncodedLen`)
-    fn clear_crl() -> Weight {
+    /// Storage: `Crl::RevokedCerts` (r:0 w:1000)
+    /// Proof: `Crl::RevokedCerts` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::PendingRemovals` (r:1 w:1)
+    /// Proof: `Crl::PendingRemovals` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 1000]`.
+    fn clear_crl(n: u32, ) -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `0`
-        //  Estimated: `0`
+        //  Estimated: `3546`
         // Minimum execution time: 2_596_000 picoseconds.
-        Weight::from_parts(2_777_000, 0)
+        Weight::from_parts(2_777_000, 3546)
+            // Per-certificate cost is dominated by the storage writes until proper benchmarks are run.
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+    }
+    fn update_tcb_info() -> Weight {
+        // TODO: regenerate with the `update_tcb_info` benchmark. Until then, use the Intel quote
+        // verification weight of the TEE verifier as an upper bound: it checks the TCB info
+        // signature and chain too, besides the quote ones. The proof size sums the bounds of
+        // the `Crl::CertificateAuthorities`, 2 `Crl::RevokedCerts`, `Timestamp::Now`,
+        // `Crl::TcbInfos` and `Crl::RootRotations` reads.
+        Weight::from_parts(8_811_384_000, 33915)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Crl::CertificateAuthorities` (r:1 w:1)
+    /// Proof: `Crl::CertificateAuthorities` (`max_values`: None, `max_size`: Some(2144), added: 4619, mode: `MaxEncodedLen`)
+    fn set_staleness_policy() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `601`
+        //  Estimated: `5609`
+        // Use register_ca weights as conservative upper bound until proper benchmarks are run.
+        Weight::from_parts(25_601_000, 5609)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Crl::CertificateAuthorities` (r:1 w:0)
+    /// Proof: `Crl::CertificateAuthorities` (`max_values`: None, `max_size`: Some(2144), added: 4619, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::RootRotations` (r:0 w:1)
+    /// Proof: `Crl::RootRotations` (`max_values`: None, `max_size`: Some(2139), added: 4614, mode: `MaxEncodedLen`)
+    fn schedule_root_rotation() -> Weight {
+        // TODO: regenerate with the `schedule_root_rotation` benchmark. Until then, use the
+        // `register_ca` execution time: it does the same work, storing a root certificate. The
+        // proof size sums the bounds of the storage reads above.
+        Weight::from_parts(25_601_000, 5122)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Crl::RootRotations` (r:0 w:1)
+    /// Proof: `Crl::RootRotations` (`max_values`: None, `max_size`: Some(2139), added: 4614, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::CertificateAuthorities` (r:1 w:1)
+    /// Proof: `Crl::CertificateAuthorities` (`max_values`: None, `max_size`: Some(2144), added: 4619, mode: `MaxEncodedLen`)
+    fn complete_root_rotation() -> Weight {
+        // TODO: regenerate with the `complete_root_rotation` benchmark. Until then, use the
+        // `register_ca` execution time: it does the same work, storing a root certificate. The
+        // proof size sums the bounds of the storage reads above.
+        Weight::from_parts(25_601_000, 4619)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Crl::CertificateAuthorities` (r:1 w:0)
+    /// Proof: `Crl::CertificateAuthorities` (`max_values`: None, `max_size`: Some(2144), added: 4619, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::DistributionPoints` (r:0 w:1)
+    /// Proof: `Crl::DistributionPoints` (`max_values`: None, `max_size`: Some(67694), added: 70169, mode: `MaxEncodedLen`)
+    fn set_distribution_points() -> Weight {
+        // TODO: regenerate with the `set_distribution_points` benchmark. Until then, use the
+        // `register_ca` execution time, and the bounds of both the storage items above as
+        // proof size: the whole distribution points entry can be replaced.
+        Weight::from_parts(25_601_000, 74788)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
             .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
 }
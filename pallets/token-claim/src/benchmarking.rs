@@ -16,20 +16,26 @@
 use super::*;
 use crate::beneficiary::Beneficiary;
 use crate::utils::{secp_utils::*, *};
-use crate::{EthereumAddress, EthereumSignature};
+use crate::{
+    BitcoinAddress, BitcoinSignature, EthereumAddress, EthereumSignature, SolanaAddress,
+    SolanaSignature,
+};
 use codec::{Decode, Encode};
 use frame_benchmarking::v2::*;
 use frame_support::traits::UnfilteredDispatchable;
 use frame_system::RawOrigin;
-use sp_io::crypto::{ecdsa_generate, ecdsa_sign};
+use sp_core::H256;
+use sp_io::crypto::{ecdsa_generate, ecdsa_sign, ed25519_generate, ed25519_sign};
 use sp_runtime::{
-    traits::{IdentifyAccount, ValidateUnsigned},
+    traits::{Hash, IdentifyAccount, Keccak256, ValidateUnsigned},
     MultiSignature, MultiSigner, Saturating,
 };
 
 pub trait BenchmarkHelper<Signature, Signer> {
     fn sign_claim(message: &[u8]) -> (Signature, Signer);
-    fn sign_claim_ethereum(message: &[u8]) -> (EthereumSignature, EthereumAddress);
+    fn sign_claim_ethereum_hash(msg_hash: &[u8; 32]) -> (EthereumSignature, EthereumAddress);
+    fn sign_claim_solana(message: &[u8]) -> (SolanaSignature, SolanaAddress);
+    fn sign_claim_bitcoin(message: &[u8]) -> (BitcoinSignature, BitcoinAddress);
 }
 
 impl BenchmarkHelper<MultiSignature, MultiSigner> for () {
@@ -42,9 +48,19 @@ impl BenchmarkHelper<MultiSignature, MultiSigner> for () {
         (signature, signer)
     }
 
-    fn sign_claim_ethereum(message: &[u8]) -> (EthereumSignature, EthereumAddress) {
+    fn sign_claim_ethereum_hash(msg_hash: &[u8; 32]) -> (EthereumSignature, EthereumAddress) {
         let sk = secret_from_seed(b"//EthBeneficiary");
-        (sig(&sk, message), eth(&sk))
+        (sig_hash(&sk, msg_hash), eth(&sk))
+    }
+
+    fn sign_claim_solana(message: &[u8]) -> (SolanaSignature, SolanaAddress) {
+        let public = ed25519_generate(0.into(), Some(b"//SolBeneficiary".to_vec()));
+        (ed25519_sign(0.into(), &public, message).unwrap(), public.0)
+    }
+
+    fn sign_claim_bitcoin(message: &[u8]) -> (BitcoinSignature, BitcoinAddress) {
+        let sk = secret_from_seed(b"//BtcBeneficiary");
+        (btc_sig(&sk, message, 31), btc(&sk))
     }
 }
 
@@ -56,6 +72,29 @@ fn get_claim_message<T: Config>() -> BoundedVec<u8, <T as Config>::MaxClaimMessa
     .unwrap()
 }
 
+const CAMPAIGN_ID: CampaignId = 0;
+
+fn vesting_config<T: Config>() -> VestingConfigOf<T> {
+    VestingConfig {
+        start: 10u32.into(),
+        duration: 100u32.into(),
+    }
+}
+
+fn vested_campaign_config<T: Config>() -> CampaignConfigOf<T> {
+    CampaignConfig {
+        vesting: Some(vesting_config::<T>()),
+        ..Default::default()
+    }
+}
+
+fn insert_beneficiary<T: Config>(beneficiary: Beneficiary<T>, amount: BalanceOf<T>) {
+    // Worst case: the beneficiary overrides the campaign vesting schedule
+    BeneficiaryVesting::<T>::insert(CAMPAIGN_ID, &beneficiary, vesting_config::<T>());
+    Beneficiaries::<T>::insert(CAMPAIGN_ID, beneficiary, amount);
+    BeneficiariesCount::<T>::mutate(CAMPAIGN_ID, |count| *count += 1);
+}
+
 fn init_claim_state<T: Config>(
     n: u32,
     begin_claim: bool,
@@ -63,7 +102,7 @@ fn init_claim_state<T: Config>(
     let (beneficiaries, total_amount) = get_beneficiaries_map::<T>(n);
     if n > 0 {
         let _ = T::Currency::mint_into(
-            &Pallet::<T>::account_id(),
+            &Pallet::<T>::campaign_account_id(CAMPAIGN_ID),
             total_amount.saturating_mul(2u32.into()), // Just to be extra safe
         )
         .unwrap();
@@ -72,14 +111,80 @@ fn init_claim_state<T: Config>(
     if begin_claim {
         Pallet::<T>::begin_claim(
             RawOrigin::Root.into(),
+            CAMPAIGN_ID,
             beneficiaries.clone(),
             get_claim_message::<T>(),
+            vested_campaign_config::<T>(),
         )
         .unwrap();
     }
     beneficiaries
 }
 
+fn init_merkle_claim_state<T: Config>(
+    beneficiary: &Beneficiary<T>,
+    amount: BalanceOf<T>,
+    depth: u32,
+) -> MerkleProof {
+    let path = (0..depth)
+        .map(|i| H256::repeat_byte(i as u8 + 1))
+        .collect::<Vec<_>>();
+    // In a full tree, the leftmost leaf is always hashed on the left
+    let root = path.iter().fold(
+        Keccak256::hash(&Pallet::<T>::merkle_leaf(beneficiary, amount)),
+        |node, sibling| Keccak256::hash(&[node.as_bytes(), sibling.as_bytes()].concat()),
+    );
+
+    let _ = T::Currency::mint_into(
+        &Pallet::<T>::campaign_account_id(CAMPAIGN_ID),
+        amount.saturating_mul(2u32.into()), // Just to be extra safe
+    )
+    .unwrap();
+    Pallet::<T>::begin_merkle_claim(
+        RawOrigin::Root.into(),
+        CAMPAIGN_ID,
+        MerkleCommitment {
+            root,
+            number_of_leaves: 1 << depth,
+        },
+        amount,
+        get_claim_message::<T>(),
+        vested_campaign_config::<T>(),
+    )
+    .unwrap();
+
+    MerkleProof {
+        leaf_index: 0,
+        path: BoundedVec::truncate_from(path),
+    }
+}
+
+fn zk_commitment<T: Config>() -> ZkCommitmentOf<T> {
+    let (verifier, vk_hash) = T::ClaimProofVerifier::register_benchmark_vk();
+    ZkCommitment {
+        root: H256::repeat_byte(1),
+        verifier,
+        vk_hash,
+    }
+}
+
+fn init_zk_claim_state<T: Config>(amount: BalanceOf<T>) {
+    let _ = T::Currency::mint_into(
+        &Pallet::<T>::campaign_account_id(CAMPAIGN_ID),
+        amount.saturating_mul(2u32.into()), // Just to be extra safe
+    )
+    .unwrap();
+    Pallet::<T>::begin_zk_claim(
+        RawOrigin::Root.into(),
+        CAMPAIGN_ID,
+        zk_commitment::<T>(),
+        amount,
+        get_claim_message::<T>(),
+        vested_campaign_config::<T>(),
+    )
+    .unwrap();
+}
+
 #[benchmarks]
 mod benchmarks {
 
@@ -90,7 +195,13 @@ mod benchmarks {
         let beneficiaries = init_claim_state::<T>(0, false);
 
         #[extrinsic_call]
-        begin_claim(RawOrigin::Root, beneficiaries, get_claim_message::<T>());
+        begin_claim(
+            RawOrigin::Root,
+            CAMPAIGN_ID,
+            beneficiaries,
+            get_claim_message::<T>(),
+            Default::default(),
+        );
     }
 
     #[benchmark]
@@ -104,10 +215,11 @@ mod benchmarks {
         // Insert beneficiary
         let amount =
             BalanceOf::<T>::from(T::Currency::minimum_balance().saturating_add(1u32.into()));
-        Beneficiaries::<T>::insert(beneficiary.clone(), amount);
-        assert!(Beneficiaries::<T>::get(beneficiary.clone()).is_some());
+        insert_beneficiary::<T>(beneficiary.clone(), amount);
+        assert!(Beneficiaries::<T>::get(CAMPAIGN_ID, beneficiary.clone()).is_some());
 
         let call_enc = Call::<T>::claim {
+            campaign_id: CAMPAIGN_ID,
             beneficiary: signer,
             signature,
         }
@@ -122,7 +234,7 @@ mod benchmarks {
         }
 
         // sanity check
-        assert!(Beneficiaries::<T>::get(beneficiary).is_none());
+        assert!(Beneficiaries::<T>::get(CAMPAIGN_ID, beneficiary).is_none());
     }
 
     #[benchmark]
@@ -131,22 +243,24 @@ mod benchmarks {
 
         let msg = get_claim_message::<T>();
         let dest: T::AccountId = account("test dest", 0, 0);
-        let claim_message = [
+        let amount =
+            BalanceOf::<T>::from(T::Currency::minimum_balance().saturating_add(1u32.into()));
+        // Worst case: the EIP-712 signature is verified after the EIP-191 one
+        let msg_hash = eip712_hash_claim::<T>(
+            CAMPAIGN_ID,
             msg.as_slice(),
-            T::EthMsgSeparator::get(),
-            T::AccountIdBytesToSign::to_bytes_literal(&dest).as_slice(),
-        ]
-        .concat();
-        let (signature, signer) = T::BenchmarkHelper::sign_claim_ethereum(claim_message.as_slice());
+            &dest,
+            amount.unique_saturated_into(),
+        );
+        let (signature, signer) = T::BenchmarkHelper::sign_claim_ethereum_hash(&msg_hash);
         let beneficiary = Beneficiary::<T>::Ethereum(signer.clone());
 
         // Insert beneficiary
-        let amount =
-            BalanceOf::<T>::from(T::Currency::minimum_balance().saturating_add(1u32.into()));
-        Beneficiaries::<T>::insert(beneficiary.clone(), amount);
-        assert!(Beneficiaries::<T>::get(beneficiary.clone()).is_some());
+        insert_beneficiary::<T>(beneficiary.clone(), amount);
+        assert!(Beneficiaries::<T>::get(CAMPAIGN_ID, beneficiary.clone()).is_some());
 
         let call_enc = Call::<T>::claim_ethereum {
+            campaign_id: CAMPAIGN_ID,
             beneficiary: signer,
             signature,
             dest,
@@ -162,7 +276,7 @@ mod benchmarks {
         }
 
         // sanity check
-        assert!(Beneficiaries::<T>::get(beneficiary).is_none());
+        assert!(Beneficiaries::<T>::get(CAMPAIGN_ID, beneficiary).is_none());
     }
 
     #[benchmark]
@@ -171,13 +285,14 @@ mod benchmarks {
 
         let beneficiary_account: T::AccountId = account("", 10, 10);
         let beneficiary = Beneficiary::<T>::Substrate(beneficiary_account.clone());
-        assert!(Beneficiaries::<T>::get(&beneficiary).is_some());
+        assert!(Beneficiaries::<T>::get(CAMPAIGN_ID, &beneficiary).is_some());
+        BeneficiaryVesting::<T>::insert(CAMPAIGN_ID, &beneficiary, vesting_config::<T>());
 
         #[extrinsic_call]
-        claim_for(RawOrigin::Root, beneficiary_account);
+        claim_for(RawOrigin::Root, CAMPAIGN_ID, beneficiary_account);
 
         // sanity check
-        assert!(Beneficiaries::<T>::get(beneficiary).is_none());
+        assert!(Beneficiaries::<T>::get(CAMPAIGN_ID, beneficiary).is_none());
     }
 
     #[benchmark]
@@ -192,14 +307,14 @@ mod benchmarks {
         // Insert beneficiary
         let amount =
             BalanceOf::<T>::from(T::Currency::minimum_balance().saturating_add(1u32.into()));
-        Beneficiaries::<T>::insert(beneficiary.clone(), amount);
-        assert!(Beneficiaries::<T>::get(beneficiary.clone()).is_some());
+        insert_beneficiary::<T>(beneficiary.clone(), amount);
+        assert!(Beneficiaries::<T>::get(CAMPAIGN_ID, beneficiary.clone()).is_some());
 
         #[extrinsic_call]
-        claim_ethereum_for(RawOrigin::Root, eth_addr, dest);
+        claim_ethereum_for(RawOrigin::Root, CAMPAIGN_ID, eth_addr, dest);
 
         // sanity check
-        assert!(Beneficiaries::<T>::get(beneficiary).is_none());
+        assert!(Beneficiaries::<T>::get(CAMPAIGN_ID, beneficiary).is_none());
     }
 
     #[benchmark]
@@ -207,54 +322,289 @@ mod benchmarks {
         // Init claim
         Pallet::<T>::begin_claim(
             RawOrigin::Root.into(),
+            CAMPAIGN_ID,
             BTreeMap::new(),
             get_claim_message::<T>(),
+            Default::default(),
         )
         .unwrap();
 
         // Prepare beneficiaries and sufficient amount
         let (beneficiaries, total_amount) = get_beneficiaries_map::<T>(n);
         let _ = T::Currency::mint_into(
-            &Pallet::<T>::account_id(),
+            &Pallet::<T>::campaign_account_id(CAMPAIGN_ID),
             total_amount.saturating_mul(2u32.into()), // Just to be extra safe
         )
         .unwrap();
 
         #[extrinsic_call]
-        add_beneficiaries(RawOrigin::Root, beneficiaries);
+        add_beneficiaries(RawOrigin::Root, CAMPAIGN_ID, beneficiaries);
     }
 
     #[benchmark]
     fn end_claim() {
         let _ = init_claim_state::<T>(0, true);
 
-        // Mint some tokens into pallet account just to trigger a transfer
+        // Mint some tokens into campaign account just to trigger a transfer
         let _ = T::Currency::mint_into(
-            &Pallet::<T>::account_id(),
+            &Pallet::<T>::campaign_account_id(CAMPAIGN_ID),
             T::Currency::minimum_balance().saturating_mul(100u32.into()),
         )
         .unwrap();
 
         #[extrinsic_call]
-        end_claim(RawOrigin::Root);
+        end_claim(RawOrigin::Root, CAMPAIGN_ID);
 
-        assert_eq!(Pallet::<T>::pot(), BalanceOf::<T>::zero());
+        assert_eq!(Pallet::<T>::pot(CAMPAIGN_ID), BalanceOf::<T>::zero());
     }
 
     #[benchmark]
     fn remove_beneficiaries(n: Linear<1, <T as Config>::MAX_OP_BENEFICIARIES>) {
-        let (beneficiaries, _) = get_beneficiaries_map::<T>(n);
-        beneficiaries
-            .into_iter()
-            .for_each(|(account, amount)| Beneficiaries::<T>::insert(account, amount));
-        assert_eq!(Beneficiaries::<T>::count(), n);
+        let beneficiaries = init_claim_state::<T>(n, true);
+        for beneficiary in beneficiaries.keys() {
+            BeneficiaryVesting::<T>::insert(CAMPAIGN_ID, beneficiary, vesting_config::<T>());
+        }
+        Pallet::<T>::end_claim(RawOrigin::Root.into(), CAMPAIGN_ID).unwrap();
+        assert_eq!(BeneficiariesCount::<T>::get(CAMPAIGN_ID), n);
 
         let caller: T::AccountId = whitelisted_caller();
 
         #[extrinsic_call]
-        remove_beneficiaries(RawOrigin::Signed(caller), Some(n));
+        remove_beneficiaries(RawOrigin::Signed(caller), CAMPAIGN_ID, Some(n));
+
+        assert_eq!(BeneficiariesCount::<T>::get(CAMPAIGN_ID), 0);
+        assert!(!Campaigns::<T>::contains_key(CAMPAIGN_ID));
+        assert_eq!(BeneficiaryVesting::<T>::iter_prefix(CAMPAIGN_ID).count(), 0);
+    }
+
+    #[benchmark]
+    fn begin_merkle_claim() {
+        let total = T::Currency::minimum_balance().saturating_mul(100u32.into());
+        let _ =
+            T::Currency::mint_into(&Pallet::<T>::campaign_account_id(CAMPAIGN_ID), total).unwrap();
+        let merkle = MerkleCommitment {
+            root: H256::repeat_byte(1),
+            number_of_leaves: u32::MAX,
+        };
+
+        #[extrinsic_call]
+        begin_merkle_claim(
+            RawOrigin::Root,
+            CAMPAIGN_ID,
+            merkle,
+            total,
+            get_claim_message::<T>(),
+            Default::default(),
+        );
 
-        assert_eq!(Beneficiaries::<T>::count(), 0);
+        assert!(Campaigns::<T>::get(CAMPAIGN_ID).unwrap().merkle.is_some());
+    }
+
+    #[benchmark]
+    fn claim_merkle(n: Linear<1, { MAX_MERKLE_PROOF_LEN - 1 }>) {
+        let msg = get_claim_message::<T>();
+        let (signature, signer) = T::BenchmarkHelper::sign_claim(msg.as_slice());
+        let beneficiary = Beneficiary::<T>::Substrate(signer.clone().into_account());
+        let amount =
+            BalanceOf::<T>::from(T::Currency::minimum_balance().saturating_add(1u32.into()));
+        let proof = init_merkle_claim_state::<T>(&beneficiary, amount, n);
+
+        let call_enc = Call::<T>::claim_merkle {
+            campaign_id: CAMPAIGN_ID,
+            beneficiary: signer,
+            amount,
+            proof,
+            signature,
+        }
+        .encode();
+        let source = sp_runtime::transaction_validity::TransactionSource::External;
+
+        #[block]
+        {
+            let call = <Call<T> as Decode>::decode(&mut &*call_enc).unwrap();
+            super::Pallet::<T>::validate_unsigned(source, &call).unwrap();
+            call.dispatch_bypass_filter(RawOrigin::None.into()).unwrap();
+        }
+
+        // sanity check
+        assert!(Pallet::<T>::is_claimed(CAMPAIGN_ID, 0));
+    }
+
+    #[benchmark]
+    fn claim_ethereum_merkle(n: Linear<1, { MAX_MERKLE_PROOF_LEN - 1 }>) {
+        let msg = get_claim_message::<T>();
+        let dest: T::AccountId = account("test dest", 0, 0);
+        let amount =
+            BalanceOf::<T>::from(T::Currency::minimum_balance().saturating_add(1u32.into()));
+        // Worst case: the EIP-712 signature is verified after the EIP-191 one
+        let msg_hash = eip712_hash_claim::<T>(
+            CAMPAIGN_ID,
+            msg.as_slice(),
+            &dest,
+            amount.unique_saturated_into(),
+        );
+        let (signature, signer) = T::BenchmarkHelper::sign_claim_ethereum_hash(&msg_hash);
+        let beneficiary = Beneficiary::<T>::Ethereum(signer);
+        let proof = init_merkle_claim_state::<T>(&beneficiary, amount, n);
+
+        let call_enc = Call::<T>::claim_ethereum_merkle {
+            campaign_id: CAMPAIGN_ID,
+            beneficiary: signer,
+            amount,
+            proof,
+            signature,
+            dest,
+        }
+        .encode();
+        let source = sp_runtime::transaction_validity::TransactionSource::External;
+
+        #[block]
+        {
+            let call = <Call<T> as Decode>::decode(&mut &*call_enc).unwrap();
+            super::Pallet::<T>::validate_unsigned(source, &call).unwrap();
+            call.dispatch_bypass_filter(RawOrigin::None.into()).unwrap();
+        }
+
+        // sanity check
+        assert!(Pallet::<T>::is_claimed(CAMPAIGN_ID, 0));
+    }
+
+    #[benchmark]
+    fn set_beneficiaries_vesting(n: Linear<1, <T as Config>::MAX_OP_BENEFICIARIES>) {
+        let beneficiaries = init_claim_state::<T>(n, true)
+            .into_keys()
+            .map(|beneficiary| (beneficiary, Some(vesting_config::<T>())))
+            .collect::<BTreeMap<_, _>>();
+
+        #[extrinsic_call]
+        set_beneficiaries_vesting(RawOrigin::Root, CAMPAIGN_ID, beneficiaries);
+
+        assert_eq!(
+            BeneficiaryVesting::<T>::iter_prefix(CAMPAIGN_ID).count(),
+            n as usize
+        );
+    }
+
+    #[benchmark]
+    fn begin_zk_claim() {
+        let total = T::Currency::minimum_balance().saturating_mul(100u32.into());
+        let _ =
+            T::Currency::mint_into(&Pallet::<T>::campaign_account_id(CAMPAIGN_ID), total).unwrap();
+
+        #[extrinsic_call]
+        begin_zk_claim(
+            RawOrigin::Root,
+            CAMPAIGN_ID,
+            zk_commitment::<T>(),
+            total,
+            get_claim_message::<T>(),
+            Default::default(),
+        );
+
+        assert!(Campaigns::<T>::get(CAMPAIGN_ID).unwrap().zk.is_some());
+    }
+
+    #[benchmark]
+    fn claim_zk() {
+        let dest: T::AccountId = account("test dest", 0, 0);
+        let nullifier = H256::repeat_byte(3);
+        let amount =
+            BalanceOf::<T>::from(T::Currency::minimum_balance().saturating_add(1u32.into()));
+        init_zk_claim_state::<T>(amount);
+
+        // The proof verification is not benchmarked here: it's charged according to the
+        // weights of the verifier.
+        #[block]
+        {
+            let (campaign, _) = Pallet::<T>::check_zk_claimant(CAMPAIGN_ID, &nullifier).unwrap();
+            Pallet::<T>::process_zk_claim(CAMPAIGN_ID, nullifier, dest.clone(), amount).unwrap();
+            Pallet::<T>::do_claim(CAMPAIGN_ID, dest, amount, campaign.vesting).unwrap();
+        }
+
+        // sanity check
+        assert!(Nullifiers::<T>::contains_key(CAMPAIGN_ID, nullifier));
+    }
+
+    #[benchmark]
+    fn claim_solana() {
+        let _ = init_claim_state::<T>(<T as Config>::MAX_OP_BENEFICIARIES - 1, true);
+
+        let msg = get_claim_message::<T>();
+        let dest: T::AccountId = account("test dest", 0, 0);
+        let claim_message = [
+            msg.as_slice(),
+            T::EthMsgSeparator::get(),
+            T::AccountIdBytesToSign::to_bytes_literal(&dest).as_slice(),
+        ]
+        .concat();
+        let (signature, signer) = T::BenchmarkHelper::sign_claim_solana(claim_message.as_slice());
+        let beneficiary = Beneficiary::<T>::Solana(signer);
+
+        // Insert beneficiary
+        let amount =
+            BalanceOf::<T>::from(T::Currency::minimum_balance().saturating_add(1u32.into()));
+        insert_beneficiary::<T>(beneficiary.clone(), amount);
+        assert!(Beneficiaries::<T>::get(CAMPAIGN_ID, beneficiary.clone()).is_some());
+
+        let call_enc = Call::<T>::claim_solana {
+            campaign_id: CAMPAIGN_ID,
+            beneficiary: signer,
+            signature,
+            dest,
+        }
+        .encode();
+        let source = sp_runtime::transaction_validity::TransactionSource::External;
+
+        #[block]
+        {
+            let call = <Call<T> as Decode>::decode(&mut &*call_enc).unwrap();
+            super::Pallet::<T>::validate_unsigned(source, &call).unwrap();
+            call.dispatch_bypass_filter(RawOrigin::None.into()).unwrap();
+        }
+
+        // sanity check
+        assert!(Beneficiaries::<T>::get(CAMPAIGN_ID, beneficiary).is_none());
+    }
+
+    #[benchmark]
+    fn claim_bitcoin() {
+        let _ = init_claim_state::<T>(<T as Config>::MAX_OP_BENEFICIARIES - 1, true);
+
+        let msg = get_claim_message::<T>();
+        let dest: T::AccountId = account("test dest", 0, 0);
+        let claim_message = [
+            msg.as_slice(),
+            T::EthMsgSeparator::get(),
+            T::AccountIdBytesToSign::to_bytes_literal(&dest).as_slice(),
+        ]
+        .concat();
+        let (signature, signer) = T::BenchmarkHelper::sign_claim_bitcoin(claim_message.as_slice());
+        let beneficiary = Beneficiary::<T>::Bitcoin(signer);
+
+        // Insert beneficiary
+        let amount =
+            BalanceOf::<T>::from(T::Currency::minimum_balance().saturating_add(1u32.into()));
+        insert_beneficiary::<T>(beneficiary.clone(), amount);
+        assert!(Beneficiaries::<T>::get(CAMPAIGN_ID, beneficiary.clone()).is_some());
+
+        let call_enc = Call::<T>::claim_bitcoin {
+            campaign_id: CAMPAIGN_ID,
+            beneficiary: signer,
+            signature,
+            dest,
+        }
+        .encode();
+        let source = sp_runtime::transaction_validity::TransactionSource::External;
+
+        #[block]
+        {
+            let call = <Call<T> as Decode>::decode(&mut &*call_enc).unwrap();
+            super::Pallet::<T>::validate_unsigned(source, &call).unwrap();
+            call.dispatch_bypass_filter(RawOrigin::None.into()).unwrap();
+        }
+
+        // sanity check
+        assert!(Beneficiaries::<T>::get(CAMPAIGN_ID, beneficiary).is_none());
     }
 
     #[cfg(test)]
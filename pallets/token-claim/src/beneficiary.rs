@@ -1,39 +1,98 @@
-use crate::{Config, EthereumAddress, EthereumSignature};
+use crate::{
+    BalanceOf, CampaignId, Config, EthereumAddress, EthereumSignature, SolanaAddress,
+    SolanaSignature,
+};
 use alloc::vec::Vec;
 use codec::{Decode, Encode, MaxEncodedLen};
 use core::cmp::Ordering;
 use frame_support::{defensive, pallet_prelude::TypeInfo};
 use serde::{self, Deserialize, Serialize};
-use sp_runtime::traits::Verify;
+use sp_runtime::{
+    traits::{UniqueSaturatedInto, Verify},
+    RuntimeDebug,
+};
 
 pub const MSG_PREFIX: &[u8] = b"<Bytes>";
 pub const MSG_SUFFIX: &[u8] = b"</Bytes>";
 
+/// The EIP-712 domain of the typed data signed by Ethereum beneficiaries. The salt is the id of
+/// the campaign to claim from.
+pub const EIP712_DOMAIN_TYPE: &[u8] =
+    b"EIP712Domain(string name,string version,uint256 chainId,bytes32 salt)";
+/// The EIP-712 type of the claims signed by Ethereum beneficiaries.
+pub const EIP712_CLAIM_TYPE: &[u8] = b"Claim(string message,string destination,uint256 amount)";
+pub const EIP712_VERSION: &[u8] = b"1";
+
 #[derive(Clone, PartialEq, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, MaxEncodedLen)]
 #[scale_info(skip_type_params(T))]
-/// A beneficiary with a Substrate-based address or an Ethereum address
+/// A beneficiary with a Substrate-based address, or an Ethereum, Solana or Bitcoin address
 pub enum Beneficiary<T: Config> {
     /// A beneficiary with a Substrate address
     Substrate(T::AccountId),
     /// A beneficiary with an Ethereum address
     Ethereum(EthereumAddress),
+    /// A beneficiary with a Solana address (i.e. an ed25519 public key)
+    Solana(SolanaAddress),
+    /// A beneficiary with a Bitcoin address
+    Bitcoin(BitcoinAddress),
 }
 
+/// A Bitcoin address committing to the hash160 of a public key
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Encode,
+    Decode,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+    MaxEncodedLen,
+    RuntimeDebug,
+)]
+pub enum BitcoinAddress {
+    /// A legacy pay-to-public-key-hash address ('1...')
+    P2pkh([u8; 20]),
+    /// A native SegWit pay-to-witness-public-key-hash address ('bc1q...')
+    P2wpkh([u8; 20]),
+}
+
+/// A BIP-137 Bitcoin message signature: a header byte, encoding the recovery id and the type of
+/// the signing address, followed by 'r' and 's'
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct BitcoinSignature(pub [u8; 65]);
+
 impl<T: Config> PartialOrd for Beneficiary<T> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
+impl<T: Config> Beneficiary<T> {
+    /// Beneficiaries of different kinds are ordered by kind, in declaration order
+    fn kind(&self) -> u8 {
+        match self {
+            Beneficiary::Substrate(_) => 0,
+            Beneficiary::Ethereum(_) => 1,
+            Beneficiary::Solana(_) => 2,
+            Beneficiary::Bitcoin(_) => 3,
+        }
+    }
+}
+
 impl<T: Config> Ord for Beneficiary<T> {
     fn cmp(&self, other: &Self) -> Ordering {
         use Beneficiary::*;
 
         match (self, other) {
-            (Substrate(_), Ethereum(_)) => Ordering::Less,
-            (Ethereum(_), Substrate(_)) => Ordering::Greater,
             (Substrate(sa), Substrate(sb)) => sa.cmp(sb),
             (Ethereum(ea), Ethereum(eb)) => ea.cmp(eb),
+            (Solana(sa), Solana(sb)) => sa.cmp(sb),
+            (Bitcoin(ba), Bitcoin(bb)) => ba.cmp(bb),
+            _ => self.kind().cmp(&other.kind()),
         }
     }
 }
@@ -45,6 +104,8 @@ impl<T: Config> core::fmt::Debug for Beneficiary<T> {
         match self {
             Substrate(s) => s.fmt(f),
             Ethereum(e) => e.fmt(f),
+            Solana(s) => write!(f, "0x{}", sp_core::hexdisplay::HexDisplay::from(s)),
+            Bitcoin(b) => b.fmt(f),
         }
     }
 }
@@ -77,6 +138,21 @@ impl<T: frame_system::Config> AccountIdToBytesLiteral<T> for AccountId32ToSs58By
 pub enum ClaimSignature<T: Config> {
     Substrate(T::Signature),
     Ethereum((EthereumSignature, T::AccountId)),
+    Solana((SolanaSignature, T::AccountId)),
+    Bitcoin((BitcoinSignature, T::AccountId)),
+}
+
+/// The claim message followed by the destination Substrate account, as signed by beneficiaries
+/// with a non-Substrate address. The destination must be signed as well, otherwise anyone could
+/// intercept the transaction and change it.
+fn msg_with_dest<T: Config>(claim_message: &[u8], dest: &T::AccountId) -> Vec<u8> {
+    let dest_account = T::AccountIdBytesToSign::to_bytes_literal(dest);
+    [
+        claim_message,
+        T::EthMsgSeparator::get(),
+        dest_account.as_slice(),
+    ]
+    .concat()
 }
 
 pub fn eip191_hash_message(message: &[u8]) -> [u8; 32] {
@@ -88,6 +164,43 @@ pub fn eip191_hash_message(message: &[u8]) -> [u8; 32] {
     sp_core::keccak_256(&eth_message)
 }
 
+/// The EIP-712 hash of the typed data claiming 'amount' tokens from campaign 'campaign_id' (whose
+/// claim message is 'claim_message') and sending them to 'dest'.
+pub fn eip712_hash_claim<T: Config>(
+    campaign_id: CampaignId,
+    claim_message: &[u8],
+    dest: &T::AccountId,
+    amount: u128,
+) -> [u8; 32] {
+    use sp_core::keccak_256;
+
+    let word = |value: u128| {
+        let mut word = [0u8; 32];
+        word[16..].copy_from_slice(&value.to_be_bytes());
+        word
+    };
+    let domain_separator = keccak_256(
+        &[
+            keccak_256(EIP712_DOMAIN_TYPE),
+            keccak_256(T::Eip712DomainName::get()),
+            keccak_256(EIP712_VERSION),
+            word(T::Eip712ChainId::get().into()),
+            word(campaign_id.into()),
+        ]
+        .concat(),
+    );
+    let claim_hash = keccak_256(
+        &[
+            keccak_256(EIP712_CLAIM_TYPE),
+            keccak_256(claim_message),
+            keccak_256(&T::AccountIdBytesToSign::to_bytes_literal(dest)),
+            word(amount),
+        ]
+        .concat(),
+    );
+    keccak_256(&[b"\x19\x01".as_slice(), &domain_separator, &claim_hash].concat())
+}
+
 pub fn eth_recover(
     msg_to_sign: &[u8; 32],
     eth_sig: &EthereumSignature,
@@ -99,8 +212,85 @@ pub fn eth_recover(
     Ok(EthereumAddress::from(pk.to_eth_address()?))
 }
 
+/// The double SHA-256 hash of 'message' as signed by Bitcoin wallets (BIP-137).
+pub fn bitcoin_hash_message(message: &[u8]) -> [u8; 32] {
+    let mut btc_message = b"\x18Bitcoin Signed Message:\n".to_vec();
+    // The message length is encoded as a Bitcoin CompactSize
+    match message.len() {
+        len @ 0..=0xfc => btc_message.push(len as u8),
+        len @ 0xfd..=0xffff => {
+            btc_message.push(0xfd);
+            btc_message.extend_from_slice(&(len as u16).to_le_bytes());
+        }
+        len => {
+            btc_message.push(0xfe);
+            btc_message.extend_from_slice(&(len as u32).to_le_bytes());
+        }
+    }
+    btc_message.extend_from_slice(message);
+    sp_io::hashing::sha2_256(&sp_io::hashing::sha2_256(&btc_message))
+}
+
+/// The RIPEMD-160 hash of the SHA-256 hash of 'data', as committed to by Bitcoin addresses.
+pub fn hash160(data: &[u8]) -> [u8; 20] {
+    use ripemd::{Digest, Ripemd160};
+
+    let mut hash = [0u8; 20];
+    hash.copy_from_slice(&Ripemd160::digest(sp_io::hashing::sha2_256(data)));
+    hash
+}
+
+/// Recover the hash160 of the public key signing 'msg_to_sign', and whether it is compressed, as
+/// given by the BIP-137 header of 'btc_sig': 27-30 for an uncompressed public key, 31-34 or 39-42
+/// for a compressed one. Other headers (e.g. 35-38 for P2SH-P2WPKH) are not supported.
+pub fn btc_recover(
+    msg_to_sign: &[u8; 32],
+    btc_sig: &BitcoinSignature,
+) -> Result<([u8; 20], bool), ()> {
+    let header = btc_sig.0[0];
+    let mut sig = [0u8; 65];
+    sig[..64].copy_from_slice(&btc_sig.0[1..]);
+    sig[64] = header.wrapping_sub(27) % 4;
+
+    match header {
+        27..=30 => {
+            let pk_raw =
+                sp_io::crypto::secp256k1_ecdsa_recover(&sig, msg_to_sign).map_err(|_| ())?;
+            let pk = [&[0x04][..], pk_raw.as_slice()].concat();
+            Ok((hash160(&pk), false))
+        }
+        31..=34 | 39..=42 => {
+            let pk = sp_io::crypto::secp256k1_ecdsa_recover_compressed(&sig, msg_to_sign)
+                .map_err(|_| ())?;
+            Ok((hash160(&pk), true))
+        }
+        _ => Err(()),
+    }
+}
+
+impl BitcoinAddress {
+    /// Whether the public key hashing to 'pk_hash' can sign for this address. Wallets disagree on
+    /// the BIP-137 header of P2WPKH signatures (39-42 as per BIP-137, 31-34 for e.g. Electrum and
+    /// Sparrow), so the header of a compressed key doesn't tell the address type apart: only the
+    /// key hash is compared. Uncompressed keys can't sign for P2WPKH addresses.
+    fn is_signed_by(&self, pk_hash: &[u8; 20], compressed: bool) -> bool {
+        match self {
+            BitcoinAddress::P2pkh(hash) => hash == pk_hash,
+            BitcoinAddress::P2wpkh(hash) => compressed && hash == pk_hash,
+        }
+    }
+}
+
 impl<T: Config> ClaimSignature<T> {
-    pub fn verify(&self, claim_message: &[u8], beneficiary: &Beneficiary<T>) -> bool {
+    /// Verify the signature of 'beneficiary' on the claim of 'amount' tokens from campaign
+    /// 'campaign_id', whose claim message is 'claim_message'.
+    pub fn verify(
+        &self,
+        campaign_id: CampaignId,
+        claim_message: &[u8],
+        beneficiary: &Beneficiary<T>,
+        amount: BalanceOf<T>,
+    ) -> bool {
         match (beneficiary, self) {
             // Beneficiary with Substrate address
             (Beneficiary::<T>::Substrate(sub_addr), ClaimSignature::<T>::Substrate(sub_sig)) => {
@@ -117,20 +307,42 @@ impl<T: Config> ClaimSignature<T> {
                 Beneficiary::<T>::Ethereum(eth_addr),
                 ClaimSignature::<T>::Ethereum((eth_sig, dest)),
             ) => {
-                // We need to append to the message the destination Substrate account, otherwise anyone could
-                // intercept the transaction and change the destination
-                let dest_account = T::AccountIdBytesToSign::to_bytes_literal(dest);
-                let msg_with_dest = [
+                // Check signature is successful and signer from signature is the same as beneficiary,
+                // either for the EIP-191 (personal_sign) message or for the EIP-712 typed data
+                let is_signer = |msg_to_sign: &[u8; 32]| {
+                    eth_recover(msg_to_sign, eth_sig)
+                        .map_or_else(|_| false, |derived_address| &derived_address == eth_addr)
+                };
+                is_signer(&eip191_hash_message(
+                    msg_with_dest::<T>(claim_message, dest).as_slice(),
+                )) || is_signer(&eip712_hash_claim::<T>(
+                    campaign_id,
                     claim_message,
-                    T::EthMsgSeparator::get(),
-                    dest_account.as_slice(),
-                ]
-                .concat();
-
-                // Check signature is successful and signer from signature is the same as beneficiary
-                let msg_to_sign = eip191_hash_message(msg_with_dest.as_slice());
-                eth_recover(&msg_to_sign, eth_sig)
-                    .map_or_else(|_| false, |derived_address| &derived_address == eth_addr)
+                    dest,
+                    amount.unique_saturated_into(),
+                ))
+            }
+
+            // Beneficiary with Solana address: wallets sign the raw message bytes
+            (Beneficiary::<T>::Solana(sol_addr), ClaimSignature::<T>::Solana((sol_sig, dest))) => {
+                sp_io::crypto::ed25519_verify(
+                    sol_sig,
+                    msg_with_dest::<T>(claim_message, dest).as_slice(),
+                    &sp_core::ed25519::Public::from_raw(*sol_addr),
+                )
+            }
+
+            // Beneficiary with Bitcoin address
+            (
+                Beneficiary::<T>::Bitcoin(btc_addr),
+                ClaimSignature::<T>::Bitcoin((btc_sig, dest)),
+            ) => {
+                let msg_to_sign =
+                    bitcoin_hash_message(msg_with_dest::<T>(claim_message, dest).as_slice());
+                btc_recover(&msg_to_sign, btc_sig).map_or_else(
+                    |_| false,
+                    |(pk_hash, compressed)| btc_addr.is_signed_by(&pk_hash, compressed),
+                )
             }
             _ => {
                 defensive!();
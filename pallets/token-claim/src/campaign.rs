@@ -0,0 +1,112 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+    pallet_prelude::{ConstU32, TypeInfo},
+    BoundedVec, DefaultNoBound, RuntimeDebug,
+};
+use sp_core::H256;
+
+/// The identifier of a claim campaign.
+pub type CampaignId = u64;
+
+/// The maximum length of a Merkle proof, enough for a tree with `u32::MAX` leaves.
+pub const MAX_MERKLE_PROOF_LEN: u32 = 32;
+
+/// The commitment to the beneficiaries of a Merkle campaign.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct MerkleCommitment {
+    /// The root of the Keccak256 binary Merkle tree whose leaves are the SCALE encoded
+    /// `(beneficiary, amount)` pairs
+    pub root: H256,
+    /// The number of leaves of the tree
+    pub number_of_leaves: u32,
+}
+
+/// The proof that a `(beneficiary, amount)` pair is a leaf of the Merkle tree of a campaign.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub struct MerkleProof {
+    /// The position of the leaf in the tree
+    pub leaf_index: u32,
+    /// The hashes of the siblings on the path from the leaf to the root
+    pub path: BoundedVec<H256, ConstU32<MAX_MERKLE_PROOF_LEN>>,
+}
+
+/// The vesting schedule of claimed tokens: nothing is unlocked before block `start` (the cliff),
+/// then tokens unlock linearly over `duration` blocks.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct VestingConfig<BlockNumber> {
+    /// The block from which claimed tokens start unlocking
+    pub start: BlockNumber,
+    /// The number of blocks over which claimed tokens unlock
+    pub duration: BlockNumber,
+}
+
+/// The commitment of a ZK campaign: beneficiaries claim by proving, with the verification key
+/// `vk_hash` registered in `verifier`, that they know a secret committed to by `root`.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct ZkCommitment<VerifierId> {
+    /// The root of the commitment to the beneficiaries secrets and amounts
+    pub root: H256,
+    /// The verifier the claim proofs are submitted to
+    pub verifier: VerifierId,
+    /// The hash of the verification key of the claim circuit, registered in `verifier`
+    pub vk_hash: H256,
+}
+
+/// Parameters of a claim campaign, supplied at the moment of its start.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, RuntimeDebug, DefaultNoBound)]
+pub struct CampaignConfig<AccountId, BlockNumber> {
+    /// An account allowed to manage the campaign besides **ManagerOrigin**.
+    pub manager: Option<AccountId>,
+    /// Destination for the unclaimed tokens. **T::UnclaimedDestination** if not specified.
+    pub unclaimed_destination: Option<AccountId>,
+    /// The block from which beneficiaries can claim. The current block if not specified.
+    pub start: Option<BlockNumber>,
+    /// The block from which beneficiaries cannot claim anymore. If not specified, the campaign
+    /// lasts until explicitly ended.
+    pub end: Option<BlockNumber>,
+    /// The vesting schedule of the claimed tokens, unless overridden for a beneficiary. Tokens
+    /// are claimed liquid if not specified.
+    pub vesting: Option<VestingConfig<BlockNumber>>,
+}
+
+/// The state of a claim campaign.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct CampaignInfo<AccountId, BlockNumber, ClaimMessage, VerifierId> {
+    /// The message beneficiaries need to sign for claiming their tokens
+    pub claim_message: ClaimMessage,
+    /// An account allowed to manage the campaign besides **ManagerOrigin**
+    pub manager: Option<AccountId>,
+    /// Destination for the unclaimed tokens
+    pub unclaimed_destination: AccountId,
+    /// The block from which beneficiaries can claim
+    pub start: BlockNumber,
+    /// The block from which beneficiaries cannot claim anymore
+    pub end: Option<BlockNumber>,
+    /// Whether the campaign has not been ended yet
+    pub active: bool,
+    /// The commitment to the beneficiaries, for campaigns not keeping them in storage
+    pub merkle: Option<MerkleCommitment>,
+    /// The vesting schedule of the claimed tokens, unless overridden for a beneficiary
+    pub vesting: Option<VestingConfig<BlockNumber>>,
+    /// The commitment to the beneficiaries, for campaigns claimed with zero-knowledge proofs
+    pub zk: Option<ZkCommitment<VerifierId>>,
+}
+
+impl<AccountId, BlockNumber: PartialOrd, ClaimMessage, VerifierId>
+    CampaignInfo<AccountId, BlockNumber, ClaimMessage, VerifierId>
+{
+    /// Whether the campaign period is over at block `now`.
+    pub fn is_over(&self, now: &BlockNumber) -> bool {
+        self.end.as_ref().is_some_and(|end| now >= end)
+    }
+
+    /// Whether beneficiaries could claim at block `now`, provided the campaign has started.
+    pub fn is_active(&self, now: &BlockNumber) -> bool {
+        self.active && !self.is_over(now)
+    }
+
+    /// Whether the campaign period has started at block `now`.
+    pub fn has_started(&self, now: &BlockNumber) -> bool {
+        now >= &self.start
+    }
+}
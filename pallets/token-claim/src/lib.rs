@@ -17,11 +17,16 @@
 #![deny(missing_docs)]
 
 //! This pallet allows to perform token giveaways to selected beneficiaries with a manual claiming process.
-//! Only a **ManagerOrigin** is allowed to start and end a claiming process, as well as adding the corresponding
-//! beneficiaries and remove leftover ones. Only one claim can be held at a time.
-//! The claiming process for beneficiaries is completely feeless, and handled via unsigned extrinsics.
-//! A Beneficiary can be either a Substrate addresses (of type **T::AccountId**) or Ethereum addresses,
-//! and the procedure for claiming is slightly different:
+//! Giveaways are organized in claim campaigns, each one identified by a **CampaignId**. Several campaigns
+//! can be held at the same time, each one with its own beneficiaries, claim message, funding account,
+//! claiming period, destination for the unclaimed funds and (optional) manager account.
+//! Only a **ManagerOrigin** is allowed to start a campaign, while both **ManagerOrigin** and the campaign
+//! manager are allowed to end it, as well as adding the corresponding beneficiaries. Once a campaign
+//! has ended anyone can remove its leftover beneficiaries.
+//! The claiming process for beneficiaries is completely feeless, and handled via unsigned extrinsics
+//! naming the campaign to claim from.
+//! A Beneficiary can be either a Substrate addresses (of type **T::AccountId**), or an Ethereum, Solana or
+//! Bitcoin address, and the procedure for claiming is slightly different:
 //!
 //! Substrate beneficiaries need to provide a signature on a claiming message, established at the
 //! time of claim start, from the same address that is loaded in the beneficiaries list. The
@@ -32,22 +37,61 @@
 //! Ethereum beneficiaries need to provide a Substrate destination address on which they want the tokens
 //! to be sent. In order to be allowed to do that, they need to provide a signature on a claiming message,
 //! established at the time of claim start, followed by a separator (currently '\n') and a byte encoding of
-//! the destination address as defined by **T::AccountIdBytesToSign**.
+//! the destination address as defined by **T::AccountIdBytesToSign**. Alternatively, they can sign the EIP-712
+//! typed data `Claim(string message,string destination,uint256 amount)`, holding the claim message, the byte
+//! encoding of the destination address and the amount they are entitled to, in the domain
+//! `EIP712Domain(string name,string version,uint256 chainId,bytes32 salt)`, whose name and chain id are
+//! **T::Eip712DomainName** and **T::Eip712ChainId**, version is "1" and salt is the campaign id (see
+//! **eip712_hash_claim**).
+//! Solana and Bitcoin beneficiaries claim in the same way, via **claim_solana** and **claim_bitcoin**: the
+//! former sign the very same bytes with their ed25519 key, the latter sign them as a Bitcoin message (BIP-137)
+//! from a P2PKH or a P2WPKH address.
 //!
-//! When claim ends, unclaimed funds will be transferred to **T::UnclaimedDestination**, and the beneficiaries
-//! list will be cleared.
+//! Each campaign is funded by its own account, derived from **T::PalletId** and the campaign id (see
+//! **campaign_account_id**). Beneficiaries can claim only between the start and the end of the campaign.
+//! When a campaign ends, its unclaimed funds will be transferred to the campaign unclaimed destination
+//! (**T::UnclaimedDestination** if not specified), and its beneficiaries list can be cleared.
 //! Please note that is possible to add/remove up to **T::MaxOpBeneficiaries** at a time.
 //! Larger batches require multiple, separate calls to **add_beneficiaries** and **remove_beneficiaries**.
-//! It is possible to add new beneficiaries only when a claim has started (or at the moment of start) and if
-//! the account associated to the pallet has enough funds to cover all of them, and remove them only when
-//! a claim has ended.
+//! It is possible to add new beneficiaries only when a campaign has started (or at the moment of start) and if
+//! the account associated to the campaign has enough funds to cover all of them, and remove them only when
+//! the campaign has ended.
+//!
+//! For large airdrops, a campaign can be started with **begin_merkle_claim**, committing only to the root of
+//! a Keccak256 binary Merkle tree whose leaves are the SCALE encoded `(beneficiary, amount)` pairs, instead of
+//! loading all the beneficiaries in storage. Beneficiaries of such a campaign claim via **claim_merkle** and
+//! **claim_ethereum_merkle**, supplying the amount they are entitled to and a Merkle proof of it along with the
+//! usual signature. Claimed leaves are tracked in a bitmap, cleared via **remove_beneficiaries** once the
+//! campaign has ended.
+//!
+//! A campaign can specify a vesting schedule (a cliff followed by a linear unlock) for the claimed tokens,
+//! which can be overridden for individual beneficiaries via **set_beneficiaries_vesting**. Tokens claimed
+//! under a vesting schedule are transferred to the destination and locked by **T::VestingSchedule**.
+//!
+//! For private airdrops, a campaign can be started with **begin_zk_claim**, committing to the root of the
+//! beneficiaries secrets along with the verifier and the verification key of a claim circuit. Beneficiaries
+//! of such a campaign claim via **claim_zk**, supplying a zero-knowledge proof, verified by
+//! **T::ClaimProofVerifier**, whose public inputs are (see **zk_claim_pubs**) the campaign root, a nullifier,
+//! the destination account and the claimed amount. Unlike the other claims, **claim_zk** is a signed call, as
+//! verifying the proof is too expensive to be done for free: its fee is refunded if the claim succeeds. Used
+//! nullifiers are recorded to prevent double claims, and cleared via **remove_beneficiaries** once the
+//! campaign has ended.
 
 #![allow(clippy::borrow_interior_mutable_const)]
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 mod beneficiary;
-pub use beneficiary::{AccountId32ToSs58BytesToSign, AccountIdToBytesLiteral, Beneficiary};
+mod campaign;
+pub use beneficiary::{
+    eip712_hash_claim, AccountId32ToSs58BytesToSign, AccountIdToBytesLiteral, Beneficiary,
+    BitcoinAddress, BitcoinSignature,
+};
+pub use campaign::{
+    CampaignConfig, CampaignId, CampaignInfo, MerkleCommitment, MerkleProof, VestingConfig,
+    ZkCommitment, MAX_MERKLE_PROOF_LEN,
+};
+pub mod migrations;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -55,12 +99,16 @@ mod tests;
 #[cfg(any(test, feature = "runtime-benchmarks"))]
 mod utils;
 mod weight;
-use core::marker::PhantomData;
+mod zk;
+pub use zk::ClaimProofVerifier;
 
 extern crate alloc;
 
-use alloc::{collections::btree_map::BTreeMap, vec, vec::Vec};
-use sp_runtime::traits::{AccountIdConversion, Zero};
+use alloc::{boxed::Box, collections::btree_map::BTreeMap, vec, vec::Vec};
+use sp_core::H256;
+use sp_runtime::traits::{
+    AccountIdConversion, Convert, One, Saturating, UniqueSaturatedInto, Zero,
+};
 
 use frame_support::{
     defensive,
@@ -69,24 +117,59 @@ use frame_support::{
     traits::{
         fungible::{Inspect, Mutate},
         tokens::{Fortitude, Preservation},
-        Get,
+        Get, VestingSchedule,
     },
     BoundedVec, PalletId,
 };
 
-pub use sp_core::{ecdsa::Signature as EthereumSignature, H160 as EthereumAddress};
+pub use sp_core::{
+    ecdsa::Signature as EthereumSignature, ed25519::Signature as SolanaSignature,
+    H160 as EthereumAddress,
+};
+
+/// A Solana address, i.e. an ed25519 public key
+pub type SolanaAddress = [u8; 32];
 
 type BalanceOf<T> =
     <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
 type ClaimMessage<T> = BoundedVec<u8, <T as Config>::MaxClaimMessageLength>;
 
+type CampaignConfigOf<T> = CampaignConfig<
+    <T as frame_system::Config>::AccountId,
+    frame_system::pallet_prelude::BlockNumberFor<T>,
+>;
+
+type VestingConfigOf<T> = VestingConfig<frame_system::pallet_prelude::BlockNumberFor<T>>;
+
+type VerifierIdOf<T> = <<T as Config>::ClaimProofVerifier as ClaimProofVerifier>::VerifierId;
+
+type ClaimProofOf<T> = <<T as Config>::ClaimProofVerifier as ClaimProofVerifier>::Proof;
+
+type ZkCommitmentOf<T> = ZkCommitment<VerifierIdOf<T>>;
+
+type CampaignInfoOf<T> = CampaignInfo<
+    <T as frame_system::Config>::AccountId,
+    frame_system::pallet_prelude::BlockNumberFor<T>,
+    ClaimMessage<T>,
+    VerifierIdOf<T>,
+>;
+
 impl<T: Config> From<Error<T>> for TransactionValidityError {
     fn from(error: Error<T>) -> TransactionValidityError {
         let e = match error {
-            Error::AlreadyEnded => InvalidTransaction::Stale,
+            Error::AlreadyEnded
+            | Error::CampaignNotFound
+            | Error::AlreadyClaimed
+            | Error::NullifierAlreadyUsed => InvalidTransaction::Stale,
+            Error::NotStarted => InvalidTransaction::Future,
             Error::NotEligible => InvalidTransaction::BadSigner,
-            Error::BadSignature => InvalidTransaction::BadProof,
+            Error::BadSignature | Error::InvalidMerkleProof | Error::InvalidZkProof => {
+                InvalidTransaction::BadProof
+            }
+            Error::NotMerkleCampaign | Error::CannotVest | Error::NotZkCampaign => {
+                InvalidTransaction::Call
+            }
             _ => {
                 defensive!();
                 InvalidTransaction::Custom(0u8)
@@ -108,11 +191,14 @@ pub mod pallet {
     use frame_support::{pallet_prelude::*, traits::DefensiveSaturating};
     use frame_system::pallet_prelude::*;
     use sp_runtime::{
-        traits::{IdentifyAccount, Verify},
+        traits::{IdentifyAccount, Keccak256, Verify},
         TokenError,
     };
 
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
@@ -120,7 +206,7 @@ pub mod pallet {
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
-        /// The claim's pallet id, used for deriving its sovereign account ID.
+        /// The claim's pallet id, used for deriving the campaigns' sovereign account IDs.
         #[pallet::constant]
         type PalletId: Get<PalletId>;
 
@@ -130,7 +216,7 @@ pub mod pallet {
         /// The currency type.
         type Currency: Mutate<Self::AccountId>;
 
-        /// Destination for unclaimed assets
+        /// Default destination for unclaimed assets, used for campaigns not specifying one
         type UnclaimedDestination: Get<Self::AccountId>;
 
         /// Weight information for extrinsics in this pallet.
@@ -142,13 +228,14 @@ pub mod pallet {
         /// The type of signature to be supplied for claiming the tokens for Substrate beneiciaries
         type Signature: Verify<Signer = Self::Signer> + Parameter;
 
-        /// Means of converting an account ID to bytes suitable to be signed on Ethereum-side
+        /// Means of converting an account ID to bytes suitable to be signed on Ethereum, Solana
+        /// and Bitcoin side
         type AccountIdBytesToSign: crate::beneficiary::AccountIdToBytesLiteral<
             Self,
             AccountId = Self::AccountId,
         >;
 
-        /// The maximum number of allowed beneficiaries.
+        /// The maximum number of allowed beneficiaries for each campaign.
         #[pallet::constant]
         type MaxBeneficiaries: Get<u32>;
 
@@ -160,10 +247,37 @@ pub mod pallet {
         #[pallet::constant]
         type MaxOpBeneficiaries: Get<u32>;
 
-        /// Special character to be used to separated, for Ethereum claimers, the Claim Message and the destination address.
+        /// Special character to be used to separated, for Ethereum, Solana and Bitcoin claimers, the Claim Message and the destination address.
         #[pallet::constant]
         type EthMsgSeparator: Get<&'static [u8]>;
 
+        /// The name in the EIP-712 domain of the typed data signed by Ethereum claimers.
+        #[pallet::constant]
+        type Eip712DomainName: Get<&'static [u8]>;
+
+        /// The chain id in the EIP-712 domain of the typed data signed by Ethereum claimers.
+        /// Wallets refuse to sign typed data for a chain id other than the one of the network
+        /// they are connected to (e.g. MetaMask's `eth_signTypedData_v4`), so this should be the
+        /// id of a network any wallet can connect to, such as 1 (Ethereum mainnet). Chains
+        /// sharing the domain name must use different ids: the salt is just the campaign id, so
+        /// a claim signed for one of them could be replayed on the others.
+        #[pallet::constant]
+        type Eip712ChainId: Get<u64>;
+
+        /// The vesting schedules locking the tokens claimed from vested campaigns or by vested
+        /// beneficiaries.
+        type VestingSchedule: VestingSchedule<
+            Self::AccountId,
+            Moment = BlockNumberFor<Self>,
+            Currency: frame_support::traits::Currency<Self::AccountId, Balance = BalanceOf<Self>>,
+        >;
+
+        /// Converts a number of blocks to a balance, for computing the amount unlocked per block.
+        type BlockNumberToBalance: Convert<BlockNumberFor<Self>, BalanceOf<Self>>;
+
+        /// The verifier of the zero-knowledge proofs claiming from ZK campaigns.
+        type ClaimProofVerifier: ClaimProofVerifier;
+
         /// The maximum number of beneficiaries allowed to be updated within a single operation. Used in benchmarks.
         /// Must be equal to T::MaxOpBeneficiaries::get()
         #[cfg(feature = "runtime-benchmarks")]
@@ -174,35 +288,57 @@ pub mod pallet {
         type BenchmarkHelper: crate::benchmarking::BenchmarkHelper<Self::Signature, Self::Signer>;
     }
 
-    /// Candidates eligible to receive a claim with the associated balance they have right to
+    /// The claim campaigns, from their start until all their beneficiaries have been removed
+    #[pallet::storage]
+    pub type Campaigns<T: Config> = StorageMap<_, Twox64Concat, CampaignId, CampaignInfoOf<T>>;
+
+    /// Candidates eligible to receive a claim in a campaign with the associated balance they have right to
     #[pallet::storage]
     pub type Beneficiaries<T: Config> =
-        CountedStorageMap<_, Twox64Concat, Beneficiary<T>, BalanceOf<T>>;
+        StorageDoubleMap<_, Twox64Concat, CampaignId, Twox64Concat, Beneficiary<T>, BalanceOf<T>>;
 
-    /// Total tokens claimable from the current claim.  
+    /// Number of beneficiaries (or claimed bitmap words, for Merkle campaigns, or used nullifiers,
+    /// for ZK campaigns) in storage for each campaign
     #[pallet::storage]
-    pub type TotalClaimable<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+    pub type BeneficiariesCount<T: Config> =
+        StorageMap<_, Twox64Concat, CampaignId, u32, ValueQuery>;
 
-    /// Whether there is an active claim or not
+    /// Total tokens claimable from each active campaign.
     #[pallet::storage]
-    pub type ClaimActive<T: Config> = StorageValue<_, bool, ValueQuery>;
+    pub type TotalClaimable<T: Config> =
+        StorageMap<_, Twox64Concat, CampaignId, BalanceOf<T>, ValueQuery>;
 
-    /// Id of the current (or the last) claim
+    /// The leaves already claimed from each Merkle campaign, as words of a bitmap
     #[pallet::storage]
-    pub type ClaimId<T: Config> = StorageValue<_, (u64, ClaimMessage<T>)>;
+    pub type ClaimedBitmap<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, CampaignId, Twox64Concat, u32, u128, ValueQuery>;
 
-    /// Account id of this pallet
+    /// The vesting schedules overriding the campaign one for individual beneficiaries
     #[pallet::storage]
-    pub type PalletAccountId<T: Config> = StorageValue<_, T::AccountId>;
+    pub type BeneficiaryVesting<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        CampaignId,
+        Twox64Concat,
+        Beneficiary<T>,
+        VestingConfigOf<T>,
+    >;
+
+    /// The nullifiers already used for claiming from each ZK campaign
+    #[pallet::storage]
+    pub type Nullifiers<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, CampaignId, Blake2_128Concat, H256, ()>;
 
     /// Genesis config for this pallet
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
-        /// Genesis beneficiaries
-        pub beneficiaries: Vec<(Beneficiary<T>, BalanceOf<T>)>,
-        /// Genesis claim message
-        pub claim_message: ClaimMessage<T>,
+        /// Genesis campaigns, with their claim message and beneficiaries
+        pub campaigns: Vec<(
+            CampaignId,
+            ClaimMessage<T>,
+            Vec<(Beneficiary<T>, BalanceOf<T>)>,
+        )>,
     }
 
     #[pallet::genesis_build]
@@ -213,24 +349,31 @@ pub mod pallet {
             // Sanity check
             assert!(T::MaxOpBeneficiaries::get() <= T::MaxBeneficiaries::get());
 
-            TotalClaimable::<T>::put(BalanceOf::<T>::zero());
-
-            // Add beneficiaries
-            let num_beneficiaries = self.beneficiaries.len();
+            for (campaign_id, claim_message, beneficiaries) in &self.campaigns {
+                assert!(
+                    !Campaigns::<T>::contains_key(campaign_id),
+                    "DuplicatedCampaign"
+                );
+                assert!(!claim_message.is_empty(), "InvalidClaimMessage");
+                let campaign = <Pallet<T>>::new_campaign(
+                    claim_message.clone(),
+                    CampaignConfigOf::<T>::default(),
+                )
+                .expect("Default campaign config is always valid");
+                <Pallet<T>>::ensure_campaign_account(*campaign_id);
+                Campaigns::<T>::insert(campaign_id, campaign);
 
-            if num_beneficiaries > 0 {
-                assert!(!self.claim_message.is_empty(), "InvalidClaimMessage");
                 // Start adding beneficiaries if specified
                 // Note: Considering it's a genesis build there is no need here
                 //       to enforce a check on MaxOpBeneficiaries
-                assert_ok!(<Pallet<T>>::check_max_beneficiaries(num_beneficiaries));
+                assert_ok!(<Pallet<T>>::check_max_beneficiaries(
+                    *campaign_id,
+                    beneficiaries.len()
+                ));
                 assert_ok!(<Pallet<T>>::do_add_beneficiaries(
-                    self.beneficiaries.clone().into_iter().collect(),
+                    *campaign_id,
+                    beneficiaries.clone().into_iter().collect(),
                 ));
-
-                // Initialize other storage variables
-                ClaimActive::<T>::put(true);
-                ClaimId::<T>::put((0, self.claim_message.clone()));
             }
         }
     }
@@ -240,13 +383,15 @@ pub mod pallet {
     pub enum Event<T: Config> {
         /// Beginning of a new claim campaign
         ClaimStarted {
-            /// The id of the claim that has just started
-            claim_id: u64,
-            /// The claim message for the claim that has just started
+            /// The id of the campaign that has just started
+            campaign_id: CampaignId,
+            /// The claim message for the campaign that has just started
             claim_message: ClaimMessage<T>,
         },
         /// Some amount has been claimed by the beneficiary
         Claimed {
+            /// The campaign the tokens were claimed from
+            campaign_id: CampaignId,
             /// Who claimed the tokens
             beneficiary: Beneficiary<T>,
             /// How many tokens were claimed
@@ -254,24 +399,40 @@ pub mod pallet {
         },
         /// Ending of the claim campaign
         ClaimEnded {
-            /// The id of the claim that has just ended
-            claim_id: u64,
-            /// The claim message for the claim that has just ended
+            /// The id of the campaign that has just ended
+            campaign_id: CampaignId,
+            /// The claim message for the campaign that has just ended
             claim_message: ClaimMessage<T>,
         },
         /// Some beneficiaries have been removed
         BeneficiariesRemoved {
-            /// The number of beneficiaries remaining in storage
+            /// The campaign the beneficiaries were removed from
+            campaign_id: CampaignId,
+            /// The number of beneficiaries of the campaign remaining in storage
             remaining: u32,
         },
-        /// No more beneficiaries to remove
-        NoMoreBeneficiaries,
+        /// No more beneficiaries to remove. The campaign has been removed from storage.
+        NoMoreBeneficiaries {
+            /// The campaign that has been removed
+            campaign_id: CampaignId,
+        },
+        /// Some amount has been claimed from a ZK campaign
+        ZkClaimed {
+            /// The campaign the tokens were claimed from
+            campaign_id: CampaignId,
+            /// The nullifier of the claim
+            nullifier: H256,
+            /// Where the tokens were sent
+            dest: T::AccountId,
+            /// How many tokens were claimed
+            amount: BalanceOf<T>,
+        },
     }
 
     /// Error for the claim pallet.
     #[pallet::error]
     pub enum Error<T> {
-        /// Attempt to perform an action that is invalid when there is a claim campaign active.
+        /// Attempt to perform an action that is invalid when the claim campaign is active.
         AlreadyStarted,
         /// Account requested a claim but it is not present among the Beneficiaries
         NotEligible,
@@ -283,102 +444,331 @@ pub mod pallet {
         TooManyBeneficiaries,
         /// Attempt to modify the balance of an already added beneficiary
         AlreadyPresent,
-        /// Attempt to perform an action that is invalid when there is no claim campaign active.
+        /// Attempt to perform an action that is invalid when the claim campaign is not active.
         AlreadyEnded,
-        /// Attempt to start a claim while there are still beneficiaries in storage from a previous one
+        /// Attempt to start a claim campaign while there are still beneficiaries in storage from a
+        /// previous one with the same id
         NonEmptyBeneficiaries,
         /// Signature verification failed for a given beneficiary
         BadSignature,
         /// Supplied an invalid claim message
         InvalidClaimMessage,
+        /// The claim campaign does not exist
+        CampaignNotFound,
+        /// Attempt to claim before the start of the claim campaign
+        NotStarted,
+        /// The claim campaign would end before starting
+        InvalidCampaignPeriod,
+        /// Attempt to perform an action that is invalid for a Merkle campaign
+        MerkleCampaign,
+        /// Attempt to claim with a Merkle proof from a campaign without a Merkle commitment
+        NotMerkleCampaign,
+        /// Supplied a Merkle commitment to an empty tree
+        InvalidMerkleCommitment,
+        /// The supplied Merkle proof doesn't match the campaign commitment
+        InvalidMerkleProof,
+        /// The leaf of the Merkle tree has already been claimed
+        AlreadyClaimed,
+        /// Supplied a vesting schedule with zero duration
+        InvalidVestingSchedule,
+        /// The claimed tokens cannot be vested to the destination
+        CannotVest,
+        /// Attempt to perform an action that is invalid for a ZK campaign
+        ZkCampaign,
+        /// Attempt to claim with a zero-knowledge proof from a campaign without a ZK commitment
+        NotZkCampaign,
+        /// The nullifier has already been used for claiming from the campaign
+        NullifierAlreadyUsed,
+        /// The supplied zero-knowledge proof doesn't verify
+        InvalidZkProof,
+        /// The verification key of the ZK commitment is not registered in its verifier
+        VkNotRegistered,
     }
 
-    #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
-            let account = Self::account_id();
+    impl<T: Config> Pallet<T> {
+        /// The account ID of the claim pot of a campaign.
+        pub fn campaign_account_id(campaign_id: CampaignId) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(campaign_id)
+        }
+
+        /// Return the amount of money in the pot of a campaign.
+        /// The existential deposit is not part of the pot so claim account never gets deleted.
+        pub fn pot(campaign_id: CampaignId) -> BalanceOf<T> {
+            T::Currency::reducible_balance(
+                &Self::campaign_account_id(campaign_id),
+                Preservation::Preserve,
+                Fortitude::Polite,
+            )
+        }
+
+        /// The leaf of the Merkle tree of a campaign for a beneficiary entitled to 'amount' tokens.
+        pub fn merkle_leaf(beneficiary: &Beneficiary<T>, amount: BalanceOf<T>) -> Vec<u8> {
+            (beneficiary, amount).encode()
+        }
+
+        /// Whether the leaf at 'leaf_index' of the Merkle tree of a campaign has been claimed.
+        pub fn is_claimed(campaign_id: CampaignId, leaf_index: u32) -> bool {
+            let (word, mask) = Self::bitmap_position(leaf_index);
+            ClaimedBitmap::<T>::get(campaign_id, word) & mask != 0
+        }
+
+        /// The public inputs of the proof claiming 'amount' tokens from a ZK campaign committing
+        /// to 'root', as big-endian 32-byte words: the root, the nullifier, the SCALE encoded
+        /// 'dest' split in 16-byte chunks and the amount (as u128).
+        /// The root and the nullifier must be elements of the scalar field of the claim circuit.
+        pub fn zk_claim_pubs(
+            root: &H256,
+            nullifier: &H256,
+            dest: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> Vec<[u8; 32]> {
+            fn word(bytes: &[u8]) -> [u8; 32] {
+                let mut word = [0; 32];
+                word[32 - bytes.len()..].copy_from_slice(bytes);
+                word
+            }
+            let amount: u128 = amount.unique_saturated_into();
+            let mut pubs = vec![root.0, nullifier.0];
+            pubs.extend(dest.encode().chunks(16).map(word));
+            pubs.push(word(&amount.to_be_bytes()));
+            pubs
+        }
+
+        fn bitmap_position(leaf_index: u32) -> (u32, u128) {
+            (leaf_index / u128::BITS, 1 << (leaf_index % u128::BITS))
+        }
+
+        fn set_claimed(campaign_id: CampaignId, leaf_index: u32) {
+            let (word, mask) = Self::bitmap_position(leaf_index);
+            ClaimedBitmap::<T>::mutate(campaign_id, word, |bits| {
+                if *bits == 0 {
+                    // New word in storage
+                    BeneficiariesCount::<T>::mutate(campaign_id, |count| {
+                        *count = count.saturating_add(1)
+                    });
+                }
+                *bits |= mask;
+            });
+        }
+
+        fn ensure_campaign_account(campaign_id: CampaignId) {
+            let account = Self::campaign_account_id(campaign_id);
             if T::Currency::balance(&account).is_zero() {
                 // Mint existential deposit
                 let _ = T::Currency::mint_into(&account, T::Currency::minimum_balance());
             }
-            Weight::zero()
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        /// The account ID of the claim pot.
-        pub fn account_id() -> T::AccountId {
-            // Check the memorized storage value.
-            if let Some(id) = PalletAccountId::<T>::get() {
-                return id;
+        fn new_campaign(
+            claim_message: ClaimMessage<T>,
+            config: CampaignConfigOf<T>,
+        ) -> Result<CampaignInfoOf<T>, Error<T>> {
+            let now = frame_system::Pallet::<T>::block_number();
+            let start = config.start.unwrap_or(now);
+            if config.end.is_some_and(|end| end <= start || end <= now) {
+                Err(Error::<T>::InvalidCampaignPeriod)?;
+            }
+            if let Some(vesting) = &config.vesting {
+                Self::check_vesting(vesting)?;
             }
 
-            // Create account if not present
-            let id = T::PalletId::get().into_account_truncating();
-            PalletAccountId::<T>::put(&id);
-            id
+            Ok(CampaignInfo {
+                claim_message,
+                manager: config.manager,
+                unclaimed_destination: config
+                    .unclaimed_destination
+                    .unwrap_or_else(T::UnclaimedDestination::get),
+                start,
+                end: config.end,
+                active: true,
+                merkle: None,
+                vesting: config.vesting,
+                zk: None,
+            })
         }
 
-        /// Return the amount of money in the pot.
-        /// The existential deposit is not part of the pot so claim account never gets deleted.
-        pub fn pot() -> BalanceOf<T> {
-            T::Currency::reducible_balance(
-                &Self::account_id(),
-                Preservation::Preserve,
-                Fortitude::Polite,
-            )
+        fn do_begin_claim(
+            origin: OriginFor<T>,
+            campaign_id: CampaignId,
+            claim_message: ClaimMessage<T>,
+            config: CampaignConfigOf<T>,
+        ) -> Result<CampaignInfoOf<T>, DispatchError> {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            // Sanity check: we've removed all the beneficiaries of a previous campaign with the same id
+            if let Some(campaign) = Campaigns::<T>::get(campaign_id) {
+                if campaign.active {
+                    Err(Error::<T>::AlreadyStarted)?;
+                }
+                Err(Error::<T>::NonEmptyBeneficiaries)?;
+            }
+
+            if claim_message.is_empty() {
+                Err(Error::<T>::InvalidClaimMessage)?;
+            }
+
+            // Note: Theoretically we should hold some funds from the manager account for
+            // storing the campaign and its claim_message.
+            // However, considering that only ManagerOrigin can start campaigns and the
+            // message is limited in size, we avoid introducing this unnecessary complexity here.
+            let campaign = Self::new_campaign(claim_message, config)?;
+            Self::ensure_campaign_account(campaign_id);
+            Ok(campaign)
         }
 
-        fn do_claim(dest: T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
-            T::Currency::transfer(&Self::account_id(), &dest, amount, Preservation::Preserve)?;
+        fn campaign(campaign_id: CampaignId) -> Result<CampaignInfoOf<T>, Error<T>> {
+            Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)
+        }
+
+        fn ensure_campaign_manager(
+            origin: OriginFor<T>,
+            campaign: &CampaignInfoOf<T>,
+        ) -> DispatchResult {
+            if let Err(origin) = T::ManagerOrigin::try_origin(origin) {
+                let who = ensure_signed(origin)?;
+                ensure!(
+                    campaign.manager.as_ref() == Some(&who),
+                    DispatchError::BadOrigin
+                );
+            }
+            Ok(())
+        }
+
+        pub(crate) fn do_claim(
+            campaign_id: CampaignId,
+            dest: T::AccountId,
+            amount: BalanceOf<T>,
+            vesting: Option<VestingConfigOf<T>>,
+        ) -> DispatchResult {
+            T::Currency::transfer(
+                &Self::campaign_account_id(campaign_id),
+                &dest,
+                amount,
+                Preservation::Preserve,
+            )?;
+            // Lock the claimed tokens according to the vesting schedule
+            if let Some(vesting) = vesting {
+                T::VestingSchedule::add_vesting_schedule(
+                    &dest,
+                    amount,
+                    Self::unlocked_per_block(amount, &vesting),
+                    vesting.start,
+                )?;
+            }
             // Subtract the claimed token from the TotalClaimable
-            TotalClaimable::<T>::mutate(|required_amount| {
+            TotalClaimable::<T>::mutate(campaign_id, |required_amount| {
                 *required_amount = required_amount.defensive_saturating_sub(amount)
             });
             Ok(())
         }
 
         fn process_claim(
+            campaign_id: CampaignId,
+            campaign: &CampaignInfoOf<T>,
             beneficiary: Beneficiary<T>,
-        ) -> core::result::Result<BalanceOf<T>, DispatchError> {
+        ) -> core::result::Result<(BalanceOf<T>, Option<VestingConfigOf<T>>), DispatchError>
+        {
+            let vesting = Self::take_vesting(campaign_id, campaign, &beneficiary);
             // See if account is eligible to get a claim
-            Beneficiaries::<T>::try_mutate_exists(beneficiary.clone(), |amount| {
-                let mut ret_amount = BalanceOf::<T>::zero();
-                *amount = match amount {
-                    // Account is eligible to get a claim
-                    Some(amount) => {
-                        // Execute payment
-                        let available = Self::pot();
-                        if *amount > available {
-                            log::warn!("Claimable amount {amount:?} bigger than total available {available:?}");
-                            Err(TokenError::FundsUnavailable)?; // Prevent going under the existential deposit of the account
+            let amount = Beneficiaries::<T>::try_mutate_exists(
+                campaign_id,
+                beneficiary.clone(),
+                |amount| {
+                    let mut ret_amount = BalanceOf::<T>::zero();
+                    *amount = match amount {
+                        // Account is eligible to get a claim
+                        Some(amount) => {
+                            // Execute payment
+                            let available = Self::pot(campaign_id);
+                            if *amount > available {
+                                log::warn!("Claimable amount {amount:?} bigger than total available {available:?}");
+                                Err(TokenError::FundsUnavailable)?; // Prevent going under the existential deposit of the account
+                            }
+                            log::trace!("Claimed {amount:?} for {beneficiary:?}");
+                            Self::deposit_event(Event::<T>::Claimed {
+                                campaign_id,
+                                beneficiary,
+                                amount: *amount,
+                            });
+                            ret_amount = *amount;
+                            None
                         }
-                        log::trace!("Claimed {amount:?} for {beneficiary:?}");
-                        Self::deposit_event(Event::<T>::Claimed {
-                            beneficiary,
-                            amount: *amount,
-                        });
-                        ret_amount = *amount;
-                        None
-                    }
-                    // Account is not eligible to receive funds
-                    _ => Err(Error::<T>::NotEligible)?,
-                };
-                Ok::<_, DispatchError>(ret_amount)
-            })
+                        // Account is not eligible to receive funds
+                        _ => Err(Error::<T>::NotEligible)?,
+                    };
+                    Ok::<_, DispatchError>(ret_amount)
+                },
+            )?;
+            BeneficiariesCount::<T>::mutate(campaign_id, |count| {
+                *count = count.defensive_saturating_sub(1)
+            });
+            Ok((amount, vesting))
+        }
+
+        fn vesting_of(
+            campaign_id: CampaignId,
+            campaign: &CampaignInfoOf<T>,
+            beneficiary: &Beneficiary<T>,
+        ) -> Option<VestingConfigOf<T>> {
+            BeneficiaryVesting::<T>::get(campaign_id, beneficiary).or(campaign.vesting)
+        }
+
+        fn take_vesting(
+            campaign_id: CampaignId,
+            campaign: &CampaignInfoOf<T>,
+            beneficiary: &Beneficiary<T>,
+        ) -> Option<VestingConfigOf<T>> {
+            BeneficiaryVesting::<T>::take(campaign_id, beneficiary).or(campaign.vesting)
+        }
+
+        fn check_vesting(vesting: &VestingConfigOf<T>) -> Result<(), Error<T>> {
+            if vesting.duration.is_zero() {
+                Err(Error::<T>::InvalidVestingSchedule)?;
+            }
+            Ok(())
+        }
+
+        /// The amount to unlock at each block for 'amount' tokens to be fully unlocked within
+        /// the vesting duration.
+        fn unlocked_per_block(amount: BalanceOf<T>, vesting: &VestingConfigOf<T>) -> BalanceOf<T> {
+            let duration = T::BlockNumberToBalance::convert(vesting.duration).max(One::one());
+            let per_block = amount / duration;
+            if per_block.saturating_mul(duration) < amount {
+                per_block.saturating_add(One::one())
+            } else {
+                per_block
+            }
+        }
+
+        pub(crate) fn check_can_vest(
+            dest: &T::AccountId,
+            amount: BalanceOf<T>,
+            vesting: Option<VestingConfigOf<T>>,
+        ) -> Result<(), Error<T>> {
+            if let Some(vesting) = vesting {
+                T::VestingSchedule::can_add_vesting_schedule(
+                    dest,
+                    amount,
+                    Self::unlocked_per_block(amount, &vesting),
+                    vesting.start,
+                )
+                .map_err(|_| Error::<T>::CannotVest)?;
+            }
+            Ok(())
         }
 
         fn do_add_beneficiaries(
+            campaign_id: CampaignId,
             beneficiaries: BTreeMap<Beneficiary<T>, BalanceOf<T>>,
         ) -> DispatchResult {
             // Check that the pot has enough funds to cover for all the beneficiaries
-            let available_amount = Self::pot();
-            let mut required_amount = TotalClaimable::<T>::get();
+            let available_amount = Self::pot(campaign_id);
+            let mut required_amount = TotalClaimable::<T>::get(campaign_id);
 
             beneficiaries
                 .iter()
                 .try_for_each::<_, DispatchResult>(|(beneficiary, amount)| {
-                    if Beneficiaries::<T>::contains_key(beneficiary) {
+                    if Beneficiaries::<T>::contains_key(campaign_id, beneficiary) {
                         // Account already exists
                         log::warn!("Beneficiary {beneficiary:?} already added.");
                         Err(Error::<T>::AlreadyPresent)?;
@@ -389,7 +779,7 @@ pub mod pallet {
                         Err(Error::<T>::NothingToClaim)?;
                     }
 
-                    // Account doesn't exist. Add its token amount to the required amount this pallet's account should have
+                    // Account doesn't exist. Add its token amount to the required amount this campaign's account should have
                     required_amount = required_amount.defensive_saturating_add(*amount);
                     log::trace!("Added beneficiary {beneficiary:?}. To claim: {amount:?}");
 
@@ -398,25 +788,40 @@ pub mod pallet {
                         Err(TokenError::FundsUnavailable)?;
                     }
 
-                    Beneficiaries::<T>::insert(beneficiary, amount);
+                    Beneficiaries::<T>::insert(campaign_id, beneficiary, amount);
 
                     Ok(())
                 })?;
 
-            // Update total claimable
-            TotalClaimable::<T>::put(required_amount);
+            // Update total claimable and beneficiaries count
+            TotalClaimable::<T>::insert(campaign_id, required_amount);
+            BeneficiariesCount::<T>::mutate(campaign_id, |count| {
+                *count = count.saturating_add(beneficiaries.len() as u32)
+            });
 
             Ok(())
         }
 
-        fn check_claim_status(should_be_active: bool) -> Result<(), Error<T>> {
-            match (ClaimActive::<T>::get(), should_be_active) {
+        fn check_claim_status(
+            campaign: &CampaignInfoOf<T>,
+            should_be_active: bool,
+        ) -> Result<(), Error<T>> {
+            let now = frame_system::Pallet::<T>::block_number();
+            match (campaign.is_active(&now), should_be_active) {
                 (false, true) => Err(Error::<T>::AlreadyEnded),
                 (true, false) => Err(Error::<T>::AlreadyStarted),
                 _ => Ok(()),
             }
         }
 
+        fn check_claim_started(campaign: &CampaignInfoOf<T>) -> Result<(), Error<T>> {
+            Self::check_claim_status(campaign, true)?;
+            if !campaign.has_started(&frame_system::Pallet::<T>::block_number()) {
+                Err(Error::<T>::NotStarted)?;
+            }
+            Ok(())
+        }
+
         fn check_max_op_beneficiaries(new_beneficiaries_len: usize) -> DispatchResult {
             if new_beneficiaries_len > T::MaxOpBeneficiaries::get() as usize {
                 log::warn!(
@@ -428,9 +833,12 @@ pub mod pallet {
             Ok(())
         }
 
-        fn check_max_beneficiaries(new_beneficiaries_len: usize) -> DispatchResult {
+        fn check_max_beneficiaries(
+            campaign_id: CampaignId,
+            new_beneficiaries_len: usize,
+        ) -> DispatchResult {
             // Check we have space for all the beneficiaries we are trying to add
-            let actual_beneficiaries_len = Beneficiaries::<T>::count();
+            let actual_beneficiaries_len = BeneficiariesCount::<T>::get(campaign_id);
             if actual_beneficiaries_len + new_beneficiaries_len as u32 > T::MaxBeneficiaries::get()
             {
                 log::warn!(
@@ -443,60 +851,224 @@ pub mod pallet {
             Ok(())
         }
 
-        fn check_beneficiaries_len(new_beneficiaries_len: usize) -> DispatchResult {
+        fn check_beneficiaries_len(
+            campaign_id: CampaignId,
+            new_beneficiaries_len: usize,
+        ) -> DispatchResult {
             Self::check_max_op_beneficiaries(new_beneficiaries_len)?;
-            Self::check_max_beneficiaries(new_beneficiaries_len)?;
+            Self::check_max_beneficiaries(campaign_id, new_beneficiaries_len)?;
             Ok(())
         }
 
-        fn do_remove_beneficiaries(limit: u32) {
-            // Start removing remaining beneficiaries if present
-
-            let num_beneficiaries = Beneficiaries::<T>::count();
-            if num_beneficiaries > 0 {
-                let result = Beneficiaries::<T>::clear(limit, None);
-
-                if result.maybe_cursor.is_some() {
-                    Self::deposit_event(Event::<T>::BeneficiariesRemoved {
-                        remaining: num_beneficiaries - result.unique,
-                    });
-                } else {
-                    Self::deposit_event(Event::<T>::NoMoreBeneficiaries);
+        fn do_remove_beneficiaries(
+            campaign_id: CampaignId,
+            campaign: &CampaignInfoOf<T>,
+            limit: u32,
+        ) {
+            // Start removing remaining beneficiaries (or claimed bitmap words, or used nullifiers)
+            // of the campaign
+
+            let num_beneficiaries = BeneficiariesCount::<T>::get(campaign_id);
+            let result = match (campaign.merkle, campaign.zk) {
+                (Some(_), _) => ClaimedBitmap::<T>::clear_prefix(campaign_id, limit, None),
+                (_, Some(_)) => Nullifiers::<T>::clear_prefix(campaign_id, limit, None),
+                (None, None) => {
+                    // Vesting overrides are kept only for beneficiaries in storage, under the
+                    // same key order: clearing as many of them leaves none behind once all the
+                    // beneficiaries are removed.
+                    let _ = BeneficiaryVesting::<T>::clear_prefix(campaign_id, limit, None);
+                    Beneficiaries::<T>::clear_prefix(campaign_id, limit, None)
                 }
+            };
+
+            if result.maybe_cursor.is_some() {
+                let remaining = num_beneficiaries.saturating_sub(result.unique);
+                BeneficiariesCount::<T>::insert(campaign_id, remaining);
+                Self::deposit_event(Event::<T>::BeneficiariesRemoved {
+                    campaign_id,
+                    remaining,
+                });
+            } else {
+                // The campaign is over and has no more beneficiaries: forget about it
+                BeneficiariesCount::<T>::remove(campaign_id);
+                Campaigns::<T>::remove(campaign_id);
+                Self::deposit_event(Event::<T>::NoMoreBeneficiaries { campaign_id });
             }
         }
 
         fn check_claimant(
+            campaign_id: CampaignId,
             beneficiary: &Beneficiary<T>,
             signature: ClaimSignature<T>,
-        ) -> Result<(), Error<T>> {
+        ) -> Result<CampaignInfoOf<T>, Error<T>> {
             // Pre-requisites
-            // 1. Check claim is active
-            Self::check_claim_status(true)?;
+            // 1. Check campaign exists and is claimable
+            let campaign = Self::campaign(campaign_id)?;
+            Self::check_claim_started(&campaign)?;
 
             // 2. Check beneficiary is eligible
-            if !Beneficiaries::<T>::contains_key(beneficiary) {
-                Err(Error::<T>::NotEligible)?;
+            let amount =
+                Beneficiaries::<T>::get(campaign_id, beneficiary).ok_or(Error::<T>::NotEligible)?;
+
+            // Check signature
+            if !signature.verify(
+                campaign_id,
+                campaign.claim_message.as_slice(),
+                beneficiary,
+                amount,
+            ) {
+                Err(Error::<T>::BadSignature)?
+            }
+
+            Ok(campaign)
+        }
+
+        fn check_merkle_claimant(
+            campaign_id: CampaignId,
+            beneficiary: &Beneficiary<T>,
+            amount: BalanceOf<T>,
+            proof: &MerkleProof,
+            signature: ClaimSignature<T>,
+        ) -> Result<CampaignInfoOf<T>, Error<T>> {
+            // Pre-requisites
+            // 1. Check campaign exists, is claimable and commits to a Merkle tree
+            let campaign = Self::campaign(campaign_id)?;
+            Self::check_claim_started(&campaign)?;
+            let merkle = campaign.merkle.ok_or(Error::<T>::NotMerkleCampaign)?;
+
+            // 2. Check leaf has not been claimed yet
+            if Self::is_claimed(campaign_id, proof.leaf_index) {
+                Err(Error::<T>::AlreadyClaimed)?;
+            }
+
+            // 3. Check beneficiary is eligible to the amount
+            let leaf = Self::merkle_leaf(beneficiary, amount);
+            if !binary_merkle_tree::verify_proof::<Keccak256, _, _>(
+                &merkle.root,
+                proof.path.iter().copied(),
+                merkle.number_of_leaves as usize,
+                proof.leaf_index as usize,
+                leaf.as_slice(),
+            ) {
+                Err(Error::<T>::InvalidMerkleProof)?;
             }
 
             // Check signature
-            let claim_message = ClaimId::<T>::get().unwrap().1;
-            if !signature.verify(claim_message.as_slice(), beneficiary) {
+            if !signature.verify(
+                campaign_id,
+                campaign.claim_message.as_slice(),
+                beneficiary,
+                amount,
+            ) {
                 Err(Error::<T>::BadSignature)?
             }
 
+            Ok(campaign)
+        }
+
+        fn process_merkle_claim(
+            campaign_id: CampaignId,
+            leaf_index: u32,
+            beneficiary: Beneficiary<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            // The tree could commit to more than the campaign has been funded with
+            let available = Self::pot(campaign_id).min(TotalClaimable::<T>::get(campaign_id));
+            if amount > available {
+                log::warn!("Claimable amount {amount:?} bigger than total available {available:?}");
+                Err(TokenError::FundsUnavailable)?;
+            }
+            Self::set_claimed(campaign_id, leaf_index);
+            log::trace!("Claimed {amount:?} for {beneficiary:?}");
+            Self::deposit_event(Event::<T>::Claimed {
+                campaign_id,
+                beneficiary,
+                amount,
+            });
+            Ok(())
+        }
+
+        pub(crate) fn check_zk_claimant(
+            campaign_id: CampaignId,
+            nullifier: &H256,
+        ) -> Result<(CampaignInfoOf<T>, ZkCommitmentOf<T>), Error<T>> {
+            // Pre-requisites
+            // 1. Check campaign exists, is claimable and commits to a claim circuit
+            let campaign = Self::campaign(campaign_id)?;
+            Self::check_claim_started(&campaign)?;
+            let zk = campaign.zk.ok_or(Error::<T>::NotZkCampaign)?;
+
+            // 2. Check nullifier has not been used yet
+            if Nullifiers::<T>::contains_key(campaign_id, nullifier) {
+                Err(Error::<T>::NullifierAlreadyUsed)?;
+            }
+
+            Ok((campaign, zk))
+        }
+
+        fn verify_zk_claim(
+            zk: &ZkCommitmentOf<T>,
+            nullifier: &H256,
+            dest: &T::AccountId,
+            amount: BalanceOf<T>,
+            proof: &ClaimProofOf<T>,
+        ) -> Result<(), Error<T>> {
+            let pubs = Self::zk_claim_pubs(&zk.root, nullifier, dest, amount);
+            T::ClaimProofVerifier::verify_claim_proof(&zk.verifier, &zk.vk_hash, proof, &pubs)
+                .map_err(|e| {
+                    log::debug!("Invalid claim proof: {e:?}");
+                    Error::<T>::InvalidZkProof
+                })
+        }
+
+        /// The weight of verifying the proof of a 'claim_zk' call.
+        fn verify_zk_claim_weight(
+            nullifier: &H256,
+            dest: &T::AccountId,
+            amount: BalanceOf<T>,
+            proof: &ClaimProofOf<T>,
+        ) -> Weight {
+            // The verification cost doesn't depend on the value of the root
+            let pubs = Self::zk_claim_pubs(&H256::zero(), nullifier, dest, amount);
+            T::ClaimProofVerifier::verify_claim_proof_weight(proof, &pubs)
+        }
+
+        pub(crate) fn process_zk_claim(
+            campaign_id: CampaignId,
+            nullifier: H256,
+            dest: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            // The commitment could be to more than the campaign has been funded with
+            let available = Self::pot(campaign_id).min(TotalClaimable::<T>::get(campaign_id));
+            if amount > available {
+                log::warn!("Claimable amount {amount:?} bigger than total available {available:?}");
+                Err(TokenError::FundsUnavailable)?;
+            }
+            Nullifiers::<T>::insert(campaign_id, nullifier, ());
+            BeneficiariesCount::<T>::mutate(campaign_id, |count| *count = count.saturating_add(1));
+            log::trace!("Claimed {amount:?} with nullifier {nullifier:?}");
+            Self::deposit_event(Event::<T>::ZkClaimed {
+                campaign_id,
+                nullifier,
+                dest,
+                amount,
+            });
             Ok(())
         }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Declare the beginning of a new claim and start adding beneficiaries (if specified).
+        /// Declare the beginning of a new claim campaign and start adding beneficiaries (if specified).
+        /// The 'config' establishes the campaign manager, where unclaimed tokens are sent and the
+        /// claiming period.
         /// Raise an Error if:
-        /// - There is an already active claim
+        /// - There is an already started campaign with the same id
         /// - The claim message is empty
+        /// - The campaign would end before starting
         /// - An error has occurred during insertion of the beneficiaries (insufficient amount,
-        ///   duplicates, pallet doesn't have enough funds)
+        ///   duplicates, campaign account doesn't have enough funds)
         /// - Trying to add too many beneficiaries (more than **T::MaxOpBeneficiaries**)
         /// The add_beneficiaries operation is atomic. If one insertion fails, the whole extrinsic fails.
         /// Origin must be the ManagerOrigin.
@@ -506,68 +1078,49 @@ pub mod pallet {
         )]
         pub fn begin_claim(
             origin: OriginFor<T>,
+            campaign_id: CampaignId,
             beneficiaries: BTreeMap<Beneficiary<T>, BalanceOf<T>>,
             claim_message: ClaimMessage<T>,
+            config: CampaignConfigOf<T>,
         ) -> DispatchResult {
-            T::ManagerOrigin::ensure_origin(origin)?;
-
-            // Sanity check: we've removed all the beneficiaries
-            if Beneficiaries::<T>::count() > 0 {
-                Err(Error::<T>::NonEmptyBeneficiaries)?;
-            }
-
-            if claim_message.is_empty() {
-                Err(Error::<T>::InvalidClaimMessage)?;
-            }
-
-            // Set claim as active
-            Self::check_claim_status(false)?;
-            ClaimActive::<T>::put(true);
+            // Set campaign as active
+            let campaign = Self::do_begin_claim(origin, campaign_id, claim_message, config)?;
+            Campaigns::<T>::insert(campaign_id, &campaign);
 
             let num_beneficiaries = beneficiaries.len();
 
             if num_beneficiaries > 0 {
                 // Check that we are not adding too many here
                 // Note: we don't need to check for MaxBeneficiaries considering that:
-                // - When starting a claim there are no beneficiaries in storage
+                // - When starting a campaign there are no beneficiaries in storage for it
                 // - It always holds that T::MaxOpBeneficiaries <= T::MaxBeneficiaries
                 // Thus the following check, alone, it's enough
                 Self::check_max_op_beneficiaries(num_beneficiaries)?;
 
                 // Start adding beneficiaries if specified
-                Self::do_add_beneficiaries(beneficiaries)?;
+                Self::do_add_beneficiaries(campaign_id, beneficiaries)?;
             }
 
-            // Increase claim id
-            // Note: Theoretically we should hold some funds from the manager account for
-            // storing claim_id and claim_message.
-            // However, considering that it's only one message, limited in size,
-            // we avoid introducing this unnecessary complexity here.
-            ClaimId::<T>::mutate(|t| {
-                let new_t = t.clone().map_or((0, claim_message.clone()), |v| {
-                    (v.0 + 1, claim_message.clone())
-                });
-                *t = Some(new_t.clone());
-                Self::deposit_event(Event::<T>::ClaimStarted {
-                    claim_id: new_t.0,
-                    claim_message: new_t.1,
-                });
+            Self::deposit_event(Event::<T>::ClaimStarted {
+                campaign_id,
+                claim_message: campaign.claim_message,
             });
 
             Ok(())
         }
 
-        /// Claim tokens for a 'beneficiary' with a Substrate address, provided a
-        /// 'signature' on the actual claim message.
+        /// Claim tokens from campaign 'campaign_id' for a 'beneficiary' with a Substrate address,
+        /// provided a 'signature' on the campaign claim message.
         /// 'origin' must be none.
         /// Fails if:
         /// - 'beneficiary' is not entitled to any token
         /// - The supplied 'signature' is invalid.
-        /// - There is no active airdrop
+        /// - The campaign is not active or its claiming period has not started yet
         #[pallet::call_index(1)]
         #[pallet::weight(T::WeightInfo::claim())]
         pub fn claim(
             origin: OriginFor<T>,
+            campaign_id: CampaignId,
             beneficiary: T::Signer,
             signature: T::Signature,
         ) -> DispatchResult {
@@ -575,128 +1128,177 @@ pub mod pallet {
             let beneficiary_account = beneficiary.into_account();
             let beneficiary = Beneficiary::<T>::Substrate(beneficiary_account.clone());
             let signature = ClaimSignature::<T>::Substrate(signature);
-            Self::check_claimant(&beneficiary, signature)?;
-            let amount = Self::process_claim(beneficiary)?;
-            Self::do_claim(beneficiary_account, amount)
+            let campaign = Self::check_claimant(campaign_id, &beneficiary, signature)?;
+            let (amount, vesting) = Self::process_claim(campaign_id, &campaign, beneficiary)?;
+            Self::do_claim(campaign_id, beneficiary_account, amount, vesting)
         }
 
-        /// Allows ManagerOrigin to claim tokens in place of a Substrate beneficiary 'dest' (taking care of the fees).
+        /// Allows ManagerOrigin or the campaign manager to claim tokens in place of a Substrate
+        /// beneficiary 'dest' (taking care of the fees).
         /// Fails if:
         /// - 'dest' is not entitled to any token
-        /// - There is no active airdrop
+        /// - The campaign is not active or its claiming period has not started yet
         /// 'origin' must be signed
         #[pallet::call_index(2)]
         #[pallet::weight(T::WeightInfo::claim_for())]
-        pub fn claim_for(origin: OriginFor<T>, dest: T::AccountId) -> DispatchResult {
-            T::ManagerOrigin::ensure_origin(origin)?;
-            Self::check_claim_status(true)?;
-            let amount = Self::process_claim(Beneficiary::<T>::Substrate(dest.clone()))?;
-            Self::do_claim(dest, amount)
+        pub fn claim_for(
+            origin: OriginFor<T>,
+            campaign_id: CampaignId,
+            dest: T::AccountId,
+        ) -> DispatchResult {
+            let campaign = Self::campaign(campaign_id)?;
+            Self::ensure_campaign_manager(origin, &campaign)?;
+            Self::check_claim_started(&campaign)?;
+            let (amount, vesting) = Self::process_claim(
+                campaign_id,
+                &campaign,
+                Beneficiary::<T>::Substrate(dest.clone()),
+            )?;
+            Self::do_claim(campaign_id, dest, amount, vesting)
         }
 
-        /// Add beneficiaries.
+        /// Add beneficiaries to campaign 'campaign_id'.
         /// Raise an Error if:
-        /// - There is no active airdrop
-        /// - There isn't enough balance in the pallets' account to cover for the tokens belonging to the supplied beneficiaries
+        /// - The campaign is not active
+        /// - There isn't enough balance in the campaign's account to cover for the tokens belonging to the supplied beneficiaries
         /// - Attempt to modify the claimable amount of an already existing beneficiary or adding a duplicate
         /// - Attempt to assign 0 tokens to a beneficiary.
         /// This is an atomic operation.
-        /// Origin must be the ManagerOrigin.
+        /// Origin must be the ManagerOrigin or the campaign manager.
         #[pallet::call_index(3)]
         #[pallet::weight(T::WeightInfo::add_beneficiaries(beneficiaries.len() as u32))]
         pub fn add_beneficiaries(
             origin: OriginFor<T>,
+            campaign_id: CampaignId,
             beneficiaries: BTreeMap<Beneficiary<T>, BalanceOf<T>>,
         ) -> DispatchResult {
-            Self::check_claim_status(true)?;
-            T::ManagerOrigin::ensure_origin(origin)?;
+            let campaign = Self::campaign(campaign_id)?;
+            Self::check_claim_status(&campaign, true)?;
+            Self::ensure_campaign_manager(origin, &campaign)?;
+            if campaign.merkle.is_some() {
+                Err(Error::<T>::MerkleCampaign)?;
+            }
+            if campaign.zk.is_some() {
+                Err(Error::<T>::ZkCampaign)?;
+            }
 
             let num_beneficiaries = beneficiaries.len();
 
             if num_beneficiaries > 0 {
                 // Check that we are not adding too many here
-                Self::check_beneficiaries_len(num_beneficiaries)?;
+                Self::check_beneficiaries_len(campaign_id, num_beneficiaries)?;
 
                 // Start adding beneficiaries if specified
-                Self::do_add_beneficiaries(beneficiaries)?;
+                Self::do_add_beneficiaries(campaign_id, beneficiaries)?;
             }
 
             Ok(())
         }
 
-        /// End a claim. Storage variables will be cleared.
-        /// Any unclaimed balance will be sent to the destination specified as per 'UnclaimedDestination'.
-        /// Raise an Error if attempting to end an already ended claim.
-        /// This extrinsic will attempt to remove as many beneficiaries as possible from storage.
-        /// However, if there are more than **T::MaxOpBeneficiaries**, subsequent call(s) to 'remove_beneficiaries' must be made.
-        /// Origin must be 'ManagerOrigin'.
+        /// End campaign 'campaign_id'.
+        /// Any unclaimed balance will be sent to the campaign unclaimed destination.
+        /// Raise an Error if attempting to end an already ended campaign.
+        /// The beneficiaries of the campaign must be then removed via 'remove_beneficiaries'.
+        /// Origin must be 'ManagerOrigin' or the campaign manager, or any signed origin once the
+        /// campaign period is over.
         #[pallet::call_index(4)]
         #[pallet::weight(T::WeightInfo::end_claim())]
-        pub fn end_claim(origin: OriginFor<T>) -> DispatchResult {
-            T::ManagerOrigin::ensure_origin(origin)?;
+        pub fn end_claim(origin: OriginFor<T>, campaign_id: CampaignId) -> DispatchResult {
+            let mut campaign = Self::campaign(campaign_id)?;
+            if campaign.is_over(&frame_system::Pallet::<T>::block_number()) {
+                // Anyone can finalize a campaign whose period is over
+                if let Err(origin) = T::ManagerOrigin::try_origin(origin) {
+                    ensure_signed(origin)?;
+                }
+            } else {
+                Self::ensure_campaign_manager(origin, &campaign)?;
+            }
 
-            // Set claim as inactive
-            Self::check_claim_status(true)?;
-            ClaimActive::<T>::put(false);
+            // Set campaign as inactive
+            if !campaign.active {
+                Err(Error::<T>::AlreadyEnded)?;
+            }
+            campaign.active = false;
 
-            // Deal with any remaining balance in the pallet's account
-            let unclaimed_destination = T::UnclaimedDestination::get();
-            if unclaimed_destination != Self::account_id() {
-                let remaining_funds = Self::pot();
+            // Deal with any remaining balance in the campaign's account
+            let account = Self::campaign_account_id(campaign_id);
+            if campaign.unclaimed_destination != account {
+                let remaining_funds = Self::pot(campaign_id);
                 T::Currency::transfer(
-                    &Self::account_id(),
-                    &unclaimed_destination,
+                    &account,
+                    &campaign.unclaimed_destination,
                     remaining_funds,
                     Preservation::Preserve,
                 )?;
                 log::debug!("Sending {remaining_funds:?} to specified destination");
             }
 
-            // Set total claimable to 0
-            TotalClaimable::<T>::put(BalanceOf::<T>::zero());
+            // Nothing more to claim
+            TotalClaimable::<T>::remove(campaign_id);
+            if let Some(zk) = &campaign.zk {
+                T::ClaimProofVerifier::release_vk(&zk.verifier, &zk.vk_hash);
+            }
 
             // End claim
-            let (claim_id, claim_message) = ClaimId::<T>::get().unwrap();
             Self::deposit_event(Event::<T>::ClaimEnded {
-                claim_id,
-                claim_message,
+                campaign_id,
+                claim_message: campaign.claim_message.clone(),
             });
+            if BeneficiariesCount::<T>::get(campaign_id) == 0 {
+                Campaigns::<T>::remove(campaign_id);
+            } else {
+                Campaigns::<T>::insert(campaign_id, campaign);
+            }
 
             Ok(())
         }
 
         /// Remove up to 'limit' beneficiaries (or up to **T::MaxOpBeneficiaries** if 'limit'
-        /// is not specified) from storage. Fails if there is a claim in progress.
+        /// is not specified) of campaign 'campaign_id' from storage. Fails if the campaign
+        /// is still in progress. Once all the beneficiaries are removed, the campaign is
+        /// removed as well.
         /// Origin must be signed.
         #[pallet::call_index(5)]
         #[pallet::weight(T::WeightInfo::remove_beneficiaries(
-            u32::min(Beneficiaries::<T>::count(), limit.unwrap_or(T::MaxOpBeneficiaries::get())))
+            u32::min(BeneficiariesCount::<T>::get(campaign_id), limit.unwrap_or(T::MaxOpBeneficiaries::get())))
             .saturating_add(T::DbWeight::get().reads(1_u64))
         )]
-        pub fn remove_beneficiaries(origin: OriginFor<T>, limit: Option<u32>) -> DispatchResult {
+        pub fn remove_beneficiaries(
+            origin: OriginFor<T>,
+            campaign_id: CampaignId,
+            limit: Option<u32>,
+        ) -> DispatchResult {
             let _ = ensure_signed(origin)?;
 
-            // Check claim inactive
-            Self::check_claim_status(false)?;
+            // Check campaign ended
+            let campaign = Self::campaign(campaign_id)?;
+            if campaign.active {
+                Err(Error::<T>::AlreadyStarted)?;
+            }
 
             // Remove as many beneficiaries as possible
-            Self::do_remove_beneficiaries(limit.unwrap_or(T::MaxOpBeneficiaries::get()));
+            Self::do_remove_beneficiaries(
+                campaign_id,
+                &campaign,
+                limit.unwrap_or(T::MaxOpBeneficiaries::get()),
+            );
 
             Ok(())
         }
 
-        /// Claim tokens for a 'beneficiary' with an Ethereum address and send them to 'dest',
-        /// provided a 'signature' on the actual claim message and 'dest'.
-        /// The message to sign should follow the EIP-191 standard.
+        /// Claim tokens from campaign 'campaign_id' for a 'beneficiary' with an Ethereum address
+        /// and send them to 'dest', provided a 'signature' on the campaign claim message and 'dest'.
+        /// The message to sign should follow either the EIP-191 or the EIP-712 standard.
         /// 'origin' must be none.
         /// Fails if:
         /// - 'beneficiary' is not entitled to any token
         /// - The supplied 'signature' is invalid.
-        /// - There is no active airdrop
+        /// - The campaign is not active or its claiming period has not started yet
         #[pallet::call_index(6)]
         #[pallet::weight(T::WeightInfo::claim_ethereum())]
         pub fn claim_ethereum(
             origin: OriginFor<T>,
+            campaign_id: CampaignId,
             beneficiary: EthereumAddress,
             signature: EthereumSignature,
             dest: T::AccountId,
@@ -704,27 +1306,318 @@ pub mod pallet {
             ensure_none(origin)?;
             let beneficiary = Beneficiary::<T>::Ethereum(beneficiary);
             let signature = ClaimSignature::<T>::Ethereum((signature, dest.clone()));
-            Self::check_claimant(&beneficiary, signature)?;
-            let amount = Self::process_claim(beneficiary)?;
-            Self::do_claim(dest, amount)
+            let campaign = Self::check_claimant(campaign_id, &beneficiary, signature)?;
+            let (amount, vesting) = Self::process_claim(campaign_id, &campaign, beneficiary)?;
+            Self::do_claim(campaign_id, dest, amount, vesting)
         }
 
-        /// Allows ManagerOrigin to claim tokens in place of a Substrate beneficiary 'dest' (taking care of the fees).
+        /// Allows ManagerOrigin or the campaign manager to claim tokens in place of an Ethereum
+        /// 'beneficiary', sending them to 'dest' (taking care of the fees).
         /// Fails if:
-        /// - 'dest' is not entitled to any token
-        /// - There is no active airdrop
+        /// - 'beneficiary' is not entitled to any token
+        /// - The campaign is not active or its claiming period has not started yet
         /// 'origin' must be signed
         #[pallet::call_index(7)]
         #[pallet::weight(T::WeightInfo::claim_ethereum_for())]
         pub fn claim_ethereum_for(
             origin: OriginFor<T>,
+            campaign_id: CampaignId,
             beneficiary: EthereumAddress,
             dest: T::AccountId,
         ) -> DispatchResult {
-            T::ManagerOrigin::ensure_origin(origin)?;
-            Self::check_claim_status(true)?;
-            let amount = Self::process_claim(Beneficiary::<T>::Ethereum(beneficiary))?;
-            Self::do_claim(dest, amount)
+            let campaign = Self::campaign(campaign_id)?;
+            Self::ensure_campaign_manager(origin, &campaign)?;
+            Self::check_claim_started(&campaign)?;
+            let (amount, vesting) = Self::process_claim(
+                campaign_id,
+                &campaign,
+                Beneficiary::<T>::Ethereum(beneficiary),
+            )?;
+            Self::do_claim(campaign_id, dest, amount, vesting)
+        }
+
+        /// Declare the beginning of a new claim campaign whose beneficiaries are not kept in
+        /// storage, but committed to by the root of a Merkle tree ('merkle').
+        /// The campaign account must hold at least 'total' tokens, the sum of the amounts in
+        /// the leaves of the tree.
+        /// The 'config' establishes the campaign manager, where unclaimed tokens are sent and the
+        /// claiming period.
+        /// Raise an Error if:
+        /// - There is an already started campaign with the same id
+        /// - The claim message is empty
+        /// - The campaign would end before starting
+        /// - The Merkle tree is empty or 'total' is zero
+        /// - The campaign account doesn't have enough funds
+        /// Origin must be the ManagerOrigin.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::begin_merkle_claim())]
+        pub fn begin_merkle_claim(
+            origin: OriginFor<T>,
+            campaign_id: CampaignId,
+            merkle: MerkleCommitment,
+            total: BalanceOf<T>,
+            claim_message: ClaimMessage<T>,
+            config: CampaignConfigOf<T>,
+        ) -> DispatchResult {
+            let mut campaign = Self::do_begin_claim(origin, campaign_id, claim_message, config)?;
+
+            if merkle.number_of_leaves == 0 {
+                Err(Error::<T>::InvalidMerkleCommitment)?;
+            }
+            if total.is_zero() {
+                Err(Error::<T>::NothingToClaim)?;
+            }
+            if total > Self::pot(campaign_id) {
+                Err(TokenError::FundsUnavailable)?;
+            }
+
+            // Set campaign as active
+            campaign.merkle = Some(merkle);
+            Campaigns::<T>::insert(campaign_id, &campaign);
+            TotalClaimable::<T>::insert(campaign_id, total);
+
+            Self::deposit_event(Event::<T>::ClaimStarted {
+                campaign_id,
+                claim_message: campaign.claim_message,
+            });
+
+            Ok(())
+        }
+
+        /// Claim 'amount' tokens from Merkle campaign 'campaign_id' for a 'beneficiary' with a
+        /// Substrate address, provided a Merkle 'proof' of the '(beneficiary, amount)' leaf and a
+        /// 'signature' on the campaign claim message.
+        /// 'origin' must be none.
+        /// Fails if:
+        /// - The campaign doesn't commit to a Merkle tree
+        /// - The supplied 'proof' is invalid or its leaf has already been claimed
+        /// - The supplied 'signature' is invalid.
+        /// - The campaign is not active or its claiming period has not started yet
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::claim_merkle(proof.path.len() as u32))]
+        pub fn claim_merkle(
+            origin: OriginFor<T>,
+            campaign_id: CampaignId,
+            beneficiary: T::Signer,
+            amount: BalanceOf<T>,
+            proof: MerkleProof,
+            signature: T::Signature,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+            let beneficiary_account = beneficiary.into_account();
+            let beneficiary = Beneficiary::<T>::Substrate(beneficiary_account.clone());
+            let signature = ClaimSignature::<T>::Substrate(signature);
+            let campaign =
+                Self::check_merkle_claimant(campaign_id, &beneficiary, amount, &proof, signature)?;
+            Self::process_merkle_claim(campaign_id, proof.leaf_index, beneficiary, amount)?;
+            Self::do_claim(campaign_id, beneficiary_account, amount, campaign.vesting)
+        }
+
+        /// Claim 'amount' tokens from Merkle campaign 'campaign_id' for a 'beneficiary' with an
+        /// Ethereum address and send them to 'dest', provided a Merkle 'proof' of the
+        /// '(beneficiary, amount)' leaf and a 'signature' on the campaign claim message and 'dest'.
+        /// The message to sign should follow either the EIP-191 or the EIP-712 standard.
+        /// 'origin' must be none.
+        /// Fails if:
+        /// - The campaign doesn't commit to a Merkle tree
+        /// - The supplied 'proof' is invalid or its leaf has already been claimed
+        /// - The supplied 'signature' is invalid.
+        /// - The campaign is not active or its claiming period has not started yet
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::claim_ethereum_merkle(proof.path.len() as u32))]
+        pub fn claim_ethereum_merkle(
+            origin: OriginFor<T>,
+            campaign_id: CampaignId,
+            beneficiary: EthereumAddress,
+            amount: BalanceOf<T>,
+            proof: MerkleProof,
+            signature: EthereumSignature,
+            dest: T::AccountId,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+            let beneficiary = Beneficiary::<T>::Ethereum(beneficiary);
+            let signature = ClaimSignature::<T>::Ethereum((signature, dest.clone()));
+            let campaign =
+                Self::check_merkle_claimant(campaign_id, &beneficiary, amount, &proof, signature)?;
+            Self::process_merkle_claim(campaign_id, proof.leaf_index, beneficiary, amount)?;
+            Self::do_claim(campaign_id, dest, amount, campaign.vesting)
+        }
+
+        /// Set the vesting schedules of some 'beneficiaries' of campaign 'campaign_id',
+        /// overriding the campaign one, or clear them if `None`.
+        /// Raise an Error if:
+        /// - The campaign is not active or commits to a Merkle tree
+        /// - Some of the supplied beneficiaries is not entitled to any token
+        /// - Some of the supplied vesting schedules has zero duration
+        /// - Trying to update too many beneficiaries (more than **T::MaxOpBeneficiaries**)
+        /// This is an atomic operation.
+        /// Origin must be the ManagerOrigin or the campaign manager.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::set_beneficiaries_vesting(beneficiaries.len() as u32))]
+        pub fn set_beneficiaries_vesting(
+            origin: OriginFor<T>,
+            campaign_id: CampaignId,
+            beneficiaries: BTreeMap<Beneficiary<T>, Option<VestingConfigOf<T>>>,
+        ) -> DispatchResult {
+            let campaign = Self::campaign(campaign_id)?;
+            Self::check_claim_status(&campaign, true)?;
+            Self::ensure_campaign_manager(origin, &campaign)?;
+            if campaign.merkle.is_some() {
+                Err(Error::<T>::MerkleCampaign)?;
+            }
+            if campaign.zk.is_some() {
+                Err(Error::<T>::ZkCampaign)?;
+            }
+            Self::check_max_op_beneficiaries(beneficiaries.len())?;
+
+            for (beneficiary, vesting) in beneficiaries {
+                if !Beneficiaries::<T>::contains_key(campaign_id, &beneficiary) {
+                    Err(Error::<T>::NotEligible)?;
+                }
+                match vesting {
+                    Some(vesting) => {
+                        Self::check_vesting(&vesting)?;
+                        BeneficiaryVesting::<T>::insert(campaign_id, &beneficiary, vesting);
+                    }
+                    None => BeneficiaryVesting::<T>::remove(campaign_id, &beneficiary),
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Declare the beginning of a new claim campaign whose beneficiaries are not known, but
+        /// committed to by 'zk': they claim by proving, against the verification key registered in
+        /// the verifier of 'zk', knowledge of a secret committed to by its root.
+        /// The campaign account must hold at least 'total' tokens, the sum of the amounts the
+        /// beneficiaries are entitled to.
+        /// The 'config' establishes the campaign manager, where unclaimed tokens are sent and the
+        /// claiming period.
+        /// Raise an Error if:
+        /// - There is an already started campaign with the same id
+        /// - The claim message is empty
+        /// - The campaign would end before starting
+        /// - 'total' is zero
+        /// - The campaign account doesn't have enough funds
+        /// - The verification key of 'zk' is not registered in its verifier
+        /// The verification key is kept registered until the campaign ends.
+        /// Origin must be the ManagerOrigin.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::begin_zk_claim())]
+        pub fn begin_zk_claim(
+            origin: OriginFor<T>,
+            campaign_id: CampaignId,
+            zk: ZkCommitmentOf<T>,
+            total: BalanceOf<T>,
+            claim_message: ClaimMessage<T>,
+            config: CampaignConfigOf<T>,
+        ) -> DispatchResult {
+            let mut campaign = Self::do_begin_claim(origin, campaign_id, claim_message, config)?;
+
+            if total.is_zero() {
+                Err(Error::<T>::NothingToClaim)?;
+            }
+            if total > Self::pot(campaign_id) {
+                Err(TokenError::FundsUnavailable)?;
+            }
+            T::ClaimProofVerifier::retain_vk(&zk.verifier, &zk.vk_hash).map_err(|e| {
+                log::debug!("Cannot retain claim vk: {e:?}");
+                Error::<T>::VkNotRegistered
+            })?;
+
+            // Set campaign as active
+            campaign.zk = Some(zk);
+            Campaigns::<T>::insert(campaign_id, &campaign);
+            TotalClaimable::<T>::insert(campaign_id, total);
+
+            Self::deposit_event(Event::<T>::ClaimStarted {
+                campaign_id,
+                claim_message: campaign.claim_message,
+            });
+
+            Ok(())
+        }
+
+        /// Claim 'amount' tokens from ZK campaign 'campaign_id' and send them to 'dest', provided a
+        /// zero-knowledge 'proof' whose public inputs are the campaign root, 'nullifier', 'dest' and
+        /// 'amount' (see **zk_claim_pubs**).
+        /// 'origin' must be signed, but needs not be 'dest': the fee is refunded if the claim
+        /// succeeds. The proof is too expensive to be verified for free when validating unsigned
+        /// transactions, so failed claims are paid for by whoever submitted them.
+        /// Fails if:
+        /// - The campaign doesn't commit to a claim circuit
+        /// - 'nullifier' has already been used
+        /// - The supplied 'proof' is invalid.
+        /// - The campaign is not active or its claiming period has not started yet
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::claim_zk().saturating_add(
+            Pallet::<T>::verify_zk_claim_weight(nullifier, dest, *amount, proof)
+        ))]
+        pub fn claim_zk(
+            origin: OriginFor<T>,
+            campaign_id: CampaignId,
+            nullifier: H256,
+            amount: BalanceOf<T>,
+            dest: T::AccountId,
+            proof: Box<ClaimProofOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            let (campaign, zk) = Self::check_zk_claimant(campaign_id, &nullifier)?;
+            Self::verify_zk_claim(&zk, &nullifier, &dest, amount, &proof)?;
+            Self::process_zk_claim(campaign_id, nullifier, dest.clone(), amount)?;
+            Self::do_claim(campaign_id, dest, amount, campaign.vesting)?;
+            Ok(Pays::No.into())
+        }
+
+        /// Claim tokens from campaign 'campaign_id' for a 'beneficiary' with a Solana address
+        /// and send them to 'dest', provided an ed25519 'signature' on the campaign claim message
+        /// and 'dest'.
+        /// 'origin' must be none.
+        /// Fails if:
+        /// - 'beneficiary' is not entitled to any token
+        /// - The supplied 'signature' is invalid.
+        /// - The campaign is not active or its claiming period has not started yet
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::claim_solana())]
+        pub fn claim_solana(
+            origin: OriginFor<T>,
+            campaign_id: CampaignId,
+            beneficiary: SolanaAddress,
+            signature: SolanaSignature,
+            dest: T::AccountId,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+            let beneficiary = Beneficiary::<T>::Solana(beneficiary);
+            let signature = ClaimSignature::<T>::Solana((signature, dest.clone()));
+            let campaign = Self::check_claimant(campaign_id, &beneficiary, signature)?;
+            let (amount, vesting) = Self::process_claim(campaign_id, &campaign, beneficiary)?;
+            Self::do_claim(campaign_id, dest, amount, vesting)
+        }
+
+        /// Claim tokens from campaign 'campaign_id' for a 'beneficiary' with a Bitcoin address
+        /// and send them to 'dest', provided a 'signature' on the campaign claim message and 'dest'.
+        /// The message to sign should follow the BIP-137 standard. For a compressed public key,
+        /// either the P2PKH or the P2WPKH headers are accepted, whatever the type of 'beneficiary'.
+        /// 'origin' must be none.
+        /// Fails if:
+        /// - 'beneficiary' is not entitled to any token
+        /// - The supplied 'signature' is invalid.
+        /// - The campaign is not active or its claiming period has not started yet
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::claim_bitcoin())]
+        pub fn claim_bitcoin(
+            origin: OriginFor<T>,
+            campaign_id: CampaignId,
+            beneficiary: BitcoinAddress,
+            signature: BitcoinSignature,
+            dest: T::AccountId,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+            let beneficiary = Beneficiary::<T>::Bitcoin(beneficiary);
+            let signature = ClaimSignature::<T>::Bitcoin((signature, dest.clone()));
+            let campaign = Self::check_claimant(campaign_id, &beneficiary, signature)?;
+            let (amount, vesting) = Self::process_claim(campaign_id, &campaign, beneficiary)?;
+            Self::do_claim(campaign_id, dest, amount, vesting)
         }
     }
 
@@ -737,28 +1630,115 @@ pub mod pallet {
 
             let provides = match call {
                 Call::claim {
+                    campaign_id,
                     beneficiary,
                     signature,
                 } => {
-                    let beneficiary =
-                        Beneficiary::<T>::Substrate(beneficiary.clone().into_account());
+                    let dest = beneficiary.clone().into_account();
+                    let beneficiary = Beneficiary::<T>::Substrate(dest.clone());
                     let signature = ClaimSignature::<T>::Substrate(signature.clone());
-                    Self::check_claimant(&beneficiary, signature)?;
-                    vec![("claim", ClaimId::<T>::get().unwrap(), beneficiary).encode()]
+                    let campaign = Self::check_claimant(*campaign_id, &beneficiary, signature)?;
+                    Self::check_can_vest(
+                        &dest,
+                        Beneficiaries::<T>::get(campaign_id, &beneficiary).unwrap_or_default(),
+                        Self::vesting_of(*campaign_id, &campaign, &beneficiary),
+                    )?;
+                    vec![("claim", campaign_id, beneficiary).encode()]
                 }
                 Call::claim_ethereum {
+                    campaign_id,
                     beneficiary,
                     signature,
                     dest,
                 } => {
                     let beneficiary = Beneficiary::<T>::Ethereum(*beneficiary);
                     let signature = ClaimSignature::<T>::Ethereum((*signature, dest.clone()));
-                    Self::check_claimant(&beneficiary, signature)?;
+                    let campaign = Self::check_claimant(*campaign_id, &beneficiary, signature)?;
+                    Self::check_can_vest(
+                        dest,
+                        Beneficiaries::<T>::get(campaign_id, &beneficiary).unwrap_or_default(),
+                        Self::vesting_of(*campaign_id, &campaign, &beneficiary),
+                    )?;
                     // Note: 'dest' is not included in the 'provides' as to avoid a possible attack
                     // where a valid beneficiary would be able to craft multiple valid transactions
                     // with different 'dest' parameter, filling the mempool for a given block and
                     // effectively forbidding other transactions to go through.
-                    vec![("claim_ethereum", ClaimId::<T>::get().unwrap(), beneficiary).encode()]
+                    vec![("claim_ethereum", campaign_id, beneficiary).encode()]
+                }
+                Call::claim_solana {
+                    campaign_id,
+                    beneficiary,
+                    signature,
+                    dest,
+                } => {
+                    let beneficiary = Beneficiary::<T>::Solana(*beneficiary);
+                    let signature = ClaimSignature::<T>::Solana((*signature, dest.clone()));
+                    let campaign = Self::check_claimant(*campaign_id, &beneficiary, signature)?;
+                    Self::check_can_vest(
+                        dest,
+                        Beneficiaries::<T>::get(campaign_id, &beneficiary).unwrap_or_default(),
+                        Self::vesting_of(*campaign_id, &campaign, &beneficiary),
+                    )?;
+                    // Note: 'dest' is not included in the 'provides', see 'claim_ethereum'.
+                    vec![("claim_solana", campaign_id, beneficiary).encode()]
+                }
+                Call::claim_bitcoin {
+                    campaign_id,
+                    beneficiary,
+                    signature,
+                    dest,
+                } => {
+                    let beneficiary = Beneficiary::<T>::Bitcoin(*beneficiary);
+                    let signature = ClaimSignature::<T>::Bitcoin((*signature, dest.clone()));
+                    let campaign = Self::check_claimant(*campaign_id, &beneficiary, signature)?;
+                    Self::check_can_vest(
+                        dest,
+                        Beneficiaries::<T>::get(campaign_id, &beneficiary).unwrap_or_default(),
+                        Self::vesting_of(*campaign_id, &campaign, &beneficiary),
+                    )?;
+                    // Note: 'dest' is not included in the 'provides', see 'claim_ethereum'.
+                    vec![("claim_bitcoin", campaign_id, beneficiary).encode()]
+                }
+                Call::claim_merkle {
+                    campaign_id,
+                    beneficiary,
+                    amount,
+                    proof,
+                    signature,
+                } => {
+                    let dest = beneficiary.clone().into_account();
+                    let beneficiary = Beneficiary::<T>::Substrate(dest.clone());
+                    let signature = ClaimSignature::<T>::Substrate(signature.clone());
+                    let campaign = Self::check_merkle_claimant(
+                        *campaign_id,
+                        &beneficiary,
+                        *amount,
+                        proof,
+                        signature,
+                    )?;
+                    Self::check_can_vest(&dest, *amount, campaign.vesting)?;
+                    // Note: the leaf index identifies the claim, as it could not be claimed twice.
+                    vec![("claim_merkle", campaign_id, proof.leaf_index).encode()]
+                }
+                Call::claim_ethereum_merkle {
+                    campaign_id,
+                    beneficiary,
+                    amount,
+                    proof,
+                    signature,
+                    dest,
+                } => {
+                    let beneficiary = Beneficiary::<T>::Ethereum(*beneficiary);
+                    let signature = ClaimSignature::<T>::Ethereum((*signature, dest.clone()));
+                    let campaign = Self::check_merkle_claimant(
+                        *campaign_id,
+                        &beneficiary,
+                        *amount,
+                        proof,
+                        signature,
+                    )?;
+                    Self::check_can_vest(dest, *amount, campaign.vesting)?;
+                    vec![("claim_merkle", campaign_id, proof.leaf_index).encode()]
                 }
                 _ => return Err(InvalidTransaction::Call.into()),
             };
@@ -773,15 +1753,3 @@ pub mod pallet {
         }
     }
 }
-
-/// TypedGet implementation to get the AccountId of the Claim pallet.
-pub struct ClaimAccountId<R>(PhantomData<R>);
-impl<R> sp_runtime::traits::TypedGet for ClaimAccountId<R>
-where
-    R: crate::Config,
-{
-    type Type = <R as frame_system::Config>::AccountId;
-    fn get() -> Self::Type {
-        <crate::Pallet<R>>::account_id()
-    }
-}
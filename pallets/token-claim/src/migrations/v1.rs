@@ -0,0 +1,351 @@
+// Copyright 2026, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Migrate from storage v0 to v1.
+//!
+//! In v0, the pallet supported a single claim at a time, funded by the pallet account:
+//! ```ignore
+//! type Beneficiaries = CountedStorageMap<_, Twox64Concat, Beneficiary<T>, BalanceOf<T>>;
+//! type TotalClaimable = StorageValue<_, BalanceOf<T>, ValueQuery>;
+//! type ClaimActive = StorageValue<_, bool, ValueQuery>;
+//! type ClaimId = StorageValue<_, (u64, ClaimMessage<T>)>;
+//! type PalletAccountId = StorageValue<_, T::AccountId>;
+//! ```
+//!
+//! In v1, beneficiaries, totals and claim messages are keyed by campaign id, and each campaign
+//! is funded by its own account. The last v0 claim, if still active or with beneficiaries left
+//! to remove, becomes the campaign with the same id, and the funds of an active claim are moved
+//! to the campaign account.
+//!
+//! The beneficiaries are all moved in the upgrade block: `pre_upgrade` fails if there are too
+//! many of them to fit in a block (see [`migration_weight`]).
+
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+
+use frame_support::{
+    migrations::VersionedMigration,
+    traits::{
+        fungible::{Inspect, Mutate},
+        tokens::{Fortitude, Preservation},
+        UncheckedOnRuntimeUpgrade,
+    },
+    weights::Weight,
+};
+use sp_core::Get;
+use sp_runtime::traits::{AccountIdConversion, Zero};
+
+use crate::{Beneficiaries, BeneficiariesCount, CampaignInfo, Campaigns, Pallet, TotalClaimable};
+
+/// The worst case weight of the migration of a claim with `beneficiaries` beneficiaries: an
+/// active claim, whose funds are moved to the campaign account.
+pub fn migration_weight<T: frame_system::Config>(beneficiaries: u32) -> Weight {
+    let beneficiaries = beneficiaries as u64;
+    T::DbWeight::get().reads_writes(8 + beneficiaries, 10 + 2 * beneficiaries)
+}
+
+/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the single claim from v0 to a v1 campaign.
+pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+mod v0 {
+    use frame_support::{storage_alias, Twox64Concat};
+
+    use crate::{BalanceOf, Beneficiary, ClaimMessage};
+
+    #[storage_alias]
+    pub type Beneficiaries<T: crate::Config> =
+        CountedStorageMap<crate::Pallet<T>, Twox64Concat, Beneficiary<T>, BalanceOf<T>>;
+
+    #[storage_alias]
+    pub type TotalClaimable<T: crate::Config> = StorageValue<crate::Pallet<T>, BalanceOf<T>>;
+
+    #[storage_alias]
+    pub type ClaimActive<T: crate::Config> = StorageValue<crate::Pallet<T>, bool>;
+
+    #[storage_alias]
+    pub type ClaimId<T: crate::Config> = StorageValue<crate::Pallet<T>, (u64, ClaimMessage<T>)>;
+
+    #[storage_alias]
+    pub type PalletAccountId<T: crate::Config> =
+        StorageValue<crate::Pallet<T>, <T as frame_system::Config>::AccountId>;
+}
+
+impl<T: crate::Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut reads: u64 = 5;
+        let mut writes: u64 = 4;
+
+        let claim_id = v0::ClaimId::<T>::take();
+        let active = v0::ClaimActive::<T>::take().unwrap_or_default();
+        let total_claimable = v0::TotalClaimable::<T>::take().unwrap_or_default();
+        v0::PalletAccountId::<T>::kill();
+
+        // Old and new beneficiaries share the same storage prefix: remove all the old ones before
+        // inserting them again.
+        let beneficiaries = v0::Beneficiaries::<T>::iter().collect::<alloc::vec::Vec<_>>();
+        let _ = v0::Beneficiaries::<T>::clear(u32::MAX, None);
+        reads += beneficiaries.len() as u64 + 1;
+        writes += beneficiaries.len() as u64 + 1;
+
+        let Some((campaign_id, claim_message)) = claim_id else {
+            return T::DbWeight::get().reads_writes(reads, writes);
+        };
+        if !active && beneficiaries.is_empty() {
+            // The last claim is over and has been cleaned up: nothing to migrate
+            return T::DbWeight::get().reads_writes(reads, writes);
+        }
+
+        Campaigns::<T>::insert(
+            campaign_id,
+            CampaignInfo {
+                claim_message,
+                manager: None,
+                unclaimed_destination: T::UnclaimedDestination::get(),
+                start: Zero::zero(),
+                end: None,
+                active,
+                merkle: None,
+                vesting: None,
+                zk: None,
+            },
+        );
+        BeneficiariesCount::<T>::insert(campaign_id, beneficiaries.len() as u32);
+        writes += 2;
+        let count = beneficiaries.len();
+        for (beneficiary, amount) in beneficiaries {
+            Beneficiaries::<T>::insert(campaign_id, beneficiary, amount);
+            writes += 1;
+        }
+
+        if active {
+            TotalClaimable::<T>::insert(campaign_id, total_claimable);
+            // Move the claim funds from the pallet account to the campaign one
+            let pallet_account: T::AccountId = T::PalletId::get().into_account_truncating();
+            let funds = T::Currency::reducible_balance(
+                &pallet_account,
+                Preservation::Expendable,
+                Fortitude::Polite,
+            );
+            if let Err(e) = T::Currency::transfer(
+                &pallet_account,
+                &Pallet::<T>::campaign_account_id(campaign_id),
+                funds,
+                Preservation::Expendable,
+            ) {
+                log::warn!(
+                    target: "runtime::token-claim",
+                    "Token claim migration V0->V1: cannot move {funds:?} to campaign {campaign_id}: {e:?}",
+                );
+            }
+            reads += 2;
+            writes += 3;
+        }
+
+        log::info!(
+            target: "runtime::token-claim",
+            "Token claim migration V0->V1: migrated claim {campaign_id} with {count} beneficiaries",
+        );
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        let count = v0::Beneficiaries::<T>::count();
+        frame_support::ensure!(
+            migration_weight::<T>(count).all_lte(T::BlockWeights::get().max_block),
+            "token-claim pre_upgrade v0->v1: too many beneficiaries to migrate in a block"
+        );
+        let total_claimable = v0::TotalClaimable::<T>::get().unwrap_or_default();
+        Ok((count, total_claimable).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        let (pre_count, pre_total_claimable) =
+            <(u32, crate::BalanceOf<T>)>::decode(&mut state.as_slice())
+                .map_err(|_| "Failed to decode pre_upgrade state")?;
+        frame_support::ensure!(
+            !v0::ClaimActive::<T>::exists() && !v0::ClaimId::<T>::exists(),
+            "token-claim post_upgrade v0->v1: old claim left"
+        );
+        let count = BeneficiariesCount::<T>::iter_values().sum::<u32>();
+        frame_support::ensure!(
+            count == pre_count,
+            "token-claim post_upgrade v0->v1: expected {pre_count} beneficiaries, got {count}"
+        );
+        let total_claimable = TotalClaimable::<T>::iter_values()
+            .fold(crate::BalanceOf::<T>::zero(), |acc, v| acc + v);
+        frame_support::ensure!(
+            total_claimable == pre_total_claimable,
+            "token-claim post_upgrade v0->v1: total claimable mismatch"
+        );
+        Ok(())
+    }
+}
+
+/// [`UncheckedOnRuntimeUpgrade`] implementation [`InnerMigrateV0ToV1`] wrapped in a
+/// [`VersionedMigration`], which ensures that:
+/// - The migration only runs once when the on-chain storage version is 0
+/// - The on-chain storage version is updated to `1` after the migration executes
+/// - Reads/Writes from checking/setting the on-chain storage version are accounted for
+pub type MigrateV0ToV1<T> = VersionedMigration<
+    0,
+    1,
+    InnerMigrateV0ToV1<T>,
+    crate::Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mock::*, Beneficiary};
+    use frame_support::assert_ok;
+
+    const CLAIM_ID: u64 = 3;
+
+    fn legacy_account() -> AccountId {
+        ClaimPalletId::get().into_account_truncating()
+    }
+
+    fn insert_legacy_claim(active: bool, beneficiaries: &[(Beneficiary<Test>, Balance)]) {
+        let total = beneficiaries
+            .iter()
+            .map(|(_, amount)| amount)
+            .sum::<Balance>();
+        v0::ClaimId::<Test>::put((CLAIM_ID, INIT_CLAIM_MESSAGE.clone()));
+        v0::ClaimActive::<Test>::put(active);
+        v0::TotalClaimable::<Test>::put(if active { total } else { 0 });
+        v0::PalletAccountId::<Test>::put(legacy_account());
+        for (beneficiary, amount) in beneficiaries {
+            v0::Beneficiaries::<Test>::insert(beneficiary, amount);
+        }
+        if active {
+            Balances::mint_into(&legacy_account(), total + EXISTENTIAL_DEPOSIT).unwrap();
+        }
+    }
+
+    fn assert_legacy_storage_removed() {
+        assert!(!v0::ClaimId::<Test>::exists());
+        assert!(!v0::ClaimActive::<Test>::exists());
+        assert!(!v0::TotalClaimable::<Test>::exists());
+        assert!(!v0::PalletAccountId::<Test>::exists());
+    }
+
+    #[test]
+    fn active_claim_becomes_campaign() {
+        test().execute_with(|| {
+            insert_legacy_claim(true, &GENESIS_BENEFICIARIES);
+
+            InnerMigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+            assert_legacy_storage_removed();
+            let campaign = Campaigns::<Test>::get(CLAIM_ID).unwrap();
+            assert!(campaign.active);
+            assert_eq!(campaign.claim_message, INIT_CLAIM_MESSAGE.clone());
+            assert_eq!(
+                campaign.unclaimed_destination,
+                UnclaimedDestinationMockAccount::get()
+            );
+            assert_eq!(
+                BeneficiariesCount::<Test>::get(CLAIM_ID) as usize,
+                GENESIS_BENEFICIARIES.len()
+            );
+            for (beneficiary, amount) in GENESIS_BENEFICIARIES.iter() {
+                assert_eq!(
+                    Beneficiaries::<Test>::get(CLAIM_ID, beneficiary),
+                    Some(*amount)
+                );
+            }
+            assert_eq!(
+                TotalClaimable::<Test>::get(CLAIM_ID),
+                SUFFICIENT_GENESIS_BALANCE
+            );
+
+            // Funds have been moved to the campaign account
+            assert_eq!(Balances::free_balance(legacy_account()), 0);
+            assert_eq!(Pallet::<Test>::pot(CLAIM_ID), SUFFICIENT_GENESIS_BALANCE);
+            assert_ok!(Pallet::<Test>::claim_for(
+                Origin::Signed(MANAGER_USER).into(),
+                CLAIM_ID,
+                USER_1_RAW
+            ));
+        });
+    }
+
+    #[test]
+    fn ended_claim_with_leftovers_becomes_inactive_campaign() {
+        test().execute_with(|| {
+            insert_legacy_claim(false, &GENESIS_BENEFICIARIES);
+
+            InnerMigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+            assert_legacy_storage_removed();
+            assert!(!Campaigns::<Test>::get(CLAIM_ID).unwrap().active);
+            assert_eq!(
+                BeneficiariesCount::<Test>::get(CLAIM_ID) as usize,
+                GENESIS_BENEFICIARIES.len()
+            );
+            assert_eq!(TotalClaimable::<Test>::get(CLAIM_ID), 0);
+
+            assert_ok!(Pallet::<Test>::remove_beneficiaries(
+                Origin::Signed(USER_1_RAW).into(),
+                CLAIM_ID,
+                None
+            ));
+            assert!(!Campaigns::<Test>::contains_key(CLAIM_ID));
+        });
+    }
+
+    #[test]
+    fn ended_claim_without_leftovers_is_dropped() {
+        test().execute_with(|| {
+            insert_legacy_claim(false, &[]);
+
+            InnerMigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+            assert_legacy_storage_removed();
+            assert_eq!(Campaigns::<Test>::iter().count(), 0);
+            assert_eq!(BeneficiariesCount::<Test>::iter().count(), 0);
+        });
+    }
+
+    #[test]
+    fn weight_at_most_the_worst_case() {
+        test().execute_with(|| {
+            insert_legacy_claim(true, &GENESIS_BENEFICIARIES);
+
+            let weight = InnerMigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+            assert_eq!(
+                weight,
+                migration_weight::<Test>(GENESIS_BENEFICIARIES.len() as u32)
+            );
+        });
+    }
+
+    #[test]
+    fn no_claim() {
+        test().execute_with(|| {
+            InnerMigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+            assert_legacy_storage_removed();
+            assert_eq!(Campaigns::<Test>::iter().count(), 0);
+        });
+    }
+}
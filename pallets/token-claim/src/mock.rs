@@ -21,27 +21,35 @@
 use std::{collections::BTreeMap, sync::LazyLock};
 
 use frame_support::{
-    derive_impl, parameter_types,
-    traits::{EitherOfDiverse, EnsureOrigin},
+    derive_impl,
+    dispatch::DispatchResult,
+    parameter_types,
+    traits::{EitherOfDiverse, EnsureOrigin, WithdrawReasons},
+    weights::Weight,
     BoundedVec, PalletId,
 };
 use frame_system::{EnsureRoot, RawOrigin};
-use sp_core::ConstU128;
+use sp_core::{ConstU128, H256};
+use sp_io::hashing::keccak_256;
 use sp_runtime::{
     testing::{TestSignature, UintAuthorityId},
-    traits::{AccountIdConversion, IdentityLookup},
-    BuildStorage, RuntimeAppPublic,
+    traits::{ConvertInto, IdentityLookup},
+    BuildStorage, DispatchError, RuntimeAppPublic,
 };
 
 use crate::beneficiary::{AccountIdToBytesLiteral, Beneficiary};
 use crate::utils::{get_beneficiaries_map, secp_utils::*};
-use crate::{EthereumAddress, EthereumSignature};
+use crate::{
+    BitcoinAddress, BitcoinSignature, CampaignId, ClaimProofVerifier, EthereumAddress,
+    EthereumSignature, SolanaAddress, SolanaSignature,
+};
 
 pub type Balance = u128;
 pub type AccountId = u64;
 pub type Origin = RawOrigin<AccountId>;
 
 pub const EXISTENTIAL_DEPOSIT: Balance = 1;
+pub const MAX_VESTING_SCHEDULES: u32 = 3;
 
 pub const USER_1_RAW: AccountId = 42;
 pub const USER_1: Beneficiary<Test> = Beneficiary::<Test>::Substrate(USER_1_RAW);
@@ -62,6 +70,40 @@ pub const USER_6_RAW: EthereumAddress = EthereumAddress(hex_literal::hex!(
 ));
 pub const USER_6: Beneficiary<Test> = Beneficiary::<Test>::Ethereum(USER_6_RAW);
 pub const USER_6_AMOUNT: Balance = 100_000_000_000;
+/// The secret key of the ed25519 TEST 1 vector of RFC 8032
+pub const SOLANA_SECRET: [u8; 32] =
+    hex_literal::hex!("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60");
+pub const USER_7_RAW: SolanaAddress =
+    hex_literal::hex!("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a");
+pub const USER_7: Beneficiary<Test> = Beneficiary::<Test>::Solana(USER_7_RAW);
+pub const USER_7_AMOUNT: Balance = 70_000_000_000;
+/// The signature of USER_7 on "INIT_CLAIM_MESSAGE||@||USER_1_RAW"
+pub const USER_7_SIGNATURE_USER_1_DEST: [u8; 64] = hex_literal::hex!("50e5621ad74d8a0a4e8a3ba2b54bf60c498521e80cd125e6ef1b3cda7fd9b5872382eb00edc5e3b1b8f39df80e9064f5204fd24efaf0b721e1775a764d88ad0c");
+/// The secret key 1, whose compressed public key hashes to the well-known addresses
+/// 1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH and bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4, and whose
+/// uncompressed public key hashes to 1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm
+pub const BITCOIN_SECRET: [u8; 32] =
+    hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000001");
+pub const USER_8_RAW: BitcoinAddress = BitcoinAddress::P2pkh(hex_literal::hex!(
+    "751e76e8199196d454941c45d1b3a323f1433bd6"
+));
+pub const USER_8: Beneficiary<Test> = Beneficiary::<Test>::Bitcoin(USER_8_RAW);
+pub const USER_8_AMOUNT: Balance = 80_000_000_000;
+pub const USER_9_RAW: BitcoinAddress = BitcoinAddress::P2wpkh(hex_literal::hex!(
+    "751e76e8199196d454941c45d1b3a323f1433bd6"
+));
+pub const USER_9: Beneficiary<Test> = Beneficiary::<Test>::Bitcoin(USER_9_RAW);
+pub const USER_9_AMOUNT: Balance = 90_000_000_000;
+pub const USER_10_RAW: BitcoinAddress = BitcoinAddress::P2pkh(hex_literal::hex!(
+    "91b24bf9f5288532960ac687abb035127b1d28a5"
+));
+pub const USER_10: Beneficiary<Test> = Beneficiary::<Test>::Bitcoin(USER_10_RAW);
+pub const USER_10_AMOUNT: Balance = 10_000_000_000;
+/// The BIP-137 signatures of BITCOIN_SECRET on "INIT_CLAIM_MESSAGE||@||USER_1_RAW", for the
+/// addresses of USER_8, USER_9 and USER_10
+pub const USER_8_SIGNATURE_USER_1_DEST: BitcoinSignature = BitcoinSignature(hex_literal::hex!("1f3f0b7a330a113bbccf052bdf57d3245ab733a6687b67a2a82e5a36aeb569e2472205acee3cf8b5a9e46fd815c846c8dce2962c9fb5193df29a1a3adcae204f87"));
+pub const USER_9_SIGNATURE_USER_1_DEST: BitcoinSignature = BitcoinSignature(hex_literal::hex!("273f0b7a330a113bbccf052bdf57d3245ab733a6687b67a2a82e5a36aeb569e2472205acee3cf8b5a9e46fd815c846c8dce2962c9fb5193df29a1a3adcae204f87"));
+pub const USER_10_SIGNATURE_USER_1_DEST: BitcoinSignature = BitcoinSignature(hex_literal::hex!("1b3f0b7a330a113bbccf052bdf57d3245ab733a6687b67a2a82e5a36aeb569e2472205acee3cf8b5a9e46fd815c846c8dce2962c9fb5193df29a1a3adcae204f87"));
 pub const NON_BENEFICIARY_RAW: AccountId = 6;
 pub const NON_BENEFICIARY: Beneficiary<Test> = Beneficiary::<Test>::Substrate(NON_BENEFICIARY_RAW);
 
@@ -69,7 +111,7 @@ impl From<Beneficiary<Test>> for UintAuthorityId {
     fn from(b: Beneficiary<Test>) -> UintAuthorityId {
         match b {
             Beneficiary::<Test>::Substrate(address) => UintAuthorityId::from(address),
-            Beneficiary::<Test>::Ethereum(_) => unreachable!(),
+            _ => unreachable!(),
         }
     }
 }
@@ -110,7 +152,57 @@ pub static USER_3_SIGN_USER_1_DEST: LazyLock<(EthereumAddress, EthereumSignature
         (address, eth_signature)
     });
 
+/// The EIP-712 hash of the claim of USER_3_AMOUNT from CAMPAIGN_ID to USER_1_RAW, computed by
+/// alloy (`TypedData::eip712_signing_hash`) from the `eth_signTypedData_v4` payload:
+/// ```json
+/// {
+///   "types": {
+///     "EIP712Domain": [
+///       {"name": "name", "type": "string"},
+///       {"name": "version", "type": "string"},
+///       {"name": "chainId", "type": "uint256"},
+///       {"name": "salt", "type": "bytes32"}
+///     ],
+///     "Claim": [
+///       {"name": "message", "type": "string"},
+///       {"name": "destination", "type": "string"},
+///       {"name": "amount", "type": "uint256"}
+///     ]
+///   },
+///   "primaryType": "Claim",
+///   "domain": {
+///     "name": "TestClaim",
+///     "version": "1",
+///     "chainId": 1,
+///     "salt": "0x0000000000000000000000000000000000000000000000000000000000000000"
+///   },
+///   "message": {"message": "TestMessage", "destination": "42", "amount": "50000000000"}
+/// }
+/// ```
+pub const USER_3_TYPED_DATA_HASH_USER_1_DEST: [u8; 32] =
+    hex_literal::hex!("651cdb06a784b917a19f92a3bbbde3cbb630815a9429d5113f5d2a0ac6b4aff3");
+/// The EIP-712 signature of USER_3 on the claim of USER_3_AMOUNT from CAMPAIGN_ID to USER_1_RAW,
+/// computed by alloy (`PrivateKeySigner`) on USER_3_TYPED_DATA_HASH_USER_1_DEST
+pub const USER_3_TYPED_SIGNATURE_USER_1_DEST: [u8; 65] = hex_literal::hex!("af33c42650167af4a96c305e82b83d53e0a75999b6794bf7f894e5cec8aaf0d75aa87c7da3e752b3714cdfa45782fbbf017f0088d394be01839b7a961ba6e1cd1b");
+
+/// The EIP-712 signature of USER_3 on the claim of 'amount' from 'campaign_id' to 'dest'
+pub fn user_3_typed_signature(
+    campaign_id: CampaignId,
+    dest: AccountId,
+    amount: Balance,
+) -> EthereumSignature {
+    let secret_bytes =
+        hex_literal::hex!("7b2d076abcc1215ef9c5a37da07f50c92de1048b2e1e7a27b74c0ce154f9cbae");
+    let msg_hash =
+        crate::eip712_hash_claim::<Test>(campaign_id, INIT_CLAIM_MESSAGE.as_slice(), &dest, amount);
+    sig_hash(&parse_secret(&secret_bytes[..]), &msg_hash)
+}
+
 pub const MANAGER_USER: AccountId = 666;
+pub const CAMPAIGN_MANAGER_USER: AccountId = 777;
+
+pub const CAMPAIGN_ID: CampaignId = 0;
+pub const OTHER_CAMPAIGN_ID: CampaignId = 1;
 
 pub static GENESIS_BENEFICIARIES: [(Beneficiary<Test>, Balance); 3] = [
     (USER_1, USER_1_AMOUNT),
@@ -172,12 +264,72 @@ impl AccountIdToBytesLiteral<Test> for MockAccountIdToBytesConversion {
     }
 }
 
+pub const MOCK_ZK_VERIFIER: u8 = 1;
+pub const MOCK_VERIFY_WEIGHT_PER_PUB: u64 = 1_000;
+/// The only verification key registered in the mock verifier.
+pub const MOCK_ZK_VK_HASH: H256 = H256::repeat_byte(2);
+
+parameter_types! {
+    /// The references to `MOCK_ZK_VK_HASH` taken by the campaigns.
+    pub static MockVkRefs: u32 = 0;
+}
+
+/// The proof of the mock verifier for 'pubs' against the verification key 'vk_hash'.
+pub fn mock_claim_proof(vk_hash: &H256, pubs: &[[u8; 32]]) -> H256 {
+    H256(keccak_256(
+        &[vk_hash.as_bytes(), pubs.concat().as_slice()].concat(),
+    ))
+}
+
+/// Accepts only the proofs computed by `mock_claim_proof` for the `MOCK_ZK_VERIFIER` verifier.
+pub struct MockClaimProofVerifier;
+
+impl ClaimProofVerifier for MockClaimProofVerifier {
+    type VerifierId = u8;
+    type Proof = H256;
+
+    fn verify_claim_proof(
+        verifier: &u8,
+        vk_hash: &H256,
+        proof: &H256,
+        pubs: &[[u8; 32]],
+    ) -> DispatchResult {
+        if *verifier != MOCK_ZK_VERIFIER || *proof != mock_claim_proof(vk_hash, pubs) {
+            Err(DispatchError::Other("Invalid proof"))?;
+        }
+        Ok(())
+    }
+
+    fn verify_claim_proof_weight(_proof: &H256, pubs: &[[u8; 32]]) -> Weight {
+        Weight::from_parts(MOCK_VERIFY_WEIGHT_PER_PUB * pubs.len() as u64, 0)
+    }
+
+    fn retain_vk(verifier: &u8, vk_hash: &H256) -> DispatchResult {
+        if *verifier != MOCK_ZK_VERIFIER || *vk_hash != MOCK_ZK_VK_HASH {
+            Err(DispatchError::Other("Vk not registered"))?;
+        }
+        MockVkRefs::mutate(|refs| *refs += 1);
+        Ok(())
+    }
+
+    fn release_vk(_verifier: &u8, _vk_hash: &H256) {
+        MockVkRefs::mutate(|refs| *refs -= 1);
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn register_benchmark_vk() -> (u8, H256) {
+        (MOCK_ZK_VERIFIER, MOCK_ZK_VK_HASH)
+    }
+}
+
 parameter_types! {
     pub const ClaimPalletId: PalletId = PalletId(*b"zkvt/clm");
     pub const MaxBeneficiaries: u32 = 100;
     pub const MaxOpBeneficiaries: u32 = MaxBeneficiaries::get() - 1;
     pub const MaxClaimMessageLength: u32 = 100;
     pub const EthMsgSeparator: &'static [u8] = b"@";
+    pub const Eip712DomainName: &'static [u8] = b"TestClaim";
+    pub const Eip712ChainId: u64 = 1;
     pub UnclaimedDestinationMockAccount: AccountId = 111;
 }
 
@@ -192,10 +344,22 @@ impl crate::benchmarking::BenchmarkHelper<TestSignature, UintAuthorityId> for Mo
         (signature, signer)
     }
 
-    fn sign_claim_ethereum(message: &[u8]) -> (EthereumSignature, EthereumAddress) {
+    fn sign_claim_ethereum_hash(msg_hash: &[u8; 32]) -> (EthereumSignature, EthereumAddress) {
+        use crate::utils::secp_utils::*;
+        let sk = secret_from_seed(b"//TestBeneficiary");
+        (sig_hash(&sk, msg_hash), eth(&sk))
+    }
+
+    fn sign_claim_solana(message: &[u8]) -> (SolanaSignature, SolanaAddress) {
+        use sp_core::Pair;
+        let pair = sp_core::ed25519::Pair::from_seed(&SOLANA_SECRET);
+        (pair.sign(message), pair.public().0)
+    }
+
+    fn sign_claim_bitcoin(message: &[u8]) -> (BitcoinSignature, BitcoinAddress) {
         use crate::utils::secp_utils::*;
         let sk = secret_from_seed(b"//TestBeneficiary");
-        (sig(&sk, message), eth(&sk))
+        (btc_sig(&sk, message, 31), btc(&sk))
     }
 }
 
@@ -213,6 +377,11 @@ impl crate::Config for Test {
     type MaxOpBeneficiaries = MaxOpBeneficiaries;
     type AccountIdBytesToSign = MockAccountIdToBytesConversion;
     type EthMsgSeparator = EthMsgSeparator;
+    type Eip712DomainName = Eip712DomainName;
+    type Eip712ChainId = Eip712ChainId;
+    type VestingSchedule = Vesting;
+    type BlockNumberToBalance = ConvertInto;
+    type ClaimProofVerifier = MockClaimProofVerifier;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = MockBenchmarkHelper;
     #[cfg(feature = "runtime-benchmarks")]
@@ -225,6 +394,7 @@ frame_support::construct_runtime!(
     {
         System: frame_system,
         Balances: pallet_balances,
+        Vesting: pallet_vesting,
         Claim: crate,
     }
 );
@@ -250,6 +420,23 @@ impl pallet_balances::Config for Test {
     type ExistentialDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
 }
 
+parameter_types! {
+    pub const MinVestedTransfer: Balance = EXISTENTIAL_DEPOSIT;
+    pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
+        WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+}
+
+impl pallet_vesting::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type BlockNumberToBalance = ConvertInto;
+    type MinVestedTransfer = MinVestedTransfer;
+    type WeightInfo = ();
+    type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+    type BlockNumberProvider = System;
+    const MAX_VESTING_SCHEDULES: u32 = MAX_VESTING_SCHEDULES;
+}
+
 pub enum WithGenesisBeneficiaries {
     Yes,
     No,
@@ -270,7 +457,7 @@ pub fn test_with_configs(
         .build_storage()
         .unwrap();
 
-    let claim_account_id = ClaimPalletId::get().into_account_truncating();
+    let claim_account_id = Claim::campaign_account_id(CAMPAIGN_ID);
     let claim_genesis_balance = match genesis_claim_balance {
         GenesisClaimBalance::Sufficient => SUFFICIENT_GENESIS_BALANCE,
         GenesisClaimBalance::Insufficient => INSUFFICIENT_GENESIS_BALANCE,
@@ -287,11 +474,14 @@ pub fn test_with_configs(
         .unwrap();
 
     crate::GenesisConfig::<Test> {
-        beneficiaries: match with_genesis_beneficiaries {
-            WithGenesisBeneficiaries::Yes => GENESIS_BENEFICIARIES.to_vec(),
+        campaigns: match with_genesis_beneficiaries {
+            WithGenesisBeneficiaries::Yes => vec![(
+                CAMPAIGN_ID,
+                INIT_CLAIM_MESSAGE.clone(),
+                GENESIS_BENEFICIARIES.to_vec(),
+            )],
             WithGenesisBeneficiaries::No => vec![],
         },
-        claim_message: INIT_CLAIM_MESSAGE.clone(),
     }
     .assimilate_storage(&mut t)
     .unwrap();
@@ -309,7 +499,7 @@ pub fn test_genesis_with_beneficiaries(n: u32) -> sp_io::TestExternalities {
         .build_storage()
         .unwrap();
 
-    let claim_account_id = ClaimPalletId::get().into_account_truncating();
+    let claim_account_id = Claim::campaign_account_id(CAMPAIGN_ID);
     let claim_genesis_balance = 42_000_000_000;
 
     pallet_balances::GenesisConfig::<Test> {
@@ -322,8 +512,11 @@ pub fn test_genesis_with_beneficiaries(n: u32) -> sp_io::TestExternalities {
     .unwrap();
 
     crate::GenesisConfig::<Test> {
-        beneficiaries: get_beneficiaries_map::<Test>(n).0.into_iter().collect(),
-        claim_message: INIT_CLAIM_MESSAGE.clone(),
+        campaigns: vec![(
+            CAMPAIGN_ID,
+            INIT_CLAIM_MESSAGE.clone(),
+            get_beneficiaries_map::<Test>(n).0.into_iter().collect(),
+        )],
     }
     .assimilate_storage(&mut t)
     .unwrap();
@@ -341,7 +534,7 @@ pub fn test_genesis_empty_claim_message(n: u32) -> sp_io::TestExternalities {
         .build_storage()
         .unwrap();
 
-    let claim_account_id = ClaimPalletId::get().into_account_truncating();
+    let claim_account_id = Claim::campaign_account_id(CAMPAIGN_ID);
     let claim_genesis_balance = 42_000_000_000;
 
     pallet_balances::GenesisConfig::<Test> {
@@ -354,8 +547,11 @@ pub fn test_genesis_empty_claim_message(n: u32) -> sp_io::TestExternalities {
     .unwrap();
 
     crate::GenesisConfig::<Test> {
-        beneficiaries: get_beneficiaries_map::<Test>(n).0.into_iter().collect(),
-        claim_message: EMPTY_CLAIM_MESSAGE.clone(),
+        campaigns: vec![(
+            CAMPAIGN_ID,
+            EMPTY_CLAIM_MESSAGE.clone(),
+            get_beneficiaries_map::<Test>(n).0.into_iter().collect(),
+        )],
     }
     .assimilate_storage(&mut t)
     .unwrap();
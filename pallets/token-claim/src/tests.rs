@@ -5,7 +5,6 @@ use crate::EthereumSignature;
 use crate::*;
 use frame_support::{assert_err, assert_noop, assert_ok};
 use frame_system::{EventRecord, Phase};
-use sp_core::TypedGet;
 use sp_runtime::{traits::BadOrigin, RuntimeAppPublic, TokenError};
 
 pub fn assert_evt(event: Event<Test>, context: &str) {
@@ -32,20 +31,57 @@ fn assert_evt_gen(contains: bool, event: Event<Test>, context: &str) {
     )
 }
 
+fn claim_active(campaign_id: CampaignId) -> bool {
+    Campaigns::<Test>::get(campaign_id).is_some_and(|campaign| campaign.active)
+}
+
+fn claim_message(campaign_id: CampaignId) -> Option<ClaimMessage<Test>> {
+    Campaigns::<Test>::get(campaign_id).map(|campaign| campaign.claim_message)
+}
+
+fn insert_beneficiary(campaign_id: CampaignId, beneficiary: Beneficiary<Test>, amount: Balance) {
+    if !Beneficiaries::<Test>::contains_key(campaign_id, &beneficiary) {
+        BeneficiariesCount::<Test>::mutate(campaign_id, |count| *count += 1);
+    }
+    Beneficiaries::<Test>::insert(campaign_id, beneficiary, amount);
+}
+
+fn insert_ended_campaign(campaign_id: CampaignId) {
+    Campaigns::<Test>::insert(
+        campaign_id,
+        CampaignInfo {
+            claim_message: INIT_CLAIM_MESSAGE.clone(),
+            manager: None,
+            unclaimed_destination: UnclaimedDestinationMockAccount::get(),
+            start: 0,
+            end: None,
+            active: false,
+            merkle: None,
+            vesting: None,
+            zk: None,
+        },
+    );
+}
+
 mod genesis_build {
     use super::*;
     #[test]
     fn genesis_default_build() {
         test().execute_with(|| {
-            assert!(Beneficiaries::<Test>::iter().next().is_none());
-            assert_eq!(TotalClaimable::<Test>::get(), BalanceOf::<Test>::zero());
-            assert!(!ClaimActive::<Test>::get());
-            assert!(ClaimId::<Test>::get().is_none());
+            assert!(Beneficiaries::<Test>::iter_prefix(CAMPAIGN_ID)
+                .next()
+                .is_none());
+            assert_eq!(
+                TotalClaimable::<Test>::get(CAMPAIGN_ID),
+                BalanceOf::<Test>::zero()
+            );
+            assert!(!claim_active(CAMPAIGN_ID));
+            assert!(claim_message(CAMPAIGN_ID).is_none());
             assert_eq!(
-                Balances::free_balance(Claim::account_id()),
+                Balances::free_balance(Claim::campaign_account_id(CAMPAIGN_ID)),
                 EXISTENTIAL_DEPOSIT
             );
-            assert_eq!(Claim::pot(), 0);
+            assert_eq!(Claim::pot(CAMPAIGN_ID), 0);
         })
     }
 
@@ -67,20 +103,20 @@ mod genesis_build {
         )
         .execute_with(|| {
             assert_eq!(
-                Beneficiaries::<Test>::iter().collect::<BTreeMap<_, _>>(),
+                Beneficiaries::<Test>::iter_prefix(CAMPAIGN_ID).collect::<BTreeMap<_, _>>(),
                 GENESIS_BENEFICIARIES_MAP.clone()
             );
-            assert_eq!(TotalClaimable::<Test>::get(), SUFFICIENT_GENESIS_BALANCE);
-            assert!(ClaimActive::<Test>::get());
             assert_eq!(
-                ClaimId::<Test>::get(),
-                Some((0, INIT_CLAIM_MESSAGE.clone()))
+                TotalClaimable::<Test>::get(CAMPAIGN_ID),
+                SUFFICIENT_GENESIS_BALANCE
             );
+            assert!(claim_active(CAMPAIGN_ID));
+            assert_eq!(claim_message(CAMPAIGN_ID), Some(INIT_CLAIM_MESSAGE.clone()));
             assert_eq!(
-                Balances::free_balance(Claim::account_id()),
+                Balances::free_balance(Claim::campaign_account_id(CAMPAIGN_ID)),
                 SUFFICIENT_GENESIS_BALANCE + EXISTENTIAL_DEPOSIT
             );
-            assert_eq!(Claim::pot(), SUFFICIENT_GENESIS_BALANCE);
+            assert_eq!(Claim::pot(CAMPAIGN_ID), SUFFICIENT_GENESIS_BALANCE);
         });
     }
 
@@ -102,10 +138,27 @@ mod genesis_build {
     }
 
     #[test]
-    fn account_id_as_expected() {
-        test().execute_with(|| {
-            assert_eq!(Claim::account_id(), ClaimAccountId::<Test>::get());
-        });
+    #[should_panic(expected = "DuplicatedCampaign")]
+    fn genesis_build_duplicated_campaign_fails() {
+        let mut t = frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap();
+        crate::GenesisConfig::<Test> {
+            campaigns: vec![
+                (CAMPAIGN_ID, INIT_CLAIM_MESSAGE.clone(), vec![]),
+                (CAMPAIGN_ID, INIT_CLAIM_MESSAGE.clone(), vec![]),
+            ],
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+    }
+
+    #[test]
+    fn campaign_accounts_are_distinct() {
+        assert_ne!(
+            Claim::campaign_account_id(CAMPAIGN_ID),
+            Claim::campaign_account_id(OTHER_CAMPAIGN_ID)
+        );
     }
 }
 
@@ -116,28 +169,32 @@ mod begin_claim {
         test().execute_with(|| {
             assert_ok!(Claim::begin_claim(
                 Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID,
                 EMPTY_BENEFICIARIES_MAP.clone(),
                 INIT_CLAIM_MESSAGE.clone(),
+                Default::default()
             ));
             assert_evt(
                 Event::ClaimStarted {
-                    claim_id: 0,
+                    campaign_id: CAMPAIGN_ID,
                     claim_message: INIT_CLAIM_MESSAGE.clone(),
                 },
                 "New claim",
             );
-            assert!(Beneficiaries::<Test>::iter().next().is_none());
-            assert_eq!(TotalClaimable::<Test>::get(), BalanceOf::<Test>::zero());
-            assert!(ClaimActive::<Test>::get());
+            assert!(Beneficiaries::<Test>::iter_prefix(CAMPAIGN_ID)
+                .next()
+                .is_none());
             assert_eq!(
-                ClaimId::<Test>::get().unwrap(),
-                (0, INIT_CLAIM_MESSAGE.clone())
+                TotalClaimable::<Test>::get(CAMPAIGN_ID),
+                BalanceOf::<Test>::zero()
             );
+            assert!(claim_active(CAMPAIGN_ID));
+            assert_eq!(claim_message(CAMPAIGN_ID), Some(INIT_CLAIM_MESSAGE.clone()));
             assert_eq!(
-                Balances::free_balance(Claim::account_id()),
+                Balances::free_balance(Claim::campaign_account_id(CAMPAIGN_ID)),
                 EXISTENTIAL_DEPOSIT
             );
-            assert_eq!(Claim::pot(), 0);
+            assert_eq!(Claim::pot(CAMPAIGN_ID), 0);
         })
     }
 
@@ -147,14 +204,16 @@ mod begin_claim {
             assert_err!(
                 Claim::begin_claim(
                     Origin::Signed(USER_1_RAW).into(),
+                    CAMPAIGN_ID,
                     EMPTY_BENEFICIARIES_MAP.clone(),
-                    INIT_CLAIM_MESSAGE.clone()
+                    INIT_CLAIM_MESSAGE.clone(),
+                    Default::default()
                 ),
                 BadOrigin
             );
             assert_not_evt(
                 Event::ClaimStarted {
-                    claim_id: 0,
+                    campaign_id: CAMPAIGN_ID,
                     claim_message: INIT_CLAIM_MESSAGE.clone(),
                 },
                 "No new claim",
@@ -171,26 +230,28 @@ mod begin_claim {
         .execute_with(|| {
             assert_ok!(Claim::begin_claim(
                 Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID,
                 GENESIS_BENEFICIARIES_MAP.clone(),
-                INIT_CLAIM_MESSAGE.clone()
+                INIT_CLAIM_MESSAGE.clone(),
+                Default::default()
             ));
             assert_evt(
                 Event::ClaimStarted {
-                    claim_id: 0,
+                    campaign_id: CAMPAIGN_ID,
                     claim_message: INIT_CLAIM_MESSAGE.clone(),
                 },
                 "New claim",
             );
             assert_eq!(
-                Beneficiaries::<Test>::iter().collect::<BTreeMap<_, _>>(),
+                Beneficiaries::<Test>::iter_prefix(CAMPAIGN_ID).collect::<BTreeMap<_, _>>(),
                 GENESIS_BENEFICIARIES_MAP.clone()
             );
-            assert_eq!(TotalClaimable::<Test>::get(), SUFFICIENT_GENESIS_BALANCE);
-            assert!(ClaimActive::<Test>::get());
             assert_eq!(
-                ClaimId::<Test>::get(),
-                Some((0, INIT_CLAIM_MESSAGE.clone()))
+                TotalClaimable::<Test>::get(CAMPAIGN_ID),
+                SUFFICIENT_GENESIS_BALANCE
             );
+            assert!(claim_active(CAMPAIGN_ID));
+            assert_eq!(claim_message(CAMPAIGN_ID), Some(INIT_CLAIM_MESSAGE.clone()));
         })
     }
 
@@ -204,8 +265,10 @@ mod begin_claim {
             assert_noop!(
                 Claim::begin_claim(
                     Origin::Signed(MANAGER_USER).into(),
+                    CAMPAIGN_ID,
                     GENESIS_BENEFICIARIES_MAP.clone(),
-                    INIT_CLAIM_MESSAGE.clone()
+                    INIT_CLAIM_MESSAGE.clone(),
+                    Default::default()
                 ),
                 TokenError::FundsUnavailable
             );
@@ -222,8 +285,10 @@ mod begin_claim {
             assert_noop!(
                 Claim::begin_claim(
                     Origin::Signed(MANAGER_USER).into(),
+                    CAMPAIGN_ID,
                     utils::get_beneficiaries_map::<Test>(MaxOpBeneficiaries::get() + 1).0,
-                    INIT_CLAIM_MESSAGE.clone()
+                    INIT_CLAIM_MESSAGE.clone(),
+                    Default::default()
                 ),
                 Error::<Test>::TooManyBeneficiaries
             );
@@ -240,8 +305,10 @@ mod begin_claim {
             assert_noop!(
                 Claim::begin_claim(
                     Origin::Signed(MANAGER_USER).into(),
+                    CAMPAIGN_ID,
                     utils::get_beneficiaries_map::<Test>(MaxOpBeneficiaries::get() + 1).0,
-                    EMPTY_CLAIM_MESSAGE.clone()
+                    EMPTY_CLAIM_MESSAGE.clone(),
+                    Default::default()
                 ),
                 Error::<Test>::InvalidClaimMessage
             );
@@ -253,12 +320,14 @@ mod begin_claim {
         test().execute_with(|| {
             assert_ok!(Claim::begin_claim(
                 Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID,
                 EMPTY_BENEFICIARIES_MAP.clone(),
-                INIT_CLAIM_MESSAGE.clone()
+                INIT_CLAIM_MESSAGE.clone(),
+                Default::default()
             ));
             assert_evt(
                 Event::ClaimStarted {
-                    claim_id: 0,
+                    campaign_id: CAMPAIGN_ID,
                     claim_message: INIT_CLAIM_MESSAGE.clone(),
                 },
                 "New claim",
@@ -266,17 +335,20 @@ mod begin_claim {
             assert_err!(
                 Claim::begin_claim(
                     Origin::Signed(MANAGER_USER).into(),
+                    CAMPAIGN_ID,
                     GENESIS_BENEFICIARIES_MAP.clone(),
-                    INIT_CLAIM_MESSAGE.clone()
+                    INIT_CLAIM_MESSAGE.clone(),
+                    Default::default()
                 ),
                 Error::<Test>::AlreadyStarted
             );
-            assert_not_evt(
-                Event::ClaimStarted {
-                    claim_id: 1,
-                    claim_message: INIT_CLAIM_MESSAGE.clone(),
-                },
-                "No new claim",
+            assert_eq!(
+                System::events()
+                    .into_iter()
+                    .filter(|r| matches!(r.event, TestEvent::Claim(Event::ClaimStarted { .. })))
+                    .count(),
+                1,
+                "No new claim"
             );
         })
     }
@@ -295,23 +367,36 @@ mod claim_common {
             let (user_signer, user_signature, _) = USER_1_SIGN.clone();
             assert_ok!(Claim::claim(
                 Origin::None.into(),
+                CAMPAIGN_ID,
                 user_signer.clone(),
                 user_signature.clone()
             ));
             assert_noop!(
-                Claim::claim(Origin::None.into(), user_signer, user_signature),
+                Claim::claim(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    user_signer,
+                    user_signature
+                ),
                 Error::<Test>::NotEligible
             );
 
             let (address, eth_signature) = *USER_3_SIGN_USER_1_DEST;
             assert_ok!(Claim::claim_ethereum(
                 Origin::None.into(),
+                CAMPAIGN_ID,
                 address,
                 eth_signature,
                 USER_1_RAW
             ));
             assert_noop!(
-                Claim::claim_ethereum(Origin::None.into(), address, eth_signature, USER_1_RAW),
+                Claim::claim_ethereum(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    address,
+                    eth_signature,
+                    USER_1_RAW
+                ),
                 Error::<Test>::NotEligible
             );
         });
@@ -327,14 +412,25 @@ mod claim_common {
             let (mut user_signer, user_signature, _) = USER_1_SIGN.clone();
             user_signer.0 += 1;
             assert_noop!(
-                Claim::claim(Origin::None.into(), user_signer, user_signature),
+                Claim::claim(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    user_signer,
+                    user_signature
+                ),
                 Error::<Test>::NotEligible
             );
 
             let (mut address, eth_signature) = *USER_3_SIGN_USER_1_DEST;
             address.as_mut()[0] += 1;
             assert_noop!(
-                Claim::claim_ethereum(Origin::None.into(), address, eth_signature, USER_1_RAW),
+                Claim::claim_ethereum(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    address,
+                    eth_signature,
+                    USER_1_RAW
+                ),
                 Error::<Test>::NotEligible
             );
         });
@@ -351,14 +447,25 @@ mod claim_common {
             user_signature.1[0] += 1u8; // Alter signature
 
             assert_noop!(
-                Claim::claim(Origin::None.into(), user_signer, user_signature),
+                Claim::claim(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    user_signer,
+                    user_signature
+                ),
                 Error::<Test>::BadSignature
             );
 
             let (address, mut eth_signature) = *USER_3_SIGN_USER_1_DEST;
             eth_signature.0[0] += 1;
             assert_noop!(
-                Claim::claim_ethereum(Origin::None.into(), address, eth_signature, USER_1_RAW),
+                Claim::claim_ethereum(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    address,
+                    eth_signature,
+                    USER_1_RAW
+                ),
                 Error::<Test>::BadSignature
             );
         });
@@ -375,16 +482,23 @@ mod claim_common {
             let user_2_signer = sp_runtime::testing::UintAuthorityId::from(USER_2);
 
             assert_noop!(
-                Claim::claim(Origin::None.into(), user_2_signer, user_1_signature),
+                Claim::claim(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    user_2_signer,
+                    user_1_signature
+                ),
                 Error::<Test>::BadSignature
             );
 
-            let _ = Balances::mint_into(&Claim::account_id(), USER_6_AMOUNT).unwrap();
-            Beneficiaries::<Test>::insert(USER_6, USER_6_AMOUNT);
+            let _ = Balances::mint_into(&Claim::campaign_account_id(CAMPAIGN_ID), USER_6_AMOUNT)
+                .unwrap();
+            insert_beneficiary(CAMPAIGN_ID, USER_6, USER_6_AMOUNT);
             let (_, user_3_signature) = *USER_3_SIGN_USER_1_DEST;
             assert_noop!(
                 Claim::claim_ethereum(
                     Origin::None.into(),
+                    CAMPAIGN_ID,
                     USER_6_RAW,
                     user_3_signature,
                     USER_1_RAW
@@ -403,13 +517,19 @@ mod claim_common {
         )
         .execute_with(|| {
             let (user_signer, user_signature, _) = USER_1_SIGN.clone();
-            Beneficiaries::<Test>::insert(USER_1, Balances::total_issuance()); // Increase astronomically
+            insert_beneficiary(CAMPAIGN_ID, USER_1, Balances::total_issuance()); // Increase astronomically
             assert_err!(
-                Claim::claim(Origin::None.into(), user_signer, user_signature),
+                Claim::claim(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    user_signer,
+                    user_signature
+                ),
                 TokenError::FundsUnavailable
             );
             assert_not_evt(
                 Event::Claimed {
+                    campaign_id: CAMPAIGN_ID,
                     beneficiary: USER_1,
                     amount: Balances::total_issuance(),
                 },
@@ -417,13 +537,20 @@ mod claim_common {
             );
 
             let (address, eth_signature) = *USER_3_SIGN_USER_1_DEST;
-            Beneficiaries::<Test>::insert(USER_3, Balances::total_issuance()); // Increase astronomically
+            insert_beneficiary(CAMPAIGN_ID, USER_3, Balances::total_issuance()); // Increase astronomically
             assert_err!(
-                Claim::claim_ethereum(Origin::None.into(), address, eth_signature, USER_1_RAW),
+                Claim::claim_ethereum(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    address,
+                    eth_signature,
+                    USER_1_RAW
+                ),
                 TokenError::FundsUnavailable
             );
             assert_not_evt(
                 Event::Claimed {
+                    campaign_id: CAMPAIGN_ID,
                     beneficiary: USER_3,
                     amount: Balances::total_issuance(),
                 },
@@ -435,15 +562,27 @@ mod claim_common {
     #[test]
     fn cannot_claim_while_claim_inactive() {
         test().execute_with(|| {
+            insert_ended_campaign(CAMPAIGN_ID);
             let (user_signer, user_signature, _) = USER_1_SIGN.clone();
             assert_noop!(
-                Claim::claim(Origin::None.into(), user_signer, user_signature),
+                Claim::claim(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    user_signer,
+                    user_signature
+                ),
                 Error::<Test>::AlreadyEnded
             );
 
             let (address, eth_signature) = *USER_3_SIGN_USER_1_DEST;
             assert_noop!(
-                Claim::claim_ethereum(Origin::None.into(), address, eth_signature, USER_1_RAW),
+                Claim::claim_ethereum(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    address,
+                    eth_signature,
+                    USER_1_RAW
+                ),
                 Error::<Test>::AlreadyEnded
             );
         })
@@ -460,6 +599,7 @@ mod claim_common {
             assert_noop!(
                 Claim::claim(
                     Origin::Signed(USER_1_RAW).into(),
+                    CAMPAIGN_ID,
                     user_signer,
                     user_signature
                 ),
@@ -470,6 +610,7 @@ mod claim_common {
             assert_noop!(
                 Claim::claim_ethereum(
                     Origin::Signed(USER_1_RAW).into(),
+                    CAMPAIGN_ID,
                     address,
                     eth_signature,
                     USER_1_RAW
@@ -492,23 +633,28 @@ mod claim_specific {
             let (user_signer, user_signature, _) = USER_1_SIGN.clone();
             assert_ok!(Claim::claim(
                 Origin::None.into(),
+                CAMPAIGN_ID,
                 user_signer,
                 user_signature
             ));
             assert_evt(
                 Event::Claimed {
+                    campaign_id: CAMPAIGN_ID,
                     beneficiary: USER_1,
                     amount: USER_1_AMOUNT,
                 },
                 "Successfull claim",
             );
-            assert_eq!(Claim::pot(), SUFFICIENT_GENESIS_BALANCE - USER_1_AMOUNT);
             assert_eq!(
-                TotalClaimable::<Test>::get(),
+                Claim::pot(CAMPAIGN_ID),
+                SUFFICIENT_GENESIS_BALANCE - USER_1_AMOUNT
+            );
+            assert_eq!(
+                TotalClaimable::<Test>::get(CAMPAIGN_ID),
                 SUFFICIENT_GENESIS_BALANCE - USER_1_AMOUNT
             );
             assert_eq!(Balances::free_balance(USER_1_RAW), USER_1_AMOUNT);
-            assert!(Beneficiaries::<Test>::get(USER_1).is_none());
+            assert!(Beneficiaries::<Test>::get(CAMPAIGN_ID, USER_1).is_none());
         });
     }
 
@@ -522,6 +668,7 @@ mod claim_specific {
             let (user_signer, _, user_signature) = USER_1_SIGN.clone();
             assert_ok!(Claim::claim(
                 Origin::None.into(),
+                CAMPAIGN_ID,
                 user_signer,
                 user_signature
             ));
@@ -550,7 +697,12 @@ mod claim_specific {
                 .unwrap();
 
             assert_noop!(
-                Claim::claim(Origin::None.into(), user_signer, user_signature),
+                Claim::claim(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    user_signer,
+                    user_signature
+                ),
                 Error::<Test>::BadSignature
             );
         });
@@ -572,6 +724,7 @@ mod claim_ethereum_specific {
             // Claim
             assert_ok!(Claim::claim_ethereum(
                 Origin::None.into(),
+                CAMPAIGN_ID,
                 address,
                 eth_signature,
                 USER_1_RAW
@@ -579,18 +732,22 @@ mod claim_ethereum_specific {
 
             assert_evt(
                 Event::Claimed {
+                    campaign_id: CAMPAIGN_ID,
                     beneficiary: USER_3,
                     amount: USER_3_AMOUNT,
                 },
                 "Successfull claim",
             );
-            assert_eq!(Claim::pot(), SUFFICIENT_GENESIS_BALANCE - USER_3_AMOUNT);
             assert_eq!(
-                TotalClaimable::<Test>::get(),
+                Claim::pot(CAMPAIGN_ID),
+                SUFFICIENT_GENESIS_BALANCE - USER_3_AMOUNT
+            );
+            assert_eq!(
+                TotalClaimable::<Test>::get(CAMPAIGN_ID),
                 SUFFICIENT_GENESIS_BALANCE - USER_3_AMOUNT
             );
             assert_eq!(Balances::free_balance(USER_1_RAW), USER_3_AMOUNT);
-            assert!(Beneficiaries::<Test>::get(USER_3).is_none());
+            assert!(Beneficiaries::<Test>::get(CAMPAIGN_ID, USER_3).is_none());
         })
     }
 
@@ -606,6 +763,7 @@ mod claim_ethereum_specific {
             let eth_signature = EthereumSignature::from_raw(hex_literal::hex!("40d1584a3e935358410d5e7bdca3082806c2af6a8a71baf44ece9a3f117499c03cf03116c0ed28b66704660870d41af1719e8e9d2271c7276bde0bd1b117e0c81b"));
             assert_ok!(Claim::claim_ethereum(
                 Origin::None.into(),
+                CAMPAIGN_ID,
                 USER_3_RAW,
                 eth_signature,
                 USER_1_RAW
@@ -613,223 +771,675 @@ mod claim_ethereum_specific {
 
             assert_evt(
                 Event::Claimed {
+                    campaign_id: CAMPAIGN_ID,
                     beneficiary: USER_3,
                     amount: USER_3_AMOUNT,
                 },
                 "Successfull claim",
             );
-            assert_eq!(Claim::pot(), SUFFICIENT_GENESIS_BALANCE - USER_3_AMOUNT);
-            assert_eq!(TotalClaimable::<Test>::get(), SUFFICIENT_GENESIS_BALANCE - USER_3_AMOUNT);
+            assert_eq!(Claim::pot(CAMPAIGN_ID), SUFFICIENT_GENESIS_BALANCE - USER_3_AMOUNT);
+            assert_eq!(TotalClaimable::<Test>::get(CAMPAIGN_ID), SUFFICIENT_GENESIS_BALANCE - USER_3_AMOUNT);
             assert_eq!(Balances::free_balance(USER_1_RAW), USER_3_AMOUNT);
-            assert!(Beneficiaries::<Test>::get(USER_3).is_none());
+            assert!(Beneficiaries::<Test>::get(CAMPAIGN_ID, USER_3).is_none());
         })
     }
-}
 
-mod claim_for {
-    use super::*;
+    #[test]
+    fn eip712_hash_claim_matches_wallets() {
+        assert_eq!(
+            crate::eip712_hash_claim::<Test>(
+                CAMPAIGN_ID,
+                INIT_CLAIM_MESSAGE.as_slice(),
+                &USER_1_RAW,
+                USER_3_AMOUNT
+            ),
+            USER_3_TYPED_DATA_HASH_USER_1_DEST
+        );
+    }
 
     #[test]
-    fn claim_for() {
+    fn claim_ethereum_with_typed_data() {
         test_with_configs(
             WithGenesisBeneficiaries::Yes,
             GenesisClaimBalance::Sufficient,
         )
         .execute_with(|| {
-            assert_ok!(Claim::claim_for(
-                Origin::Signed(MANAGER_USER).into(),
+            let eth_signature = EthereumSignature::from_raw(USER_3_TYPED_SIGNATURE_USER_1_DEST);
+            assert_eq!(
+                user_3_typed_signature(CAMPAIGN_ID, USER_1_RAW, USER_3_AMOUNT),
+                eth_signature
+            );
+
+            assert_ok!(Claim::claim_ethereum(
+                Origin::None.into(),
+                CAMPAIGN_ID,
+                USER_3_RAW,
+                eth_signature,
                 USER_1_RAW
             ));
+
             assert_evt(
                 Event::Claimed {
-                    beneficiary: USER_1,
-                    amount: USER_1_AMOUNT,
+                    campaign_id: CAMPAIGN_ID,
+                    beneficiary: USER_3,
+                    amount: USER_3_AMOUNT,
                 },
-                "Successfull claim for another beneficiary",
-            );
-            assert_eq!(Claim::pot(), SUFFICIENT_GENESIS_BALANCE - USER_1_AMOUNT);
-            assert_eq!(
-                TotalClaimable::<Test>::get(),
-                SUFFICIENT_GENESIS_BALANCE - USER_1_AMOUNT
+                "Successfull claim",
             );
-            assert_eq!(Balances::free_balance(USER_1_RAW), USER_1_AMOUNT);
-            assert!(Beneficiaries::<Test>::get(USER_1).is_none());
-        });
+            assert_eq!(Balances::free_balance(USER_1_RAW), USER_3_AMOUNT);
+            assert!(Beneficiaries::<Test>::get(CAMPAIGN_ID, USER_3).is_none());
+        })
     }
 
     #[test]
-    fn claim_ethereum_for() {
+    fn cannot_claim_ethereum_with_typed_data_for_other_claims() {
         test_with_configs(
             WithGenesisBeneficiaries::Yes,
             GenesisClaimBalance::Sufficient,
         )
         .execute_with(|| {
-            assert_ok!(Claim::claim_ethereum_for(
-                Origin::Signed(MANAGER_USER).into(),
-                USER_3_RAW,
-                USER_1_RAW
-            ));
-            assert_evt(
-                Event::Claimed {
-                    beneficiary: USER_3,
-                    amount: USER_3_AMOUNT,
-                },
-                "Successfull claim for another beneficiary",
-            );
-            assert_eq!(Claim::pot(), SUFFICIENT_GENESIS_BALANCE - USER_3_AMOUNT);
-            assert_eq!(
-                TotalClaimable::<Test>::get(),
-                SUFFICIENT_GENESIS_BALANCE - USER_3_AMOUNT
-            );
-            assert_eq!(Balances::free_balance(USER_1_RAW), USER_3_AMOUNT);
-            assert!(Beneficiaries::<Test>::get(USER_3).is_none());
-        });
+            for eth_signature in [
+                user_3_typed_signature(OTHER_CAMPAIGN_ID, USER_1_RAW, USER_3_AMOUNT),
+                user_3_typed_signature(CAMPAIGN_ID, USER_1_RAW + 1, USER_3_AMOUNT),
+                user_3_typed_signature(CAMPAIGN_ID, USER_1_RAW, USER_3_AMOUNT - 1),
+            ] {
+                assert_noop!(
+                    Claim::claim_ethereum(
+                        Origin::None.into(),
+                        CAMPAIGN_ID,
+                        USER_3_RAW,
+                        eth_signature,
+                        USER_1_RAW
+                    ),
+                    Error::<Test>::BadSignature
+                );
+            }
+        })
+    }
+}
+
+mod claim_solana_specific {
+    use super::*;
+    use sp_core::Pair;
+
+    const OTHER_DEST: AccountId = 24;
+
+    fn add_user_7() {
+        let _ =
+            Balances::mint_into(&Claim::campaign_account_id(CAMPAIGN_ID), USER_7_AMOUNT).unwrap();
+        insert_beneficiary(CAMPAIGN_ID, USER_7, USER_7_AMOUNT);
     }
 
     #[test]
-    fn claim_for_insufficient_balance() {
-        // Should never happen
+    fn claim_solana() {
         test_with_configs(
             WithGenesisBeneficiaries::Yes,
             GenesisClaimBalance::Sufficient,
         )
         .execute_with(|| {
-            Beneficiaries::<Test>::insert(NON_BENEFICIARY, SUFFICIENT_GENESIS_BALANCE + 1);
-            assert_err!(
-                Claim::claim_for(Origin::Signed(MANAGER_USER).into(), NON_BENEFICIARY_RAW),
-                TokenError::FundsUnavailable
-            );
-            assert_not_evt(
+            add_user_7();
+
+            assert_ok!(Claim::claim_solana(
+                Origin::None.into(),
+                CAMPAIGN_ID,
+                USER_7_RAW,
+                SolanaSignature::from_raw(USER_7_SIGNATURE_USER_1_DEST),
+                USER_1_RAW
+            ));
+
+            assert_evt(
                 Event::Claimed {
-                    beneficiary: NON_BENEFICIARY,
-                    amount: SUFFICIENT_GENESIS_BALANCE + 1,
+                    campaign_id: CAMPAIGN_ID,
+                    beneficiary: USER_7,
+                    amount: USER_7_AMOUNT,
                 },
-                "Cannot claim for other if money not available",
+                "Successfull claim",
             );
+            assert_eq!(Claim::pot(CAMPAIGN_ID), SUFFICIENT_GENESIS_BALANCE);
+            assert_eq!(Balances::free_balance(USER_1_RAW), USER_7_AMOUNT);
+            assert!(Beneficiaries::<Test>::get(CAMPAIGN_ID, USER_7).is_none());
         })
     }
 
     #[test]
-    fn claim_for_wrong_beneficiary() {
+    fn claim_solana_with_keypair_signature() {
         test_with_configs(
             WithGenesisBeneficiaries::Yes,
             GenesisClaimBalance::Sufficient,
         )
         .execute_with(|| {
-            assert_noop!(
-                Claim::claim_for(Origin::Signed(MANAGER_USER).into(), NON_BENEFICIARY_RAW),
-                Error::<Test>::NotEligible
-            );
-        });
-    }
+            add_user_7();
+
+            let pair = sp_core::ed25519::Pair::from_seed(&SOLANA_SECRET);
+            assert_eq!(pair.public().0, USER_7_RAW);
+            let msg_to_sign = [
+                INIT_CLAIM_MESSAGE.as_slice(),
+                EthMsgSeparator::get(),
+                MockAccountIdToBytesConversion::to_bytes_literal(&OTHER_DEST).as_slice(),
+            ]
+            .concat();
 
-    #[test]
-    fn cannot_claim_for_while_claim_inactive() {
-        test().execute_with(|| {
-            assert_err!(
-                Claim::claim_for(Origin::Signed(MANAGER_USER).into(), USER_1_RAW),
-                Error::<Test>::AlreadyEnded
-            );
+            assert_ok!(Claim::claim_solana(
+                Origin::None.into(),
+                CAMPAIGN_ID,
+                USER_7_RAW,
+                pair.sign(&msg_to_sign),
+                OTHER_DEST
+            ));
+            assert_eq!(Balances::free_balance(OTHER_DEST), USER_7_AMOUNT);
         })
     }
-}
 
-mod add_beneficiaries {
-    use super::*;
     #[test]
-    fn add_beneficiaries_wrong_origin() {
+    fn cannot_claim_solana_for_another_dest() {
         test_with_configs(
             WithGenesisBeneficiaries::Yes,
             GenesisClaimBalance::Sufficient,
         )
         .execute_with(|| {
-            assert_err!(
-                Claim::add_beneficiaries(
-                    Origin::Signed(USER_1_RAW).into(),
-                    NEW_BENEFICIARIES_MAP.clone()
+            add_user_7();
+
+            assert_noop!(
+                Claim::claim_solana(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    USER_7_RAW,
+                    SolanaSignature::from_raw(USER_7_SIGNATURE_USER_1_DEST),
+                    OTHER_DEST
                 ),
-                BadOrigin
+                Error::<Test>::BadSignature
             );
         })
     }
 
     #[test]
-    fn cannot_add_beneficiaries_while_claim_inactive() {
-        test().execute_with(|| {
-            assert_err!(
-                Claim::add_beneficiaries(
-                    Origin::Signed(MANAGER_USER).into(),
-                    NEW_BENEFICIARIES_MAP.clone()
+    fn cannot_claim_solana_with_bad_signature() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            add_user_7();
+
+            let mut bad_signature = USER_7_SIGNATURE_USER_1_DEST;
+            bad_signature[0] ^= 1;
+            assert_noop!(
+                Claim::claim_solana(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    USER_7_RAW,
+                    SolanaSignature::from_raw(bad_signature),
+                    USER_1_RAW
                 ),
-                Error::<Test>::AlreadyEnded
+                Error::<Test>::BadSignature
             );
         })
     }
 
     #[test]
-    fn cannot_add_too_many_op_beneficiaries() {
+    fn cannot_claim_solana_if_not_beneficiary() {
         test_with_configs(
-            WithGenesisBeneficiaries::No,
+            WithGenesisBeneficiaries::Yes,
             GenesisClaimBalance::Sufficient,
         )
         .execute_with(|| {
-            assert_ok!(Claim::begin_claim(
-                Origin::Signed(MANAGER_USER).into(),
-                EMPTY_BENEFICIARIES_MAP.clone(),
-                INIT_CLAIM_MESSAGE.clone()
-            ));
             assert_noop!(
-                Claim::add_beneficiaries(
-                    Origin::Signed(MANAGER_USER).into(),
-                    utils::get_beneficiaries_map::<Test>(MaxOpBeneficiaries::get() + 1).0
+                Claim::claim_solana(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    USER_7_RAW,
+                    SolanaSignature::from_raw(USER_7_SIGNATURE_USER_1_DEST),
+                    USER_1_RAW
                 ),
-                Error::<Test>::TooManyBeneficiaries
+                Error::<Test>::NotEligible
             );
         })
     }
+}
+
+mod claim_bitcoin_specific {
+    use super::*;
+
+    const OTHER_DEST: AccountId = 24;
+
+    fn add_bitcoin_users() {
+        let _ = Balances::mint_into(
+            &Claim::campaign_account_id(CAMPAIGN_ID),
+            USER_8_AMOUNT + USER_9_AMOUNT + USER_10_AMOUNT,
+        )
+        .unwrap();
+        insert_beneficiary(CAMPAIGN_ID, USER_8, USER_8_AMOUNT);
+        insert_beneficiary(CAMPAIGN_ID, USER_9, USER_9_AMOUNT);
+        insert_beneficiary(CAMPAIGN_ID, USER_10, USER_10_AMOUNT);
+    }
 
     #[test]
-    fn cannot_add_beneficiaries_over_the_max() {
+    fn claim_bitcoin() {
         test_with_configs(
             WithGenesisBeneficiaries::Yes,
             GenesisClaimBalance::Sufficient,
         )
         .execute_with(|| {
-            assert_noop!(
-                Claim::add_beneficiaries(
-                    Origin::Signed(MANAGER_USER).into(),
-                    utils::get_beneficiaries_map::<Test>(
-                        MaxBeneficiaries::get() - GENESIS_BENEFICIARIES.len() as u32 + 1
-                    )
-                    .0
+            add_bitcoin_users();
+
+            let mut claimed = 0;
+            for (address, beneficiary, amount, signature) in [
+                (
+                    USER_8_RAW,
+                    USER_8,
+                    USER_8_AMOUNT,
+                    USER_8_SIGNATURE_USER_1_DEST,
                 ),
-                Error::<Test>::MaxNumBeneficiariesReached
-            );
+                (
+                    USER_9_RAW,
+                    USER_9,
+                    USER_9_AMOUNT,
+                    USER_9_SIGNATURE_USER_1_DEST,
+                ),
+                (
+                    USER_10_RAW,
+                    USER_10,
+                    USER_10_AMOUNT,
+                    USER_10_SIGNATURE_USER_1_DEST,
+                ),
+            ] {
+                assert_ok!(Claim::claim_bitcoin(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    address,
+                    signature,
+                    USER_1_RAW
+                ));
+                claimed += amount;
+
+                assert_evt(
+                    Event::Claimed {
+                        campaign_id: CAMPAIGN_ID,
+                        beneficiary: beneficiary.clone(),
+                        amount,
+                    },
+                    "Successfull claim",
+                );
+                assert_eq!(Balances::free_balance(USER_1_RAW), claimed);
+                assert!(Beneficiaries::<Test>::get(CAMPAIGN_ID, beneficiary).is_none());
+            }
+            assert_eq!(Claim::pot(CAMPAIGN_ID), SUFFICIENT_GENESIS_BALANCE);
         })
     }
 
     #[test]
-    fn add_beneficiaries_sufficient_funds() {
+    fn cannot_claim_bitcoin_for_another_public_key_encoding() {
         test_with_configs(
             WithGenesisBeneficiaries::Yes,
             GenesisClaimBalance::Sufficient,
         )
         .execute_with(|| {
-            let _ = Balances::mint_into(&Claim::account_id(), NEW_SUFFICIENT_BALANCE).unwrap();
-            assert_ok!(Claim::add_beneficiaries(
-                Origin::Signed(MANAGER_USER).into(),
+            add_bitcoin_users();
+
+            // USER_10 is the uncompressed public key of USER_8 and USER_9
+            for (address, signature) in [
+                (USER_8_RAW, USER_10_SIGNATURE_USER_1_DEST),
+                (USER_9_RAW, USER_10_SIGNATURE_USER_1_DEST),
+                (USER_10_RAW, USER_8_SIGNATURE_USER_1_DEST),
+                (USER_10_RAW, USER_9_SIGNATURE_USER_1_DEST),
+            ] {
+                assert_noop!(
+                    Claim::claim_bitcoin(
+                        Origin::None.into(),
+                        CAMPAIGN_ID,
+                        address,
+                        signature,
+                        USER_1_RAW
+                    ),
+                    Error::<Test>::BadSignature
+                );
+            }
+        })
+    }
+
+    #[test]
+    fn claim_bitcoin_whatever_the_header_of_a_compressed_key() {
+        // Electrum and Sparrow sign for P2WPKH addresses with the P2PKH headers
+        for (address, beneficiary, amount, signature) in [
+            (
+                USER_9_RAW,
+                USER_9,
+                USER_9_AMOUNT,
+                USER_8_SIGNATURE_USER_1_DEST,
+            ),
+            (
+                USER_8_RAW,
+                USER_8,
+                USER_8_AMOUNT,
+                USER_9_SIGNATURE_USER_1_DEST,
+            ),
+        ] {
+            test_with_configs(
+                WithGenesisBeneficiaries::Yes,
+                GenesisClaimBalance::Sufficient,
+            )
+            .execute_with(|| {
+                add_bitcoin_users();
+
+                assert_ok!(Claim::claim_bitcoin(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    address,
+                    signature,
+                    USER_1_RAW
+                ));
+                assert_eq!(Balances::free_balance(USER_1_RAW), amount);
+                assert!(Beneficiaries::<Test>::get(CAMPAIGN_ID, beneficiary).is_none());
+            })
+        }
+    }
+
+    #[test]
+    fn cannot_claim_bitcoin_with_unsupported_header() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            add_bitcoin_users();
+
+            // 35-38 are the headers of P2SH-P2WPKH addresses
+            let mut signature = USER_9_SIGNATURE_USER_1_DEST;
+            for header in [0, 26, 35, 38, 43, u8::MAX] {
+                signature.0[0] = header;
+                assert_noop!(
+                    Claim::claim_bitcoin(
+                        Origin::None.into(),
+                        CAMPAIGN_ID,
+                        USER_9_RAW,
+                        signature,
+                        USER_1_RAW
+                    ),
+                    Error::<Test>::BadSignature
+                );
+            }
+        })
+    }
+
+    #[test]
+    fn cannot_claim_bitcoin_for_another_dest() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            add_bitcoin_users();
+
+            assert_noop!(
+                Claim::claim_bitcoin(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    USER_8_RAW,
+                    USER_8_SIGNATURE_USER_1_DEST,
+                    OTHER_DEST
+                ),
+                Error::<Test>::BadSignature
+            );
+        })
+    }
+
+    #[test]
+    fn cannot_claim_bitcoin_if_not_beneficiary() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            assert_noop!(
+                Claim::claim_bitcoin(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    USER_8_RAW,
+                    USER_8_SIGNATURE_USER_1_DEST,
+                    USER_1_RAW
+                ),
+                Error::<Test>::NotEligible
+            );
+        })
+    }
+}
+
+mod claim_for {
+    use super::*;
+
+    #[test]
+    fn claim_for() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            assert_ok!(Claim::claim_for(
+                Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID,
+                USER_1_RAW
+            ));
+            assert_evt(
+                Event::Claimed {
+                    campaign_id: CAMPAIGN_ID,
+                    beneficiary: USER_1,
+                    amount: USER_1_AMOUNT,
+                },
+                "Successfull claim for another beneficiary",
+            );
+            assert_eq!(
+                Claim::pot(CAMPAIGN_ID),
+                SUFFICIENT_GENESIS_BALANCE - USER_1_AMOUNT
+            );
+            assert_eq!(
+                TotalClaimable::<Test>::get(CAMPAIGN_ID),
+                SUFFICIENT_GENESIS_BALANCE - USER_1_AMOUNT
+            );
+            assert_eq!(Balances::free_balance(USER_1_RAW), USER_1_AMOUNT);
+            assert!(Beneficiaries::<Test>::get(CAMPAIGN_ID, USER_1).is_none());
+        });
+    }
+
+    #[test]
+    fn claim_ethereum_for() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            assert_ok!(Claim::claim_ethereum_for(
+                Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID,
+                USER_3_RAW,
+                USER_1_RAW
+            ));
+            assert_evt(
+                Event::Claimed {
+                    campaign_id: CAMPAIGN_ID,
+                    beneficiary: USER_3,
+                    amount: USER_3_AMOUNT,
+                },
+                "Successfull claim for another beneficiary",
+            );
+            assert_eq!(
+                Claim::pot(CAMPAIGN_ID),
+                SUFFICIENT_GENESIS_BALANCE - USER_3_AMOUNT
+            );
+            assert_eq!(
+                TotalClaimable::<Test>::get(CAMPAIGN_ID),
+                SUFFICIENT_GENESIS_BALANCE - USER_3_AMOUNT
+            );
+            assert_eq!(Balances::free_balance(USER_1_RAW), USER_3_AMOUNT);
+            assert!(Beneficiaries::<Test>::get(CAMPAIGN_ID, USER_3).is_none());
+        });
+    }
+
+    #[test]
+    fn claim_for_insufficient_balance() {
+        // Should never happen
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            insert_beneficiary(CAMPAIGN_ID, NON_BENEFICIARY, SUFFICIENT_GENESIS_BALANCE + 1);
+            assert_err!(
+                Claim::claim_for(
+                    Origin::Signed(MANAGER_USER).into(),
+                    CAMPAIGN_ID,
+                    NON_BENEFICIARY_RAW
+                ),
+                TokenError::FundsUnavailable
+            );
+            assert_not_evt(
+                Event::Claimed {
+                    campaign_id: CAMPAIGN_ID,
+                    beneficiary: NON_BENEFICIARY,
+                    amount: SUFFICIENT_GENESIS_BALANCE + 1,
+                },
+                "Cannot claim for other if money not available",
+            );
+        })
+    }
+
+    #[test]
+    fn claim_for_wrong_beneficiary() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            assert_noop!(
+                Claim::claim_for(
+                    Origin::Signed(MANAGER_USER).into(),
+                    CAMPAIGN_ID,
+                    NON_BENEFICIARY_RAW
+                ),
+                Error::<Test>::NotEligible
+            );
+        });
+    }
+
+    #[test]
+    fn cannot_claim_for_while_claim_inactive() {
+        test().execute_with(|| {
+            insert_ended_campaign(CAMPAIGN_ID);
+            assert_err!(
+                Claim::claim_for(Origin::Signed(MANAGER_USER).into(), CAMPAIGN_ID, USER_1_RAW),
+                Error::<Test>::AlreadyEnded
+            );
+        })
+    }
+}
+
+mod add_beneficiaries {
+    use super::*;
+    #[test]
+    fn add_beneficiaries_wrong_origin() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            assert_err!(
+                Claim::add_beneficiaries(
+                    Origin::Signed(USER_1_RAW).into(),
+                    CAMPAIGN_ID,
+                    NEW_BENEFICIARIES_MAP.clone()
+                ),
+                BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn cannot_add_beneficiaries_while_claim_inactive() {
+        test().execute_with(|| {
+            insert_ended_campaign(CAMPAIGN_ID);
+            assert_err!(
+                Claim::add_beneficiaries(
+                    Origin::Signed(MANAGER_USER).into(),
+                    CAMPAIGN_ID,
+                    NEW_BENEFICIARIES_MAP.clone()
+                ),
+                Error::<Test>::AlreadyEnded
+            );
+        })
+    }
+
+    #[test]
+    fn cannot_add_too_many_op_beneficiaries() {
+        test_with_configs(
+            WithGenesisBeneficiaries::No,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            assert_ok!(Claim::begin_claim(
+                Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID,
+                EMPTY_BENEFICIARIES_MAP.clone(),
+                INIT_CLAIM_MESSAGE.clone(),
+                Default::default()
+            ));
+            assert_noop!(
+                Claim::add_beneficiaries(
+                    Origin::Signed(MANAGER_USER).into(),
+                    CAMPAIGN_ID,
+                    utils::get_beneficiaries_map::<Test>(MaxOpBeneficiaries::get() + 1).0
+                ),
+                Error::<Test>::TooManyBeneficiaries
+            );
+        })
+    }
+
+    #[test]
+    fn cannot_add_beneficiaries_over_the_max() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            assert_noop!(
+                Claim::add_beneficiaries(
+                    Origin::Signed(MANAGER_USER).into(),
+                    CAMPAIGN_ID,
+                    utils::get_beneficiaries_map::<Test>(
+                        MaxBeneficiaries::get() - GENESIS_BENEFICIARIES.len() as u32 + 1
+                    )
+                    .0
+                ),
+                Error::<Test>::MaxNumBeneficiariesReached
+            );
+        })
+    }
+
+    #[test]
+    fn add_beneficiaries_sufficient_funds() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            let _ = Balances::mint_into(
+                &Claim::campaign_account_id(CAMPAIGN_ID),
+                NEW_SUFFICIENT_BALANCE,
+            )
+            .unwrap();
+            assert_ok!(Claim::add_beneficiaries(
+                Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID,
                 NEW_BENEFICIARIES_MAP.clone()
             ));
             assert_eq!(
-                Claim::pot(),
+                Claim::pot(CAMPAIGN_ID),
                 SUFFICIENT_GENESIS_BALANCE + NEW_SUFFICIENT_BALANCE
             );
             assert_eq!(
-                TotalClaimable::<Test>::get(),
+                TotalClaimable::<Test>::get(CAMPAIGN_ID),
                 SUFFICIENT_GENESIS_BALANCE + NEW_SUFFICIENT_BALANCE
             );
             assert_eq!(
-                Beneficiaries::<Test>::iter().collect::<BTreeMap<_, _>>(),
+                Beneficiaries::<Test>::iter_prefix(CAMPAIGN_ID).collect::<BTreeMap<_, _>>(),
                 GENESIS_BENEFICIARIES_MAP
                     .clone()
                     .into_iter()
@@ -847,12 +1457,16 @@ mod add_beneficiaries {
         )
         .execute_with(|| {
             // Just add enough funds to cover for first insertions but not all
-            let _ =
-                Balances::mint_into(&Claim::account_id(), USER_4_AMOUNT + USER_5_AMOUNT).unwrap();
+            let _ = Balances::mint_into(
+                &Claim::campaign_account_id(CAMPAIGN_ID),
+                USER_4_AMOUNT + USER_5_AMOUNT,
+            )
+            .unwrap();
 
             assert_noop!(
                 Claim::add_beneficiaries(
                     Origin::Signed(MANAGER_USER).into(),
+                    CAMPAIGN_ID,
                     NEW_BENEFICIARIES_MAP.clone()
                 ),
                 TokenError::FundsUnavailable
@@ -870,6 +1484,7 @@ mod add_beneficiaries {
             assert_err!(
                 Claim::add_beneficiaries(
                     Origin::Signed(MANAGER_USER).into(),
+                    CAMPAIGN_ID,
                     GENESIS_BENEFICIARIES_MAP.clone()
                 ),
                 Error::<Test>::AlreadyPresent
@@ -887,6 +1502,7 @@ mod add_beneficiaries {
             assert_err!(
                 Claim::add_beneficiaries(
                     Origin::Signed(MANAGER_USER).into(),
+                    CAMPAIGN_ID,
                     vec![(USER_6, BalanceOf::<Test>::zero())]
                         .into_iter()
                         .collect()
@@ -905,6 +1521,7 @@ mod add_beneficiaries {
         .execute_with(|| {
             assert_ok!(Claim::add_beneficiaries(
                 Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID,
                 BTreeMap::new()
             ));
         })
@@ -920,10 +1537,11 @@ mod remove_beneficiaries {
 
         // Add MaxOpBeneficiaries + 1
         e.execute_with(|| {
+            insert_ended_campaign(CAMPAIGN_ID);
             utils::get_beneficiaries_map::<Test>(MaxOpBeneficiaries::get())
                 .0
                 .into_iter()
-                .for_each(|account| Beneficiaries::<Test>::insert(account.0, account.1));
+                .for_each(|account| insert_beneficiary(CAMPAIGN_ID, account.0, account.1));
         });
         e.commit_all().unwrap();
 
@@ -931,10 +1549,17 @@ mod remove_beneficiaries {
             // First remove call should succeed but be insufficient
             assert_ok!(Claim::remove_beneficiaries(
                 Origin::Signed(USER_1_RAW).into(),
+                CAMPAIGN_ID,
                 None
             ));
-            assert_evt(Event::NoMoreBeneficiaries, "No more beneficiaries");
-            assert_eq!(Beneficiaries::<Test>::count(), 0);
+            assert_evt(
+                Event::NoMoreBeneficiaries {
+                    campaign_id: CAMPAIGN_ID,
+                },
+                "No more beneficiaries",
+            );
+            assert_eq!(BeneficiariesCount::<Test>::get(CAMPAIGN_ID), 0);
+            assert!(!Campaigns::<Test>::contains_key(CAMPAIGN_ID));
         });
     }
 
@@ -944,10 +1569,11 @@ mod remove_beneficiaries {
 
         // Add MaxOpBeneficiaries + 1
         e.execute_with(|| {
+            insert_ended_campaign(CAMPAIGN_ID);
             utils::get_beneficiaries_map::<Test>(MaxOpBeneficiaries::get())
                 .0
                 .into_iter()
-                .for_each(|account| Beneficiaries::<Test>::insert(account.0, account.1));
+                .for_each(|account| insert_beneficiary(CAMPAIGN_ID, account.0, account.1));
         });
         e.commit_all().unwrap();
 
@@ -956,180 +1582,1959 @@ mod remove_beneficiaries {
             // First remove call should succeed but be insufficient
             assert_ok!(Claim::remove_beneficiaries(
                 Origin::Signed(USER_1_RAW).into(),
+                CAMPAIGN_ID,
                 Some(limit)
             ));
             let remaining = MaxOpBeneficiaries::get() - limit;
             assert_evt(
-                Event::BeneficiariesRemoved { remaining },
+                Event::BeneficiariesRemoved {
+                    campaign_id: CAMPAIGN_ID,
+                    remaining,
+                },
                 "Beneficiaries removed",
             );
-            assert_eq!(Beneficiaries::<Test>::count(), remaining);
-        });
+            assert_eq!(BeneficiariesCount::<Test>::get(CAMPAIGN_ID), remaining);
+            assert!(Campaigns::<Test>::contains_key(CAMPAIGN_ID));
+        });
+    }
+
+    #[test]
+    fn cannot_remove_beneficiaries_of_unknown_campaign() {
+        test().execute_with(|| {
+            assert_noop!(
+                Claim::remove_beneficiaries(Origin::Signed(USER_1_RAW).into(), CAMPAIGN_ID, None),
+                Error::<Test>::CampaignNotFound
+            );
+        })
+    }
+
+    #[test]
+    fn cannot_remove_beneficiaries_if_claim_in_progress() {
+        test().execute_with(|| {
+            Claim::begin_claim(
+                Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID,
+                EMPTY_BENEFICIARIES_MAP.clone(),
+                INIT_CLAIM_MESSAGE.clone(),
+                Default::default(),
+            )
+            .unwrap();
+            assert_noop!(
+                Claim::remove_beneficiaries(Origin::Signed(USER_1_RAW).into(), CAMPAIGN_ID, None),
+                Error::<Test>::AlreadyStarted
+            );
+        })
+    }
+}
+
+mod end_claim {
+    use super::*;
+    #[test]
+    fn end_claim() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            // Give other balance. Now Self::pot() == SUFFICIENT_GENESIS_BALANCE * 2
+            let _ = Balances::mint_into(
+                &Claim::campaign_account_id(CAMPAIGN_ID),
+                SUFFICIENT_GENESIS_BALANCE,
+            )
+            .unwrap();
+            assert_ok!(Claim::end_claim(
+                Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID
+            ));
+            assert_evt(
+                Event::ClaimEnded {
+                    campaign_id: CAMPAIGN_ID,
+                    claim_message: INIT_CLAIM_MESSAGE.clone(),
+                },
+                "Claim finished",
+            );
+
+            assert!(!claim_active(CAMPAIGN_ID));
+            assert_eq!(
+                BeneficiariesCount::<Test>::get(CAMPAIGN_ID) as usize,
+                GENESIS_BENEFICIARIES.len()
+            ); // Sanity check: beneficiaries not removed
+            assert_eq!(Claim::pot(CAMPAIGN_ID), 0);
+            assert_eq!(
+                Balances::free_balance(Claim::campaign_account_id(CAMPAIGN_ID)),
+                EXISTENTIAL_DEPOSIT
+            );
+            assert_eq!(TotalClaimable::<Test>::get(CAMPAIGN_ID), 0);
+            assert_eq!(
+                Balances::reducible_balance(
+                    &UnclaimedDestinationMockAccount::get(),
+                    Preservation::Expendable,
+                    Fortitude::Polite,
+                ),
+                SUFFICIENT_GENESIS_BALANCE * 2
+            );
+        });
+    }
+
+    #[test]
+    fn end_claim_wrong_origin() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            assert_err!(
+                Claim::end_claim(Origin::Signed(USER_1_RAW).into(), CAMPAIGN_ID),
+                BadOrigin
+            );
+            assert_not_evt(
+                Event::ClaimEnded {
+                    campaign_id: CAMPAIGN_ID,
+                    claim_message: INIT_CLAIM_MESSAGE.clone(),
+                },
+                "No end claim",
+            );
+        })
+    }
+
+    #[test]
+    fn double_end_claim() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            assert_ok!(Claim::end_claim(
+                Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID
+            ));
+            assert_err!(
+                Claim::end_claim(Origin::Signed(MANAGER_USER).into(), CAMPAIGN_ID),
+                Error::<Test>::AlreadyEnded
+            );
+        });
+    }
+
+    #[test]
+    fn end_claim_new_claim() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            assert_ok!(Claim::end_claim(
+                Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID
+            ));
+            assert_ok!(Claim::remove_beneficiaries(
+                Origin::Signed(USER_1_RAW).into(),
+                CAMPAIGN_ID,
+                None
+            ));
+
+            assert_ok!(Claim::begin_claim(
+                Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID,
+                EMPTY_BENEFICIARIES_MAP.clone(),
+                INIT_CLAIM_MESSAGE.clone(),
+                Default::default()
+            ));
+            assert_evt(
+                Event::ClaimStarted {
+                    campaign_id: CAMPAIGN_ID,
+                    claim_message: INIT_CLAIM_MESSAGE.clone(),
+                },
+                "New claim",
+            );
+            assert!(Beneficiaries::<Test>::iter_prefix(CAMPAIGN_ID)
+                .next()
+                .is_none());
+            assert_eq!(
+                TotalClaimable::<Test>::get(CAMPAIGN_ID),
+                BalanceOf::<Test>::zero()
+            );
+            assert!(claim_active(CAMPAIGN_ID));
+            assert_eq!(claim_message(CAMPAIGN_ID), Some(INIT_CLAIM_MESSAGE.clone()));
+            assert_eq!(
+                Balances::free_balance(Claim::campaign_account_id(CAMPAIGN_ID)),
+                EXISTENTIAL_DEPOSIT
+            );
+            assert_eq!(Claim::pot(CAMPAIGN_ID), 0);
+        });
+    }
+
+    #[test]
+    fn cannot_init_new_claim_if_leftovers_beneficiaries() {
+        test().execute_with(|| {
+            insert_ended_campaign(CAMPAIGN_ID);
+            insert_beneficiary(CAMPAIGN_ID, USER_6, USER_6_AMOUNT);
+
+            assert_noop!(
+                Claim::begin_claim(
+                    Origin::Signed(MANAGER_USER).into(),
+                    CAMPAIGN_ID,
+                    EMPTY_BENEFICIARIES_MAP.clone(),
+                    INIT_CLAIM_MESSAGE.clone(),
+                    Default::default()
+                ),
+                Error::<Test>::NonEmptyBeneficiaries
+            );
+
+            // Remove beneficiary and try again
+            assert_ok!(Claim::remove_beneficiaries(
+                Origin::Signed(USER_1_RAW).into(),
+                CAMPAIGN_ID,
+                None
+            ));
+
+            assert_ok!(Claim::begin_claim(
+                Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID,
+                EMPTY_BENEFICIARIES_MAP.clone(),
+                INIT_CLAIM_MESSAGE.clone(),
+                Default::default()
+            ));
+            assert_evt(
+                Event::ClaimStarted {
+                    campaign_id: CAMPAIGN_ID,
+                    claim_message: INIT_CLAIM_MESSAGE.clone(),
+                },
+                "New claim",
+            );
+        })
+    }
+}
+
+mod campaigns {
+    use super::*;
+    use crate::Call as ClaimCall;
+    use sp_runtime::{
+        traits::ValidateUnsigned,
+        transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidityError},
+    };
+
+    fn begin_other_campaign(config: CampaignConfigOf<Test>) -> DispatchResult {
+        let _ = Balances::mint_into(
+            &Claim::campaign_account_id(OTHER_CAMPAIGN_ID),
+            NEW_SUFFICIENT_BALANCE + EXISTENTIAL_DEPOSIT,
+        )
+        .unwrap();
+        Claim::begin_claim(
+            Origin::Signed(MANAGER_USER).into(),
+            OTHER_CAMPAIGN_ID,
+            NEW_BENEFICIARIES_MAP.clone(),
+            INIT_CLAIM_MESSAGE.clone(),
+            config,
+        )
+    }
+
+    fn managed_by(manager: AccountId) -> CampaignConfigOf<Test> {
+        CampaignConfig {
+            manager: Some(manager),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn concurrent_campaigns_are_independent() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            assert_ok!(begin_other_campaign(Default::default()));
+            assert!(claim_active(CAMPAIGN_ID));
+            assert!(claim_active(OTHER_CAMPAIGN_ID));
+
+            assert_eq!(
+                TotalClaimable::<Test>::get(CAMPAIGN_ID),
+                SUFFICIENT_GENESIS_BALANCE
+            );
+            assert_eq!(
+                TotalClaimable::<Test>::get(OTHER_CAMPAIGN_ID),
+                NEW_SUFFICIENT_BALANCE
+            );
+            assert_eq!(Claim::pot(CAMPAIGN_ID), SUFFICIENT_GENESIS_BALANCE);
+            assert_eq!(Claim::pot(OTHER_CAMPAIGN_ID), NEW_SUFFICIENT_BALANCE);
+            assert_eq!(
+                BeneficiariesCount::<Test>::get(OTHER_CAMPAIGN_ID) as usize,
+                NEW_BENEFICIARIES.len()
+            );
+
+            // A beneficiary of a campaign is not a beneficiary of the other one
+            assert_noop!(
+                Claim::claim_for(
+                    Origin::Signed(MANAGER_USER).into(),
+                    OTHER_CAMPAIGN_ID,
+                    USER_1_RAW
+                ),
+                Error::<Test>::NotEligible
+            );
+            assert_ok!(Claim::claim_for(
+                Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID,
+                USER_1_RAW
+            ));
+            assert_eq!(
+                Claim::pot(CAMPAIGN_ID),
+                SUFFICIENT_GENESIS_BALANCE - USER_1_AMOUNT
+            );
+            assert_eq!(Claim::pot(OTHER_CAMPAIGN_ID), NEW_SUFFICIENT_BALANCE);
+
+            // Ending a campaign doesn't affect the other one
+            assert_ok!(Claim::end_claim(
+                Origin::Signed(MANAGER_USER).into(),
+                OTHER_CAMPAIGN_ID
+            ));
+            assert!(!claim_active(OTHER_CAMPAIGN_ID));
+            assert!(claim_active(CAMPAIGN_ID));
+            assert_eq!(Claim::pot(OTHER_CAMPAIGN_ID), 0);
+            assert_eq!(
+                Claim::pot(CAMPAIGN_ID),
+                SUFFICIENT_GENESIS_BALANCE - USER_1_AMOUNT
+            );
+            assert_eq!(
+                TotalClaimable::<Test>::get(CAMPAIGN_ID),
+                SUFFICIENT_GENESIS_BALANCE - USER_1_AMOUNT
+            );
+        })
+    }
+
+    #[test]
+    fn campaign_manager_can_manage_its_campaign() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            assert_ok!(begin_other_campaign(managed_by(CAMPAIGN_MANAGER_USER)));
+
+            let (beneficiary, amount) = (Beneficiary::<Test>::Substrate(USER_1_RAW), 1);
+            let _ = Balances::mint_into(&Claim::campaign_account_id(OTHER_CAMPAIGN_ID), amount)
+                .unwrap();
+            assert_ok!(Claim::add_beneficiaries(
+                Origin::Signed(CAMPAIGN_MANAGER_USER).into(),
+                OTHER_CAMPAIGN_ID,
+                vec![(beneficiary, amount)].into_iter().collect()
+            ));
+            assert_ok!(Claim::claim_for(
+                Origin::Signed(CAMPAIGN_MANAGER_USER).into(),
+                OTHER_CAMPAIGN_ID,
+                USER_1_RAW
+            ));
+            assert_ok!(Claim::end_claim(
+                Origin::Signed(CAMPAIGN_MANAGER_USER).into(),
+                OTHER_CAMPAIGN_ID
+            ));
+        })
+    }
+
+    #[test]
+    fn campaign_manager_cannot_manage_other_campaigns() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            assert_ok!(begin_other_campaign(managed_by(CAMPAIGN_MANAGER_USER)));
+
+            assert_noop!(
+                Claim::add_beneficiaries(
+                    Origin::Signed(CAMPAIGN_MANAGER_USER).into(),
+                    CAMPAIGN_ID,
+                    NEW_BENEFICIARIES_MAP.clone()
+                ),
+                BadOrigin
+            );
+            assert_noop!(
+                Claim::claim_for(
+                    Origin::Signed(CAMPAIGN_MANAGER_USER).into(),
+                    CAMPAIGN_ID,
+                    USER_1_RAW
+                ),
+                BadOrigin
+            );
+            assert_noop!(
+                Claim::end_claim(Origin::Signed(CAMPAIGN_MANAGER_USER).into(), CAMPAIGN_ID),
+                BadOrigin
+            );
+            assert_noop!(
+                Claim::end_claim(Origin::Signed(USER_1_RAW).into(), OTHER_CAMPAIGN_ID),
+                BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn campaign_manager_cannot_begin_campaigns() {
+        test().execute_with(|| {
+            assert_noop!(
+                Claim::begin_claim(
+                    Origin::Signed(CAMPAIGN_MANAGER_USER).into(),
+                    OTHER_CAMPAIGN_ID,
+                    EMPTY_BENEFICIARIES_MAP.clone(),
+                    INIT_CLAIM_MESSAGE.clone(),
+                    managed_by(CAMPAIGN_MANAGER_USER)
+                ),
+                BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn cannot_claim_before_start() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            assert_ok!(begin_other_campaign(CampaignConfig {
+                start: Some(10),
+                ..Default::default()
+            }));
+            let (beneficiary, _) = NEW_BENEFICIARIES[0].clone();
+            let Beneficiary::Substrate(dest) = beneficiary else {
+                panic!("Unexpected beneficiary")
+            };
+
+            // Beneficiaries can be added before the claiming period starts
+            assert_ok!(Claim::add_beneficiaries(
+                Origin::Signed(MANAGER_USER).into(),
+                OTHER_CAMPAIGN_ID,
+                BTreeMap::new()
+            ));
+            assert_noop!(
+                Claim::claim_for(Origin::Signed(MANAGER_USER).into(), OTHER_CAMPAIGN_ID, dest),
+                Error::<Test>::NotStarted
+            );
+
+            System::set_block_number(10);
+            assert_ok!(Claim::claim_for(
+                Origin::Signed(MANAGER_USER).into(),
+                OTHER_CAMPAIGN_ID,
+                dest
+            ));
+        })
+    }
+
+    #[test]
+    fn cannot_claim_after_end() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            assert_ok!(begin_other_campaign(CampaignConfig {
+                end: Some(10),
+                ..Default::default()
+            }));
+            let (beneficiary, _) = NEW_BENEFICIARIES[0].clone();
+            let Beneficiary::Substrate(dest) = beneficiary else {
+                panic!("Unexpected beneficiary")
+            };
+
+            System::set_block_number(10);
+            assert!(claim_active(OTHER_CAMPAIGN_ID)); // Not ended yet
+            assert_noop!(
+                Claim::claim_for(Origin::Signed(MANAGER_USER).into(), OTHER_CAMPAIGN_ID, dest),
+                Error::<Test>::AlreadyEnded
+            );
+            assert_noop!(
+                Claim::add_beneficiaries(
+                    Origin::Signed(MANAGER_USER).into(),
+                    OTHER_CAMPAIGN_ID,
+                    BTreeMap::new()
+                ),
+                Error::<Test>::AlreadyEnded
+            );
+        })
+    }
+
+    #[test]
+    fn anyone_can_end_campaign_after_end() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            assert_ok!(begin_other_campaign(CampaignConfig {
+                end: Some(10),
+                ..Default::default()
+            }));
+
+            assert_noop!(
+                Claim::end_claim(Origin::Signed(USER_1_RAW).into(), OTHER_CAMPAIGN_ID),
+                BadOrigin
+            );
+            System::set_block_number(10);
+            assert_noop!(
+                Claim::end_claim(Origin::None.into(), OTHER_CAMPAIGN_ID),
+                BadOrigin
+            );
+            assert_ok!(Claim::end_claim(
+                Origin::Signed(USER_1_RAW).into(),
+                OTHER_CAMPAIGN_ID
+            ));
+            assert_evt(
+                Event::ClaimEnded {
+                    campaign_id: OTHER_CAMPAIGN_ID,
+                    claim_message: INIT_CLAIM_MESSAGE.clone(),
+                },
+                "Claim ended by anyone",
+            );
+        })
+    }
+
+    #[test]
+    fn cannot_begin_campaign_with_invalid_period() {
+        test().execute_with(|| {
+            System::set_block_number(5);
+            for (start, end) in [
+                (None, 5),
+                (None, 4),
+                (Some(10), 10),
+                (Some(10), 9),
+                (Some(1), 4),
+            ] {
+                assert_noop!(
+                    Claim::begin_claim(
+                        Origin::Signed(MANAGER_USER).into(),
+                        CAMPAIGN_ID,
+                        EMPTY_BENEFICIARIES_MAP.clone(),
+                        INIT_CLAIM_MESSAGE.clone(),
+                        CampaignConfig {
+                            start,
+                            end: Some(end),
+                            ..Default::default()
+                        }
+                    ),
+                    Error::<Test>::InvalidCampaignPeriod
+                );
+            }
+        })
+    }
+
+    #[test]
+    fn unclaimed_tokens_go_to_campaign_destination() {
+        const DESTINATION: AccountId = 888;
+
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            assert_ok!(begin_other_campaign(CampaignConfig {
+                unclaimed_destination: Some(DESTINATION),
+                ..Default::default()
+            }));
+            assert_ok!(Claim::end_claim(
+                Origin::Signed(MANAGER_USER).into(),
+                OTHER_CAMPAIGN_ID
+            ));
+            assert_eq!(Balances::free_balance(DESTINATION), NEW_SUFFICIENT_BALANCE);
+            assert_eq!(
+                Balances::free_balance(UnclaimedDestinationMockAccount::get()),
+                0
+            );
+        })
+    }
+
+    #[test]
+    fn unknown_campaign() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            let (user_signer, user_signature, _) = USER_1_SIGN.clone();
+            assert_noop!(
+                Claim::claim(
+                    Origin::None.into(),
+                    OTHER_CAMPAIGN_ID,
+                    user_signer,
+                    user_signature
+                ),
+                Error::<Test>::CampaignNotFound
+            );
+            assert_noop!(
+                Claim::claim_for(
+                    Origin::Signed(MANAGER_USER).into(),
+                    OTHER_CAMPAIGN_ID,
+                    USER_1_RAW
+                ),
+                Error::<Test>::CampaignNotFound
+            );
+            assert_noop!(
+                Claim::add_beneficiaries(
+                    Origin::Signed(MANAGER_USER).into(),
+                    OTHER_CAMPAIGN_ID,
+                    NEW_BENEFICIARIES_MAP.clone()
+                ),
+                Error::<Test>::CampaignNotFound
+            );
+            assert_noop!(
+                Claim::end_claim(Origin::Signed(MANAGER_USER).into(), OTHER_CAMPAIGN_ID),
+                Error::<Test>::CampaignNotFound
+            );
+        })
+    }
+
+    #[test]
+    fn validate_unsigned_campaign_period() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            let (user_signer, user_signature, _) = USER_1_SIGN.clone();
+            let call = |campaign_id| ClaimCall::claim {
+                campaign_id,
+                beneficiary: user_signer.clone(),
+                signature: user_signature.clone(),
+            };
+
+            assert_eq!(
+                Pallet::<Test>::validate_unsigned(
+                    TransactionSource::External,
+                    &call(OTHER_CAMPAIGN_ID)
+                ),
+                Err(TransactionValidityError::Invalid(InvalidTransaction::Stale))
+            );
+
+            Campaigns::<Test>::mutate(CAMPAIGN_ID, |c| c.as_mut().unwrap().start = 10);
+            assert_eq!(
+                Pallet::<Test>::validate_unsigned(TransactionSource::External, &call(CAMPAIGN_ID)),
+                Err(TransactionValidityError::Invalid(
+                    InvalidTransaction::Future
+                ))
+            );
+
+            Campaigns::<Test>::mutate(CAMPAIGN_ID, |c| c.as_mut().unwrap().end = Some(20));
+            System::set_block_number(20);
+            assert_eq!(
+                Pallet::<Test>::validate_unsigned(TransactionSource::External, &call(CAMPAIGN_ID)),
+                Err(TransactionValidityError::Invalid(InvalidTransaction::Stale))
+            );
+        })
+    }
+}
+
+mod merkle {
+    use super::*;
+    use crate::Call as ClaimCall;
+    use codec::Encode;
+    use sp_runtime::{
+        testing::UintAuthorityId,
+        traits::{Keccak256, ValidateUnsigned},
+        transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidityError},
+    };
+
+    const MERKLE_BENEFICIARIES: [(Beneficiary<Test>, Balance); 4] = [
+        (USER_1, USER_1_AMOUNT),
+        (USER_2, USER_2_AMOUNT),
+        (USER_3, USER_3_AMOUNT),
+        (USER_4, USER_4_AMOUNT),
+    ];
+    const MERKLE_TOTAL: Balance = USER_1_AMOUNT + USER_2_AMOUNT + USER_3_AMOUNT + USER_4_AMOUNT;
+
+    fn merkle_tree(
+        leaves: &[(Beneficiary<Test>, Balance)],
+    ) -> (MerkleCommitment, Vec<MerkleProof>) {
+        let encoded = leaves
+            .iter()
+            .map(|(beneficiary, amount)| Claim::merkle_leaf(beneficiary, *amount))
+            .collect::<Vec<_>>();
+        let commitment = MerkleCommitment {
+            root: binary_merkle_tree::merkle_root::<Keccak256, _>(encoded.iter()),
+            number_of_leaves: encoded.len() as u32,
+        };
+        let proofs = (0..encoded.len())
+            .map(|i| MerkleProof {
+                leaf_index: i as u32,
+                path: BoundedVec::truncate_from(
+                    binary_merkle_tree::merkle_proof::<Keccak256, _, _>(encoded.iter(), i).proof,
+                ),
+            })
+            .collect();
+        (commitment, proofs)
+    }
+
+    fn begin_merkle_claim(total: Balance) -> Vec<MerkleProof> {
+        let (commitment, proofs) = merkle_tree(&MERKLE_BENEFICIARIES);
+        let _ = Balances::mint_into(
+            &Claim::campaign_account_id(CAMPAIGN_ID),
+            total + EXISTENTIAL_DEPOSIT,
+        )
+        .unwrap();
+        assert_ok!(Claim::begin_merkle_claim(
+            Origin::Signed(MANAGER_USER).into(),
+            CAMPAIGN_ID,
+            commitment,
+            total,
+            INIT_CLAIM_MESSAGE.clone(),
+            Default::default()
+        ));
+        proofs
+    }
+
+    fn claim_user_1(proof: MerkleProof) -> DispatchResult {
+        let (user_signer, user_signature, _) = USER_1_SIGN.clone();
+        Claim::claim_merkle(
+            Origin::None.into(),
+            CAMPAIGN_ID,
+            user_signer,
+            USER_1_AMOUNT,
+            proof,
+            user_signature,
+        )
+    }
+
+    #[test]
+    fn begin_merkle_claim_works() {
+        test().execute_with(|| {
+            let _ = begin_merkle_claim(MERKLE_TOTAL);
+
+            assert_evt(
+                Event::ClaimStarted {
+                    campaign_id: CAMPAIGN_ID,
+                    claim_message: INIT_CLAIM_MESSAGE.clone(),
+                },
+                "Merkle claim started",
+            );
+            assert!(claim_active(CAMPAIGN_ID));
+            assert_eq!(
+                Campaigns::<Test>::get(CAMPAIGN_ID).unwrap().merkle,
+                Some(merkle_tree(&MERKLE_BENEFICIARIES).0)
+            );
+            assert_eq!(TotalClaimable::<Test>::get(CAMPAIGN_ID), MERKLE_TOTAL);
+            assert_eq!(BeneficiariesCount::<Test>::get(CAMPAIGN_ID), 0);
+        })
+    }
+
+    #[test]
+    fn begin_merkle_claim_wrong_origin() {
+        test().execute_with(|| {
+            let (commitment, _) = merkle_tree(&MERKLE_BENEFICIARIES);
+            assert_noop!(
+                Claim::begin_merkle_claim(
+                    Origin::Signed(USER_1_RAW).into(),
+                    CAMPAIGN_ID,
+                    commitment,
+                    MERKLE_TOTAL,
+                    INIT_CLAIM_MESSAGE.clone(),
+                    Default::default()
+                ),
+                BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn cannot_begin_merkle_claim_with_invalid_params() {
+        test().execute_with(|| {
+            let (commitment, _) = merkle_tree(&MERKLE_BENEFICIARIES);
+            let _ = Balances::mint_into(
+                &Claim::campaign_account_id(CAMPAIGN_ID),
+                MERKLE_TOTAL + EXISTENTIAL_DEPOSIT,
+            )
+            .unwrap();
+            let begin = |commitment, total| {
+                Claim::begin_merkle_claim(
+                    Origin::Signed(MANAGER_USER).into(),
+                    CAMPAIGN_ID,
+                    commitment,
+                    total,
+                    INIT_CLAIM_MESSAGE.clone(),
+                    Default::default(),
+                )
+            };
+
+            assert_noop!(
+                begin(
+                    MerkleCommitment {
+                        number_of_leaves: 0,
+                        ..commitment
+                    },
+                    MERKLE_TOTAL
+                ),
+                Error::<Test>::InvalidMerkleCommitment
+            );
+            assert_noop!(begin(commitment, 0), Error::<Test>::NothingToClaim);
+            assert_noop!(
+                begin(commitment, MERKLE_TOTAL + 1),
+                TokenError::FundsUnavailable
+            );
+        })
+    }
+
+    #[test]
+    fn claim_merkle_works() {
+        test().execute_with(|| {
+            let proofs = begin_merkle_claim(MERKLE_TOTAL);
+
+            assert_ok!(claim_user_1(proofs[0].clone()));
+
+            assert_evt(
+                Event::Claimed {
+                    campaign_id: CAMPAIGN_ID,
+                    beneficiary: USER_1,
+                    amount: USER_1_AMOUNT,
+                },
+                "Merkle claim",
+            );
+            assert_eq!(Balances::free_balance(USER_1_RAW), USER_1_AMOUNT);
+            assert!(Claim::is_claimed(CAMPAIGN_ID, 0));
+            assert!(!Claim::is_claimed(CAMPAIGN_ID, 1));
+            assert_eq!(Claim::pot(CAMPAIGN_ID), MERKLE_TOTAL - USER_1_AMOUNT);
+            assert_eq!(
+                TotalClaimable::<Test>::get(CAMPAIGN_ID),
+                MERKLE_TOTAL - USER_1_AMOUNT
+            );
+            assert_eq!(BeneficiariesCount::<Test>::get(CAMPAIGN_ID), 1);
+        })
+    }
+
+    #[test]
+    fn claim_ethereum_merkle_works() {
+        test().execute_with(|| {
+            let proofs = begin_merkle_claim(MERKLE_TOTAL);
+            let (address, eth_signature) = *USER_3_SIGN_USER_1_DEST;
+
+            assert_ok!(Claim::claim_ethereum_merkle(
+                Origin::None.into(),
+                CAMPAIGN_ID,
+                address,
+                USER_3_AMOUNT,
+                proofs[2].clone(),
+                eth_signature,
+                USER_1_RAW
+            ));
+
+            assert_evt(
+                Event::Claimed {
+                    campaign_id: CAMPAIGN_ID,
+                    beneficiary: USER_3,
+                    amount: USER_3_AMOUNT,
+                },
+                "Merkle ethereum claim",
+            );
+            assert_eq!(Balances::free_balance(USER_1_RAW), USER_3_AMOUNT);
+            assert!(Claim::is_claimed(CAMPAIGN_ID, 2));
+        })
+    }
+
+    #[test]
+    fn claim_ethereum_merkle_with_typed_data() {
+        test().execute_with(|| {
+            let proofs = begin_merkle_claim(MERKLE_TOTAL);
+
+            // The signature must be on the amount of the leaf
+            assert_noop!(
+                Claim::claim_ethereum_merkle(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    USER_3_RAW,
+                    USER_3_AMOUNT,
+                    proofs[2].clone(),
+                    user_3_typed_signature(CAMPAIGN_ID, USER_1_RAW, USER_3_AMOUNT + 1),
+                    USER_1_RAW
+                ),
+                Error::<Test>::BadSignature
+            );
+            assert_ok!(Claim::claim_ethereum_merkle(
+                Origin::None.into(),
+                CAMPAIGN_ID,
+                USER_3_RAW,
+                USER_3_AMOUNT,
+                proofs[2].clone(),
+                EthereumSignature::from_raw(USER_3_TYPED_SIGNATURE_USER_1_DEST),
+                USER_1_RAW
+            ));
+
+            assert_eq!(Balances::free_balance(USER_1_RAW), USER_3_AMOUNT);
+            assert!(Claim::is_claimed(CAMPAIGN_ID, 2));
+        })
+    }
+
+    #[test]
+    fn cannot_claim_merkle_twice() {
+        test().execute_with(|| {
+            let proofs = begin_merkle_claim(MERKLE_TOTAL);
+
+            assert_ok!(claim_user_1(proofs[0].clone()));
+            assert_noop!(
+                claim_user_1(proofs[0].clone()),
+                Error::<Test>::AlreadyClaimed
+            );
+        })
+    }
+
+    #[test]
+    fn cannot_claim_merkle_with_invalid_proof() {
+        test().execute_with(|| {
+            let proofs = begin_merkle_claim(MERKLE_TOTAL);
+            let (user_signer, user_signature, _) = USER_1_SIGN.clone();
+
+            // Wrong amount
+            assert_noop!(
+                Claim::claim_merkle(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    user_signer,
+                    USER_1_AMOUNT + 1,
+                    proofs[0].clone(),
+                    user_signature
+                ),
+                Error::<Test>::InvalidMerkleProof
+            );
+            // Wrong leaf
+            assert_noop!(
+                claim_user_1(proofs[1].clone()),
+                Error::<Test>::InvalidMerkleProof
+            );
+            // Wrong path
+            let mut path = proofs[0].path.to_vec();
+            path[0].0[0] ^= 1;
+            let proof = MerkleProof {
+                path: BoundedVec::truncate_from(path),
+                ..proofs[0].clone()
+            };
+            assert_noop!(claim_user_1(proof), Error::<Test>::InvalidMerkleProof);
+            // Leaf out of the tree
+            let mut proof = proofs[0].clone();
+            proof.leaf_index = MERKLE_BENEFICIARIES.len() as u32;
+            assert_noop!(claim_user_1(proof), Error::<Test>::InvalidMerkleProof);
+        })
+    }
+
+    #[test]
+    fn cannot_claim_merkle_with_bad_signature() {
+        test().execute_with(|| {
+            let proofs = begin_merkle_claim(MERKLE_TOTAL);
+            let (_, user_1_signature, _) = USER_1_SIGN.clone();
+
+            assert_noop!(
+                Claim::claim_merkle(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    UintAuthorityId::from(USER_2),
+                    USER_2_AMOUNT,
+                    proofs[1].clone(),
+                    user_1_signature
+                ),
+                Error::<Test>::BadSignature
+            );
+        })
+    }
+
+    #[test]
+    fn cannot_claim_more_than_committed_total() {
+        test().execute_with(|| {
+            let proofs = begin_merkle_claim(USER_2_AMOUNT);
+
+            assert_noop!(
+                claim_user_1(proofs[0].clone()),
+                TokenError::FundsUnavailable
+            );
+        })
+    }
+
+    #[test]
+    fn merkle_and_regular_campaigns_dont_mix() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            let (commitment, proofs) = merkle_tree(&MERKLE_BENEFICIARIES);
+
+            // Merkle claim from a regular campaign
+            assert_noop!(
+                claim_user_1(proofs[0].clone()),
+                Error::<Test>::NotMerkleCampaign
+            );
+
+            let _ = Balances::mint_into(
+                &Claim::campaign_account_id(OTHER_CAMPAIGN_ID),
+                MERKLE_TOTAL + EXISTENTIAL_DEPOSIT,
+            )
+            .unwrap();
+            assert_ok!(Claim::begin_merkle_claim(
+                Origin::Signed(MANAGER_USER).into(),
+                OTHER_CAMPAIGN_ID,
+                commitment,
+                MERKLE_TOTAL,
+                INIT_CLAIM_MESSAGE.clone(),
+                Default::default()
+            ));
+
+            // Regular claim from a Merkle campaign
+            let (user_signer, user_signature, _) = USER_1_SIGN.clone();
+            assert_noop!(
+                Claim::claim(
+                    Origin::None.into(),
+                    OTHER_CAMPAIGN_ID,
+                    user_signer,
+                    user_signature
+                ),
+                Error::<Test>::NotEligible
+            );
+            assert_noop!(
+                Claim::add_beneficiaries(
+                    Origin::Signed(MANAGER_USER).into(),
+                    OTHER_CAMPAIGN_ID,
+                    NEW_BENEFICIARIES_MAP.clone()
+                ),
+                Error::<Test>::MerkleCampaign
+            );
+        })
+    }
+
+    #[test]
+    fn claimed_bitmap_words() {
+        test().execute_with(|| {
+            for leaf_index in [0, 1, 127, 128, 1000, u32::MAX] {
+                assert!(!Claim::is_claimed(CAMPAIGN_ID, leaf_index));
+                Claim::set_claimed(CAMPAIGN_ID, leaf_index);
+                assert!(Claim::is_claimed(CAMPAIGN_ID, leaf_index));
+            }
+            assert!(!Claim::is_claimed(CAMPAIGN_ID, 2));
+            assert!(!Claim::is_claimed(CAMPAIGN_ID, 129));
+            assert!(!Claim::is_claimed(OTHER_CAMPAIGN_ID, 0));
+            assert_eq!(BeneficiariesCount::<Test>::get(CAMPAIGN_ID), 4);
+        })
+    }
+
+    #[test]
+    fn end_and_remove_merkle_campaign() {
+        test().execute_with(|| {
+            let proofs = begin_merkle_claim(MERKLE_TOTAL);
+            assert_ok!(claim_user_1(proofs[0].clone()));
+
+            assert_ok!(Claim::end_claim(
+                Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID
+            ));
+            assert_eq!(
+                Balances::free_balance(UnclaimedDestinationMockAccount::get()),
+                MERKLE_TOTAL - USER_1_AMOUNT
+            );
+            assert_noop!(claim_user_1(proofs[0].clone()), Error::<Test>::AlreadyEnded);
+
+            // Claimed bitmap is still to be removed
+            assert!(!claim_active(CAMPAIGN_ID));
+            assert_ok!(Claim::remove_beneficiaries(
+                Origin::Signed(USER_1_RAW).into(),
+                CAMPAIGN_ID,
+                None
+            ));
+            assert_evt(
+                Event::NoMoreBeneficiaries {
+                    campaign_id: CAMPAIGN_ID,
+                },
+                "Claimed bitmap removed",
+            );
+            assert!(!Campaigns::<Test>::contains_key(CAMPAIGN_ID));
+            assert!(!Claim::is_claimed(CAMPAIGN_ID, 0));
+        })
+    }
+
+    #[test]
+    fn validate_unsigned_merkle() {
+        test().execute_with(|| {
+            let proofs = begin_merkle_claim(MERKLE_TOTAL);
+            let (user_signer, user_signature, _) = USER_1_SIGN.clone();
+            let call = |amount, proof| ClaimCall::claim_merkle {
+                campaign_id: CAMPAIGN_ID,
+                beneficiary: user_signer.clone(),
+                amount,
+                proof,
+                signature: user_signature.clone(),
+            };
+            let validate = |call: ClaimCall<Test>| {
+                Pallet::<Test>::validate_unsigned(TransactionSource::External, &call)
+            };
+
+            assert_eq!(
+                validate(call(USER_1_AMOUNT, proofs[0].clone()))
+                    .unwrap()
+                    .provides,
+                vec![("claim_merkle", CAMPAIGN_ID, 0u32).encode()]
+            );
+            assert_eq!(
+                validate(call(USER_1_AMOUNT, proofs[1].clone())),
+                Err(TransactionValidityError::Invalid(
+                    InvalidTransaction::BadProof
+                ))
+            );
+
+            assert_ok!(claim_user_1(proofs[0].clone()));
+            assert_eq!(
+                validate(call(USER_1_AMOUNT, proofs[0].clone())),
+                Err(TransactionValidityError::Invalid(InvalidTransaction::Stale))
+            );
+
+            Campaigns::<Test>::mutate(CAMPAIGN_ID, |c| c.as_mut().unwrap().merkle = None);
+            assert_eq!(
+                validate(call(USER_1_AMOUNT, proofs[0].clone())),
+                Err(TransactionValidityError::Invalid(InvalidTransaction::Call))
+            );
+        })
+    }
+}
+
+mod vesting {
+    use super::*;
+    use crate::Call as ClaimCall;
+    use frame_support::traits::VestingSchedule;
+    use pallet_vesting::VestingInfo;
+    use sp_runtime::{
+        traits::ValidateUnsigned,
+        transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidityError},
+    };
+
+    const VESTING: VestingConfigOf<Test> = VestingConfig {
+        start: 10,
+        duration: 100,
+    };
+    const OTHER_VESTING: VestingConfigOf<Test> = VestingConfig {
+        start: 5,
+        duration: 2,
+    };
+
+    fn begin_vested_claim(vesting: Option<VestingConfigOf<Test>>) {
+        let _ = Balances::mint_into(
+            &Claim::campaign_account_id(CAMPAIGN_ID),
+            SUFFICIENT_GENESIS_BALANCE + EXISTENTIAL_DEPOSIT,
+        )
+        .unwrap();
+        assert_ok!(Claim::begin_claim(
+            Origin::Signed(MANAGER_USER).into(),
+            CAMPAIGN_ID,
+            GENESIS_BENEFICIARIES_MAP.clone(),
+            INIT_CLAIM_MESSAGE.clone(),
+            CampaignConfig {
+                vesting,
+                ..Default::default()
+            }
+        ));
+    }
+
+    fn claim_user_1() -> DispatchResult {
+        let (user_signer, user_signature, _) = USER_1_SIGN.clone();
+        Claim::claim(
+            Origin::None.into(),
+            CAMPAIGN_ID,
+            user_signer,
+            user_signature,
+        )
+    }
+
+    fn vesting_schedules(who: AccountId) -> Vec<VestingInfo<Balance, u32>> {
+        pallet_vesting::Vesting::<Test>::get(who)
+            .map(|schedules| schedules.to_vec())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn claim_vested() {
+        test().execute_with(|| {
+            begin_vested_claim(Some(VESTING));
+
+            assert_ok!(claim_user_1());
+
+            assert_eq!(Balances::free_balance(USER_1_RAW), USER_1_AMOUNT);
+            assert_eq!(
+                vesting_schedules(USER_1_RAW),
+                vec![VestingInfo::new(USER_1_AMOUNT, USER_1_AMOUNT / 100, 10)]
+            );
+            assert_eq!(Vesting::vesting_balance(&USER_1_RAW), Some(USER_1_AMOUNT));
+
+            // Linear unlock after the cliff
+            System::set_block_number(60);
+            assert_eq!(
+                Vesting::vesting_balance(&USER_1_RAW),
+                Some(USER_1_AMOUNT / 2)
+            );
+            System::set_block_number(110);
+            assert_eq!(Vesting::vesting_balance(&USER_1_RAW), Some(0));
+        })
+    }
+
+    #[test]
+    fn claim_ethereum_vested() {
+        test().execute_with(|| {
+            begin_vested_claim(Some(VESTING));
+            let (address, eth_signature) = *USER_3_SIGN_USER_1_DEST;
+
+            assert_ok!(Claim::claim_ethereum(
+                Origin::None.into(),
+                CAMPAIGN_ID,
+                address,
+                eth_signature,
+                USER_1_RAW
+            ));
+
+            assert_eq!(
+                vesting_schedules(USER_1_RAW),
+                vec![VestingInfo::new(USER_3_AMOUNT, USER_3_AMOUNT / 100, 10)]
+            );
+        })
+    }
+
+    #[test]
+    fn claim_liquid_if_not_vested() {
+        test().execute_with(|| {
+            begin_vested_claim(None);
+
+            assert_ok!(claim_user_1());
+
+            assert_eq!(Balances::free_balance(USER_1_RAW), USER_1_AMOUNT);
+            assert_eq!(Vesting::vesting_balance(&USER_1_RAW), None);
+        })
+    }
+
+    #[test]
+    fn per_block_amount_unlocks_within_duration() {
+        test().execute_with(|| {
+            begin_vested_claim(Some(VestingConfig {
+                start: 10,
+                duration: 9,
+            }));
+
+            assert_ok!(claim_user_1());
+
+            // The claimed amount is not a multiple of the duration
+            let per_block = USER_1_AMOUNT / 9 + 1;
+            assert_eq!(
+                vesting_schedules(USER_1_RAW),
+                vec![VestingInfo::new(USER_1_AMOUNT, per_block, 10)]
+            );
+            System::set_block_number(19);
+            assert_eq!(Vesting::vesting_balance(&USER_1_RAW), Some(0));
+        })
+    }
+
+    #[test]
+    fn beneficiary_vesting_overrides_campaign_one() {
+        test().execute_with(|| {
+            begin_vested_claim(Some(VESTING));
+            assert_ok!(Claim::set_beneficiaries_vesting(
+                Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID,
+                [(USER_2, Some(OTHER_VESTING))].into_iter().collect()
+            ));
+            assert_eq!(
+                BeneficiaryVesting::<Test>::get(CAMPAIGN_ID, USER_2),
+                Some(OTHER_VESTING)
+            );
+            let Beneficiary::Substrate(user_2_raw) = USER_2 else {
+                unreachable!()
+            };
+
+            assert_ok!(Claim::claim_for(
+                Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID,
+                user_2_raw
+            ));
+
+            assert_eq!(
+                vesting_schedules(user_2_raw),
+                vec![VestingInfo::new(USER_2_AMOUNT, USER_2_AMOUNT / 2, 5)]
+            );
+            assert!(!BeneficiaryVesting::<Test>::contains_key(
+                CAMPAIGN_ID,
+                USER_2
+            ));
+        })
+    }
+
+    #[test]
+    fn beneficiary_vesting_on_liquid_campaign() {
+        test().execute_with(|| {
+            begin_vested_claim(None);
+            assert_ok!(Claim::set_beneficiaries_vesting(
+                Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID,
+                [(USER_1, Some(VESTING)), (USER_3, Some(VESTING))]
+                    .into_iter()
+                    .collect()
+            ));
+            // Clear the override: USER_3 claims liquid
+            assert_ok!(Claim::set_beneficiaries_vesting(
+                Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID,
+                [(USER_3, None)].into_iter().collect()
+            ));
+
+            assert_ok!(claim_user_1());
+            assert_ok!(Claim::claim_ethereum_for(
+                Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID,
+                USER_3_RAW,
+                NON_BENEFICIARY_RAW
+            ));
+
+            assert_eq!(Vesting::vesting_balance(&USER_1_RAW), Some(USER_1_AMOUNT));
+            assert_eq!(Balances::free_balance(NON_BENEFICIARY_RAW), USER_3_AMOUNT);
+            assert_eq!(Vesting::vesting_balance(&NON_BENEFICIARY_RAW), None);
+        })
+    }
+
+    #[test]
+    fn cannot_set_invalid_vesting() {
+        test().execute_with(|| {
+            let invalid = VestingConfig {
+                start: 10,
+                duration: 0,
+            };
+            let _ = Balances::mint_into(
+                &Claim::campaign_account_id(CAMPAIGN_ID),
+                SUFFICIENT_GENESIS_BALANCE + EXISTENTIAL_DEPOSIT,
+            )
+            .unwrap();
+            assert_noop!(
+                Claim::begin_claim(
+                    Origin::Signed(MANAGER_USER).into(),
+                    CAMPAIGN_ID,
+                    GENESIS_BENEFICIARIES_MAP.clone(),
+                    INIT_CLAIM_MESSAGE.clone(),
+                    CampaignConfig {
+                        vesting: Some(invalid),
+                        ..Default::default()
+                    }
+                ),
+                Error::<Test>::InvalidVestingSchedule
+            );
+
+            begin_vested_claim(None);
+            assert_noop!(
+                Claim::set_beneficiaries_vesting(
+                    Origin::Signed(MANAGER_USER).into(),
+                    CAMPAIGN_ID,
+                    [(USER_1, Some(invalid))].into_iter().collect()
+                ),
+                Error::<Test>::InvalidVestingSchedule
+            );
+        })
+    }
+
+    #[test]
+    fn cannot_set_beneficiaries_vesting() {
+        test().execute_with(|| {
+            begin_vested_claim(None);
+            let set_vesting = |origin: AccountId, beneficiary| {
+                Claim::set_beneficiaries_vesting(
+                    Origin::Signed(origin).into(),
+                    CAMPAIGN_ID,
+                    [(beneficiary, Some(VESTING))].into_iter().collect(),
+                )
+            };
+
+            assert_noop!(set_vesting(USER_1_RAW, USER_1), BadOrigin);
+            assert_noop!(
+                set_vesting(MANAGER_USER, NON_BENEFICIARY),
+                Error::<Test>::NotEligible
+            );
+            assert_noop!(
+                Claim::set_beneficiaries_vesting(
+                    Origin::Signed(MANAGER_USER).into(),
+                    CAMPAIGN_ID,
+                    utils::get_beneficiaries_map::<Test>(MaxOpBeneficiaries::get() + 1)
+                        .0
+                        .into_keys()
+                        .map(|beneficiary| (beneficiary, None))
+                        .collect()
+                ),
+                Error::<Test>::TooManyBeneficiaries
+            );
+
+            assert_ok!(Claim::end_claim(
+                Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID
+            ));
+            assert_noop!(
+                set_vesting(MANAGER_USER, USER_1),
+                Error::<Test>::AlreadyEnded
+            );
+        })
+    }
+
+    #[test]
+    fn cannot_set_beneficiaries_vesting_on_merkle_campaign() {
+        test().execute_with(|| {
+            let _ = Balances::mint_into(
+                &Claim::campaign_account_id(CAMPAIGN_ID),
+                USER_1_AMOUNT + EXISTENTIAL_DEPOSIT,
+            )
+            .unwrap();
+            assert_ok!(Claim::begin_merkle_claim(
+                Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID,
+                MerkleCommitment {
+                    root: Default::default(),
+                    number_of_leaves: 1,
+                },
+                USER_1_AMOUNT,
+                INIT_CLAIM_MESSAGE.clone(),
+                Default::default()
+            ));
+
+            assert_noop!(
+                Claim::set_beneficiaries_vesting(
+                    Origin::Signed(MANAGER_USER).into(),
+                    CAMPAIGN_ID,
+                    [(USER_1, Some(VESTING))].into_iter().collect()
+                ),
+                Error::<Test>::MerkleCampaign
+            );
+        })
+    }
+
+    #[test]
+    fn cannot_claim_vested_if_destination_cannot_vest() {
+        test().execute_with(|| {
+            begin_vested_claim(Some(VESTING));
+            let _ = Balances::mint_into(&USER_1_RAW, 1_000).unwrap();
+            for _ in 0..MAX_VESTING_SCHEDULES {
+                assert_ok!(Vesting::add_vesting_schedule(&USER_1_RAW, 100, 1, 10));
+            }
+
+            let (user_signer, user_signature, _) = USER_1_SIGN.clone();
+            assert_eq!(
+                Pallet::<Test>::validate_unsigned(
+                    TransactionSource::External,
+                    &ClaimCall::claim {
+                        campaign_id: CAMPAIGN_ID,
+                        beneficiary: user_signer,
+                        signature: user_signature,
+                    }
+                ),
+                Err(TransactionValidityError::Invalid(InvalidTransaction::Call))
+            );
+            assert_err!(
+                claim_user_1(),
+                pallet_vesting::Error::<Test>::AtMaxVestingSchedules
+            );
+        })
+    }
+
+    #[test]
+    fn remove_beneficiaries_clears_vesting_overrides() {
+        let mut e = test();
+
+        e.execute_with(|| {
+            insert_ended_campaign(CAMPAIGN_ID);
+            utils::get_beneficiaries_map::<Test>(MaxOpBeneficiaries::get())
+                .0
+                .into_iter()
+                .enumerate()
+                .for_each(|(i, (beneficiary, amount))| {
+                    if i % 3 == 0 {
+                        BeneficiaryVesting::<Test>::insert(CAMPAIGN_ID, &beneficiary, VESTING);
+                    }
+                    insert_beneficiary(CAMPAIGN_ID, beneficiary, amount)
+                });
+        });
+        e.commit_all().unwrap();
+
+        // Limits on removal apply only to committed storage
+        while e.execute_with(|| Campaigns::<Test>::contains_key(CAMPAIGN_ID)) {
+            e.execute_with(|| {
+                assert_ok!(Claim::remove_beneficiaries(
+                    Origin::Signed(USER_1_RAW).into(),
+                    CAMPAIGN_ID,
+                    Some(7)
+                ));
+            });
+            e.commit_all().unwrap();
+        }
+
+        e.execute_with(|| {
+            assert_eq!(
+                BeneficiaryVesting::<Test>::iter_prefix(CAMPAIGN_ID).count(),
+                0
+            );
+        });
+    }
+}
+
+mod zk {
+    use super::*;
+    use crate::Call as ClaimCall;
+    use frame_support::{
+        dispatch::{DispatchResultWithPostInfo, GetDispatchInfo, Pays},
+        traits::VestingSchedule,
+        weights::Weight,
+    };
+    use sp_core::H256;
+    use sp_runtime::{
+        traits::ValidateUnsigned,
+        transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidityError},
+    };
+
+    const ZK_TOTAL: Balance = USER_1_AMOUNT + USER_2_AMOUNT;
+    const ZK: ZkCommitment<u8> = ZkCommitment {
+        root: H256::repeat_byte(1),
+        verifier: MOCK_ZK_VERIFIER,
+        vk_hash: MOCK_ZK_VK_HASH,
+    };
+    const NULLIFIER: H256 = H256::repeat_byte(3);
+    const OTHER_NULLIFIER: H256 = H256::repeat_byte(4);
+    const OTHER_DEST: AccountId = 24_000;
+    const SUBMITTER: AccountId = 25_000;
+
+    fn begin_zk_claim(total: Balance, vesting: Option<VestingConfigOf<Test>>) {
+        let _ = Balances::mint_into(
+            &Claim::campaign_account_id(CAMPAIGN_ID),
+            total + EXISTENTIAL_DEPOSIT,
+        )
+        .unwrap();
+        assert_ok!(Claim::begin_zk_claim(
+            Origin::Signed(MANAGER_USER).into(),
+            CAMPAIGN_ID,
+            ZK,
+            total,
+            INIT_CLAIM_MESSAGE.clone(),
+            CampaignConfig {
+                vesting,
+                ..Default::default()
+            }
+        ));
+    }
+
+    fn proof(nullifier: H256, dest: AccountId, amount: Balance) -> H256 {
+        mock_claim_proof(
+            &ZK.vk_hash,
+            &Claim::zk_claim_pubs(&ZK.root, &nullifier, &dest, amount),
+        )
+    }
+
+    fn claim_zk(
+        nullifier: H256,
+        dest: AccountId,
+        amount: Balance,
+        proof: H256,
+    ) -> DispatchResultWithPostInfo {
+        Claim::claim_zk(
+            Origin::Signed(SUBMITTER).into(),
+            CAMPAIGN_ID,
+            nullifier,
+            amount,
+            dest,
+            Box::new(proof),
+        )
+    }
+
+    fn claim_user_1(nullifier: H256) -> DispatchResultWithPostInfo {
+        claim_zk(
+            nullifier,
+            USER_1_RAW,
+            USER_1_AMOUNT,
+            proof(nullifier, USER_1_RAW, USER_1_AMOUNT),
+        )
+    }
+
+    #[test]
+    fn zk_claim_pubs_layout() {
+        let mut dest_word = [0; 32];
+        dest_word[24..].copy_from_slice(&USER_1_RAW.to_le_bytes());
+        let mut amount_word = [0; 32];
+        amount_word[16..].copy_from_slice(&USER_1_AMOUNT.to_be_bytes());
+
+        assert_eq!(
+            Claim::zk_claim_pubs(&ZK.root, &NULLIFIER, &USER_1_RAW, USER_1_AMOUNT),
+            vec![ZK.root.0, NULLIFIER.0, dest_word, amount_word]
+        );
+    }
+
+    #[test]
+    fn begin_zk_claim_works() {
+        test().execute_with(|| {
+            begin_zk_claim(ZK_TOTAL, None);
+
+            assert_evt(
+                Event::ClaimStarted {
+                    campaign_id: CAMPAIGN_ID,
+                    claim_message: INIT_CLAIM_MESSAGE.clone(),
+                },
+                "ZK claim started",
+            );
+            assert!(claim_active(CAMPAIGN_ID));
+            let campaign = Campaigns::<Test>::get(CAMPAIGN_ID).unwrap();
+            assert_eq!(campaign.zk, Some(ZK));
+            assert_eq!(campaign.merkle, None);
+            assert_eq!(TotalClaimable::<Test>::get(CAMPAIGN_ID), ZK_TOTAL);
+            assert_eq!(BeneficiariesCount::<Test>::get(CAMPAIGN_ID), 0);
+            assert_eq!(MockVkRefs::get(), 1);
+        })
+    }
+
+    #[test]
+    fn begin_zk_claim_wrong_origin() {
+        test().execute_with(|| {
+            assert_noop!(
+                Claim::begin_zk_claim(
+                    Origin::Signed(USER_1_RAW).into(),
+                    CAMPAIGN_ID,
+                    ZK,
+                    ZK_TOTAL,
+                    INIT_CLAIM_MESSAGE.clone(),
+                    Default::default()
+                ),
+                BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn cannot_begin_zk_claim_with_invalid_params() {
+        test().execute_with(|| {
+            let _ = Balances::mint_into(
+                &Claim::campaign_account_id(CAMPAIGN_ID),
+                ZK_TOTAL + EXISTENTIAL_DEPOSIT,
+            )
+            .unwrap();
+            let begin = |total| {
+                Claim::begin_zk_claim(
+                    Origin::Signed(MANAGER_USER).into(),
+                    CAMPAIGN_ID,
+                    ZK,
+                    total,
+                    INIT_CLAIM_MESSAGE.clone(),
+                    Default::default(),
+                )
+            };
+
+            assert_noop!(begin(0), Error::<Test>::NothingToClaim);
+            assert_noop!(begin(ZK_TOTAL + 1), TokenError::FundsUnavailable);
+            assert_eq!(MockVkRefs::get(), 0);
+        })
+    }
+
+    #[test]
+    fn cannot_begin_zk_claim_with_unregistered_vk() {
+        test().execute_with(|| {
+            let _ = Balances::mint_into(
+                &Claim::campaign_account_id(CAMPAIGN_ID),
+                ZK_TOTAL + EXISTENTIAL_DEPOSIT,
+            )
+            .unwrap();
+
+            assert_noop!(
+                Claim::begin_zk_claim(
+                    Origin::Signed(MANAGER_USER).into(),
+                    CAMPAIGN_ID,
+                    ZkCommitment {
+                        vk_hash: H256::repeat_byte(42),
+                        ..ZK
+                    },
+                    ZK_TOTAL,
+                    INIT_CLAIM_MESSAGE.clone(),
+                    Default::default(),
+                ),
+                Error::<Test>::VkNotRegistered
+            );
+            assert_eq!(MockVkRefs::get(), 0);
+        })
+    }
+
+    #[test]
+    fn claim_zk_works() {
+        test().execute_with(|| {
+            begin_zk_claim(ZK_TOTAL, None);
+
+            assert_eq!(claim_user_1(NULLIFIER).unwrap().pays_fee, Pays::No);
+
+            assert_evt(
+                Event::ZkClaimed {
+                    campaign_id: CAMPAIGN_ID,
+                    nullifier: NULLIFIER,
+                    dest: USER_1_RAW,
+                    amount: USER_1_AMOUNT,
+                },
+                "ZK claim",
+            );
+            assert_eq!(Balances::free_balance(USER_1_RAW), USER_1_AMOUNT);
+            assert_eq!(Vesting::vesting_balance(&USER_1_RAW), None);
+            assert!(Nullifiers::<Test>::contains_key(CAMPAIGN_ID, NULLIFIER));
+            assert!(!Nullifiers::<Test>::contains_key(
+                CAMPAIGN_ID,
+                OTHER_NULLIFIER
+            ));
+            assert_eq!(Claim::pot(CAMPAIGN_ID), ZK_TOTAL - USER_1_AMOUNT);
+            assert_eq!(
+                TotalClaimable::<Test>::get(CAMPAIGN_ID),
+                ZK_TOTAL - USER_1_AMOUNT
+            );
+            assert_eq!(BeneficiariesCount::<Test>::get(CAMPAIGN_ID), 1);
+
+            // Another claim with a different nullifier
+            assert_ok!(claim_zk(
+                OTHER_NULLIFIER,
+                OTHER_DEST,
+                USER_2_AMOUNT,
+                proof(OTHER_NULLIFIER, OTHER_DEST, USER_2_AMOUNT)
+            ));
+            assert_eq!(Balances::free_balance(OTHER_DEST), USER_2_AMOUNT);
+            assert_eq!(TotalClaimable::<Test>::get(CAMPAIGN_ID), 0);
+            assert_eq!(BeneficiariesCount::<Test>::get(CAMPAIGN_ID), 2);
+        })
+    }
+
+    #[test]
+    fn claim_zk_vested() {
+        test().execute_with(|| {
+            begin_zk_claim(
+                ZK_TOTAL,
+                Some(VestingConfig {
+                    start: 10,
+                    duration: 100,
+                }),
+            );
+
+            assert_ok!(claim_user_1(NULLIFIER));
+
+            assert_eq!(Balances::free_balance(USER_1_RAW), USER_1_AMOUNT);
+            assert_eq!(Vesting::vesting_balance(&USER_1_RAW), Some(USER_1_AMOUNT));
+        })
     }
 
     #[test]
-    fn cannot_remove_beneficiaries_if_claim_in_progress() {
+    fn cannot_claim_zk_unsigned() {
         test().execute_with(|| {
-            Claim::begin_claim(
-                Origin::Signed(MANAGER_USER).into(),
-                EMPTY_BENEFICIARIES_MAP.clone(),
-                INIT_CLAIM_MESSAGE.clone(),
-            )
-            .unwrap();
+            begin_zk_claim(ZK_TOTAL, None);
+
             assert_noop!(
-                Claim::remove_beneficiaries(Origin::Signed(USER_1_RAW).into(), None),
-                Error::<Test>::AlreadyStarted
+                Claim::claim_zk(
+                    Origin::None.into(),
+                    CAMPAIGN_ID,
+                    NULLIFIER,
+                    USER_1_AMOUNT,
+                    USER_1_RAW,
+                    Box::new(proof(NULLIFIER, USER_1_RAW, USER_1_AMOUNT)),
+                ),
+                BadOrigin
             );
         })
     }
-}
 
-mod end_claim {
-    use super::*;
     #[test]
-    fn end_claim() {
-        test_with_configs(
-            WithGenesisBeneficiaries::Yes,
-            GenesisClaimBalance::Sufficient,
-        )
-        .execute_with(|| {
-            // Give other balance. Now Self::pot() == SUFFICIENT_GENESIS_BALANCE * 2
-            let _ = Balances::mint_into(&Claim::account_id(), SUFFICIENT_GENESIS_BALANCE).unwrap();
-            assert_ok!(Claim::end_claim(Origin::Signed(MANAGER_USER).into()));
-            assert_evt(
-                Event::ClaimEnded {
-                    claim_id: 0,
-                    claim_message: INIT_CLAIM_MESSAGE.clone(),
-                },
-                "Claim finished",
-            );
+    fn cannot_claim_zk_twice() {
+        test().execute_with(|| {
+            begin_zk_claim(ZK_TOTAL, None);
 
-            assert!(!ClaimActive::<Test>::get());
-            assert_eq!(
-                Beneficiaries::<Test>::count() as usize,
-                GENESIS_BENEFICIARIES.len()
-            ); // Sanity check: beneficiaries not removed
-            assert_eq!(Claim::pot(), 0);
-            assert_eq!(
-                Balances::free_balance(Claim::account_id()),
-                EXISTENTIAL_DEPOSIT
-            );
-            assert_eq!(TotalClaimable::<Test>::get(), 0);
-            assert_eq!(
-                Balances::reducible_balance(
-                    &UnclaimedDestinationMockAccount::get(),
-                    Preservation::Expendable,
-                    Fortitude::Polite,
+            assert_ok!(claim_user_1(NULLIFIER));
+            assert_noop!(claim_user_1(NULLIFIER), Error::<Test>::NullifierAlreadyUsed);
+            // Not even to a different destination
+            assert_noop!(
+                claim_zk(
+                    NULLIFIER,
+                    OTHER_DEST,
+                    USER_2_AMOUNT,
+                    proof(NULLIFIER, OTHER_DEST, USER_2_AMOUNT)
                 ),
-                SUFFICIENT_GENESIS_BALANCE * 2
+                Error::<Test>::NullifierAlreadyUsed
             );
-        });
+        })
     }
 
     #[test]
-    fn end_claim_wrong_origin() {
-        test_with_configs(
-            WithGenesisBeneficiaries::Yes,
-            GenesisClaimBalance::Sufficient,
-        )
-        .execute_with(|| {
-            assert_err!(
-                Claim::end_claim(Origin::Signed(USER_1_RAW).into()),
-                BadOrigin
+    fn cannot_claim_zk_with_invalid_proof() {
+        test().execute_with(|| {
+            begin_zk_claim(ZK_TOTAL, None);
+            let proof = proof(NULLIFIER, USER_1_RAW, USER_1_AMOUNT);
+
+            // Wrong amount
+            assert_noop!(
+                claim_zk(NULLIFIER, USER_1_RAW, USER_1_AMOUNT + 1, proof),
+                Error::<Test>::InvalidZkProof
             );
-            assert_not_evt(
-                Event::ClaimEnded {
-                    claim_id: 0,
-                    claim_message: INIT_CLAIM_MESSAGE.clone(),
-                },
-                "No end claim",
+            // Wrong destination
+            assert_noop!(
+                claim_zk(NULLIFIER, OTHER_DEST, USER_1_AMOUNT, proof),
+                Error::<Test>::InvalidZkProof
+            );
+            // Wrong nullifier
+            assert_noop!(
+                claim_zk(OTHER_NULLIFIER, USER_1_RAW, USER_1_AMOUNT, proof),
+                Error::<Test>::InvalidZkProof
+            );
+            // Wrong proof
+            assert_noop!(
+                claim_zk(NULLIFIER, USER_1_RAW, USER_1_AMOUNT, H256::zero()),
+                Error::<Test>::InvalidZkProof
+            );
+            // Wrong verifier
+            Campaigns::<Test>::mutate(CAMPAIGN_ID, |c| {
+                c.as_mut().unwrap().zk = Some(ZkCommitment {
+                    verifier: MOCK_ZK_VERIFIER + 1,
+                    ..ZK
+                })
+            });
+            assert_noop!(
+                claim_zk(NULLIFIER, USER_1_RAW, USER_1_AMOUNT, proof),
+                Error::<Test>::InvalidZkProof
             );
         })
     }
 
     #[test]
-    fn double_end_claim() {
-        test_with_configs(
-            WithGenesisBeneficiaries::Yes,
-            GenesisClaimBalance::Sufficient,
-        )
-        .execute_with(|| {
-            assert_ok!(Claim::end_claim(Origin::Signed(MANAGER_USER).into()));
-            assert_err!(
-                Claim::end_claim(Origin::Signed(MANAGER_USER).into()),
-                Error::<Test>::AlreadyEnded
-            );
-        });
+    fn cannot_claim_more_than_committed_total() {
+        test().execute_with(|| {
+            begin_zk_claim(USER_2_AMOUNT, None);
+
+            assert_noop!(claim_user_1(NULLIFIER), TokenError::FundsUnavailable);
+        })
     }
 
     #[test]
-    fn end_claim_new_claim() {
+    fn cannot_claim_zk_before_start() {
+        test().execute_with(|| {
+            let _ = Balances::mint_into(
+                &Claim::campaign_account_id(CAMPAIGN_ID),
+                ZK_TOTAL + EXISTENTIAL_DEPOSIT,
+            )
+            .unwrap();
+            assert_ok!(Claim::begin_zk_claim(
+                Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID,
+                ZK,
+                ZK_TOTAL,
+                INIT_CLAIM_MESSAGE.clone(),
+                CampaignConfig {
+                    start: Some(10),
+                    ..Default::default()
+                }
+            ));
+
+            assert_noop!(claim_user_1(NULLIFIER), Error::<Test>::NotStarted);
+        })
+    }
+
+    #[test]
+    fn zk_and_other_campaigns_dont_mix() {
         test_with_configs(
             WithGenesisBeneficiaries::Yes,
             GenesisClaimBalance::Sufficient,
         )
         .execute_with(|| {
-            assert_ok!(Claim::end_claim(Origin::Signed(MANAGER_USER).into()));
-            assert_ok!(Claim::remove_beneficiaries(
-                Origin::Signed(USER_1_RAW).into(),
-                None
-            ));
+            // ZK claim from a regular campaign
+            assert_noop!(claim_user_1(NULLIFIER), Error::<Test>::NotZkCampaign);
 
-            assert_ok!(Claim::begin_claim(
+            let _ = Balances::mint_into(
+                &Claim::campaign_account_id(OTHER_CAMPAIGN_ID),
+                ZK_TOTAL + EXISTENTIAL_DEPOSIT,
+            )
+            .unwrap();
+            assert_ok!(Claim::begin_zk_claim(
                 Origin::Signed(MANAGER_USER).into(),
-                EMPTY_BENEFICIARIES_MAP.clone(),
-                INIT_CLAIM_MESSAGE.clone()
+                OTHER_CAMPAIGN_ID,
+                ZK,
+                ZK_TOTAL,
+                INIT_CLAIM_MESSAGE.clone(),
+                Default::default()
             ));
-            assert_evt(
-                Event::ClaimStarted {
-                    claim_id: 1,
-                    claim_message: INIT_CLAIM_MESSAGE.clone(),
-                },
-                "New claim",
+
+            // Regular claim from a ZK campaign
+            let (user_signer, user_signature, _) = USER_1_SIGN.clone();
+            assert_noop!(
+                Claim::claim(
+                    Origin::None.into(),
+                    OTHER_CAMPAIGN_ID,
+                    user_signer,
+                    user_signature
+                ),
+                Error::<Test>::NotEligible
             );
-            assert!(Beneficiaries::<Test>::iter().next().is_none());
-            assert_eq!(TotalClaimable::<Test>::get(), BalanceOf::<Test>::zero());
-            assert!(ClaimActive::<Test>::get());
-            assert_eq!(
-                ClaimId::<Test>::get(),
-                Some((1, INIT_CLAIM_MESSAGE.clone()))
+            assert_noop!(
+                Claim::add_beneficiaries(
+                    Origin::Signed(MANAGER_USER).into(),
+                    OTHER_CAMPAIGN_ID,
+                    NEW_BENEFICIARIES_MAP.clone()
+                ),
+                Error::<Test>::ZkCampaign
             );
-            assert_eq!(
-                Balances::free_balance(Claim::account_id()),
-                EXISTENTIAL_DEPOSIT
+            assert_noop!(
+                Claim::set_beneficiaries_vesting(
+                    Origin::Signed(MANAGER_USER).into(),
+                    OTHER_CAMPAIGN_ID,
+                    BTreeMap::from([(USER_1, None)])
+                ),
+                Error::<Test>::ZkCampaign
             );
-            assert_eq!(Claim::pot(), 0);
-        });
+        })
     }
 
     #[test]
-    fn cannot_init_new_claim_if_leftovers_beneficiaries() {
+    fn end_and_remove_zk_campaign() {
         test().execute_with(|| {
-            Beneficiaries::<Test>::insert(USER_6, USER_6_AMOUNT);
+            begin_zk_claim(ZK_TOTAL, None);
+            assert_ok!(claim_user_1(NULLIFIER));
 
-            assert_noop!(
-                Claim::begin_claim(
-                    Origin::Signed(MANAGER_USER).into(),
-                    EMPTY_BENEFICIARIES_MAP.clone(),
-                    INIT_CLAIM_MESSAGE.clone()
-                ),
-                Error::<Test>::NonEmptyBeneficiaries
+            assert_ok!(Claim::end_claim(
+                Origin::Signed(MANAGER_USER).into(),
+                CAMPAIGN_ID
+            ));
+            assert_eq!(
+                Balances::free_balance(UnclaimedDestinationMockAccount::get()),
+                ZK_TOTAL - USER_1_AMOUNT
             );
+            // The campaign vk is released
+            assert_eq!(MockVkRefs::get(), 0);
+            assert_noop!(claim_user_1(OTHER_NULLIFIER), Error::<Test>::AlreadyEnded);
 
-            // Remove beneficiary and try again
-            Beneficiaries::<Test>::remove(USER_6);
-
-            assert_ok!(Claim::begin_claim(
-                Origin::Signed(MANAGER_USER).into(),
-                EMPTY_BENEFICIARIES_MAP.clone(),
-                INIT_CLAIM_MESSAGE.clone()
+            // Used nullifiers are still to be removed
+            assert!(!claim_active(CAMPAIGN_ID));
+            assert_ok!(Claim::remove_beneficiaries(
+                Origin::Signed(USER_1_RAW).into(),
+                CAMPAIGN_ID,
+                None
             ));
             assert_evt(
-                Event::ClaimStarted {
-                    claim_id: 0,
-                    claim_message: INIT_CLAIM_MESSAGE.clone(),
+                Event::NoMoreBeneficiaries {
+                    campaign_id: CAMPAIGN_ID,
                 },
-                "New claim",
+                "Nullifiers removed",
+            );
+            assert!(!Campaigns::<Test>::contains_key(CAMPAIGN_ID));
+            assert!(!Nullifiers::<Test>::contains_key(CAMPAIGN_ID, NULLIFIER));
+        })
+    }
+
+    #[test]
+    fn claim_zk_weight_includes_proof_verification() {
+        let call = ClaimCall::<Test>::claim_zk {
+            campaign_id: CAMPAIGN_ID,
+            nullifier: NULLIFIER,
+            amount: USER_1_AMOUNT,
+            dest: USER_1_RAW,
+            proof: Box::new(H256::zero()),
+        };
+        // Verified once, for 4 public inputs
+        assert_eq!(
+            call.get_dispatch_info().weight,
+            <() as WeightInfo>::claim_zk()
+                .saturating_add(Weight::from_parts(4 * MOCK_VERIFY_WEIGHT_PER_PUB, 0))
+        );
+        assert_eq!(call.get_dispatch_info().pays_fee, Pays::Yes);
+    }
+
+    #[test]
+    fn claim_zk_is_not_validated_unsigned() {
+        test().execute_with(|| {
+            begin_zk_claim(ZK_TOTAL, None);
+            let call = ClaimCall::<Test>::claim_zk {
+                campaign_id: CAMPAIGN_ID,
+                nullifier: NULLIFIER,
+                amount: USER_1_AMOUNT,
+                dest: USER_1_RAW,
+                proof: Box::new(proof(NULLIFIER, USER_1_RAW, USER_1_AMOUNT)),
+            };
+
+            assert_eq!(
+                Pallet::<Test>::validate_unsigned(TransactionSource::External, &call),
+                Err(TransactionValidityError::Invalid(InvalidTransaction::Call))
             );
         })
     }
@@ -1155,7 +3560,6 @@ mod validate_unsigned {
         .execute_with(|| {
             let (user_signer, user_signature, user_signature_prefixed) = USER_1_SIGN.clone();
             let user_address = user_signer.clone().into_account();
-            let claim_id = ClaimId::<Test>::get().unwrap();
             let source = sp_runtime::transaction_validity::TransactionSource::External;
 
             // Claim bad signature
@@ -1166,6 +3570,7 @@ mod validate_unsigned {
                 Pallet::<Test>::validate_unsigned(
                     source,
                     &ClaimCall::claim {
+                        campaign_id: CAMPAIGN_ID,
                         beneficiary: user_signer.clone(),
                         signature: bad_signature
                     }
@@ -1182,6 +3587,7 @@ mod validate_unsigned {
                 Pallet::<Test>::validate_unsigned(
                     source,
                     &ClaimCall::claim {
+                        campaign_id: CAMPAIGN_ID,
                         beneficiary: user_2_signer,
                         signature: user_signature.clone()
                     }
@@ -1198,6 +3604,7 @@ mod validate_unsigned {
                 Pallet::<Test>::validate_unsigned(
                     source,
                     &ClaimCall::claim {
+                        campaign_id: CAMPAIGN_ID,
                         beneficiary: nb_signer,
                         signature: user_signature.clone()
                     }
@@ -1212,6 +3619,7 @@ mod validate_unsigned {
                 Pallet::<Test>::validate_unsigned(
                     source,
                     &ClaimCall::claim {
+                        campaign_id: CAMPAIGN_ID,
                         beneficiary: user_signer.clone(),
                         signature: user_signature.clone()
                     }
@@ -1221,7 +3629,7 @@ mod validate_unsigned {
                     requires: vec![],
                     provides: vec![(
                         "claim",
-                        claim_id.clone(),
+                        CAMPAIGN_ID,
                         Beneficiary::<Test>::Substrate(user_address),
                     )
                         .encode()],
@@ -1235,6 +3643,7 @@ mod validate_unsigned {
                 Pallet::<Test>::validate_unsigned(
                     source,
                     &ClaimCall::claim {
+                        campaign_id: CAMPAIGN_ID,
                         beneficiary: user_signer.clone(),
                         signature: user_signature_prefixed.clone()
                     }
@@ -1244,7 +3653,7 @@ mod validate_unsigned {
                     requires: vec![],
                     provides: vec![(
                         "claim",
-                        claim_id.clone(),
+                        CAMPAIGN_ID,
                         Beneficiary::<Test>::Substrate(user_address),
                     )
                         .encode()],
@@ -1254,11 +3663,12 @@ mod validate_unsigned {
             );
 
             // Claim while inactive
-            ClaimActive::<Test>::put(false);
+            Campaigns::<Test>::mutate(CAMPAIGN_ID, |c| c.as_mut().unwrap().active = false);
             assert_eq!(
                 Pallet::<Test>::validate_unsigned(
                     source,
                     &ClaimCall::claim {
+                        campaign_id: CAMPAIGN_ID,
                         beneficiary: user_signer,
                         signature: user_signature
                     }
@@ -1278,7 +3688,6 @@ mod validate_unsigned {
             // Signature on "INIT_CLAIM_MESSAGE||@||USER_1_RAW"
             let user_signer = USER_3_RAW;
             let user_signature = EthereumSignature::from_raw(hex_literal::hex!("40d1584a3e935358410d5e7bdca3082806c2af6a8a71baf44ece9a3f117499c03cf03116c0ed28b66704660870d41af1719e8e9d2271c7276bde0bd1b117e0c81b"));
-            let claim_id = ClaimId::<Test>::get().unwrap();
             let source = sp_runtime::transaction_validity::TransactionSource::External;
             let dest = USER_1_RAW;
 
@@ -1290,6 +3699,7 @@ mod validate_unsigned {
                 Pallet::<Test>::validate_unsigned(
                     source,
                     &ClaimCall::claim_ethereum {
+                        campaign_id: CAMPAIGN_ID,
                         beneficiary: user_signer,
                         signature: bad_signature,
                         dest
@@ -1301,12 +3711,13 @@ mod validate_unsigned {
             );
 
             // Claim bad user
-            let _ = Balances::mint_into(&Claim::account_id(), USER_6_AMOUNT).unwrap();
-            Beneficiaries::<Test>::insert(USER_6, USER_6_AMOUNT);
+            let _ = Balances::mint_into(&Claim::campaign_account_id(CAMPAIGN_ID), USER_6_AMOUNT).unwrap();
+            insert_beneficiary(CAMPAIGN_ID, USER_6, USER_6_AMOUNT);
             assert_eq!(
                 Pallet::<Test>::validate_unsigned(
                     source,
                     &ClaimCall::claim_ethereum {
+                        campaign_id: CAMPAIGN_ID,
                         beneficiary: USER_6_RAW,
                         signature: user_signature,
                         dest
@@ -1324,6 +3735,7 @@ mod validate_unsigned {
                 Pallet::<Test>::validate_unsigned(
                     source,
                     &ClaimCall::claim_ethereum {
+                        campaign_id: CAMPAIGN_ID,
                         beneficiary: nb_signer,
                         signature: user_signature,
                         dest
@@ -1339,6 +3751,7 @@ mod validate_unsigned {
                 Pallet::<Test>::validate_unsigned(
                     source,
                     &ClaimCall::claim_ethereum {
+                        campaign_id: CAMPAIGN_ID,
                         beneficiary: USER_3_RAW,
                         signature: user_signature,
                         dest
@@ -1349,7 +3762,7 @@ mod validate_unsigned {
                     requires: vec![],
                     provides: vec![(
                         "claim_ethereum",
-                        claim_id.clone(),
+                        CAMPAIGN_ID,
                         USER_3,
                     )
                         .encode()],
@@ -1359,11 +3772,12 @@ mod validate_unsigned {
             );
 
             // Claim while inactive
-            ClaimActive::<Test>::put(false);
+            Campaigns::<Test>::mutate(CAMPAIGN_ID, |c| c.as_mut().unwrap().active = false);
             assert_eq!(
                 Pallet::<Test>::validate_unsigned(
                     source,
                     &ClaimCall::claim_ethereum {
+                        campaign_id: CAMPAIGN_ID,
                         beneficiary: user_signer,
                         signature: user_signature,
                         dest
@@ -1374,13 +3788,133 @@ mod validate_unsigned {
         });
     }
 
+    #[test]
+    fn validate_unsigned_solana_works() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            let source = sp_runtime::transaction_validity::TransactionSource::External;
+            let _ = Balances::mint_into(&Claim::campaign_account_id(CAMPAIGN_ID), USER_7_AMOUNT)
+                .unwrap();
+            insert_beneficiary(CAMPAIGN_ID, USER_7, USER_7_AMOUNT);
+
+            // Claim bad signature
+            let mut bad_signature = USER_7_SIGNATURE_USER_1_DEST;
+            bad_signature[0] ^= 1;
+            assert_eq!(
+                Pallet::<Test>::validate_unsigned(
+                    source,
+                    &ClaimCall::claim_solana {
+                        campaign_id: CAMPAIGN_ID,
+                        beneficiary: USER_7_RAW,
+                        signature: SolanaSignature::from_raw(bad_signature),
+                        dest: USER_1_RAW
+                    }
+                ),
+                Err(TransactionValidityError::Invalid(
+                    InvalidTransaction::BadProof
+                ))
+            );
+
+            // Claim ok
+            assert_eq!(
+                Pallet::<Test>::validate_unsigned(
+                    source,
+                    &ClaimCall::claim_solana {
+                        campaign_id: CAMPAIGN_ID,
+                        beneficiary: USER_7_RAW,
+                        signature: SolanaSignature::from_raw(USER_7_SIGNATURE_USER_1_DEST),
+                        dest: USER_1_RAW
+                    }
+                ),
+                Ok(ValidTransaction {
+                    priority: 100,
+                    requires: vec![],
+                    provides: vec![("claim_solana", CAMPAIGN_ID, USER_7).encode()],
+                    longevity: TransactionLongevity::MAX,
+                    propagate: true,
+                })
+            );
+        });
+    }
+
+    #[test]
+    fn validate_unsigned_bitcoin_works() {
+        test_with_configs(
+            WithGenesisBeneficiaries::Yes,
+            GenesisClaimBalance::Sufficient,
+        )
+        .execute_with(|| {
+            let source = sp_runtime::transaction_validity::TransactionSource::External;
+            let _ = Balances::mint_into(&Claim::campaign_account_id(CAMPAIGN_ID), USER_9_AMOUNT)
+                .unwrap();
+            insert_beneficiary(CAMPAIGN_ID, USER_9, USER_9_AMOUNT);
+
+            // Claim with a signature for another address type
+            assert_eq!(
+                Pallet::<Test>::validate_unsigned(
+                    source,
+                    &ClaimCall::claim_bitcoin {
+                        campaign_id: CAMPAIGN_ID,
+                        beneficiary: USER_9_RAW,
+                        signature: USER_8_SIGNATURE_USER_1_DEST,
+                        dest: USER_1_RAW
+                    }
+                ),
+                Err(TransactionValidityError::Invalid(
+                    InvalidTransaction::BadProof
+                ))
+            );
+
+            // Claim beneficiary non existing
+            assert_eq!(
+                Pallet::<Test>::validate_unsigned(
+                    source,
+                    &ClaimCall::claim_bitcoin {
+                        campaign_id: CAMPAIGN_ID,
+                        beneficiary: USER_8_RAW,
+                        signature: USER_8_SIGNATURE_USER_1_DEST,
+                        dest: USER_1_RAW
+                    }
+                ),
+                Err(TransactionValidityError::Invalid(
+                    InvalidTransaction::BadSigner
+                ))
+            );
+
+            // Claim ok
+            assert_eq!(
+                Pallet::<Test>::validate_unsigned(
+                    source,
+                    &ClaimCall::claim_bitcoin {
+                        campaign_id: CAMPAIGN_ID,
+                        beneficiary: USER_9_RAW,
+                        signature: USER_9_SIGNATURE_USER_1_DEST,
+                        dest: USER_1_RAW
+                    }
+                ),
+                Ok(ValidTransaction {
+                    priority: 100,
+                    requires: vec![],
+                    provides: vec![("claim_bitcoin", CAMPAIGN_ID, USER_9).encode()],
+                    longevity: TransactionLongevity::MAX,
+                    propagate: true,
+                })
+            );
+        });
+    }
+
     #[test]
     fn validate_unsigned_rejects_unexpected_call() {
         test().execute_with(|| {
             assert_eq!(
                 Pallet::<Test>::validate_unsigned(
                     sp_runtime::transaction_validity::TransactionSource::External,
-                    &ClaimCall::end_claim {}
+                    &ClaimCall::end_claim {
+                        campaign_id: CAMPAIGN_ID
+                    }
                 ),
                 Err(TransactionValidityError::Invalid(InvalidTransaction::Call))
             );
@@ -1,5 +1,7 @@
 use super::*;
-use crate::{beneficiary::Beneficiary, EthereumAddress, EthereumSignature};
+use crate::{
+    beneficiary::Beneficiary, BitcoinAddress, BitcoinSignature, EthereumAddress, EthereumSignature,
+};
 use frame_benchmarking::account;
 use sp_runtime::Saturating;
 
@@ -32,7 +34,7 @@ pub fn get_beneficiaries_map<T: Config>(
 
 pub mod secp_utils {
     use super::*;
-    use beneficiary::eip191_hash_message;
+    use beneficiary::{bitcoin_hash_message, eip191_hash_message, hash160};
     use libsecp256k1::{sign, Message, PublicKey, SecretKey};
     use sp_io::hashing::keccak_256;
 
@@ -57,13 +59,32 @@ pub mod secp_utils {
     }
 
     pub fn sig(secret: &SecretKey, msg: &[u8]) -> EthereumSignature {
-        let msg = eip191_hash_message(msg);
-        let (sig, recovery_id) = sign(&Message::parse(&msg), secret);
+        sig_hash(secret, &eip191_hash_message(msg))
+    }
+
+    pub fn sig_hash(secret: &SecretKey, msg_hash: &[u8; 32]) -> EthereumSignature {
+        let (sig, recovery_id) = sign(&Message::parse(msg_hash), secret);
         let mut r = [0u8; 65];
         r[0..64].copy_from_slice(&sig.serialize()[..]);
         r[64] = recovery_id.serialize();
         EthereumSignature::from_raw(r)
     }
+
+    /// The P2PKH address of the compressed public key of 'secret'.
+    pub fn btc(secret: &SecretKey) -> BitcoinAddress {
+        BitcoinAddress::P2pkh(hash160(&public(secret).serialize_compressed()))
+    }
+
+    /// A BIP-137 signature on 'msg' by the compressed public key of 'secret', for a P2PKH
+    /// address (`header_base` 31) or a P2WPKH address (`header_base` 39).
+    pub fn btc_sig(secret: &SecretKey, msg: &[u8], header_base: u8) -> BitcoinSignature {
+        let msg = bitcoin_hash_message(msg);
+        let (sig, recovery_id) = sign(&Message::parse(&msg), secret);
+        let mut r = [0u8; 65];
+        r[0] = header_base + recovery_id.serialize();
+        r[1..65].copy_from_slice(&sig.serialize()[..]);
+        BitcoinSignature(r)
+    }
 }
 
 mod test {
@@ -111,4 +132,31 @@ mod test {
             eth_recover(&eip191_hash_message(message.as_slice()), &(eth_sig.into())).unwrap()
         );
     }
+
+    #[test]
+    fn bitcoin_consistency_check() {
+        use super::secp_utils::*;
+        use crate::beneficiary::{bitcoin_hash_message, btc_recover};
+        use crate::mock::*;
+
+        // Check we derive the well-known address of the secret key 1
+        let secret = parse_secret(&BITCOIN_SECRET[..]);
+        assert_eq!(btc(&secret), USER_8_RAW);
+
+        // Check signature and recovery work the same as Bitcoin wallets, which sign
+        // deterministically (RFC 6979)
+        let message = b"TestMessage@42";
+        assert_eq!(btc_sig(&secret, message, 31), USER_8_SIGNATURE_USER_1_DEST);
+        assert_eq!(btc_sig(&secret, message, 39), USER_9_SIGNATURE_USER_1_DEST);
+        let crate::BitcoinAddress::P2pkh(uncompressed_hash) = USER_10_RAW else {
+            unreachable!()
+        };
+        assert_eq!(
+            btc_recover(
+                &bitcoin_hash_message(message),
+                &USER_10_SIGNATURE_USER_1_DEST
+            ),
+            Ok((uncompressed_hash, false))
+        );
+    }
 }
@@ -63,180 +63,396 @@ pub trait WeightInfo {
     fn add_beneficiaries(n: u32, ) -> Weight;
     fn end_claim() -> Weight;
     fn remove_beneficiaries(n: u32, ) -> Weight;
+    fn begin_merkle_claim() -> Weight;
+    fn claim_merkle(n: u32, ) -> Weight;
+    fn claim_ethereum_merkle(n: u32, ) -> Weight;
+    fn set_beneficiaries_vesting(n: u32, ) -> Weight;
+    fn begin_zk_claim() -> Weight;
+    fn claim_zk() -> Weight;
+    fn claim_solana() -> Weight;
+    fn claim_bitcoin() -> Weight;
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
-    /// Storage: `Claim::CounterForBeneficiaries` (r:1 w:0)
-    /// Proof: `Claim::CounterForBeneficiaries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-    /// Storage: `Claim::ClaimActive` (r:1 w:1)
-    /// Proof: `Claim::ClaimActive` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
-    /// Storage: `Claim::ClaimId` (r:1 w:1)
-    /// Proof: `Claim::ClaimId` (`max_values`: Some(1), `max_size`: Some(510), added: 1005, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::Campaigns` (r:1 w:1)
+    /// Proof: `TokenClaim::Campaigns` (`max_values`: None, `max_size`: Some(705), added: 3180, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::TotalIssuance` (r:1 w:1)
+    /// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
     fn begin_claim() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `94`
-        //  Estimated: `1995`
-        // Minimum execution time: 12_614_000 picoseconds.
-        Weight::from_parts(12_884_000, 1995)
+        // TODO: re-run the benchmark, it predates the campaigns being keyed by id. The execution
+        // time is the one of the previous run plus `force_set_balance_creating` of pallet_balances,
+        // for minting the existential deposit of the campaign account. The proof size sums the
+        // bounds of the storage items above.
+        Weight::from_parts(30_000_000, 6294)
             .saturating_add(RocksDbWeight::get().reads(3_u64))
-            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
     }
-    /// Storage: `TokenClaim::ClaimActive` (r:1 w:0)
-    /// Proof: `TokenClaim::ClaimActive` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::Campaigns` (r:1 w:0)
+    /// Proof: `TokenClaim::Campaigns` (`max_values`: None, `max_size`: Some(705), added: 3180, mode: `MaxEncodedLen`)
     /// Storage: `TokenClaim::Beneficiaries` (r:1 w:1)
-    /// Proof: `TokenClaim::Beneficiaries` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
-    /// Storage: `TokenClaim::ClaimId` (r:1 w:0)
-    /// Proof: `TokenClaim::ClaimId` (`max_values`: Some(1), `max_size`: Some(510), added: 1005, mode: `MaxEncodedLen`)
-    /// Storage: `TokenClaim::PalletAccountId` (r:1 w:0)
-    /// Proof: `TokenClaim::PalletAccountId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+    /// Proof: `TokenClaim::Beneficiaries` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
     /// Storage: `System::Account` (r:2 w:2)
     /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-    /// Storage: `TokenClaim::CounterForBeneficiaries` (r:1 w:1)
-    /// Proof: `TokenClaim::CounterForBeneficiaries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::BeneficiariesCount` (r:1 w:1)
+    /// Proof: `TokenClaim::BeneficiariesCount` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
     /// Storage: `TokenClaim::TotalClaimable` (r:1 w:1)
-    /// Proof: `TokenClaim::TotalClaimable` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Proof: `TokenClaim::TotalClaimable` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::BeneficiaryVesting` (r:1 w:1)
+    /// Proof: `TokenClaim::BeneficiaryVesting` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+    /// Storage: `Vesting::Vesting` (r:1 w:1)
+    /// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Locks` (r:1 w:1)
+    /// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Freezes` (r:1 w:0)
+    /// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(185), added: 2660, mode: `MaxEncodedLen`)
     fn claim() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `2764`
-        //  Estimated: `6196`
-        // Minimum execution time: 299_391_000 picoseconds.
-        Weight::from_parts(303_609_000, 6196)
-            .saturating_add(RocksDbWeight::get().reads(8_u64))
-            .saturating_add(RocksDbWeight::get().writes(5_u64))
+        // TODO: re-run the benchmark, it predates the vesting of the claimed tokens. The execution
+        // time is the one of the previous run of `claim` plus `vested_transfer` of pallet_vesting
+        // with the maximum locks and schedules, which bounds adding a vesting schedule. The proof
+        // size sums the bounds of the storage items above.
+        Weight::from_parts(385_000_000, 28442)
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
     }
-    /// Storage: `TokenClaim::ClaimActive` (r:1 w:0)
-    /// Proof: `TokenClaim::ClaimActive` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::Campaigns` (r:1 w:0)
+    /// Proof: `TokenClaim::Campaigns` (`max_values`: None, `max_size`: Some(705), added: 3180, mode: `MaxEncodedLen`)
     /// Storage: `TokenClaim::Beneficiaries` (r:1 w:1)
-    /// Proof: `TokenClaim::Beneficiaries` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
-    /// Storage: `TokenClaim::ClaimId` (r:1 w:0)
-    /// Proof: `TokenClaim::ClaimId` (`max_values`: Some(1), `max_size`: Some(510), added: 1005, mode: `MaxEncodedLen`)
-    /// Storage: `TokenClaim::PalletAccountId` (r:1 w:0)
-    /// Proof: `TokenClaim::PalletAccountId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+    /// Proof: `TokenClaim::Beneficiaries` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
     /// Storage: `System::Account` (r:2 w:2)
     /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-    /// Storage: `TokenClaim::CounterForBeneficiaries` (r:1 w:1)
-    /// Proof: `TokenClaim::CounterForBeneficiaries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::BeneficiariesCount` (r:1 w:1)
+    /// Proof: `TokenClaim::BeneficiariesCount` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
     /// Storage: `TokenClaim::TotalClaimable` (r:1 w:1)
-    /// Proof: `TokenClaim::TotalClaimable` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Proof: `TokenClaim::TotalClaimable` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::BeneficiaryVesting` (r:1 w:1)
+    /// Proof: `TokenClaim::BeneficiaryVesting` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+    /// Storage: `Vesting::Vesting` (r:1 w:1)
+    /// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Locks` (r:1 w:1)
+    /// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Freezes` (r:1 w:0)
+    /// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(185), added: 2660, mode: `MaxEncodedLen`)
     fn claim_ethereum() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `2869`
-        //  Estimated: `6196`
-        // Minimum execution time: 256_574_000 picoseconds.
-        Weight::from_parts(263_514_000, 6196)
-            .saturating_add(RocksDbWeight::get().reads(8_u64))
-            .saturating_add(RocksDbWeight::get().writes(5_u64))
+        // TODO: re-run the benchmark, it predates EIP-712 signatures: the signer is recovered from
+        // both the EIP-191 and the EIP-712 messages in the worst case. The execution time is the
+        // one of the previous run plus a second recovery (the difference with the previous run of
+        // `claim_ethereum_for`), `vested_transfer` of pallet_vesting with the maximum locks and
+        // schedules and a margin for hashing the typed data. The proof size sums the bounds of the
+        // storage items above.
+        Weight::from_parts(510_000_000, 28442)
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
     }
-    /// Storage: `Claim::ClaimActive` (r:1 w:0)
-    /// Proof: `Claim::ClaimActive` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
-    /// Storage: `Claim::Beneficiaries` (r:1 w:1)
-    /// Proof: `Claim::Beneficiaries` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
-    /// Storage: `Claim::PalletAccountId` (r:1 w:0)
-    /// Proof: `Claim::PalletAccountId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::Campaigns` (r:1 w:0)
+    /// Proof: `TokenClaim::Campaigns` (`max_values`: None, `max_size`: Some(705), added: 3180, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::Beneficiaries` (r:1 w:1)
+    /// Proof: `TokenClaim::Beneficiaries` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
     /// Storage: `System::Account` (r:2 w:2)
     /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-    /// Storage: `Claim::TotalClaimable` (r:1 w:1)
-    /// Proof: `Claim::TotalClaimable` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-    /// Storage: `Claim::CounterForBeneficiaries` (r:1 w:1)
-    /// Proof: `Claim::CounterForBeneficiaries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::TotalClaimable` (r:1 w:1)
+    /// Proof: `TokenClaim::TotalClaimable` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::BeneficiariesCount` (r:1 w:1)
+    /// Proof: `TokenClaim::BeneficiariesCount` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::BeneficiaryVesting` (r:1 w:1)
+    /// Proof: `TokenClaim::BeneficiaryVesting` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+    /// Storage: `Vesting::Vesting` (r:1 w:1)
+    /// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Locks` (r:1 w:1)
+    /// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Freezes` (r:1 w:0)
+    /// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(185), added: 2660, mode: `MaxEncodedLen`)
     fn claim_for() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `2227`
-        //  Estimated: `6196`
-        // Minimum execution time: 100_287_000 picoseconds.
-        Weight::from_parts(102_841_000, 6196)
-            .saturating_add(RocksDbWeight::get().reads(7_u64))
-            .saturating_add(RocksDbWeight::get().writes(5_u64))
+        // TODO: re-run the benchmark, it predates the vesting of the claimed tokens. The execution
+        // time is the one of the previous run of `claim_for` plus `vested_transfer` of
+        // pallet_vesting with the maximum locks and schedules, which bounds adding a vesting
+        // schedule. The proof size sums the bounds of the storage items above.
+        Weight::from_parts(185_000_000, 28442)
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
     }
-    /// Storage: `Claim::ClaimActive` (r:1 w:0)
-    /// Proof: `Claim::ClaimActive` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
-    /// Storage: `Claim::Beneficiaries` (r:1 w:1)
-    /// Proof: `Claim::Beneficiaries` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
-    /// Storage: `Claim::PalletAccountId` (r:1 w:0)
-    /// Proof: `Claim::PalletAccountId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::Campaigns` (r:1 w:0)
+    /// Proof: `TokenClaim::Campaigns` (`max_values`: None, `max_size`: Some(705), added: 3180, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::Beneficiaries` (r:1 w:1)
+    /// Proof: `TokenClaim::Beneficiaries` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
     /// Storage: `System::Account` (r:2 w:2)
     /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-    /// Storage: `Claim::TotalClaimable` (r:1 w:1)
-    /// Proof: `Claim::TotalClaimable` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-    /// Storage: `Claim::CounterForBeneficiaries` (r:1 w:1)
-    /// Proof: `Claim::CounterForBeneficiaries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::TotalClaimable` (r:1 w:1)
+    /// Proof: `TokenClaim::TotalClaimable` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::BeneficiariesCount` (r:1 w:1)
+    /// Proof: `TokenClaim::BeneficiariesCount` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::BeneficiaryVesting` (r:1 w:1)
+    /// Proof: `TokenClaim::BeneficiaryVesting` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+    /// Storage: `Vesting::Vesting` (r:1 w:1)
+    /// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Locks` (r:1 w:1)
+    /// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Freezes` (r:1 w:0)
+    /// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(185), added: 2660, mode: `MaxEncodedLen`)
     fn claim_ethereum_for() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `2370`
-        //  Estimated: `6196`
-        // Minimum execution time: 102_110_000 picoseconds.
-        Weight::from_parts(106_999_000, 6196)
-            .saturating_add(RocksDbWeight::get().reads(7_u64))
-            .saturating_add(RocksDbWeight::get().writes(5_u64))
+        // TODO: re-run the benchmark, it predates the vesting of the claimed tokens. The execution
+        // time is the one of the previous run of `claim_ethereum_for` plus `vested_transfer` of
+        // pallet_vesting with the maximum locks and schedules, which bounds adding a vesting
+        // schedule. The proof size sums the bounds of the storage items above.
+        Weight::from_parts(190_000_000, 28442)
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
     }
-    /// Storage: `Claim::ClaimActive` (r:1 w:0)
-    /// Proof: `Claim::ClaimActive` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
-    /// Storage: `Claim::CounterForBeneficiaries` (r:1 w:1)
-    /// Proof: `Claim::CounterForBeneficiaries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-    /// Storage: `Claim::PalletAccountId` (r:1 w:0)
-    /// Proof: `Claim::PalletAccountId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::Campaigns` (r:1 w:0)
+    /// Proof: `TokenClaim::Campaigns` (`max_values`: None, `max_size`: Some(705), added: 3180, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::BeneficiariesCount` (r:1 w:1)
+    /// Proof: `TokenClaim::BeneficiariesCount` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
     /// Storage: `System::Account` (r:1 w:0)
     /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-    /// Storage: `Claim::TotalClaimable` (r:1 w:1)
-    /// Proof: `Claim::TotalClaimable` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-    /// Storage: `Claim::Beneficiaries` (r:100000 w:100000)
-    /// Proof: `Claim::Beneficiaries` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::TotalClaimable` (r:1 w:1)
+    /// Proof: `TokenClaim::TotalClaimable` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::Beneficiaries` (r:100000 w:100000)
+    /// Proof: `TokenClaim::Beneficiaries` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
     /// The range of component `n` is `[1, 100000]`.
     fn add_beneficiaries(n: u32, ) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `253`
-        //  Estimated: `3593 + n * (2532 ±0)`
-        // Minimum execution time: 20_067_000 picoseconds.
-        Weight::from_parts(20_629_000, 3593)
-            // Standard Error: 9_042
-            .saturating_add(Weight::from_parts(5_852_496, 0).saturating_mul(n.into()))
-            .saturating_add(RocksDbWeight::get().reads(5_u64))
+        // TODO: re-run the benchmark, it predates the beneficiaries being keyed by campaign. The
+        // execution time is the one of the previous run with a 20% margin for the longer keys. The
+        // proof size sums the bounds of the storage items above.
+        Weight::from_parts(25_000_000, 10785)
+            .saturating_add(Weight::from_parts(7_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
             .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
             .saturating_add(RocksDbWeight::get().writes(2_u64))
             .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
-            .saturating_add(Weight::from_parts(0, 2532).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(0, 2548).saturating_mul(n.into()))
     }
-    /// Storage: `Claim::ClaimActive` (r:1 w:1)
-    /// Proof: `Claim::ClaimActive` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
-    /// Storage: `Claim::PalletAccountId` (r:1 w:0)
-    /// Proof: `Claim::PalletAccountId` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::Campaigns` (r:1 w:1)
+    /// Proof: `TokenClaim::Campaigns` (`max_values`: None, `max_size`: Some(705), added: 3180, mode: `MaxEncodedLen`)
     /// Storage: `System::Account` (r:2 w:2)
     /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-    /// Storage: `Claim::ClaimId` (r:1 w:0)
-    /// Proof: `Claim::ClaimId` (`max_values`: Some(1), `max_size`: Some(510), added: 1005, mode: `MaxEncodedLen`)
-    /// Storage: `Claim::CounterForBeneficiaries` (r:1 w:0)
-    /// Proof: `Claim::CounterForBeneficiaries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-    /// Storage: `Claim::TotalClaimable` (r:0 w:1)
-    /// Proof: `Claim::TotalClaimable` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::BeneficiariesCount` (r:1 w:0)
+    /// Proof: `TokenClaim::BeneficiariesCount` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::TotalClaimable` (r:0 w:1)
+    /// Proof: `TokenClaim::TotalClaimable` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Vks` (r:1 w:1)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7093), added: 9568, mode: `MaxEncodedLen`)
     fn end_claim() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `853`
-        //  Estimated: `6196`
-        // Minimum execution time: 52_017_000 picoseconds.
-        Weight::from_parts(53_059_000, 6196)
-            .saturating_add(RocksDbWeight::get().reads(6_u64))
-            .saturating_add(RocksDbWeight::get().writes(4_u64))
+        // TODO: re-run the benchmark, it predates the ZK campaigns releasing their claim vk. The
+        // execution time is the one of the previous run plus releasing the vk, which reads and
+        // writes back its entry: 10us is a generous bound for the at most 7 KB of a Groth16 vk, the
+        // largest of the claim verifiers. The proof size sums the bounds of the storage items
+        // above.
+        Weight::from_parts(65_000_000, 20449)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
     }
-    /// Storage: `Claim::ClaimActive` (r:1 w:0)
-    /// Proof: `Claim::ClaimActive` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
-    /// Storage: `Claim::CounterForBeneficiaries` (r:1 w:1)
-    /// Proof: `Claim::CounterForBeneficiaries` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-    /// Storage: `Claim::Beneficiaries` (r:100000 w:100000)
-    /// Proof: `Claim::Beneficiaries` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::Campaigns` (r:1 w:1)
+    /// Proof: `TokenClaim::Campaigns` (`max_values`: None, `max_size`: Some(705), added: 3180, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::BeneficiariesCount` (r:1 w:1)
+    /// Proof: `TokenClaim::BeneficiariesCount` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::Beneficiaries` (r:100000 w:100000)
+    /// Proof: `TokenClaim::Beneficiaries` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::BeneficiaryVesting` (r:100000 w:100000)
+    /// Proof: `TokenClaim::BeneficiaryVesting` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
     /// The range of component `n` is `[1, 100000]`.
     fn remove_beneficiaries(n: u32, ) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `0 + n * (55 ±0)`
-        //  Estimated: `1489 + n * (2532 ±0)`
-        // Minimum execution time: 15_018_000 picoseconds.
-        Weight::from_parts(15_359_000, 1489)
-            // Standard Error: 8_277
-            .saturating_add(Weight::from_parts(2_268_637, 0).saturating_mul(n.into()))
+        // TODO: re-run the benchmark, it predates clearing the vesting overrides along with the
+        // beneficiaries. The execution time doubles the per beneficiary one of the previous run and
+        // adds a 20% margin. The proof size sums the bounds of the storage items above.
+        Weight::from_parts(20_000_000, 5675)
+            .saturating_add(Weight::from_parts(5_500_000, 0).saturating_mul(n.into()))
             .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 5088).saturating_mul(n.into()))
+    }
+    /// Storage: `TokenClaim::Campaigns` (r:1 w:1)
+    /// Proof: `TokenClaim::Campaigns` (`max_values`: None, `max_size`: Some(705), added: 3180, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::TotalIssuance` (r:1 w:1)
+    /// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::TotalClaimable` (r:0 w:1)
+    /// Proof: `TokenClaim::TotalClaimable` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    fn begin_merkle_claim() -> Weight {
+        // TODO: not benchmarked yet, regenerate with the `begin_merkle_claim` benchmark. It does
+        // the same work as `begin_claim` with no beneficiaries, plus storing the total claimable.
+        // The proof size sums the bounds of the storage items above.
+        Weight::from_parts(30_000_000, 6294)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `TokenClaim::Campaigns` (r:1 w:0)
+    /// Proof: `TokenClaim::Campaigns` (`max_values`: None, `max_size`: Some(705), added: 3180, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::ClaimedBitmap` (r:1 w:1)
+    /// Proof: `TokenClaim::ClaimedBitmap` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::TotalClaimable` (r:1 w:1)
+    /// Proof: `TokenClaim::TotalClaimable` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::BeneficiariesCount` (r:1 w:1)
+    /// Proof: `TokenClaim::BeneficiariesCount` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+    /// Storage: `Vesting::Vesting` (r:1 w:1)
+    /// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Locks` (r:1 w:1)
+    /// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Freezes` (r:1 w:0)
+    /// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(185), added: 2660, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[1, 31]`.
+    fn claim_merkle(n: u32, ) -> Weight {
+        // TODO: not benchmarked yet, regenerate with the `claim_merkle` benchmark. Use the `claim`
+        // weight as an upper bound: it checks the same signature and makes the same transfer, and
+        // reads and writes a beneficiary instead of a bitmap word. Each level of the proof hashes
+        // two 32 bytes nodes with keccak: 5us is a generous bound. The proof size sums the bounds
+        // of the storage items above.
+        Weight::from_parts(385_000_000, 25873)
+            .saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+    }
+    /// Storage: `TokenClaim::Campaigns` (r:1 w:0)
+    /// Proof: `TokenClaim::Campaigns` (`max_values`: None, `max_size`: Some(705), added: 3180, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::ClaimedBitmap` (r:1 w:1)
+    /// Proof: `TokenClaim::ClaimedBitmap` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::TotalClaimable` (r:1 w:1)
+    /// Proof: `TokenClaim::TotalClaimable` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::BeneficiariesCount` (r:1 w:1)
+    /// Proof: `TokenClaim::BeneficiariesCount` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+    /// Storage: `Vesting::Vesting` (r:1 w:1)
+    /// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Locks` (r:1 w:1)
+    /// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Freezes` (r:1 w:0)
+    /// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(185), added: 2660, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[1, 31]`.
+    fn claim_ethereum_merkle(n: u32, ) -> Weight {
+        // TODO: not benchmarked yet, regenerate with the `claim_ethereum_merkle` benchmark. Use the
+        // `claim_ethereum` weight as an upper bound: it checks the same signatures and makes the
+        // same transfer, and reads and writes a beneficiary instead of a bitmap word. Each level of
+        // the proof is bounded as in `claim_merkle`. The proof size sums the bounds of the storage
+        // items above.
+        Weight::from_parts(510_000_000, 25873)
+            .saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+    }
+    /// Storage: `TokenClaim::Campaigns` (r:1 w:0)
+    /// Proof: `TokenClaim::Campaigns` (`max_values`: None, `max_size`: Some(705), added: 3180, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::Beneficiaries` (r:100000 w:0)
+    /// Proof: `TokenClaim::Beneficiaries` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::BeneficiaryVesting` (r:0 w:100000)
+    /// Proof: `TokenClaim::BeneficiaryVesting` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[1, 100000]`.
+    fn set_beneficiaries_vesting(n: u32, ) -> Weight {
+        // TODO: not benchmarked yet, regenerate with the `set_beneficiaries_vesting` benchmark. Use
+        // the `add_beneficiaries` weight as an upper bound: it makes the same checks and writes for
+        // each beneficiary, and reads and writes more for the campaign. The proof size sums the
+        // bounds of the storage items above.
+        Weight::from_parts(25_000_000, 3180)
+            .saturating_add(Weight::from_parts(7_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
             .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
-            .saturating_add(RocksDbWeight::get().writes(1_u64))
             .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
-            .saturating_add(Weight::from_parts(0, 2532).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(0, 2548).saturating_mul(n.into()))
+    }
+    /// Storage: `TokenClaim::Campaigns` (r:1 w:1)
+    /// Proof: `TokenClaim::Campaigns` (`max_values`: None, `max_size`: Some(705), added: 3180, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::TotalIssuance` (r:1 w:1)
+    /// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Vks` (r:1 w:1)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7093), added: 9568, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::TotalClaimable` (r:0 w:1)
+    /// Proof: `TokenClaim::TotalClaimable` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    fn begin_zk_claim() -> Weight {
+        // TODO: not benchmarked yet, regenerate with the `begin_zk_claim` benchmark. It does the
+        // same work as `begin_merkle_claim`, plus retaining the claim vk, which reads and writes
+        // back its entry: 10us is a generous bound for the at most 7 KB of a Groth16 vk, the
+        // largest of the claim verifiers. The proof size sums the bounds of the storage items
+        // above.
+        Weight::from_parts(40_000_000, 15862)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    /// Storage: `TokenClaim::Campaigns` (r:1 w:0)
+    /// Proof: `TokenClaim::Campaigns` (`max_values`: None, `max_size`: Some(705), added: 3180, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::Nullifiers` (r:1 w:1)
+    /// Proof: `TokenClaim::Nullifiers` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::TotalClaimable` (r:1 w:1)
+    /// Proof: `TokenClaim::TotalClaimable` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::BeneficiariesCount` (r:1 w:1)
+    /// Proof: `TokenClaim::BeneficiariesCount` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+    /// Storage: `Vesting::Vesting` (r:1 w:1)
+    /// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Locks` (r:1 w:1)
+    /// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Freezes` (r:1 w:0)
+    /// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(185), added: 2660, mode: `MaxEncodedLen`)
+    fn claim_zk() -> Weight {
+        // TODO: not benchmarked yet, regenerate with the `claim_zk` benchmark. Use the `claim_for`
+        // weight as an upper bound: it makes the same transfer and vesting, and reads and writes a
+        // beneficiary instead of a nullifier. The proof verification is weighted apart. The proof
+        // size sums the bounds of the storage items above.
+        Weight::from_parts(185_000_000, 25893)
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+    }
+    /// Storage: `TokenClaim::Campaigns` (r:1 w:0)
+    /// Proof: `TokenClaim::Campaigns` (`max_values`: None, `max_size`: Some(705), added: 3180, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::Beneficiaries` (r:1 w:1)
+    /// Proof: `TokenClaim::Beneficiaries` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::BeneficiariesCount` (r:1 w:1)
+    /// Proof: `TokenClaim::BeneficiariesCount` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::TotalClaimable` (r:1 w:1)
+    /// Proof: `TokenClaim::TotalClaimable` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::BeneficiaryVesting` (r:1 w:1)
+    /// Proof: `TokenClaim::BeneficiaryVesting` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+    /// Storage: `Vesting::Vesting` (r:1 w:1)
+    /// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Locks` (r:1 w:1)
+    /// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Freezes` (r:1 w:0)
+    /// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(185), added: 2660, mode: `MaxEncodedLen`)
+    fn claim_solana() -> Weight {
+        // TODO: not benchmarked yet, regenerate with the `claim_solana` benchmark. Use the `claim`
+        // weight as an upper bound: an ed25519 signature check is not more expensive than an
+        // sr25519 one, and the storage accesses are the same. The proof size sums the bounds of the
+        // storage items above.
+        Weight::from_parts(385_000_000, 28442)
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+    }
+    /// Storage: `TokenClaim::Campaigns` (r:1 w:0)
+    /// Proof: `TokenClaim::Campaigns` (`max_values`: None, `max_size`: Some(705), added: 3180, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::Beneficiaries` (r:1 w:1)
+    /// Proof: `TokenClaim::Beneficiaries` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::BeneficiariesCount` (r:1 w:1)
+    /// Proof: `TokenClaim::BeneficiariesCount` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::TotalClaimable` (r:1 w:1)
+    /// Proof: `TokenClaim::TotalClaimable` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `TokenClaim::BeneficiaryVesting` (r:1 w:1)
+    /// Proof: `TokenClaim::BeneficiaryVesting` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+    /// Storage: `Vesting::Vesting` (r:1 w:1)
+    /// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Locks` (r:1 w:1)
+    /// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Freezes` (r:1 w:0)
+    /// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(185), added: 2660, mode: `MaxEncodedLen`)
+    fn claim_bitcoin() -> Weight {
+        // TODO: not benchmarked yet, regenerate with the `claim_bitcoin` benchmark. The execution
+        // time is the one of the previous run of `claim_ethereum`, which recovered a single
+        // secp256k1 key, plus `vested_transfer` of pallet_vesting with the maximum locks and
+        // schedules and a margin for hashing the message and the key with SHA-256 and RIPEMD-160.
+        // The proof size sums the bounds of the storage items above.
+        Weight::from_parts(350_000_000, 28442)
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
     }
 }
@@ -0,0 +1,37 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+    dispatch::DispatchResult, pallet_prelude::TypeInfo, weights::Weight, Parameter,
+};
+use sp_core::H256;
+
+/// Means of verifying the zero-knowledge proofs submitted to claim from ZK campaigns, against
+/// verification keys registered in some verifier.
+pub trait ClaimProofVerifier {
+    /// The identifier of a verifier claim proofs can be submitted to
+    type VerifierId: Parameter + Copy + MaxEncodedLen;
+    /// A claim proof for any of the verifiers
+    type Proof: Clone + PartialEq + core::fmt::Debug + Encode + Decode + TypeInfo + 'static;
+
+    /// Verify `proof` for the public inputs `pubs` (big-endian 32-byte words) against the
+    /// verification key `vk_hash` registered in `verifier`.
+    fn verify_claim_proof(
+        verifier: &Self::VerifierId,
+        vk_hash: &H256,
+        proof: &Self::Proof,
+        pubs: &[[u8; 32]],
+    ) -> DispatchResult;
+
+    /// The weight of verifying `proof` for the public inputs `pubs`.
+    fn verify_claim_proof_weight(proof: &Self::Proof, pubs: &[[u8; 32]]) -> Weight;
+
+    /// Take a reference to the verification key `vk_hash` registered in `verifier`, keeping it
+    /// registered for as long as a campaign commits to it. Fail if there is no such key.
+    fn retain_vk(verifier: &Self::VerifierId, vk_hash: &H256) -> DispatchResult;
+
+    /// Drop a reference taken by `retain_vk`.
+    fn release_vk(verifier: &Self::VerifierId, vk_hash: &H256);
+
+    /// Register a verification key to benchmark the ZK campaigns with.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn register_benchmark_vk() -> (Self::VerifierId, H256);
+}
@@ -176,10 +176,12 @@ pub fn benchmarking_utils(input: proc_macro::TokenStream) -> proc_macro::TokenSt
     };
     let opt_cfg_bound = config.clone().map(|c| quote! { + #c });
     let vk_of = quote! { VkOf #generic };
+    let vk_metadata_of = quote! { VkMetadataOf #generic };
     let proof_of = quote! { ProofOf #generic };
     let pubs_of = quote! { PubsOf #generic };
     quote! {
         type #vk_of = <#verifier as #crate_name::benchmarking_utils::Verifier>::Vk;
+        type #vk_metadata_of = <#verifier as #crate_name::benchmarking_utils::Verifier>::VkMetadata;
         type #proof_of = <#verifier as #crate_name::benchmarking_utils::Verifier>::Proof;
         type #pubs_of = <#verifier as #crate_name::benchmarking_utils::Verifier>::Pubs;
 
@@ -196,7 +198,7 @@ pub fn benchmarking_utils(input: proc_macro::TokenStream) -> proc_macro::TokenSt
         }
 
         /// Get a `VkEntry` from Vks storage.
-        fn do_get_vk<T>(hash: &sp_core::H256) -> Option<#crate_name::VkEntry<#vk_of>>
+        fn do_get_vk<T>(hash: &sp_core::H256) -> Option<#crate_name::VkEntry<#vk_of, #vk_metadata_of>>
         where
             T: #crate_name::Config<#verifier> #opt_cfg_bound,
         {
@@ -34,7 +34,8 @@ where
     T: Config<I>,
     I: 'static + Verifier,
 {
-    let vk_entry = VkEntry::new(vk);
+    let metadata = I::vk_metadata(&vk);
+    let vk_entry = VkEntry::new(vk, metadata);
     let footprint = Footprint::from_encodable(&vk_entry);
     let ticket = T::Ticket::new(&owner, footprint).unwrap();
 
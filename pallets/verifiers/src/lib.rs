@@ -32,6 +32,8 @@
 //!
 //!     type Vk = u64;
 //!
+//!     type VkMetadata = ();
+//!
 //!     fn hash_context_data() -> &'static [u8] {
 //!         b"my"
 //!     }
@@ -174,17 +176,30 @@ pub mod pallet {
         type Currency: Mutate<AccountOf<Self>>;
     }
 
-    /// A Vk with a reference count
+    /// A Vk with a reference count and the metadata the verifier extracted from it.
+    ///
+    /// The metadata are encoded last: when they are `()` the encoding is the same as
+    /// the one of a plain `(vk, ref_count)` entry.
     #[derive(Debug, Clone, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
-    pub struct VkEntry<V> {
+    pub struct VkEntry<V, M = ()> {
         vk: V,
         ref_count: u64,
+        metadata: M,
     }
 
-    impl<V> VkEntry<V> {
+    impl<V, M> VkEntry<V, M> {
         /// Construct a new vk with reference count set to 1.
-        pub fn new(vk: V) -> Self {
-            Self { vk, ref_count: 1 }
+        pub fn new(vk: V, metadata: M) -> Self {
+            Self {
+                vk,
+                ref_count: 1,
+                metadata,
+            }
+        }
+
+        /// The metadata computed by the verifier when the vk was registered.
+        pub fn metadata(&self) -> &M {
+            &self.metadata
         }
     }
 
@@ -247,8 +262,8 @@ pub mod pallet {
             }
         };
         // ensure_signed is just a struct unwrapping.
-        let verify =
-            override_verify_proof.unwrap_or_else(|| T::WeightInfo::verify_proof(proof, pubs));
+        let verify = override_verify_proof
+            .unwrap_or_else(|| verify_proof_weight::<T, I>(vk_or_hash, proof, pubs));
         let statement = T::WeightInfo::compute_statement_hash(proof, pubs);
         base.compose(vk_weight)
             .compose(verify)
@@ -256,6 +271,27 @@ pub mod pallet {
             .compose(T::OnProofVerified::weight(domain_id))
     }
 
+    /// Estimate the verify proof weight: when the proof references a registered vk, the
+    /// verifier can leverage the metadata stored next to it to be more precise.
+    ///
+    /// Reading the vk entry here doesn't need to be accounted because `submit_proof` reads
+    /// it anyway (see `WeightInfo::get_vk()`). Verifiers without metadata don't touch the
+    /// storage at all.
+    fn verify_proof_weight<T: Config<I>, I: 'static + Verifier>(
+        vk_or_hash: &VkOrHash<I::Vk>,
+        proof: &I::Proof,
+        pubs: &I::Pubs,
+    ) -> Weight {
+        match vk_or_hash {
+            VkOrHash::Hash(h) if I::VkMetadata::max_encoded_len() > 0 => Vks::<T, I>::get(h)
+                .map(|vk_entry| {
+                    T::WeightInfo::verify_proof_with_vk_metadata(proof, pubs, &vk_entry.metadata)
+                })
+                .unwrap_or_else(|| T::WeightInfo::verify_proof(proof, pubs)),
+            _ => T::WeightInfo::verify_proof(proof, pubs),
+        }
+    }
+
     /// Pallet specific events.
     #[pallet::event]
     #[pallet::generate_deposit(fn deposit_event)]
@@ -325,7 +361,7 @@ pub mod pallet {
     pub type Vks<T: Config<I>, I: 'static = ()>
     where
         I: Verifier,
-    = StorageMap<Hasher = Identity, Key = H256, Value = VkEntry<I::Vk>>;
+    = StorageMap<Hasher = Identity, Key = H256, Value = VkEntry<I::Vk, I::VkMetadata>>;
 
     #[pallet::storage]
     #[pallet::getter(fn deposits)]
@@ -378,6 +414,7 @@ pub mod pallet {
             let verify_proof_weight =
                 I::verify_proof(&vk, &proof, &pubs).map_err(Error::<T, I>::from)?;
             let statement = compute_statement_hash::<I>(&vk_or_hash, &proof, &pubs);
+            I::on_proof_verified(&vk, &proof, &pubs, statement);
             Self::deposit_event(Event::ProofVerified { statement });
             T::OnProofVerified::on_proof_verified(account, domain_id, statement);
             Ok(verify_proof_weight
@@ -395,6 +432,7 @@ pub mod pallet {
 
         /// Register a new verification key.
         /// On success emit a `VkRegistered` event that contain the hash to use on `submit_proof`.
+        /// The metadata computed by the verifier are stored next to the vk.
         /// Lock some funds, which can be unlocked by calling `unregister_vk`.
         #[pallet::call_index(1)]
         #[pallet::weight(T::WeightInfo::register_vk(vk))]
@@ -422,7 +460,8 @@ pub mod pallet {
                             .ok_or(DispatchError::Arithmetic(ArithmeticError::Overflow))?;
                     }
                     None => {
-                        *vk_entry = Some(VkEntry::new(*vk));
+                        let metadata = I::vk_metadata(&vk);
+                        *vk_entry = Some(VkEntry::new(*vk, metadata));
                     }
                 }
                 Ok::<_, DispatchError>(())
@@ -460,17 +499,79 @@ pub mod pallet {
             } else {
                 Err(Error::<T, I>::VerificationKeyNotFound)?
             }
+            Self::drop_vk_ref(&vk_hash);
+            Ok(())
+        }
+    }
+
+    impl<T: Config<I>, I: 'static> Pallet<T, I>
+    where
+        I: Verifier,
+    {
+        /// Verify a proof against a registered verification key without submitting it: no
+        /// statement is published. Meant for other pallets that gate some action on a proof.
+        /// Return the weight of the proof verification, if it differs from the estimated one
+        /// (see `verify_with_registered_vk_weight`).
+        pub fn verify_with_registered_vk(
+            vk_hash: &H256,
+            proof: &I::Proof,
+            pubs: &I::Pubs,
+        ) -> Result<Option<Weight>, DispatchError> {
+            ensure!(
+                !Self::disabled().unwrap_or_default(),
+                Error::<T, I>::DisabledVerifier
+            );
+            let vk = Vks::<T, I>::get(vk_hash)
+                .map(|vk_entry| vk_entry.vk)
+                .ok_or(Error::<T, I>::VerificationKeyNotFound)?;
+            I::verify_proof(&vk, proof, pubs).map_err(|e| Error::<T, I>::from(e).into())
+        }
+
+        /// The estimated weight of `verify_with_registered_vk`.
+        pub fn verify_with_registered_vk_weight(proof: &I::Proof, pubs: &I::Pubs) -> Weight {
+            // Disabled state check
+            T::DbWeight::get()
+                .reads(1)
+                .compose(T::WeightInfo::get_vk())
+                .compose(T::WeightInfo::verify_proof(proof, pubs))
+        }
+
+        fn drop_vk_ref(vk_hash: &H256) {
             Vks::<T, I>::mutate_exists(vk_hash, |vk_entry| match vk_entry {
                 Some(v) => {
                     v.ref_count = v.ref_count.saturating_sub(1);
                     if v.ref_count == 0 {
                         *vk_entry = None;
-                        Self::deposit_event(Event::VkUnregistered { hash: vk_hash });
+                        Self::deposit_event(Event::VkUnregistered { hash: *vk_hash });
                     }
                 }
                 None => unreachable!(),
             });
-            Ok(())
+        }
+
+        /// Take a reference to a registered verification key on behalf of another pallet: the
+        /// vk stays registered, even if all its owners unregister it, until `release_vk` is
+        /// called. No deposit is held: the caller is responsible for bounding the references.
+        pub fn retain_vk(vk_hash: &H256) -> DispatchResult {
+            Vks::<T, I>::try_mutate(vk_hash, |vk_entry| {
+                let vk_entry = vk_entry
+                    .as_mut()
+                    .ok_or(Error::<T, I>::VerificationKeyNotFound)?;
+                vk_entry.ref_count = vk_entry
+                    .ref_count
+                    .checked_add(1)
+                    .ok_or(DispatchError::Arithmetic(ArithmeticError::Overflow))?;
+                Ok(())
+            })
+        }
+
+        /// Drop a reference taken by `retain_vk`, unregistering the vk if it was the last one.
+        pub fn release_vk(vk_hash: &H256) {
+            if Vks::<T, I>::contains_key(vk_hash) {
+                Self::drop_vk_ref(vk_hash);
+            } else {
+                frame_support::defensive!("Releasing a vk that is not registered");
+            }
         }
     }
 
@@ -505,6 +606,7 @@ pub mod pallet {
             type Proof = u64;
             type Pubs = u64;
             type Vk = u64;
+            type VkMetadata = ();
             fn hash_context_data() -> &'static [u8] {
                 let context = b"other";
                 assert_ne!(FakeVerifier::hash_context_data(), context);
@@ -615,6 +717,7 @@ pub mod pallet {
             type Proof = ();
             type Pubs = ();
             type Vk = U256;
+            type VkMetadata = ();
             fn hash_context_data() -> &'static [u8] {
                 b"more"
             }
@@ -637,6 +740,7 @@ pub mod pallet {
             type Proof = ();
             type Pubs = ();
             type Vk = H256;
+            type VkMetadata = ();
 
             fn vk_hash(vk: &Self::Vk) -> Self::Vk {
                 *vk
@@ -103,6 +103,12 @@ pub mod fake_pallet {
 
     pub const PROOF_WITH_FAKE_VERSION_LOWER_BOUND: u64 = 1000;
 
+    std::thread_local! {
+        /// The statements notified to [`Verifier::on_proof_verified`].
+        pub static VERIFIED_STATEMENTS: core::cell::RefCell<Vec<sp_core::H256>> =
+            const { core::cell::RefCell::new(Vec::new()) };
+    }
+
     /// - Accept Proof iff proof == pubs and vk != 0.
     /// - If vk == 0 the vk is invalid and raise InvalidVerificationKey
     /// - If proof == 0 the proof is invalid and raise InvalidProofData
@@ -147,6 +153,8 @@ pub mod fake_pallet {
 
         type Vk = u64;
 
+        type VkMetadata = ();
+
         fn hash_context_data() -> &'static [u8] {
             b"fake"
         }
@@ -175,6 +183,15 @@ pub mod fake_pallet {
             }
         }
 
+        fn on_proof_verified(
+            _vk: &Self::Vk,
+            _proof: &Self::Proof,
+            _pubs: &Self::Pubs,
+            statement: sp_core::H256,
+        ) {
+            VERIFIED_STATEMENTS.with(|s| s.borrow_mut().push(statement));
+        }
+
         fn pubs_bytes(pubs: &Self::Pubs) -> Cow<'_, [u8]> {
             Cow::Owned(pubs.to_be_bytes().into())
         }
@@ -203,6 +220,7 @@ pub mod verifier_storage_pallet {
         type Proof = ();
         type Pubs = ();
         type Vk = ();
+        type VkMetadata = ();
 
         fn hash_context_data() -> &'static [u8] {
             b"storage2"
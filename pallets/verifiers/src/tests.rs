@@ -213,6 +213,19 @@ mod register_should {
             );
         })
     }
+
+    #[rstest]
+    fn not_change_the_vk_entry_encoding_if_the_verifier_has_no_metadata(
+        mut def_vk: sp_io::TestExternalities,
+    ) {
+        def_vk.execute_with(|| {
+            let raw = frame_support::storage::unhashed::get_raw(
+                &Vks::<Test, FakeVerifier>::hashed_key_for(REGISTERED_VK_HASH),
+            );
+
+            assert_eq!(raw, Some((REGISTERED_VK, 1_u64).encode()));
+        })
+    }
 }
 
 mod unregister_should {
@@ -383,6 +396,38 @@ mod submit_proof_should {
         });
     }
 
+    #[rstest]
+    fn notify_the_verifier_of_the_verified_statement(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            assert_ok!(FakeVerifierPallet::submit_proof(
+                RuntimeOrigin::signed(42),
+                VkOrHash::Vk(Box::new(REGISTERED_VK)),
+                Box::new(42),
+                Box::new(42),
+                None,
+            ));
+
+            fake_pallet::VERIFIED_STATEMENTS
+                .with(|s| assert_eq!(*s.borrow(), vec![VALID_HASH_REGISTERED_VK]));
+        });
+    }
+
+    #[rstest]
+    fn not_notify_the_verifier_of_invalid_proofs(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            assert!(FakeVerifierPallet::submit_proof(
+                RuntimeOrigin::signed(42),
+                VkOrHash::Vk(Box::new(REGISTERED_VK)),
+                Box::new(42),
+                Box::new(24),
+                None,
+            )
+            .is_err());
+
+            fake_pallet::VERIFIED_STATEMENTS.with(|s| assert!(s.borrow().is_empty()));
+        });
+    }
+
     #[rstest]
     fn forward_no_account_if_is_root(mut def_vk: sp_io::TestExternalities) {
         use on_proof_verified::new_proof_event;
@@ -689,6 +734,143 @@ mod submit_proof_should {
     }
 }
 
+mod verify_with_registered_vk_should {
+    use super::*;
+    use hex_literal::hex;
+    use hp_on_proof_verified::Compose as _;
+    use registered_vk::*;
+
+    #[rstest]
+    fn accept_a_valid_proof(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            assert_ok!(FakeVerifierPallet::verify_with_registered_vk(
+                &REGISTERED_VK_HASH,
+                &42,
+                &42
+            ));
+            // No statement is published
+            assert!(System::events().is_empty());
+            fake_pallet::VERIFIED_STATEMENTS.with(|s| assert!(s.borrow().is_empty()));
+        });
+    }
+
+    #[rstest]
+    fn estimate_the_weight_from_the_configured_weights() {
+        assert_eq!(
+            FakeVerifierPallet::verify_with_registered_vk_weight(&42, &24),
+            MockDbWeight::get()
+                .reads(1)
+                .compose(MockWeightInfo::get_vk())
+                .compose(MockWeightInfo::verify_proof(&42, &24))
+        );
+    }
+
+    mod reject {
+        use super::*;
+
+        #[rstest]
+        fn invalid_proof(mut def_vk: sp_io::TestExternalities) {
+            def_vk.execute_with(|| {
+                assert_noop!(
+                    FakeVerifierPallet::verify_with_registered_vk(&REGISTERED_VK_HASH, &42, &24),
+                    RError::VerifyError
+                );
+            });
+        }
+
+        #[rstest]
+        fn malformed_proof(mut def_vk: sp_io::TestExternalities) {
+            def_vk.execute_with(|| {
+                assert_noop!(
+                    FakeVerifierPallet::verify_with_registered_vk(
+                        &REGISTERED_VK_HASH,
+                        &FakeVerifier::malformed_proof(),
+                        &42
+                    ),
+                    RError::InvalidProofData
+                );
+            });
+        }
+
+        #[rstest]
+        fn unregistered_vk(mut def_vk: sp_io::TestExternalities) {
+            def_vk.execute_with(|| {
+                assert_noop!(
+                    FakeVerifierPallet::verify_with_registered_vk(
+                        &H256(hex!(
+                            "ffff0000ffff0000ffff0000ffff0000ffff0000ffff0000ffff0000ffff0000"
+                        )),
+                        &42,
+                        &42
+                    ),
+                    RError::VerificationKeyNotFound
+                );
+            });
+        }
+
+        #[rstest]
+        fn valid_proof_if_disabled(mut def_vk: sp_io::TestExternalities) {
+            def_vk.execute_with(|| {
+                DisableStorage::set(Some(true));
+                assert_noop!(
+                    FakeVerifierPallet::verify_with_registered_vk(&REGISTERED_VK_HASH, &42, &42),
+                    RError::DisabledVerifier
+                );
+            });
+        }
+    }
+}
+
+mod retain_vk_should {
+    use super::*;
+    use registered_vk::*;
+
+    #[rstest]
+    fn keep_the_vk_registered_until_released(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            assert_ok!(FakeVerifierPallet::retain_vk(&REGISTERED_VK_HASH));
+            FakeVerifierPallet::unregister_vk(RuntimeOrigin::signed(USER_1), REGISTERED_VK_HASH)
+                .unwrap();
+            assert!(FakeVerifierPallet::vks(REGISTERED_VK_HASH).is_some());
+            assert_ok!(FakeVerifierPallet::verify_with_registered_vk(
+                &REGISTERED_VK_HASH,
+                &42,
+                &42
+            ));
+
+            FakeVerifierPallet::release_vk(&REGISTERED_VK_HASH);
+            assert!(FakeVerifierPallet::vks(REGISTERED_VK_HASH).is_none());
+            System::assert_last_event(
+                Event::VkUnregistered {
+                    hash: REGISTERED_VK_HASH,
+                }
+                .into(),
+            );
+        })
+    }
+
+    #[rstest]
+    fn not_unregister_the_vk_on_release_if_its_owner_still_references_it(
+        mut def_vk: sp_io::TestExternalities,
+    ) {
+        def_vk.execute_with(|| {
+            assert_ok!(FakeVerifierPallet::retain_vk(&REGISTERED_VK_HASH));
+            FakeVerifierPallet::release_vk(&REGISTERED_VK_HASH);
+            assert!(FakeVerifierPallet::vks(REGISTERED_VK_HASH).is_some());
+        })
+    }
+
+    #[rstest]
+    fn reject_unregistered_vk(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            assert_noop!(
+                FakeVerifierPallet::retain_vk(&H256::from_low_u64_be(42)),
+                RError::VerificationKeyNotFound
+            );
+        })
+    }
+}
+
 #[cfg(test)]
 mod disable_should {
     use common::WeightInfo;
@@ -41,6 +41,9 @@ impl<T: Debug + Clone + PartialEq + Encode + Decode + TypeInfo> Arg for T {}
 /// Define the minimum traits that verification keys should implement.
 pub trait VkArg: Arg + MaxEncodedLen + EncodeLike {}
 impl<T: Arg + MaxEncodedLen + EncodeLike> VkArg for T {}
+/// Define the minimum traits that verification key metadata should implement.
+pub trait VkMetadataArg: VkArg + Default {}
+impl<T: VkArg + Default> VkMetadataArg for T {}
 
 /// The verification error type
 #[derive(Debug, PartialEq)]
@@ -70,6 +73,9 @@ pub trait Verifier {
     type Pubs: Arg;
     /// The verification key format
     type Vk: VkArg;
+    /// Verifier-defined data extracted from the verification key when it is registered and
+    /// stored next to it (see [`Verifier::vk_metadata`]). Use `()` if you don't need any.
+    type VkMetadata: VkMetadataArg;
 
     /// The context used to generate the statement hash.
     fn hash_context_data() -> &'static [u8];
@@ -90,6 +96,19 @@ pub trait Verifier {
         pubs: &Self::Pubs,
     ) -> Result<Option<Weight>, VerifyError>;
 
+    /// Called by `submit_proof` once the proof has been verified and its `statement` published:
+    /// use it to keep track of what the verified proofs attest. It's not called when a proof
+    /// is just checked (e.g. by `verify_with_registered_vk`), and its weight should be
+    /// accounted in [`WeightInfo::verify_proof`].
+    /// The default implementation does nothing.
+    fn on_proof_verified(
+        _vk: &Self::Vk,
+        _proof: &Self::Proof,
+        _pubs: &Self::Pubs,
+        _statement: H256,
+    ) {
+    }
+
     /// Validate the verification key: Should return `Ok(())` if the verification key is valid.
     /// The default implementations accept all verification keys: our business logic could
     /// need something different.
@@ -97,6 +116,15 @@ pub trait Verifier {
         Ok(())
     }
 
+    /// Compute the metadata to store next to a verification key when it is registered: it's
+    /// called just once, after `validate_vk` succeeded, so you can use it to cache anything
+    /// that is expensive to extract from the vk (e.g. to give precise weight estimations in
+    /// [`WeightInfo::verify_proof_with_vk_metadata`]).
+    /// The default implementation returns `Default::default()`.
+    fn vk_metadata(_vk: &Self::Vk) -> Self::VkMetadata {
+        Default::default()
+    }
+
     /// How to compute the verification key hash to use in statement hash computation.
     fn vk_hash(vk: &Self::Vk) -> H256 {
         sp_io::hashing::keccak_256(&Self::vk_bytes(vk)).into()
@@ -136,6 +164,18 @@ pub trait WeightInfo<V: Verifier> {
     /// Here you should map the given request to a weight computed with your verifier.
     fn verify_proof(proof: &V::Proof, pubs: &V::Pubs) -> Weight;
 
+    /// Like `verify_proof`, but used when the proof references a registered verification key:
+    /// `vk_metadata` is the one computed by [`Verifier::vk_metadata`] when the key was
+    /// registered and can be used to provide a tighter estimation.
+    /// The default implementation ignores it and falls back to `verify_proof`.
+    fn verify_proof_with_vk_metadata(
+        proof: &V::Proof,
+        pubs: &V::Pubs,
+        _vk_metadata: &V::VkMetadata,
+    ) -> Weight {
+        <Self as WeightInfo<V>>::verify_proof(proof, pubs)
+    }
+
     /// Here you should map the given request to a weight computed with your verifier.
     fn register_vk(vk: &V::Vk) -> Weight;
 
@@ -162,6 +202,7 @@ impl Verifier for () {
     type Proof = ();
     type Pubs = ();
     type Vk = ();
+    type VkMetadata = ();
 
     fn hash_context_data() -> &'static [u8] {
         b"()"
@@ -233,6 +274,7 @@ mod unit_verifier {
             type Proof = ();
             type Pubs = ();
             type Vk = ();
+            type VkMetadata = ();
             fn hash_context_data() -> &'static [u8] {
                 b"default"
             }
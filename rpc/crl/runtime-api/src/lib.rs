@@ -0,0 +1,30 @@
+// Copyright 2026, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+pub use pallet_crl::RootRotation;
+
+sp_api::decl_runtime_apis! {
+    #[api_version(1)]
+    pub trait CrlApi
+    {
+        // Returns the root certificate rotation scheduled for the given CA, if any
+        fn pending_root_rotation(ca_name: Vec<u8>) -> Option<RootRotation>;
+    }
+}
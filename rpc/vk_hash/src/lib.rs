@@ -15,7 +15,7 @@
 
 use codec::{Decode, Encode};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::ErrorObject};
-use pallet_ezkl_verifier::{Ezkl, MAX_VK_LENGTH};
+use pallet_ezkl_verifier::{Ezkl, VersionedVk as EzklVersionedVk, MAX_VK_LENGTH};
 use pallet_fflonk_verifier::{
     vk::{Fq, Fq2, Fr, G1, G2},
     Fflonk,
@@ -82,10 +82,39 @@ pub struct Plonky2Vk {
 
 #[derive(Debug, Encode, Decode, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct EzklVk {
+pub struct EzklVkData {
     pub vk_bytes: Bytes,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub enum EzklVk {
+    #[serde(alias = "v220")]
+    V22_0(EzklVkData),
+    #[serde(alias = "legacy")]
+    Legacy(EzklVkData),
+}
+
+impl TryFrom<EzklVk> for EzklVersionedVk {
+    type Error = &'static str;
+
+    fn try_from(vk: EzklVk) -> Result<Self, Self::Error> {
+        let check_length = |data: EzklVkData| {
+            let vk_bytes = data.vk_bytes.0;
+            if vk_bytes.is_empty()
+                || vk_bytes.len() & 31 != 0
+                || vk_bytes.len() > MAX_VK_LENGTH as usize
+            {
+                return Err("Incorrect Slice Length");
+            }
+            Ok(pallet_ezkl_verifier::EzklVk::new(vk_bytes))
+        };
+        match vk {
+            EzklVk::V22_0(data) => check_length(data).map(EzklVersionedVk::V22_0),
+            EzklVk::Legacy(data) => check_length(data).map(EzklVersionedVk::Legacy),
+        }
+    }
+}
+
 #[derive(Debug, Encode, Decode, Serialize, Deserialize)]
 pub struct FflonkVk {
     pub power: u8,
@@ -98,6 +127,12 @@ pub struct FflonkVk {
     pub wr: U256,
     pub x2: [[U256; 2]; 3],
     pub c0: [U256; 3],
+    #[serde(default = "default_n_public")]
+    pub n_public: u32,
+}
+
+fn default_n_public() -> u32 {
+    1
 }
 
 impl From<FflonkVk> for pallet_fflonk_verifier::vk::Vk {
@@ -117,6 +152,7 @@ impl From<FflonkVk> for pallet_fflonk_verifier::vk::Vk {
                 Fq2(Fq(vk.x2[2][0]), Fq(vk.x2[2][1])),
             ),
             c0: G1(Fq(vk.c0[0]), Fq(vk.c0[1]), Fq(vk.c0[2])),
+            n_public: vk.n_public,
         }
     }
 }
@@ -193,23 +229,14 @@ impl VKHash {
 
 impl VKHashApiServer<H256> for VKHash {
     fn ezkl(&self, vk: EzklVk) -> RpcResult<H256> {
-        let vk_bytes = vk.vk_bytes;
-        if vk_bytes.is_empty()
-            || vk_bytes.len() & 31 != 0
-            || vk_bytes.len() > MAX_VK_LENGTH as usize
-        {
-            return Err(ErrorObject::owned(
-                1,
-                "Incorrect Slice Length",
-                Some("Incorrect Slice Length".to_string()),
-            ));
-        }
-        let vk: VkOf<Ezkl<zkv_runtime::Runtime>> = pallet_ezkl_verifier::Vk::new(vk_bytes.to_vec());
+        let vk: VkOf<Ezkl<zkv_runtime::Runtime>> = vk
+            .try_into()
+            .map_err(|e: &str| ErrorObject::owned(1, e, Some(e.to_string())))?;
         Ok(Ezkl::<zkv_runtime::Runtime>::vk_hash(&vk))
     }
 
     fn fflonk(&self, vk: FflonkVk) -> RpcResult<H256> {
-        Ok(Fflonk::vk_hash(&vk.into()))
+        Ok(Fflonk::<zkv_runtime::Runtime>::vk_hash(&vk.into()))
     }
 
     fn groth16(&self, vk: Groth16Vk) -> RpcResult<H256> {
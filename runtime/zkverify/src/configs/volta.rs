@@ -23,3 +23,6 @@ pub const SS58_PREFIX: u16 = super::SS58_VOLTA_PREFIX;
 
 pub const ZKV_GENESIS_HASH: [u8; 32] =
     hex_literal::hex!("ff7fe5a610f15fe7a0c52f94f86313fb7db7d3786e7f8acf2b66c11d5be7c242");
+
+// Sepolia: a testnet any wallet can connect to, and not the id used on mainnet.
+pub const TOKEN_CLAIM_EIP712_CHAIN_ID: u64 = 11155111;
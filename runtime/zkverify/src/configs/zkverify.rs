@@ -22,3 +22,6 @@ pub const SS58_PREFIX: u16 = super::SS58_ZKV_PREFIX;
 
 pub const ZKV_GENESIS_HASH: [u8; 32] =
     hex_literal::hex!("060e3dd3fa2904d031206bb913c954687a2bcc350e5a83d33d9e273ad21460f1");
+
+// Ethereum mainnet.
+pub const TOKEN_CLAIM_EIP712_CHAIN_ID: u64 = 1;
@@ -42,7 +42,7 @@ use sp_runtime::{
         AccountIdConversion, BlakeTwo256, Block as BlockT, Bounded, ConvertInto, IdentityLookup,
         NumberFor, One, OpaqueKeys,
     },
-    transaction_validity::{TransactionSource, TransactionValidity},
+    transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
     ApplyExtrinsicResult, FixedPointNumber, MultiSignature, MultiSigner, Perquintill,
 };
 #[cfg(feature = "std")]
@@ -110,6 +110,7 @@ mod payout_conf;
 mod proxy;
 #[cfg(test)]
 mod tests;
+mod token_claim;
 pub mod types;
 mod weights;
 
@@ -632,6 +633,11 @@ parameter_types! {
 parameter_types! {
     /// Maximum length of a CA name in bytes.
     pub const MaxCaNameLength: u32 = 64;
+    /// The offchain worker refreshes the CRLs about once an hour.
+    pub const CrlOffchainWorkerInterval: BlockNumber = HOURS;
+    pub const CrlUnsignedPriority: TransactionPriority = TransactionPriority::MAX / 2;
+    /// The CA whose root signs the Intel TCB infos and the SGX/TDX PCK certificates.
+    pub const IntelCaName: &'static str = "Intel_SGX_Processor";
 }
 
 impl pallet_crl::Config for Runtime {
@@ -640,12 +646,19 @@ impl pallet_crl::Config for Runtime {
     type WeightInfo = weights::pallet_crl::ZKVWeight<Runtime>;
     type MaxCaNameLength = MaxCaNameLength;
     type UnixTime = Timestamp;
+    type OffchainWorkerInterval = CrlOffchainWorkerInterval;
+    type UnsignedPriority = CrlUnsignedPriority;
+    type IntelCaName = IntelCaName;
 }
 
 parameter_types! {
     pub const TokenClaimPalletId: PalletId = PalletId(*b"zkv/ptkc");
     pub const MaxClaimMessageLength: u32 = 500;
     pub const EthMsgSeparator: &'static [u8] = b"@";
+    pub const TokenClaimEip712DomainName: &'static [u8] = b"zkVerify";
+    // There is no EIP-155 chain id for this chain, and wallets only sign typed data for the chain
+    // they are connected to: each network uses the id of a different Ethereum network.
+    pub const TokenClaimEip712ChainId: u64 = configs::TOKEN_CLAIM_EIP712_CHAIN_ID;
 }
 
 impl pallet_token_claim::Config for Runtime {
@@ -662,6 +675,11 @@ impl pallet_token_claim::Config for Runtime {
     type MaxClaimMessageLength = MaxClaimMessageLength;
     type MaxOpBeneficiaries = MaxOpBeneficiaries;
     type EthMsgSeparator = EthMsgSeparator;
+    type Eip712DomainName = TokenClaimEip712DomainName;
+    type Eip712ChainId = TokenClaimEip712ChainId;
+    type VestingSchedule = Vesting;
+    type BlockNumberToBalance = ConvertInto;
+    type ClaimProofVerifier = token_claim::ZKVClaimProofVerifier;
     #[cfg(feature = "runtime-benchmarks")]
     const MAX_OP_BENEFICIARIES: u32 = MaxOpBeneficiaries::get();
     #[cfg(feature = "runtime-benchmarks")]
@@ -938,8 +956,11 @@ pub struct TeeCaNames;
 impl pallet_tee_verifier::CaNameProvider for TeeCaNames {
     fn ca_name_for(vk: &pallet_tee_verifier::Vk) -> &'static str {
         match vk {
-            pallet_tee_verifier::Vk::Intel { .. } => "Intel_SGX_Processor",
-            pallet_tee_verifier::Vk::Nitro => "AWS_Nitro",
+            pallet_tee_verifier::Vk::Intel { .. } => IntelCaName::get(),
+            pallet_tee_verifier::Vk::IntelTcbRegistry { .. } => IntelCaName::get(),
+            pallet_tee_verifier::Vk::Tdx { .. } => IntelCaName::get(),
+            pallet_tee_verifier::Vk::Nitro { .. } => "AWS_Nitro",
+            pallet_tee_verifier::Vk::AmdSnp { .. } => "AMD_SEV_SNP",
         }
     }
 }
@@ -947,6 +968,7 @@ impl pallet_tee_verifier::CaNameProvider for TeeCaNames {
 impl pallet_tee_verifier::Config for Runtime {
     type UnixTime = Timestamp;
     type Crl = pallet_crl::Pallet<Runtime>;
+    type TcbInfo = pallet_crl::Pallet<Runtime>;
     type CaName = TeeCaNames;
     type WeightInfo = weights::pallet_tee_verifier::ZKVWeight<Runtime>;
 }
@@ -983,7 +1005,20 @@ impl pallet_verifiers::Config<EzklVerifier> for Runtime {
     type Currency = Balances;
 }
 
-impl pallet_verifiers::Config<pallet_fflonk_verifier::Fflonk> for Runtime {
+parameter_types! {
+    pub const FflonkMaxPubs: u32 = 32;
+}
+
+impl pallet_fflonk_verifier::Config for Runtime {
+    type MaxPubs = FflonkMaxPubs;
+}
+
+// We should be sure that the max number of inputs does not exceed the benchmarked one.
+const_assert!(FflonkMaxPubs::get() <= pallet_fflonk_verifier::MAX_NUM_INPUTS);
+
+pub type FflonkVerifier = pallet_fflonk_verifier::Fflonk<Runtime>;
+
+impl pallet_verifiers::Config<FflonkVerifier> for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type OnProofVerified = Aggregate;
     type Ticket = VkRegistrationHoldConsideration;
@@ -1044,6 +1079,7 @@ parameter_types! {
                                                     // proof is ~1_400_000
     pub const Risc0MaxPubsSize: u32 = 4 + 32 * 64;  // 4: bytes for payload length,
                                                     // 32 * 64: sufficient multiple of 32 bytes
+    pub const Risc0MaxStoredClaims: u32 = 65_536;   // ~13MB of state at most
 }
 
 impl pallet_risc0_verifier::Config for Runtime {
@@ -1051,6 +1087,11 @@ impl pallet_risc0_verifier::Config for Runtime {
     type Segment20MaxSize = Risc0Segment20MaxSize;
     type MaxPubsSize = Risc0MaxPubsSize;
     type WeightInfo = weights::pallet_risc0_verifier_verify_proof::ZKVWeight<Runtime>;
+    type VerifiedClaims = Risc0Claims;
+}
+
+impl pallet_risc0_verifier::claims::Config for Runtime {
+    type MaxClaims = Risc0MaxStoredClaims;
 }
 
 impl pallet_verifiers::Config<pallet_risc0_verifier::Risc0<Runtime>> for Runtime {
@@ -1224,6 +1265,7 @@ construct_runtime!(
         SettlementUltrahonkPallet: pallet_ultrahonk_verifier = 168,
         SettlementEzklPallet: pallet_ezkl_verifier = 169,
         SettlementTeePallet: pallet_tee_verifier = 170,
+        Risc0Claims: pallet_risc0_verifier::claims = 171,
     }
 );
 
@@ -1680,6 +1722,12 @@ impl_runtime_apis! {
         }
     }
 
+    impl crl_rpc_runtime_api::CrlApi<Block> for Runtime {
+        fn pending_root_rotation(ca_name: Vec<u8>) -> Option<crl_rpc_runtime_api::RootRotation> {
+            Crl::pending_root_rotation(&ca_name)
+        }
+    }
+
     #[api_version(13)]
     impl primitives::runtime_api::ParachainHost<Block> for Runtime {
         fn validators() -> Vec<ValidatorId> {
@@ -17,7 +17,13 @@
 
 pub type Unreleased = (
     pallet_aggregate::migrations::v4::MigrateV3ToV4<crate::Runtime>,
-    pallet_ultrahonk_verifier::migrations::MigrateV1ToV2<crate::Runtime>,
+    pallet_ultrahonk_verifier::migrations::v2::MigrateV1ToV2<crate::Runtime>,
+    pallet_ultrahonk_verifier::migrations::v3::MigrateV2ToV3<crate::Runtime>,
     pallet_tee_verifier::migrations::v2::MigrateV1ToV2<crate::Runtime>,
+    pallet_fflonk_verifier::migrations::MigrateV1ToV2<crate::Runtime>,
+    pallet_ezkl_verifier::migrations::v2::MigrateV1ToV2<crate::Runtime>,
+    pallet_crl::migrations::v1::MigrateV0ToV1<crate::Runtime>,
+    pallet_crl::migrations::v2::MigrateV1ToV2<crate::Runtime>,
+    pallet_token_claim::migrations::v1::MigrateV0ToV1<crate::Runtime>,
     (),
 );
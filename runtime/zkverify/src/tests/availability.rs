@@ -30,10 +30,10 @@ fn pallet_ezkl() {
     test().execute_with(|| {
         let dummy_origin = AccountId32::new([0; 32]);
 
-        let dummy_vk = pallet_ezkl_verifier::EzklVk::new(
+        let dummy_vk = pallet_ezkl_verifier::VersionedVk::V22_0(pallet_ezkl_verifier::EzklVk::new(
             [0u8; pallet_ezkl_verifier::MAX_VK_LENGTH as usize].to_vec(),
-        );
-        let dummy_proof = Vec::new();
+        ));
+        let dummy_proof = pallet_ezkl_verifier::VersionedProof::V22_0(Vec::new());
         let dummy_pubs = Vec::new();
 
         assert!(SettlementEzklPallet::submit_proof(
@@ -53,7 +53,7 @@ fn pallet_fflonk() {
     test().execute_with(|| {
         let dummy_origin = AccountId32::new([0; 32]);
         let dummy_proof: pallet_fflonk_verifier::Proof = [0; pallet_fflonk_verifier::PROOF_SIZE];
-        let dummy_pubs: pallet_fflonk_verifier::Pubs = [0; pallet_fflonk_verifier::PUBS_SIZE];
+        let dummy_pubs: pallet_fflonk_verifier::Pubs = vec![[0; pallet_fflonk_verifier::PUBS_SIZE]];
         assert!(SettlementFFlonkPallet::submit_proof(
             RuntimeOrigin::signed(dummy_origin),
             VkOrHash::from_hash(H256::zero()),
@@ -333,7 +333,7 @@ fn pallet_sp1_availability() {
         let dummy_origin = AccountId32::new([0; 32]);
 
         let dummy_vk = H256::default();
-        let dummy_proof = Vec::new();
+        let dummy_proof = pallet_sp1_verifier::Proof::V5_0(Vec::new());
         let dummy_pubs = Vec::new();
 
         assert!(SettlementSp1Pallet::submit_proof(
@@ -368,7 +368,7 @@ fn pallet_aggregate() {
 #[test]
 fn pallet_token_claim() {
     test().execute_with(|| {
-        assert_ok!(TokenClaim::end_claim(RuntimeOrigin::root()));
+        assert_ok!(TokenClaim::end_claim(RuntimeOrigin::root(), 0));
         // just checking code builds, hence the pallet is available to the runtime
     });
 }
@@ -453,7 +453,7 @@ mod token_claim {
         test().execute_with(|| {
             let pre_balance = Balances::free_balance(Treasury::account_id());
 
-            assert_ok!(TokenClaim::end_claim(RuntimeOrigin::root()));
+            assert_ok!(TokenClaim::end_claim(RuntimeOrigin::root(), 0));
 
             // Check that treasury balance increased
             assert_eq!(
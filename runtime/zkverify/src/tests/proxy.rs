@@ -25,7 +25,7 @@ use crate::RuntimeCall;
 #[case::ezkl_submit_proof(
     RuntimeCall::SettlementEzklPallet(pallet_verifiers::Call::submit_proof{
         vk_or_hash: Default::default(),
-        proof: Default::default(),
+        proof: pallet_ezkl_verifier::VersionedProof::V22_0(Vec::new()).into(),
         pubs: Default::default(),
         domain_id: None,
         })
@@ -81,7 +81,7 @@ use crate::RuntimeCall;
 #[case::sp1(
     RuntimeCall::SettlementSp1Pallet(pallet_verifiers::Call::submit_proof{
         vk_or_hash: Default::default(),
-        proof: Default::default(),
+        proof: pallet_sp1_verifier::Proof::V5_0(Default::default()).into(),
         pubs: Default::default(),
         domain_id: None,
         })
@@ -24,3 +24,13 @@ fn version_fields_correct() {
 
     assert_eq!(expected, VERSION.spec_name)
 }
+
+#[test]
+fn token_claim_eip712_chain_id_is_per_network() {
+    let expected = if cfg!(feature = "volta") { 11155111 } else { 1 };
+
+    assert_eq!(
+        expected,
+        <Runtime as pallet_token_claim::Config>::Eip712ChainId::get()
+    )
+}
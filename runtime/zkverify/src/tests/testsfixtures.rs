@@ -85,7 +85,7 @@ pub fn test() -> sp_io::TestExternalities {
 
     // Fund also token claim pallet
     balances.push((
-        crate::TokenClaimPalletId::get().into_account_truncating(),
+        pallet_token_claim::Pallet::<super::Runtime>::campaign_account_id(0),
         total_balances() + crate::ExistentialDeposit::get(),
     ));
 
@@ -168,19 +168,22 @@ pub fn test() -> sp_io::TestExternalities {
     .unwrap();
 
     pallet_token_claim::GenesisConfig::<super::Runtime> {
-        beneficiaries: SAMPLE_USERS
-            .iter()
-            .cloned()
-            .map(|user| {
-                (
-                    pallet_token_claim::Beneficiary::<super::Runtime>::Substrate(
-                        user.raw_account.into(),
-                    ),
-                    user.starting_balance,
-                )
-            })
-            .collect(),
-        claim_message: frame_support::BoundedVec::try_from(b"TestMessage".to_vec()).unwrap(),
+        campaigns: vec![(
+            0,
+            frame_support::BoundedVec::try_from(b"TestMessage".to_vec()).unwrap(),
+            SAMPLE_USERS
+                .iter()
+                .cloned()
+                .map(|user| {
+                    (
+                        pallet_token_claim::Beneficiary::<super::Runtime>::Substrate(
+                            user.raw_account.into(),
+                        ),
+                        user.starting_balance,
+                    )
+                })
+                .collect(),
+        )],
     }
     .assimilate_storage(&mut t)
     .unwrap();
@@ -26,7 +26,7 @@ fn pallet_settlement_ezkl() {
         <<Runtime as pallet_verifiers::Config<Ezkl<Runtime>>>::WeightInfo as
         pallet_verifiers::WeightInfo<Ezkl<Runtime>>>
         ::verify_proof(
-            &Vec::new(),
+            &pallet_ezkl_verifier::VersionedProof::V22_0(Vec::new()),
             &Vec::new()
         ),
         crate::weights::pallet_ezkl_verifier::ZKVWeight::<Runtime>::verify_proof()
@@ -37,15 +37,17 @@ fn pallet_settlement_ezkl() {
 fn pallet_fflonk_verifier() {
     use pallet_fflonk_verifier::Fflonk;
     let dummy_proof = [0; pallet_fflonk_verifier::PROOF_SIZE];
-    let dummy_pubs = [0; pallet_fflonk_verifier::PUBS_SIZE];
+    let dummy_pubs = vec![[0; pallet_fflonk_verifier::PUBS_SIZE]; 3];
     use pallet_fflonk_verifier::WeightInfo;
 
     assert_eq!(
-        <<Runtime as pallet_verifiers::Config<Fflonk>>::WeightInfo as pallet_verifiers::WeightInfo<Fflonk>>::verify_proof(
+        <<Runtime as pallet_verifiers::Config<Fflonk<Runtime>>>::WeightInfo as
+            pallet_verifiers::WeightInfo<Fflonk<Runtime>>>
+            ::verify_proof(
             &dummy_proof,
             &dummy_pubs
         ),
-        crate::weights::pallet_fflonk_verifier::ZKVWeight::<Runtime>::verify_proof()
+        crate::weights::pallet_fflonk_verifier::ZKVWeight::<Runtime>::verify_proof(3)
     );
 }
 
@@ -159,13 +161,13 @@ fn pallet_settlement_plonky2_verify_proof() {
 
 #[test]
 fn pallet_settlement_sp1() {
-    use pallet_sp1_verifier::{Sp1, WeightInfo};
+    use pallet_sp1_verifier::{Proof, Sp1, WeightInfo};
 
     assert_eq!(
         <<Runtime as pallet_verifiers::Config<Sp1<Runtime>>>::WeightInfo as
             pallet_verifiers::WeightInfo<Sp1<Runtime>>>
             ::verify_proof(
-            &Vec::new(),
+            &Proof::V5_0(Vec::new()),
             &Vec::new()
         ),
         crate::weights::pallet_sp1_verifier::ZKVWeight::<Runtime>::verify_proof()
@@ -181,7 +183,7 @@ fn pallet_settlement_tee() {
         <<Runtime as pallet_verifiers::Config<Tee<Runtime>>>::WeightInfo as
             pallet_verifiers::WeightInfo<Tee<Runtime>>>
             ::register_vk(
-                &Vk::Nitro
+                &Vk::Nitro { pcrs: Default::default() }
         ),
         W::register_vk()
     );
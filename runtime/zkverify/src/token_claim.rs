@@ -0,0 +1,128 @@
+// Copyright 2024-2026, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This module contains the verifier of the proofs claiming tokens from ZK campaigns of
+//! pallet_token_claim.
+//!
+//! Concretely, ZKVClaimProofVerifier implements the pallet_token_claim::ClaimProofVerifier trait,
+//! verifying claim proofs against the verification keys registered in the Groth16 and UltraHonk
+//! verifier pallets.
+
+use crate::{SettlementGroth16Pallet, SettlementUltrahonkPallet};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{dispatch::DispatchResult, weights::Weight};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::{DispatchError, RuntimeDebug};
+
+/// The verifiers ZK campaigns can commit to.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub enum ClaimProofVerifierId {
+    /// The Groth16 verifier
+    Groth16,
+    /// The UltraHonk verifier
+    Ultrahonk,
+}
+
+/// A proof claiming tokens from a ZK campaign.
+#[derive(Clone, PartialEq, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub enum ClaimProof {
+    /// A Groth16 proof
+    Groth16(pallet_groth16_verifier::Proof),
+    /// An UltraHonk proof
+    Ultrahonk(pallet_ultrahonk_verifier::VersionedProof),
+}
+
+/// Groth16 public inputs are little-endian scalars.
+fn groth16_pubs(pubs: &[[u8; 32]]) -> pallet_groth16_verifier::Pubs {
+    pubs.iter()
+        .map(|word| pallet_groth16_verifier::Scalar(word.iter().rev().copied().collect()))
+        .collect()
+}
+
+pub struct ZKVClaimProofVerifier;
+
+impl pallet_token_claim::ClaimProofVerifier for ZKVClaimProofVerifier {
+    type VerifierId = ClaimProofVerifierId;
+    type Proof = ClaimProof;
+
+    fn verify_claim_proof(
+        verifier: &ClaimProofVerifierId,
+        vk_hash: &H256,
+        proof: &ClaimProof,
+        pubs: &[[u8; 32]],
+    ) -> DispatchResult {
+        match (verifier, proof) {
+            (ClaimProofVerifierId::Groth16, ClaimProof::Groth16(proof)) => {
+                SettlementGroth16Pallet::verify_with_registered_vk(
+                    vk_hash,
+                    proof,
+                    &groth16_pubs(pubs),
+                )?;
+            }
+            (ClaimProofVerifierId::Ultrahonk, ClaimProof::Ultrahonk(proof)) => {
+                SettlementUltrahonkPallet::verify_with_registered_vk(
+                    vk_hash,
+                    proof,
+                    &pubs.to_vec(),
+                )?;
+            }
+            _ => Err(DispatchError::Other("Proof for a different verifier"))?,
+        }
+        Ok(())
+    }
+
+    fn verify_claim_proof_weight(proof: &ClaimProof, pubs: &[[u8; 32]]) -> Weight {
+        match proof {
+            ClaimProof::Groth16(proof) => {
+                SettlementGroth16Pallet::verify_with_registered_vk_weight(
+                    proof,
+                    &groth16_pubs(pubs),
+                )
+            }
+            ClaimProof::Ultrahonk(proof) => {
+                SettlementUltrahonkPallet::verify_with_registered_vk_weight(proof, &pubs.to_vec())
+            }
+        }
+    }
+
+    fn retain_vk(verifier: &ClaimProofVerifierId, vk_hash: &H256) -> DispatchResult {
+        match verifier {
+            ClaimProofVerifierId::Groth16 => SettlementGroth16Pallet::retain_vk(vk_hash),
+            ClaimProofVerifierId::Ultrahonk => SettlementUltrahonkPallet::retain_vk(vk_hash),
+        }
+    }
+
+    fn release_vk(verifier: &ClaimProofVerifierId, vk_hash: &H256) {
+        match verifier {
+            ClaimProofVerifierId::Groth16 => SettlementGroth16Pallet::release_vk(vk_hash),
+            ClaimProofVerifierId::Ultrahonk => SettlementUltrahonkPallet::release_vk(vk_hash),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn register_benchmark_vk() -> (ClaimProofVerifierId, H256) {
+        use sp_runtime::traits::TrailingZeroInput;
+
+        // The claim benchmarks don't verify any proof: any vk will do
+        let vk = pallet_groth16_verifier::Vk::decode(&mut TrailingZeroInput::zeroes()).unwrap();
+        let vk_hash = H256::repeat_byte(2);
+        pallet_verifiers::benchmarking_utils::insert_vk_anonymous::<
+            crate::Runtime,
+            pallet_groth16_verifier::Groth16<crate::Runtime>,
+        >(vk, vk_hash);
+        (ClaimProofVerifierId::Groth16, vk_hash)
+    }
+}
@@ -58,15 +58,19 @@ use crate::parachains;
 pub struct ZKVWeight<T>(PhantomData<T>);
 
 impl<T: frame_system::Config> pallet_fflonk_verifier::WeightInfo for ZKVWeight<T> {
-    fn verify_proof() -> Weight {
+    /// The range of component `n` is `[1, 32]`.
+    fn verify_proof(n: u32, ) -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `0`
         //  Estimated: `0`
         // Minimum execution time: 16_872_837_000 picoseconds.
         Weight::from_parts(16_899_768_000, 0)
+            // TODO: the per input slope is an estimate: regenerate once we have valid
+            // multi-input proofs to benchmark.
+            .saturating_add(Weight::from_parts(32_418_274, 0).saturating_mul(n.into()))
     }
     /// Storage: `SettlementFFlonkPallet::Vks` (r:1 w:0)
-    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(553), added: 3028, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(557), added: 3032, mode: `MaxEncodedLen`)
     fn get_vk() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `635`
@@ -82,12 +86,15 @@ impl<T: frame_system::Config> pallet_fflonk_verifier::WeightInfo for ZKVWeight<T
         // Minimum execution time: 2_638_486_000 picoseconds.
         Weight::from_parts(2_644_938_000, 0)
     }
-    fn compute_statement_hash() -> Weight {
+    /// The range of component `n` is `[1, 32]`.
+    fn compute_statement_hash(n: u32, ) -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `0`
         //  Estimated: `0`
         // Minimum execution time: 6_442_000 picoseconds.
         Weight::from_parts(6_572_000, 0)
+            // TODO: the per input slope is an estimate: regenerate with the benchmarks.
+            .saturating_add(Weight::from_parts(461_089, 0).saturating_mul(n.into()))
     }
     /// Storage: `SettlementFFlonkPallet::Disabled` (r:1 w:0)
     /// Proof: `SettlementFFlonkPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
@@ -96,7 +103,7 @@ impl<T: frame_system::Config> pallet_fflonk_verifier::WeightInfo for ZKVWeight<T
     /// Storage: `Balances::Holds` (r:1 w:1)
     /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
     /// Storage: `SettlementFFlonkPallet::Vks` (r:1 w:1)
-    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(553), added: 3028, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(557), added: 3032, mode: `MaxEncodedLen`)
     fn register_vk() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `42`
@@ -111,7 +118,7 @@ impl<T: frame_system::Config> pallet_fflonk_verifier::WeightInfo for ZKVWeight<T
     /// Storage: `Balances::Holds` (r:1 w:1)
     /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
     /// Storage: `SettlementFFlonkPallet::Vks` (r:1 w:1)
-    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(553), added: 3028, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(557), added: 3032, mode: `MaxEncodedLen`)
     fn unregister_vk() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `800`
@@ -66,6 +66,11 @@ impl<T: frame_system::Config> pallet_risc0_verifier_verify_proof::WeightInfo for
         // Minimum execution time: 31_367_025_000 picoseconds.
         Weight::from_parts(31_394_697_000, 0)
     }
+    fn verify_proof_groth16() -> Weight {
+        // TODO: not benchmarked yet, we need a Groth16 receipt fixture. Use the succinct
+        // receipt weight as an upper bound.
+        Weight::from_parts(31_394_697_000, 0)
+    }
     fn verify_proof_segment_poseidon2_16() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `0`
@@ -66,6 +66,16 @@ impl<T: frame_system::Config> pallet_sp1_verifier::WeightInfo for ZKVWeight<T> {
         // Minimum execution time: 65_660_673_000 picoseconds.
         Weight::from_parts(65_701_118_000, 0)
     }
+    fn verify_proof_groth16() -> Weight {
+        // TODO: not benchmarked yet, we need wrapped proof fixtures. Use the compressed
+        // proof weight as an upper bound.
+        Weight::from_parts(65_701_118_000, 0)
+    }
+    fn verify_proof_plonk() -> Weight {
+        // TODO: not benchmarked yet, we need wrapped proof fixtures. Use the compressed
+        // proof weight as an upper bound.
+        Weight::from_parts(65_701_118_000, 0)
+    }
     /// Storage: `SettlementSp1Pallet::Vks` (r:1 w:0)
     /// Proof: `SettlementSp1Pallet::Vks` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
     fn get_vk() -> Weight {
@@ -1,6 +1,7 @@
 const { ApiPromise, WsProvider } = require('@polkadot/api');
 const Keyring = require('@polkadot/keyring').default;
 const { BigInt } = require('@polkadot/x-bigint');
+const { bnToU8a, stringToU8a, u8aConcat, u8aFixLength } = require('@polkadot/util');
 const fs = require('fs');
 const neatCsv = require('neat-csv').default;
 const readline = require('readline');
@@ -30,6 +31,7 @@ const DEFAULT_OUT_FILE_PATH = '.';
 const INIT_CAMPAIGN = true;
 const USE_PALLET_TOKEN_CLAIM = true;
 const DEFAULT_CLAIM_MSG_PREFIX = 'zkverify claim';
+const DEFAULT_CAMPAIGN_ID = 0;
 
 const INITIAL_CONFIRMATION_MSG =
 `
@@ -218,37 +220,49 @@ async function send(api, account, threshold, signatories, call) {
     return send_as_sudo(api, account, call);
 }
 
-async function send_begin_claim_tx(api, account, threshold, signatories, beneficiaries, msg) {
+function campaign_account(api, campaign_id) {
+    // Same derivation as `PalletId::into_sub_account_truncating(campaign_id)`
+    const raw = u8aConcat(
+        stringToU8a('modl'),
+        api.consts.tokenClaim.palletId.toU8a(),
+        bnToU8a(campaign_id, { bitLength: 64, isLe: true })
+    );
+    return api.createType('AccountId', u8aFixLength(raw, 256, true)).toString();
+}
+
+async function send_begin_claim_tx(api, account, threshold, signatories, campaign_id, beneficiaries, msg) {
     let begin_claim_tx = undefined;
     if (USE_PALLET_TOKEN_CLAIM) {
-        begin_claim_tx = api.tx.tokenClaim.beginClaim(beneficiaries, msg);
+        const config = { manager: null, unclaimedDestination: null, start: null, end: null, vesting: null };
+        begin_claim_tx = api.tx.tokenClaim.beginClaim(campaign_id, beneficiaries, msg, config);
     } else {
         begin_claim_tx = api.tx.claim.beginAirdrop(beneficiaries);
     }
     return send(api, account, threshold, signatories, begin_claim_tx);
 }
 
-async function send_add_beneficiaries_tx(api, account, threshold, signatories, beneficiaries) {
+async function send_add_beneficiaries_tx(api, account, threshold, signatories, campaign_id, beneficiaries) {
     let add_claim_tx = undefined;
     if (USE_PALLET_TOKEN_CLAIM) {
-        add_claim_tx = api.tx.tokenClaim.addBeneficiaries(beneficiaries);
+        add_claim_tx = api.tx.tokenClaim.addBeneficiaries(campaign_id, beneficiaries);
     } else {
         add_claim_tx = api.tx.claim.addBeneficiaries(beneficiaries);
     }
     return send(api, account, threshold, signatories, add_claim_tx);
 }
 
-async function check_preconditions(api, beneficiaries, batch_size) {
+async function check_preconditions(api, campaign_id, beneficiaries, batch_size) {
     let claim_active   = undefined;
     let free_balance   = undefined;
     let max_batch_size = 10000; // this is the default for the old pallet
 
     // fetch data
     if (USE_PALLET_TOKEN_CLAIM) {
-        claim_active = await api.query.tokenClaim.claimActive();
+        const campaign = await api.query.tokenClaim.campaigns(campaign_id);
+        claim_active = campaign.isSome && campaign.unwrap().active.isTrue;
         max_batch_size = await api.consts.tokenClaim.maxOpBeneficiaries.toNumber();
-        const pallet_address = await api.query.tokenClaim.palletAccountId();
-        free_balance = (await api.query.system.account(String(pallet_address)))["data"]["free"];
+        const campaign_address = campaign_account(api, campaign_id);
+        free_balance = (await api.query.system.account(campaign_address))["data"]["free"];
     } else {
         claim_active = await api.query.claim.airdropActive();
         const pallet_address = await api.query.claim.palletAccountId();
@@ -289,6 +303,7 @@ function usage() {
     node claim_init.js [OPTIONS] csv_input_file
 
     OPTIONS:
+    -c, --campaign-id: id of the campaign to start or extend (only for pallet tokenClaim) | ${DEFAULT_CAMPAIGN_ID}
     -b, --batch-size: max num of addresses for a single extrinsic | ${DEFAULT_BATCH_SIZE}
     -e, --end-point: url or key from ${Object.keys(DEFAULT_WS_ENDPOINTS)} | ${DEFAULT_WS_ENDPOINT}
     -h, --help: show this help and exit
@@ -321,6 +336,7 @@ function parse_args() {
         multisig_addresses: DEFAULT_MULTISIG_ADDRESSES,
         out_file_path: DEFAULT_OUT_FILE_PATH,
         claim_msg: `${DEFAULT_CLAIM_MSG_PREFIX} ${Date.now()}`,
+        campaign_id: DEFAULT_CAMPAIGN_ID,
     }
 
     // Skipping command and remove general options like -e
@@ -343,6 +359,10 @@ function parse_args() {
             result.batch_size = args[++i];
             continue;
         }
+        else if (args[i] === '-c' || args[i] === '--campaign-id') {
+            result.campaign_id = Number(args[++i]);
+            continue
+        }
         else if (args[i] === '-k' || args[i] === '--claim-msg') {
             result.claim_msg = args[++i];
             continue
@@ -487,7 +507,7 @@ async function main() {
     // Initialize the API
     const api = await ApiPromise.create({ provider: wsProvider });
 
-    if (!await check_preconditions(api, beneficiaries, options.batch_size)) {
+    if (!await check_preconditions(api, options.campaign_id, beneficiaries, options.batch_size)) {
         print_error("Some preconditions are not met; bailing out");
         wsProvider.disconnect();
         return;
@@ -505,6 +525,7 @@ async function main() {
                                                account,
                                                options.threshold,
                                                options.multisig_addresses,
+                                               options.campaign_id,
                                                beneficiaries[m],
                                                options.claim_msg);
         }
@@ -513,6 +534,7 @@ async function main() {
                                                      account,
                                                      options.threshold,
                                                      options.multisig_addresses,
+                                                     options.campaign_id,
                                                      beneficiaries[m]);
         }
         if (result === -1) {
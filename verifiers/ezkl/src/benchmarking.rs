@@ -38,9 +38,9 @@ pub mod benchmarks {
 
     #[benchmark]
     fn verify_proof() {
-        let proof = VALID_PROOF_ALT.to_vec();
+        let proof = crate::VersionedProof::V22_0(VALID_PROOF_ALT.to_vec());
         let pubs: Vec<_> = valid_instances_alt();
-        let vk = crate::EzklVk::new(VALID_VKA_ALT.to_vec());
+        let vk = crate::VersionedVk::V22_0(crate::EzklVk::new(VALID_VKA_ALT.to_vec()));
 
         let r;
         #[block]
@@ -52,7 +52,7 @@ pub mod benchmarks {
 
     #[benchmark]
     fn get_vk() {
-        let vk = crate::EzklVk::new(VALID_VKA_ALT.to_vec());
+        let vk = crate::VersionedVk::V22_0(crate::EzklVk::new(VALID_VKA_ALT.to_vec()));
         let hash = sp_core::H256::repeat_byte(2);
 
         insert_vk_anonymous::<T>(vk, hash);
@@ -67,7 +67,7 @@ pub mod benchmarks {
 
     #[benchmark]
     fn validate_vk() {
-        let vk = crate::EzklVk::new(VALID_VKA_ALT.to_vec());
+        let vk = crate::VersionedVk::V22_0(crate::EzklVk::new(VALID_VKA_ALT.to_vec()));
 
         let r;
         #[block]
@@ -79,9 +79,9 @@ pub mod benchmarks {
 
     #[benchmark]
     fn compute_statement_hash() {
-        let proof = VALID_PROOF_ALT.to_vec();
+        let proof = crate::VersionedProof::V22_0(VALID_PROOF_ALT.to_vec());
         let pubs: Vec<_> = valid_instances_alt();
-        let vk = crate::EzklVk::new(VALID_VKA_ALT.to_vec());
+        let vk = crate::VersionedVk::V22_0(crate::EzklVk::new(VALID_VKA_ALT.to_vec()));
 
         let vk = VkOrHash::Vk(vk.into());
 
@@ -95,7 +95,7 @@ pub mod benchmarks {
     fn register_vk() {
         // setup code
         let caller = funded_account::<T>();
-        let vk = crate::EzklVk::new(VALID_VKA_ALT.to_vec());
+        let vk = crate::VersionedVk::V22_0(crate::EzklVk::new(VALID_VKA_ALT.to_vec()));
 
         #[extrinsic_call]
         register_vk(RawOrigin::Signed(caller), vk.clone().into());
@@ -109,7 +109,7 @@ pub mod benchmarks {
         // setup code
         let caller = funded_account::<T>();
         let hash = sp_core::H256::repeat_byte(2);
-        let vk = crate::EzklVk::new(VALID_VKA_ALT.to_vec());
+        let vk = crate::VersionedVk::V22_0(crate::EzklVk::new(VALID_VKA_ALT.to_vec()));
 
         insert_vk::<T>(caller.clone(), vk, hash);
 
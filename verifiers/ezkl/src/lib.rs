@@ -20,7 +20,7 @@ extern crate alloc;
 use alloc::{borrow::Cow, vec::Vec};
 use codec::{Decode, Encode, MaxEncodedLen};
 use core::marker::PhantomData;
-use frame_support::{ensure, weights::Weight};
+use frame_support::{ensure, traits::StorageVersion, weights::Weight};
 use pallet_verifiers::traits::{Verifier, VerifyError};
 use scale_info::TypeInfo;
 use sp_core::{Get, H256};
@@ -54,9 +54,46 @@ impl EzklVk {
 
 pub type Proof = Vec<u8>;
 pub type Pubs = Vec<[u8; PUBS_SIZE]>;
-pub type Vk = EzklVk;
+pub type Vk = VersionedVk;
 pub use weight::WeightInfo;
 
+// Important Notes:
+// i) Please DO NOT alter the indices of existing VersionedProof's variants,
+// ii) If you are introducing new VersionedProof variants, ensure that
+// indices match those in VersionedVk.
+// iii) All the variants are verified by the same `ezkl-no-std` release for now:
+// `V22_0` only labels the proofs (statement and vk hashes), it doesn't select a
+// different verifier.
+#[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo)]
+pub enum VersionedProof {
+    #[codec(index = 0)]
+    V22_0(Proof),
+    #[codec(index = 1)]
+    Legacy(Proof),
+}
+
+// Important Notes:
+// i) Please DO NOT alter the indices of existing VersionedVk's variants,
+// ii) If you are introducing new VersionedVk variants, ensure that
+// indices match those in VersionedProof.
+// iii) As for VersionedProof, `V22_0` is just a label for now.
+#[derive(Clone, Debug, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum VersionedVk {
+    #[codec(index = 0)]
+    V22_0(EzklVk),
+    #[codec(index = 1)]
+    Legacy(EzklVk),
+}
+
+impl VersionedVk {
+    /// The inner ezkl verification key, whatever its version.
+    pub fn inner(&self) -> &EzklVk {
+        match self {
+            VersionedVk::V22_0(vk) | VersionedVk::Legacy(vk) => vk,
+        }
+    }
+}
+
 pub trait Config {
     /// Maximum supported number of public inputs.
     type MaxPubs: Get<u32>;
@@ -70,6 +107,7 @@ impl MaxEncodedLen for EzklVk {
 }
 
 pub mod benchmarking;
+pub mod migrations;
 mod verifier_should;
 pub mod weight;
 
@@ -77,11 +115,15 @@ pub mod weight;
 pub struct Ezkl<T>;
 
 impl<T: Config> Verifier for Ezkl<T> {
-    type Proof = Proof;
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+    type Proof = VersionedProof;
 
     type Pubs = Pubs;
 
-    type Vk = EzklVk;
+    type Vk = VersionedVk;
+
+    type VkMetadata = ();
 
     fn hash_context_data() -> &'static [u8] {
         b"ezkl"
@@ -96,6 +138,14 @@ impl<T: Config> Verifier for Ezkl<T> {
             pubs.len() <= T::MaxPubs::get() as usize,
             VerifyError::InvalidInput
         );
+        let (proof, vk) = match (proof, vk) {
+            (VersionedProof::V22_0(proof), VersionedVk::V22_0(vk))
+            | (VersionedProof::Legacy(proof), VersionedVk::Legacy(vk)) => (proof, vk),
+            _ => {
+                log::debug!("Proof version does not match Vk version!");
+                return Err(VerifyError::VerifyError);
+            }
+        };
         ensure!(
             proof.len() <= MAX_PROOF_LENGTH as usize,
             VerifyError::InvalidProofData
@@ -123,6 +173,7 @@ impl<T: Config> Verifier for Ezkl<T> {
     }
 
     fn validate_vk(vk: &Self::Vk) -> Result<(), VerifyError> {
+        let vk = vk.inner();
         if vk.vk_bytes.is_empty()
             || vk.vk_bytes.len() & 31 != 0
             || vk.vk_bytes.len() > MAX_VK_LENGTH as usize
@@ -138,7 +189,12 @@ impl<T: Config> Verifier for Ezkl<T> {
     }
 
     fn vk_bytes(vk: &Self::Vk) -> Cow<'_, [u8]> {
-        Self::encode_vk(vk)
+        match vk {
+            // Legacy returns raw bytes (no enum prefix) to match the pre-versioning encoding,
+            // so the hash of the vks registered before versioning doesn't change.
+            VersionedVk::Legacy(vk) => Cow::Owned(vk.vk_bytes.to_vec()),
+            _ => Cow::Owned(vk.encode()),
+        }
     }
 
     fn pubs_bytes(pubs: &Self::Pubs) -> Cow<'_, [u8]> {
@@ -148,12 +204,17 @@ impl<T: Config> Verifier for Ezkl<T> {
             .collect::<Vec<_>>();
         Cow::Owned(data)
     }
-}
 
-impl<T: Config> Ezkl<T> {
-    // Utility function for future-proofing.
-    fn encode_vk(vk: &Vk) -> Cow<'_, [u8]> {
-        Cow::Owned(vk.vk_bytes.to_vec())
+    fn verifier_version_hash(proof: &Self::Proof) -> H256 {
+        // Computed as: SHA2-256("ezkl:vx.y")
+        match proof {
+            VersionedProof::V22_0(_) => H256(hex_literal::hex!(
+                "dfce904b5e041862926420cf951180810338805f62b6cb0752fa7ac43ad05605"
+            )),
+            // Legacy returns NO_VERSION_HASH to preserve backward compatibility with
+            // the pre-versioning statement hash.
+            VersionedProof::Legacy(_) => pallet_verifiers::traits::NO_VERSION_HASH,
+        }
     }
 }
 
@@ -0,0 +1,102 @@
+// Copyright 2025-2026, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! A test runtime for the migration tests.
+
+use crate::Ezkl;
+use frame_support::{
+    derive_impl, parameter_types,
+    sp_runtime::{traits::IdentityLookup, BuildStorage},
+    traits::{fungible::HoldConsideration, LinearStoragePrice},
+};
+use sp_core::{ConstU128, ConstU32};
+
+pub type Balance = u128;
+pub type AccountId = u64;
+
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Balances: pallet_balances,
+        CommonVerifiersPallet: pallet_verifiers::common,
+        EzklPallet: crate,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::SolochainDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = frame_system::mocking::MockBlockU32<Test>;
+    type AccountId = AccountId;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type Lookup = IdentityLookup<Self::AccountId>;
+}
+
+impl pallet_balances::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+    type WeightInfo = ();
+    type Balance = Balance;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type ReserveIdentifier = [u8; 8];
+    type FreezeIdentifier = RuntimeFreezeReason;
+    type MaxLocks = ConstU32<10>;
+    type MaxReserves = ConstU32<10>;
+    type MaxFreezes = ConstU32<10>;
+    type DoneSlashHandler = ();
+}
+
+impl pallet_verifiers::common::Config for Test {
+    type CommonWeightInfo = Test;
+}
+
+parameter_types! {
+    pub const BaseDeposit: Balance = 1;
+    pub const PerByteDeposit: Balance = 2;
+    pub const HoldReasonVkRegistration: RuntimeHoldReason =
+        RuntimeHoldReason::CommonVerifiersPallet(
+            pallet_verifiers::common::HoldReason::VkRegistration
+        );
+}
+
+impl pallet_verifiers::Config<Ezkl<Test>> for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type OnProofVerified = ();
+    type WeightInfo = crate::EzklWeight<()>;
+    type Ticket = HoldConsideration<
+        AccountId,
+        Balances,
+        HoldReasonVkRegistration,
+        LinearStoragePrice<BaseDeposit, PerByteDeposit, Balance>,
+    >;
+    #[cfg(feature = "runtime-benchmarks")]
+    type Currency = Balances;
+}
+
+impl crate::Config for Test {
+    type MaxPubs = ConstU32<32>;
+}
+
+pub fn test_ext() -> sp_io::TestExternalities {
+    let mut ext = sp_io::TestExternalities::from(
+        frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap(),
+    );
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
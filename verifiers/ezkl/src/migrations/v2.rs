@@ -0,0 +1,221 @@
+// Copyright 2025-2026, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migration for the EZKL verifier pallet from V1 to V2.
+//!
+//! In V1, the `Vk` associated type was [`EzklVk`](crate::EzklVk), tied to the single
+//! `ezkl` release supported by the pallet.
+//!
+//! In V2, the `Vk` type changed to [`VersionedVk`](crate::VersionedVk), an enum
+//! labelling the vks with the `ezkl` release they target (`V22_0` and `Legacy`).
+//! Both variants are still verified by the same `ezkl-no-std` release: `V22_0` only
+//! changes the vk and statement hashes.
+//!
+//! This migration wraps all existing V1 VKs in
+//! [`VersionedVk::Legacy`](crate::VersionedVk::Legacy). The Legacy variant hashes the
+//! raw vk bytes exactly as V1 did, so the storage keys are unchanged and `Tickets`
+//! entries remain valid without modification.
+
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+use sp_core::Get;
+
+/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the storage from V1 to V2.
+///
+/// Wraps all existing [`EzklVk`](crate::EzklVk) VKs in
+/// [`VersionedVk::Legacy`](crate::VersionedVk::Legacy).
+pub struct InnerMigrateV1ToV2<T>(core::marker::PhantomData<T>);
+
+mod v1 {
+    use crate::EzklVk;
+    use codec::{Decode, Encode};
+
+    /// Migration-only struct mirroring `VkEntry<EzklVk>` with accessible fields.
+    #[derive(Decode, Encode)]
+    pub struct OldVkEntry {
+        pub vk: EzklVk,
+        pub ref_count: u64,
+    }
+}
+
+mod v2 {
+    use crate::VersionedVk;
+    use codec::{Decode, Encode};
+    use frame_support::{storage_alias, Identity};
+    use sp_core::H256;
+
+    /// Migration-only struct mirroring `VkEntry<VersionedVk>` with accessible fields.
+    #[derive(Decode, Encode)]
+    pub struct NewVkEntry {
+        pub vk: VersionedVk,
+        pub ref_count: u64,
+    }
+
+    #[storage_alias]
+    pub type Vks<T: crate::Config + pallet_verifiers::Config<crate::Ezkl<T>>> =
+        StorageMap<crate::Pallet<T>, Identity, H256, NewVkEntry>;
+}
+
+impl<T> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T>
+where
+    T: pallet_verifiers::Config<crate::Ezkl<T>> + crate::Config,
+{
+    fn on_runtime_upgrade() -> frame_support::weights::Weight {
+        let mut count: u64 = 0;
+
+        v2::Vks::<T>::translate::<v1::OldVkEntry, _>(|_, old_entry| {
+            count += 1;
+            Some(v2::NewVkEntry {
+                vk: crate::VersionedVk::Legacy(old_entry.vk),
+                ref_count: old_entry.ref_count,
+            })
+        });
+
+        log::info!(
+            target: "runtime::ezkl",
+            "EZKL migration V1->V2: migrated {} VK entries to Legacy variant",
+            count,
+        );
+
+        // Per entry: 1 read + 1 write
+        T::DbWeight::get().reads_writes(count, count)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<alloc::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+        let vk_count = v2::Vks::<T>::iter_keys().count() as u64;
+        log::info!(
+            target: "runtime::ezkl",
+            "ezkl pre_upgrade v1->v2: {vk_count} VKs to migrate to Legacy"
+        );
+        Ok(vk_count.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: alloc::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        let pre_vk_count =
+            u64::decode(&mut state.as_slice()).map_err(|_| "Failed to decode pre_upgrade state")?;
+
+        let post_vk_count = v2::Vks::<T>::iter()
+            .inspect(|(_, entry)| {
+                assert!(
+                    matches!(entry.vk, crate::VersionedVk::Legacy(_)),
+                    "All migrated VKs should be Legacy variant"
+                );
+            })
+            .count() as u64;
+
+        frame_support::ensure!(
+            post_vk_count == pre_vk_count,
+            "ezkl post_upgrade v1->v2: expected {pre_vk_count} VKs, got {post_vk_count}"
+        );
+
+        log::info!(
+            target: "runtime::ezkl",
+            "ezkl post_upgrade v1->v2: OK, migrated {post_vk_count} VKs to Legacy"
+        );
+        Ok(())
+    }
+}
+
+/// [`UncheckedOnRuntimeUpgrade`] implementation [`InnerMigrateV1ToV2`] wrapped in a
+/// [`VersionedMigration`], which ensures that:
+/// - The migration only runs once when the on-chain storage version is 1
+/// - The on-chain storage version is updated to `2` after the migration executes
+/// - Reads/Writes from checking/setting the on-chain storage version are accounted for
+pub type MigrateV1ToV2<T> = VersionedMigration<
+    1, // The migration will only execute when the on-chain storage version is 1
+    2, // The on-chain storage version will be set to 2 after the migration is complete
+    InnerMigrateV1ToV2<T>,
+    crate::Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::migrations::mock::*;
+    use crate::{Ezkl, EzklVk, VersionedVk};
+    use frame_support::{storage_alias, traits::UncheckedOnRuntimeUpgrade, Identity};
+    use pallet_verifiers::traits::Verifier;
+    use sp_core::H256;
+
+    #[storage_alias]
+    type OldVks<T: crate::Config + pallet_verifiers::Config<crate::Ezkl<T>>> =
+        StorageMap<crate::Pallet<T>, Identity, H256, v1::OldVkEntry>;
+
+    /// Compute the V1 hash for an `EzklVk`: Keccak-256 of the raw vk bytes.
+    fn v1_vk_hash(vk: &EzklVk) -> H256 {
+        sp_io::hashing::keccak_256(&vk.vk_bytes).into()
+    }
+
+    fn insert_old_vk(vk: EzklVk, ref_count: u64) -> H256 {
+        let hash = v1_vk_hash(&vk);
+        OldVks::<Test>::insert(hash, v1::OldVkEntry { vk, ref_count });
+        hash
+    }
+
+    #[test]
+    fn migrates_vk_to_legacy_variant() {
+        test_ext().execute_with(|| {
+            let vk = EzklVk::new([1; 64].to_vec());
+            let hash = insert_old_vk(vk.clone(), 3);
+
+            InnerMigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+            let entry = v2::Vks::<Test>::get(hash).expect("VK should be present after migration");
+            assert_eq!(entry.vk, VersionedVk::Legacy(vk));
+            assert_eq!(entry.ref_count, 3);
+        });
+    }
+
+    #[test]
+    fn migration_preserves_vk_hash_so_vk_is_retrievable() {
+        test_ext().execute_with(|| {
+            let vk = EzklVk::new([1; 64].to_vec());
+            let v1_hash = insert_old_vk(vk.clone(), 1);
+
+            InnerMigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+            let legacy_vk = VersionedVk::Legacy(vk);
+            assert_eq!(Ezkl::<Test>::vk_hash(&legacy_vk), v1_hash);
+            let entry = pallet_verifiers::Vks::<Test, Ezkl<Test>>::get(v1_hash)
+                .expect("VK should be decoded by pallet_verifiers::Vks");
+            assert_eq!(entry, pallet_verifiers::VkEntry::new(legacy_vk, ()));
+        });
+    }
+
+    #[test]
+    fn migrates_multiple_vks_with_correct_weight() {
+        test_ext().execute_with(|| {
+            insert_old_vk(EzklVk::new([1; 64].to_vec()), 1);
+            insert_old_vk(EzklVk::new([2; 96].to_vec()), 2);
+
+            let weight = InnerMigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+            assert_eq!(v2::Vks::<Test>::iter().count(), 2);
+            assert!(v2::Vks::<Test>::iter()
+                .all(|(_, entry)| matches!(entry.vk, VersionedVk::Legacy(_))));
+            assert_eq!(
+                weight,
+                <<Test as frame_system::Config>::DbWeight as Get<
+                    frame_support::weights::RuntimeDbWeight,
+                >>::get()
+                .reads_writes(2, 2)
+            );
+        });
+    }
+}
@@ -26,8 +26,8 @@ impl crate::Config for MockRuntime {
 
 #[test]
 fn verify_valid_proof() {
-    let vk = EzklVk::new(VALID_VKA.to_vec());
-    let proof = VALID_PROOF.to_vec();
+    let vk = VersionedVk::V22_0(EzklVk::new(VALID_VKA.to_vec()));
+    let proof = VersionedProof::V22_0(VALID_PROOF.to_vec());
     let pi = valid_instances();
 
     assert!(Ezkl::<MockRuntime>::verify_proof(&vk, &proof, &pi).is_ok());
@@ -35,16 +35,36 @@ fn verify_valid_proof() {
 
 #[test]
 fn verify_valid_proof_alt() {
-    let vk = EzklVk::new(VALID_VKA_ALT.to_vec());
-    let proof = VALID_PROOF_ALT.to_vec();
+    let vk = VersionedVk::V22_0(EzklVk::new(VALID_VKA_ALT.to_vec()));
+    let proof = VersionedProof::V22_0(VALID_PROOF_ALT.to_vec());
     let pi = valid_instances_alt();
 
     assert!(Ezkl::<MockRuntime>::verify_proof(&vk, &proof, &pi).is_ok());
 }
 
+#[test]
+fn verify_valid_legacy_proof() {
+    let vk = VersionedVk::Legacy(EzklVk::new(VALID_VKA.to_vec()));
+    let proof = VersionedProof::Legacy(VALID_PROOF.to_vec());
+    let pi = valid_instances();
+
+    assert!(Ezkl::<MockRuntime>::verify_proof(&vk, &proof, &pi).is_ok());
+}
+
 #[test]
 fn verify_vk_hash() {
-    let vk = EzklVk::new(VALID_VKA.to_vec());
+    let vk = VersionedVk::V22_0(EzklVk::new(VALID_VKA.to_vec()));
+    let vk_hash = Ezkl::<MockRuntime>::vk_hash(&vk);
+
+    assert_eq!(
+        vk_hash.as_bytes(),
+        hex_literal::hex!("74cd8176302353fc12996fcada63bed2924af9d403794975c7792d1d519eea90")
+    );
+}
+
+#[test]
+fn verify_legacy_vk_hash_is_the_pre_versioning_one() {
+    let vk = VersionedVk::Legacy(EzklVk::new(VALID_VKA.to_vec()));
     let vk_hash = Ezkl::<MockRuntime>::vk_hash(&vk);
 
     assert_eq!(
@@ -53,13 +73,59 @@ fn verify_vk_hash() {
     );
 }
 
+mod verifier_version_hash {
+    use super::*;
+
+    #[test]
+    fn commit_to_the_ezkl_version() {
+        let proof = VersionedProof::V22_0(VALID_PROOF.to_vec());
+
+        assert_eq!(
+            Ezkl::<MockRuntime>::verifier_version_hash(&proof),
+            H256(sp_io::hashing::sha2_256(b"ezkl:v22.0"))
+        );
+    }
+
+    #[test]
+    fn be_the_no_version_hash_for_legacy_proofs() {
+        let proof = VersionedProof::Legacy(VALID_PROOF.to_vec());
+
+        assert_eq!(
+            Ezkl::<MockRuntime>::verifier_version_hash(&proof),
+            pallet_verifiers::traits::NO_VERSION_HASH
+        );
+    }
+}
+
 mod reject {
     use super::*;
 
+    #[test]
+    fn mismatched_proof_and_vk_versions() {
+        let pi = valid_instances();
+
+        assert_eq!(
+            Ezkl::<MockRuntime>::verify_proof(
+                &VersionedVk::Legacy(EzklVk::new(VALID_VKA.to_vec())),
+                &VersionedProof::V22_0(VALID_PROOF.to_vec()),
+                &pi
+            ),
+            Err(VerifyError::VerifyError)
+        );
+        assert_eq!(
+            Ezkl::<MockRuntime>::verify_proof(
+                &VersionedVk::V22_0(EzklVk::new(VALID_VKA.to_vec())),
+                &VersionedProof::Legacy(VALID_PROOF.to_vec()),
+                &pi
+            ),
+            Err(VerifyError::VerifyError)
+        );
+    }
+
     #[test]
     fn invalid_public_values() {
-        let vk = EzklVk::new(VALID_VKA.to_vec());
-        let proof = VALID_PROOF.to_vec();
+        let vk = VersionedVk::V22_0(EzklVk::new(VALID_VKA.to_vec()));
+        let proof = VersionedProof::V22_0(VALID_PROOF.to_vec());
 
         let mut invalid_pubs = valid_instances();
         invalid_pubs[0][0] = 0x10;
@@ -72,8 +138,8 @@ mod reject {
 
     #[test]
     fn if_provided_too_many_public_inputs() {
-        let vk = EzklVk::new(VALID_VKA.to_vec());
-        let proof = VALID_PROOF.to_vec();
+        let vk = VersionedVk::V22_0(EzklVk::new(VALID_VKA.to_vec()));
+        let proof = VersionedProof::V22_0(VALID_PROOF.to_vec());
 
         let mut invalid_pubs = valid_instances();
         while (invalid_pubs.len() as u32) <= <MockRuntime as Config>::MaxPubs::get() {
@@ -88,8 +154,8 @@ mod reject {
 
     #[test]
     fn invalid_number_of_public_inputs() {
-        let vk = EzklVk::new(VALID_VKA.to_vec());
-        let proof = VALID_PROOF.to_vec();
+        let vk = VersionedVk::V22_0(EzklVk::new(VALID_VKA.to_vec()));
+        let proof = VersionedProof::V22_0(VALID_PROOF.to_vec());
 
         let invalid_pubs = vec![valid_instances()[0]];
 
@@ -101,11 +167,11 @@ mod reject {
 
     #[test]
     fn big_vka() {
-        let proof = VALID_PROOF.to_vec();
+        let proof = VersionedProof::V22_0(VALID_PROOF.to_vec());
         let pi = valid_instances();
 
         let invalid_vka_bytes = [0u8; MAX_VK_LENGTH as usize + 1].to_vec();
-        let invalid_vka = EzklVk::new(invalid_vka_bytes);
+        let invalid_vka = VersionedVk::V22_0(EzklVk::new(invalid_vka_bytes));
 
         assert_eq!(
             Ezkl::<MockRuntime>::verify_proof(&invalid_vka, &proof, &pi),
@@ -115,39 +181,39 @@ mod reject {
 
     #[test]
     fn big_proof() {
-        let vk = EzklVk::new(VALID_VKA.to_vec());
+        let vk = VersionedVk::V22_0(EzklVk::new(VALID_VKA.to_vec()));
         let pi = valid_instances();
 
         let invalid_proof = [0u8; MAX_PROOF_LENGTH as usize + 1].to_vec();
 
         assert_eq!(
-            Ezkl::<MockRuntime>::verify_proof(&vk, &invalid_proof, &pi),
+            Ezkl::<MockRuntime>::verify_proof(&vk, &VersionedProof::V22_0(invalid_proof), &pi),
             Err(VerifyError::InvalidProofData)
         );
     }
 
     #[test]
     fn small_proof() {
-        let vk = EzklVk::new(VALID_VKA.to_vec());
+        let vk = VersionedVk::V22_0(EzklVk::new(VALID_VKA.to_vec()));
         let pi = valid_instances();
 
         let mut invalid_proof = VALID_PROOF.to_vec();
         invalid_proof.pop();
 
         assert_eq!(
-            Ezkl::<MockRuntime>::verify_proof(&vk, &invalid_proof, &pi),
+            Ezkl::<MockRuntime>::verify_proof(&vk, &VersionedProof::V22_0(invalid_proof), &pi),
             Err(VerifyError::InvalidProofData)
         );
     }
 
     #[test]
     fn invalid_vk() {
-        let proof = VALID_PROOF.to_vec();
+        let proof = VersionedProof::V22_0(VALID_PROOF.to_vec());
         let pi = valid_instances();
 
         let mut invalid_vk_bytes = VALID_VKA.to_vec();
         invalid_vk_bytes[0] = 0x10;
-        let invalid_vk = EzklVk::new(invalid_vk_bytes);
+        let invalid_vk = VersionedVk::V22_0(EzklVk::new(invalid_vk_bytes));
 
         assert_eq!(
             Ezkl::<MockRuntime>::verify_proof(&invalid_vk, &proof, &pi),
@@ -157,14 +223,14 @@ mod reject {
 
     #[test]
     fn reject_malformed_proof() {
-        let vk = EzklVk::new(VALID_VKA.to_vec());
+        let vk = VersionedVk::V22_0(EzklVk::new(VALID_VKA.to_vec()));
         let pi = valid_instances();
 
         let mut malformed_proof = VALID_PROOF.to_vec();
         malformed_proof[0] = 0x07;
 
         assert_eq!(
-            Ezkl::<MockRuntime>::verify_proof(&vk, &malformed_proof, &pi),
+            Ezkl::<MockRuntime>::verify_proof(&vk, &VersionedProof::V22_0(malformed_proof), &pi),
             Err(VerifyError::InvalidProofData) // note that this is not an `VerifyError` variant since the faulty bytes get intercepted earlier
         );
     }
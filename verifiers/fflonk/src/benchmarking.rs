@@ -16,37 +16,49 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use crate::Fflonk as Verifier;
+use alloc::{vec, vec::Vec};
 use frame_benchmarking::v2::*;
 use frame_system::RawOrigin;
 use pallet_verifiers::traits::Verifier as _;
 use pallet_verifiers::{benchmarking_utils, VkOrHash};
 pub struct Pallet<T: Config>(crate::Pallet<T>);
-
-pub trait Config: pallet_verifiers::Config<Verifier> {}
-impl<T: pallet_verifiers::Config<Verifier>> Config for T {}
-pub type Call<T> = pallet_verifiers::Call<T, Verifier>;
+pub trait Config: crate::Config {}
+impl<T: crate::Config> Config for T {}
+pub type Call<T> = pallet_verifiers::Call<T, Verifier<T>>;
 
 include!("resources.rs");
 
-#[benchmarks]
+/// Return a vk with `n` public inputs and the related public inputs.
+///
+/// TODO: we have a valid proof just for the single public input case, so for `n > 1`
+/// the benchmark measures the verification of a proof that fails the final pairing
+/// check. Replace it with real multi-input fixtures and regenerate the weights.
+fn fflonk_instance(n: u32) -> (crate::vk::Vk, Vec<[u8; crate::PUBS_SIZE]>) {
+    let mut vk = fflonk_key();
+    vk.n_public = n;
+    (vk, vec![VALID_PUBS; n as usize])
+}
+
+#[allow(clippy::multiple_bound_locations)]
+#[benchmarks(where T: pallet_verifiers::Config<Verifier<T>>)]
 mod benchmarks {
 
     use super::*;
 
-    benchmarking_utils!(Verifier);
+    benchmarking_utils!(Verifier<T>, crate::Config);
 
     #[benchmark]
-    fn verify_proof() {
+    fn verify_proof(n: Linear<1, crate::MAX_NUM_INPUTS>) {
         let proof = VALID_PROOF;
-        let pubs = VALID_PUBS;
-        let vk = fflonk_key();
+        let (vk, pubs) = fflonk_instance(n);
 
         let r;
         #[block]
         {
             r = do_verify_proof::<T>(&vk, &proof, &pubs)
         };
-        assert!(r.is_ok());
+        // Only the single public input proof is a valid one
+        assert!(n != 1 || r.is_ok());
     }
 
     #[benchmark]
@@ -77,10 +89,9 @@ mod benchmarks {
     }
 
     #[benchmark]
-    fn compute_statement_hash() {
+    fn compute_statement_hash(n: Linear<1, crate::MAX_NUM_INPUTS>) {
         let proof = VALID_PROOF;
-        let pubs = VALID_PUBS;
-        let vk = fflonk_key();
+        let (vk, pubs) = fflonk_instance(n);
 
         let vk = VkOrHash::Vk(vk.into());
 
@@ -161,7 +172,11 @@ mod mock {
         pub const HoldReasonVkRegistration: RuntimeHoldReason = RuntimeHoldReason::CommonVerifiersPallet(pallet_verifiers::common::HoldReason::VkRegistration);
     }
 
-    impl pallet_verifiers::Config<crate::Fflonk> for Test {
+    impl crate::Config for Test {
+        type MaxPubs = ConstU32<{ crate::MAX_NUM_INPUTS }>;
+    }
+
+    impl pallet_verifiers::Config<crate::Fflonk<Test>> for Test {
         type RuntimeEvent = RuntimeEvent;
         type OnProofVerified = ();
         type WeightInfo = crate::FflonkWeight<()>;
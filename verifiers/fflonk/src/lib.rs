@@ -17,32 +17,45 @@
 
 extern crate alloc;
 
-use alloc::borrow::Cow;
+use alloc::{borrow::Cow, vec::Vec};
 use core::marker::PhantomData;
-use frame_support::weights::Weight;
+use frame_support::{ensure, traits::StorageVersion, weights::Weight};
 use pallet_verifiers::traits::{Verifier, VerifyError};
+use sp_core::Get;
 
 pub mod benchmarking;
+pub mod migrations;
 mod verifier_should;
 pub mod vk;
 mod weight;
 
 pub const PUBS_SIZE: usize = 32;
 pub const PROOF_SIZE: usize = 24 * 32;
-pub type Pubs = [u8; PUBS_SIZE];
+/// Maximum number of public inputs used in benchmarks: the runtime `MaxPubs` should not exceed it.
+pub const MAX_NUM_INPUTS: u32 = 32;
+pub type Pubs = Vec<[u8; PUBS_SIZE]>;
 pub type Proof = [u8; PROOF_SIZE];
 pub use weight::WeightInfo;
 
+pub trait Config {
+    /// Maximum supported number of public inputs.
+    type MaxPubs: Get<u32>;
+}
+
 #[pallet_verifiers::verifier]
-pub struct Fflonk;
+pub struct Fflonk<T>;
+
+impl<T: Config> Verifier for Fflonk<T> {
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
-impl Verifier for Fflonk {
     type Proof = Proof;
 
     type Pubs = Pubs;
 
     type Vk = vk::Vk;
 
+    type VkMetadata = ();
+
     fn hash_context_data() -> &'static [u8] {
         b"fflonk"
     }
@@ -52,19 +65,30 @@ impl Verifier for Fflonk {
         raw_proof: &Self::Proof,
         raw_pubs: &Self::Pubs,
     ) -> Result<Option<Weight>, VerifyError> {
+        ensure!(
+            raw_pubs.len() <= T::MaxPubs::get() as usize,
+            VerifyError::InvalidInput
+        );
+        ensure!(
+            raw_pubs.len() == vk.n_public as usize,
+            VerifyError::InvalidInput
+        );
         let vk: fflonk_verifier::VerificationKey = vk
             .clone()
             .try_into_fflonk_vk_unchecked()
             .map_err(|e| log::debug!("Invalid Vk: {e:?}"))
             .map_err(|_| VerifyError::InvalidVerificationKey)?;
-        let pubs: fflonk_verifier::Public = (*raw_pubs).into();
+        let pubs = raw_pubs
+            .iter()
+            .copied()
+            .map(fflonk_verifier::Public::from)
+            .collect::<Vec<_>>();
         let proof = fflonk_verifier::Proof::try_from(raw_proof)
             .map_err(|e| log::debug!("Cannot extract raw proof data: {e:?}"))
             .map_err(|_| VerifyError::InvalidProofData)?;
         log::trace!(
-            "Extracted public inputs [{:?}...{:?}] and proof data [{:?}...{:?}]",
-            &raw_pubs[0],
-            &raw_pubs[PUBS_SIZE - 1],
+            "Extracted {} public inputs and proof data [{:?}...{:?}]",
+            raw_pubs.len(),
             &raw_proof[0],
             &raw_proof[PROOF_SIZE - 1]
         );
@@ -76,6 +100,10 @@ impl Verifier for Fflonk {
     }
 
     fn validate_vk(vk: &Self::Vk) -> Result<(), VerifyError> {
+        ensure!(
+            vk.n_public <= T::MaxPubs::get(),
+            VerifyError::InvalidVerificationKey
+        );
         let _: fflonk_verifier::VerificationKey = vk
             .clone()
             .try_into()
@@ -84,19 +112,26 @@ impl Verifier for Fflonk {
         Ok(())
     }
 
+    fn vk_bytes(vk: &Self::Vk) -> Cow<'_, [u8]> {
+        Cow::Owned(vk.legacy_compatible_bytes())
+    }
+
     fn pubs_bytes(pubs: &Self::Pubs) -> Cow<'_, [u8]> {
-        Cow::Borrowed(pubs)
+        let data = pubs
+            .iter()
+            .flat_map(|s| s.iter().cloned())
+            .collect::<Vec<_>>();
+        Cow::Owned(data)
     }
 }
 
 /// The struct to use in runtime pallet configuration to map the weight computed by this crate
 /// benchmarks to the weight needed by the `pallet-verifiers`.
-/// In this case the implementation doesn't depends from the kind of proof or public input and
-/// the crate's benchmarks are mapped 1-1 to the `pallet-verifiers`'s one.
+/// The proof verification and the statement hash weights depend on the number of public inputs.
 pub struct FflonkWeight<W: WeightInfo>(PhantomData<W>);
 
-impl<W: WeightInfo> pallet_verifiers::WeightInfo<Fflonk> for FflonkWeight<W> {
-    fn register_vk(_vk: &<Fflonk as Verifier>::Vk) -> Weight {
+impl<T: Config, W: WeightInfo> pallet_verifiers::WeightInfo<Fflonk<T>> for FflonkWeight<W> {
+    fn register_vk(_vk: &<Fflonk<T> as Verifier>::Vk) -> Weight {
         W::register_vk()
     }
 
@@ -105,24 +140,24 @@ impl<W: WeightInfo> pallet_verifiers::WeightInfo<Fflonk> for FflonkWeight<W> {
     }
 
     fn verify_proof(
-        _proof: &<Fflonk as Verifier>::Proof,
-        _pubs: &<Fflonk as Verifier>::Pubs,
+        _proof: &<Fflonk<T> as Verifier>::Proof,
+        pubs: &<Fflonk<T> as Verifier>::Pubs,
     ) -> Weight {
-        W::verify_proof()
+        W::verify_proof(pubs.len() as u32)
     }
 
     fn get_vk() -> Weight {
         W::get_vk()
     }
 
-    fn validate_vk(_vk: &<Fflonk as Verifier>::Vk) -> Weight {
+    fn validate_vk(_vk: &<Fflonk<T> as Verifier>::Vk) -> Weight {
         W::validate_vk()
     }
 
     fn compute_statement_hash(
-        _proof: &<Fflonk as Verifier>::Proof,
-        _pubs: &<Fflonk as Verifier>::Pubs,
+        _proof: &<Fflonk<T> as Verifier>::Proof,
+        pubs: &<Fflonk<T> as Verifier>::Pubs,
     ) -> Weight {
-        W::compute_statement_hash()
+        W::compute_statement_hash(pubs.len() as u32)
     }
 }
@@ -0,0 +1,399 @@
+// Copyright 2024-2026, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migration for the fflonk verifier pallet from V1 to V2.
+//!
+//! In V1, the [`Vk`](crate::vk::Vk) didn't carry the number of public inputs: the
+//! verifier supported just circuits with exactly one public input.
+//!
+//! In V2, the `Vk` has a new `n_public` field. This migration sets `n_public = 1`
+//! for all the existing vks. Because vks with a single public input are hashed
+//! without the `n_public` field, the storage keys are unchanged and `Tickets`
+//! entries remain valid without modification.
+
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+use sp_core::Get;
+
+/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the storage from V1 to V2.
+///
+/// Add `n_public = 1` to all the existing vks.
+pub struct InnerMigrateV1ToV2<T>(core::marker::PhantomData<T>);
+
+mod v1 {
+    use crate::vk::{Fr, Vk, G1, G2};
+    use codec::{Decode, Encode};
+
+    /// Migration-only struct mirroring the V1 `Vk` (without `n_public`).
+    #[derive(Clone, Decode, Encode)]
+    pub struct OldVk {
+        pub power: u8,
+        pub k1: Fr,
+        pub k2: Fr,
+        pub w: Fr,
+        pub w3: Fr,
+        pub w4: Fr,
+        pub w8: Fr,
+        pub wr: Fr,
+        pub x2: G2,
+        pub c0: G1,
+    }
+
+    impl From<OldVk> for Vk {
+        fn from(vk: OldVk) -> Self {
+            Self {
+                power: vk.power,
+                k1: vk.k1,
+                k2: vk.k2,
+                w: vk.w,
+                w3: vk.w3,
+                w4: vk.w4,
+                w8: vk.w8,
+                wr: vk.wr,
+                x2: vk.x2,
+                c0: vk.c0,
+                n_public: 1,
+            }
+        }
+    }
+
+    /// Migration-only struct mirroring `VkEntry<OldVk>` with accessible fields.
+    #[derive(Decode, Encode)]
+    pub struct OldVkEntry {
+        pub vk: OldVk,
+        pub ref_count: u64,
+    }
+}
+
+mod v2 {
+    use crate::vk::Vk;
+    use codec::{Decode, Encode};
+    use frame_support::{storage_alias, Identity};
+    use sp_core::H256;
+
+    /// Migration-only struct mirroring `VkEntry<Vk>` with accessible fields.
+    #[derive(Encode, Decode)]
+    pub struct NewVkEntry {
+        pub vk: Vk,
+        pub ref_count: u64,
+    }
+
+    #[storage_alias]
+    pub type Vks<T: crate::Config + pallet_verifiers::Config<crate::Fflonk<T>>> =
+        StorageMap<crate::Pallet<T>, Identity, H256, NewVkEntry>;
+}
+
+impl<T> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T>
+where
+    T: pallet_verifiers::Config<crate::Fflonk<T>> + crate::Config,
+{
+    fn on_runtime_upgrade() -> frame_support::weights::Weight {
+        let mut count: u64 = 0;
+
+        v2::Vks::<T>::translate::<v1::OldVkEntry, _>(|_, old_entry| {
+            count += 1;
+            Some(v2::NewVkEntry {
+                vk: old_entry.vk.into(),
+                ref_count: old_entry.ref_count,
+            })
+        });
+
+        log::info!(
+            target: "runtime::fflonk",
+            "Fflonk migration V1->V2: migrated {} VK entries",
+            count,
+        );
+
+        // Per entry: 1 read + 1 write
+        T::DbWeight::get().reads_writes(count, count)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<alloc::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+        let vk_count = v2::Vks::<T>::iter_keys().count() as u64;
+        log::info!(
+            target: "runtime::fflonk",
+            "fflonk pre_upgrade v1->v2: {vk_count} VKs to migrate"
+        );
+        Ok(vk_count.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: alloc::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        let pre_vk_count =
+            u64::decode(&mut state.as_slice()).map_err(|_| "Failed to decode pre_upgrade state")?;
+
+        let post_vk_count = v2::Vks::<T>::iter()
+            .inspect(|(_, entry)| {
+                assert_eq!(
+                    entry.vk.n_public, 1,
+                    "All migrated VKs should have a single public input"
+                );
+            })
+            .count() as u64;
+
+        frame_support::ensure!(
+            post_vk_count == pre_vk_count,
+            "fflonk post_upgrade v1->v2: expected {pre_vk_count} VKs, got {post_vk_count}"
+        );
+
+        log::info!(
+            target: "runtime::fflonk",
+            "fflonk post_upgrade v1->v2: OK, migrated {post_vk_count} VKs"
+        );
+        Ok(())
+    }
+}
+
+/// [`UncheckedOnRuntimeUpgrade`] implementation [`InnerMigrateV1ToV2`] wrapped in a
+/// [`VersionedMigration`], which ensures that:
+/// - The migration only runs once when the on-chain storage version is 1
+/// - The on-chain storage version is updated to `2` after the migration executes
+/// - Reads/Writes from checking/setting the on-chain storage version are accounted for
+pub type MigrateV1ToV2<T> = VersionedMigration<
+    1, // The migration will only execute when the on-chain storage version is 1
+    2, // The on-chain storage version will be set to 2 after the migration is complete
+    InnerMigrateV1ToV2<T>,
+    crate::Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{vk::Vk, Fflonk};
+    use codec::Encode;
+    use frame_support::{
+        derive_impl, parameter_types,
+        sp_runtime::{traits::IdentityLookup, BuildStorage},
+        storage_alias,
+        traits::{fungible::HoldConsideration, LinearStoragePrice, UncheckedOnRuntimeUpgrade},
+        Identity,
+    };
+    use pallet_verifiers::traits::Verifier;
+    use sp_core::{ConstU128, ConstU32, H256};
+
+    include!("resources.rs");
+
+    #[storage_alias]
+    type OldVks<T: crate::Config + pallet_verifiers::Config<crate::Fflonk<T>>> =
+        StorageMap<crate::Pallet<T>, Identity, H256, v1::OldVkEntry>;
+
+    type Balance = u128;
+    type AccountId = u64;
+
+    frame_support::construct_runtime!(
+        pub enum Test {
+            System: frame_system,
+            Balances: pallet_balances,
+            CommonVerifiersPallet: pallet_verifiers::common,
+            FflonkPallet: crate,
+        }
+    );
+
+    #[derive_impl(frame_system::config_preludes::SolochainDefaultConfig as frame_system::DefaultConfig)]
+    impl frame_system::Config for Test {
+        type Block = frame_system::mocking::MockBlockU32<Test>;
+        type AccountId = AccountId;
+        type AccountData = pallet_balances::AccountData<Balance>;
+        type Lookup = IdentityLookup<Self::AccountId>;
+    }
+
+    impl pallet_balances::Config for Test {
+        type RuntimeEvent = RuntimeEvent;
+        type RuntimeHoldReason = RuntimeHoldReason;
+        type RuntimeFreezeReason = RuntimeFreezeReason;
+        type WeightInfo = ();
+        type Balance = Balance;
+        type DustRemoval = ();
+        type ExistentialDeposit = ConstU128<1>;
+        type AccountStore = System;
+        type ReserveIdentifier = [u8; 8];
+        type FreezeIdentifier = RuntimeFreezeReason;
+        type MaxLocks = ConstU32<10>;
+        type MaxReserves = ConstU32<10>;
+        type MaxFreezes = ConstU32<10>;
+        type DoneSlashHandler = ();
+    }
+
+    impl pallet_verifiers::common::Config for Test {
+        type CommonWeightInfo = Test;
+    }
+
+    parameter_types! {
+        pub const BaseDeposit: Balance = 1;
+        pub const PerByteDeposit: Balance = 2;
+        pub const HoldReasonVkRegistration: RuntimeHoldReason =
+            RuntimeHoldReason::CommonVerifiersPallet(
+                pallet_verifiers::common::HoldReason::VkRegistration
+            );
+    }
+
+    impl pallet_verifiers::Config<Fflonk<Test>> for Test {
+        type RuntimeEvent = RuntimeEvent;
+        type OnProofVerified = ();
+        type WeightInfo = crate::FflonkWeight<()>;
+        type Ticket = HoldConsideration<
+            AccountId,
+            Balances,
+            HoldReasonVkRegistration,
+            LinearStoragePrice<BaseDeposit, PerByteDeposit, Balance>,
+        >;
+        #[cfg(feature = "runtime-benchmarks")]
+        type Currency = Balances;
+    }
+
+    impl crate::Config for Test {
+        type MaxPubs = ConstU32<32>;
+    }
+
+    fn test_ext() -> sp_io::TestExternalities {
+        let mut ext = sp_io::TestExternalities::from(
+            frame_system::GenesisConfig::<Test>::default()
+                .build_storage()
+                .unwrap(),
+        );
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+
+    fn old_vk() -> v1::OldVk {
+        let vk = fflonk_key();
+        v1::OldVk {
+            power: vk.power,
+            k1: vk.k1,
+            k2: vk.k2,
+            w: vk.w,
+            w3: vk.w3,
+            w4: vk.w4,
+            w8: vk.w8,
+            wr: vk.wr,
+            x2: vk.x2,
+            c0: vk.c0,
+        }
+    }
+
+    /// Compute the V1 hash: keccak-256 of the scale encoded old vk.
+    fn v1_vk_hash(vk: &v1::OldVk) -> H256 {
+        sp_io::hashing::keccak_256(&vk.encode()).into()
+    }
+
+    #[test]
+    fn migrates_vk_with_a_single_public_input() {
+        test_ext().execute_with(|| {
+            let vk = old_vk();
+            let hash = v1_vk_hash(&vk);
+
+            OldVks::<Test>::insert(
+                hash,
+                v1::OldVkEntry {
+                    vk: vk.clone(),
+                    ref_count: 3,
+                },
+            );
+
+            InnerMigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+            let new_entry =
+                v2::Vks::<Test>::get(hash).expect("VK should be present after migration");
+            assert_eq!(new_entry.vk, Vk::from(vk));
+            assert_eq!(new_entry.vk.n_public, 1);
+            assert_eq!(new_entry.ref_count, 3);
+        });
+    }
+
+    #[test]
+    fn migration_preserves_vk_hash_so_vk_is_retrievable() {
+        test_ext().execute_with(|| {
+            let vk = old_vk();
+            let v1_hash = v1_vk_hash(&vk);
+
+            OldVks::<Test>::insert(
+                v1_hash,
+                v1::OldVkEntry {
+                    vk: vk.clone(),
+                    ref_count: 1,
+                },
+            );
+
+            InnerMigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+            let new_hash = Fflonk::<Test>::vk_hash(&vk.into());
+            assert_eq!(v1_hash, new_hash);
+            assert!(
+                pallet_verifiers::Vks::<Test, Fflonk<Test>>::contains_key(new_hash),
+                "VK should be retrievable through pallet_verifiers::Vks using the same hash"
+            );
+        });
+    }
+
+    #[test]
+    fn migrates_multiple_vks_with_correct_weight() {
+        test_ext().execute_with(|| {
+            let vk1 = old_vk();
+            let hash1 = v1_vk_hash(&vk1);
+            let mut vk2 = old_vk();
+            vk2.power += 1;
+            let hash2 = v1_vk_hash(&vk2);
+
+            OldVks::<Test>::insert(
+                hash1,
+                v1::OldVkEntry {
+                    vk: vk1,
+                    ref_count: 1,
+                },
+            );
+            OldVks::<Test>::insert(
+                hash2,
+                v1::OldVkEntry {
+                    vk: vk2,
+                    ref_count: 5,
+                },
+            );
+
+            let weight = InnerMigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+            assert_eq!(v2::Vks::<Test>::iter().count(), 2);
+            assert_eq!(v2::Vks::<Test>::get(hash1).unwrap().ref_count, 1);
+            assert_eq!(v2::Vks::<Test>::get(hash2).unwrap().ref_count, 5);
+
+            // Weight: 2 reads + 2 writes
+            assert_eq!(
+                weight,
+                <<Test as frame_system::Config>::DbWeight as Get<
+                    frame_support::weights::RuntimeDbWeight,
+                >>::get()
+                .reads_writes(2, 2)
+            );
+        });
+    }
+
+    #[test]
+    fn empty_storage_migration_is_noop() {
+        test_ext().execute_with(|| {
+            let weight = InnerMigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+            assert_eq!(v2::Vks::<Test>::iter().count(), 0);
+            assert_eq!(
+                weight,
+                <<Test as frame_system::Config>::DbWeight as Get<
+                    frame_support::weights::RuntimeDbWeight,
+                >>::get()
+                .reads_writes(0, 0)
+            );
+        });
+    }
+}
@@ -13,7 +13,8 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-static VALID_PUBS: crate::Pubs =
+#[allow(dead_code)]
+static VALID_PUBS: [u8; crate::PUBS_SIZE] =
     hex_literal::hex!("0d69b94acdfaca5bacc248a60b35b925a2374644ce0c1205db68228c8921d9d9");
 
 #[allow(dead_code)]
@@ -15,23 +15,46 @@
 
 #![cfg(test)]
 
-use sp_core::U256;
+use codec::Encode;
+use sp_core::{H256, U256};
 
 use super::*;
 include!("resources.rs");
 
+struct MockRuntime;
+
+impl crate::Config for MockRuntime {
+    type MaxPubs = sp_core::ConstU32<4>;
+}
+
+type Fflonk = crate::Fflonk<MockRuntime>;
+
+fn valid_pubs() -> Pubs {
+    vec![VALID_PUBS]
+}
+
 #[test]
 fn verify_valid_proof() {
     let vk = fflonk_key();
 
-    assert!(Fflonk::verify_proof(&vk, &VALID_PROOF, &VALID_PUBS).is_ok());
+    assert!(Fflonk::verify_proof(&vk, &VALID_PROOF, &valid_pubs()).is_ok());
 }
 
 #[test]
 fn return_the_same_bytes_as_public_inputs() {
     // We use some other bytes to be sure that the pubs are not hardcoded
     let data: [u8; 32] = VALID_PROOF[0..32].try_into().unwrap();
-    assert_eq!(Fflonk::pubs_bytes(&data).as_ref(), &data);
+    assert_eq!(Fflonk::pubs_bytes(&vec![data]).as_ref(), &data);
+}
+
+#[test]
+fn return_the_concatenated_public_inputs_bytes() {
+    let first: [u8; 32] = VALID_PROOF[0..32].try_into().unwrap();
+    let second: [u8; 32] = VALID_PROOF[32..64].try_into().unwrap();
+    assert_eq!(
+        Fflonk::pubs_bytes(&vec![first, second]).as_ref(),
+        &VALID_PROOF[0..64]
+    );
 }
 
 #[test]
@@ -40,6 +63,42 @@ fn validate_valid_vk() {
     assert!(Fflonk::validate_vk(&vk).is_ok())
 }
 
+#[test]
+fn preserve_the_legacy_hash_for_vks_with_a_single_public_input() {
+    let vk = fflonk_key();
+    let mut legacy_encoding = vk.encode();
+    legacy_encoding.truncate(legacy_encoding.len() - 4);
+
+    assert_eq!(
+        Fflonk::vk_hash(&vk),
+        H256::from(sp_io::hashing::keccak_256(&legacy_encoding))
+    );
+}
+
+#[test]
+fn hash_the_number_of_public_inputs_when_greater_than_one() {
+    let vk = fflonk_key();
+    let mut other = vk.clone();
+    other.n_public = 2;
+
+    assert_ne!(Fflonk::vk_hash(&vk), Fflonk::vk_hash(&other));
+    assert_eq!(
+        Fflonk::vk_hash(&other),
+        H256::from(sp_io::hashing::keccak_256(&other.encode()))
+    );
+}
+
+#[test]
+fn reject_vk_with_too_many_public_inputs() {
+    let mut vk = fflonk_key();
+    vk.n_public = <MockRuntime as crate::Config>::MaxPubs::get() + 1;
+
+    assert_eq!(
+        Fflonk::validate_vk(&vk),
+        Err(VerifyError::InvalidVerificationKey)
+    );
+}
+
 #[test]
 fn reject_malformed_vk() {
     let mut vk = fflonk_key();
@@ -59,8 +118,8 @@ mod reject {
     #[test]
     fn invalid_pubs() {
         let vk = fflonk_key();
-        let mut invalid_pubs = VALID_PUBS;
-        invalid_pubs[0] = invalid_pubs[0].wrapping_add(1);
+        let mut invalid_pubs = valid_pubs();
+        invalid_pubs[0][0] = invalid_pubs[0][0].wrapping_add(1);
 
         assert_eq!(
             Fflonk::verify_proof(&vk, &VALID_PROOF, &invalid_pubs),
@@ -76,7 +135,7 @@ mod reject {
         invalid_proof[invalid_proof.len() - 1] = 0x00;
 
         assert_eq!(
-            Fflonk::verify_proof(&vk, &invalid_proof, &VALID_PUBS),
+            Fflonk::verify_proof(&vk, &invalid_proof, &valid_pubs()),
             Err(VerifyError::VerifyError)
         );
     }
@@ -89,8 +148,36 @@ mod reject {
         malformed_proof[0] = 0x07;
 
         assert_eq!(
-            Fflonk::verify_proof(&vk, &malformed_proof, &VALID_PUBS),
+            Fflonk::verify_proof(&vk, &malformed_proof, &valid_pubs()),
             Err(VerifyError::InvalidProofData)
         );
     }
+
+    #[test]
+    fn pubs_that_do_not_match_the_vk_number_of_public_inputs() {
+        let vk = fflonk_key();
+        let pubs = vec![VALID_PUBS; 2];
+
+        assert_eq!(
+            Fflonk::verify_proof(&vk, &VALID_PROOF, &pubs),
+            Err(VerifyError::InvalidInput)
+        );
+        assert_eq!(
+            Fflonk::verify_proof(&vk, &VALID_PROOF, &Vec::new()),
+            Err(VerifyError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn too_many_pubs() {
+        let max_pubs = <MockRuntime as crate::Config>::MaxPubs::get();
+        let mut vk = fflonk_key();
+        vk.n_public = max_pubs + 1;
+        let pubs = vec![VALID_PUBS; vk.n_public as usize];
+
+        assert_eq!(
+            Fflonk::verify_proof(&vk, &VALID_PROOF, &pubs),
+            Err(VerifyError::InvalidInput)
+        );
+    }
 }
@@ -13,6 +13,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use alloc::vec::Vec;
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_core::U256;
@@ -39,6 +40,22 @@ pub struct Vk {
     pub wr: Fr,
     pub x2: G2,
     pub c0: G1,
+    /// Number of public inputs of the circuit (`nPublic`).
+    pub n_public: u32,
+}
+
+impl Vk {
+    /// The bytes used to compute the vk hash. Vks with a single public input are
+    /// serialized without `n_public`, i.e. exactly as before its introduction, so
+    /// their hashes (and the statements that reference them) are unchanged.
+    pub fn legacy_compatible_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.encode();
+        if self.n_public == 1 {
+            // `n_public` is the last field: just drop its encoding.
+            bytes.truncate(bytes.len() - self.n_public.encoded_size());
+        }
+        bytes
+    }
 }
 
 trait IntoBytes {
@@ -149,6 +166,7 @@ impl TryInto<fflonk_verifier::VerificationKey> for Vk {
             wr: self.wr.into(),
             x2: self.x2.try_into()?,
             c0: self.c0.try_into()?,
+            n_public: self.n_public,
         })
     }
 }
@@ -168,6 +186,7 @@ impl Vk {
             wr: self.wr.into(),
             x2: self.x2.try_into_substrate_bn_unchecked()?,
             c0: self.c0.try_into_substrate_bn_unchecked()?,
+            n_public: self.n_public,
         })
     }
 }
@@ -223,6 +242,7 @@ mod test_utils {
                 wr: value.wr.into(),
                 x2: value.x2.into(),
                 c0: value.c0.into(),
+                n_public: value.n_public,
             }
         }
     }
@@ -53,25 +53,29 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for `pallet_fflonk_verifier`.
 pub trait WeightInfo {
-    fn verify_proof() -> Weight;
+    fn verify_proof(n: u32, ) -> Weight;
     fn get_vk() -> Weight;
     fn validate_vk() -> Weight;
-    fn compute_statement_hash() -> Weight;
+    fn compute_statement_hash(n: u32, ) -> Weight;
     fn register_vk() -> Weight;
     fn unregister_vk() -> Weight;
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
-    fn verify_proof() -> Weight {
+    /// The range of component `n` is `[1, 32]`.
+    fn verify_proof(n: u32, ) -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `0`
         //  Estimated: `0`
         // Minimum execution time: 21_418_861_000 picoseconds.
         Weight::from_parts(21_606_950_000, 0)
+            // TODO: the per input slope is an estimate: regenerate once we have valid
+            // multi-input proofs to benchmark.
+            .saturating_add(Weight::from_parts(32_418_274, 0).saturating_mul(n.into()))
     }
     /// Storage: `SettlementFFlonkPallet::Vks` (r:1 w:0)
-    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(553), added: 3028, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(557), added: 3032, mode: `MaxEncodedLen`)
     fn get_vk() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `635`
@@ -87,12 +91,15 @@ impl WeightInfo for () {
         // Minimum execution time: 3_443_110_000 picoseconds.
         Weight::from_parts(3_508_029_000, 0)
     }
-    fn compute_statement_hash() -> Weight {
+    /// The range of component `n` is `[1, 32]`.
+    fn compute_statement_hash(n: u32, ) -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `0`
         //  Estimated: `0`
         // Minimum execution time: 7_176_000 picoseconds.
         Weight::from_parts(7_451_000, 0)
+            // TODO: the per input slope is an estimate: regenerate with the benchmarks.
+            .saturating_add(Weight::from_parts(461_089, 0).saturating_mul(n.into()))
     }
     /// Storage: `SettlementFFlonkPallet::Disabled` (r:1 w:0)
     /// Proof: `SettlementFFlonkPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
@@ -101,7 +108,7 @@ impl WeightInfo for () {
     /// Storage: `Balances::Holds` (r:1 w:1)
     /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
     /// Storage: `SettlementFFlonkPallet::Vks` (r:1 w:1)
-    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(553), added: 3028, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(557), added: 3032, mode: `MaxEncodedLen`)
     fn register_vk() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `42`
@@ -116,7 +123,7 @@ impl WeightInfo for () {
     /// Storage: `Balances::Holds` (r:1 w:1)
     /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
     /// Storage: `SettlementFFlonkPallet::Vks` (r:1 w:1)
-    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(553), added: 3028, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(557), added: 3032, mode: `MaxEncodedLen`)
     fn unregister_vk() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `801`
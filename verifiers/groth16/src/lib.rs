@@ -25,8 +25,7 @@ mod weight;
 use alloc::{borrow::Cow, vec::Vec};
 use core::marker::PhantomData;
 use frame_support::pallet_prelude::Weight;
-pub use groth16::{Curve, ProofWithCurve as Proof, VerificationKeyWithCurve as Vk};
-use hp_groth16::Scalar;
+pub use groth16::{Curve, ProofWithCurve as Proof, Scalar, VerificationKeyWithCurve as Vk};
 use pallet_verifiers::traits::{Verifier, VerifyError};
 
 pub const MAX_NUM_INPUTS: u32 = 64;
@@ -48,6 +47,8 @@ impl<T: Config> Verifier for Groth16<T> {
 
     type Vk = Vk;
 
+    type VkMetadata = ();
+
     fn hash_context_data() -> &'static [u8] {
         b"groth16"
     }
@@ -15,8 +15,9 @@
 
 #![cfg(feature = "runtime-benchmarks")]
 
+use crate::circuit::MAX_DEGREE_BITS;
 use crate::Plonky2 as Verifier;
-use crate::{resources::get_parameterized_test_data, Plonky2Config, MAX_DEGREE_BITS};
+use crate::{resources::get_parameterized_test_data, Plonky2Config};
 use frame_benchmarking::v2::*;
 use frame_system::RawOrigin;
 use pallet_verifiers::traits::Verifier as _;
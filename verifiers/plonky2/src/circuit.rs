@@ -0,0 +1,147 @@
+// Copyright 2024-2026, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Circuit configurations accepted by the verifier and the cost model used to
+//! weight them.
+//!
+//! Verification weights are benchmarked with the standard recursion config
+//! (see [`CircuitConfig::standard_recursion_config`]). A vk can deviate from it
+//! in the number of wires and routed wires, the FRI rate bits and query rounds,
+//! and zero-knowledge; every other parameter must be the standard one. Only
+//! configs that don't need more work than a benchmarked circuit are accepted:
+//! no more query rounds nor opened columns than the standard config, and a low
+//! degree extension no larger than the benchmarked ones. They are weighted as
+//! the standard circuit with the same low degree extension.
+
+use crate::Config;
+use frame_support::weights::Weight;
+use plonky2::field::extension::Extendable;
+use plonky2::hash::hash_types::RichField;
+use plonky2::plonk::circuit_data::{CircuitConfig, CommonCircuitData};
+use plonky2_verifier::validate::ValidateError;
+
+/// Maximum circuit size (log2 of the number of rows of the low degree
+/// extension, at the standard rate bits) accepted: the largest covered by the
+/// `verify_proof` benchmarks.
+pub const MAX_DEGREE_BITS: usize = 19;
+/// Maximum FRI rate bits (log2 of the blowup factor) accepted.
+pub const MAX_FRI_RATE_BITS: usize = 5;
+/// Salt columns added to the wires, partial products and quotient oracles
+/// when zero-knowledge is enabled.
+const ZK_SALT_COLUMNS: usize = 3 * 4;
+
+/// Check that the vk circuit config is one the verifier supports.
+pub fn validate_circuit<F, const D: usize>(
+    common: &CommonCircuitData<F, D>,
+) -> Result<(), ValidateError>
+where
+    F: RichField + Extendable<D>,
+{
+    let standard = CircuitConfig::standard_recursion_config();
+    let config = &common.config;
+    let fri = &config.fri_config;
+    let fri_params = &common.fri_params;
+
+    let mut normalized = config.clone();
+    normalized.num_wires = standard.num_wires;
+    normalized.num_routed_wires = standard.num_routed_wires;
+    normalized.zero_knowledge = standard.zero_knowledge;
+    normalized.fri_config.rate_bits = standard.fri_config.rate_bits;
+    normalized.fri_config.num_query_rounds = standard.fri_config.num_query_rounds;
+
+    let fri_security_bits = fri.rate_bits * fri.num_query_rounds + fri.proof_of_work_bits as usize;
+
+    (normalized == standard
+        && config.num_wires <= standard.num_wires
+        && config.num_routed_wires <= config.num_wires
+        && fri.rate_bits <= MAX_FRI_RATE_BITS
+        && fri_security_bits >= config.security_bits
+        && fri_params.config == *fri
+        && fri_params.hiding == config.zero_knowledge
+        && VerificationCost::new(common).is_benchmarked())
+    .then_some(())
+    .ok_or(ValidateError::UnsupportedCircuitConfig)
+}
+
+/// The parameters of a circuit that drive its verification cost.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VerificationCost {
+    /// Degree bits of the LDE, normalized to the standard rate bits.
+    degree_bits: usize,
+    query_rounds: usize,
+    opened_columns: usize,
+}
+
+impl VerificationCost {
+    pub fn new<F, const D: usize>(common: &CommonCircuitData<F, D>) -> Self
+    where
+        F: RichField + Extendable<D>,
+    {
+        let config = &common.config;
+        Self::from_parts(
+            common.fri_params.degree_bits,
+            config.fri_config.rate_bits,
+            config.fri_config.num_query_rounds,
+            config.num_wires,
+            config.num_routed_wires,
+            config.zero_knowledge,
+        )
+    }
+
+    /// The most expensive circuit accepted by [`validate_circuit`].
+    pub fn worst_case() -> Self {
+        let standard = CircuitConfig::standard_recursion_config();
+        Self::from_parts(
+            MAX_DEGREE_BITS,
+            standard.fri_config.rate_bits,
+            standard.fri_config.num_query_rounds,
+            standard.num_wires,
+            standard.num_routed_wires,
+            standard.zero_knowledge,
+        )
+    }
+
+    fn from_parts(
+        degree_bits: usize,
+        rate_bits: usize,
+        query_rounds: usize,
+        num_wires: usize,
+        num_routed_wires: usize,
+        zero_knowledge: bool,
+    ) -> Self {
+        let standard = CircuitConfig::standard_recursion_config();
+        let salt = if zero_knowledge { ZK_SALT_COLUMNS } else { 0 };
+        Self {
+            degree_bits: degree_bits + rate_bits.saturating_sub(standard.fri_config.rate_bits),
+            query_rounds,
+            opened_columns: num_wires + num_routed_wires + salt,
+        }
+    }
+
+    /// Whether the circuit needs no more work than a benchmarked one: the
+    /// standard config with the same low degree extension.
+    fn is_benchmarked(&self) -> bool {
+        let standard = CircuitConfig::standard_recursion_config();
+        self.degree_bits <= MAX_DEGREE_BITS
+            && self.query_rounds <= standard.fri_config.num_query_rounds
+            && self.opened_columns <= standard.num_wires + standard.num_routed_wires
+    }
+
+    /// The benchmarked weight of the standard circuit with the same low degree
+    /// extension, which bounds the one of this circuit.
+    pub fn weight<T: Config>(&self, config: plonky2_verifier::Plonky2Config) -> Weight {
+        crate::compute_weight::<T>(self.degree_bits, config)
+    }
+}
@@ -28,13 +28,15 @@ use frame_support::weights::Weight;
 use pallet_verifiers::traits::{Verifier, VerifyError};
 use plonky2::field::extension::Extendable;
 use plonky2::hash::hash_types::RichField;
-use plonky2::plonk::circuit_data::CircuitConfig;
 use plonky2::plonk::config::{GenericConfig, KeccakGoldilocksConfig, PoseidonGoldilocksConfig};
 use plonky2_verifier::validate::ValidateError;
 use plonky2_verifier::{deserialize_vk, verify};
 
+use crate::circuit::VerificationCost;
+
 pub mod benchmarking;
 pub mod benchmarking_verify_proof;
+mod circuit;
 mod proof;
 mod resources;
 mod verifier_should;
@@ -49,8 +51,6 @@ pub type Pubs = Vec<u8>;
 pub type Proof<T> = MorphProof<T>;
 pub type Vk<T> = VkWithConfig<T>;
 
-const MAX_DEGREE_BITS: usize = 19;
-
 impl<T: Config> Vk<T> {
     pub fn validate_size(&self) -> Result<(), VerifyError> {
         if self.bytes.len() > T::max_vk_size() as usize {
@@ -102,6 +102,8 @@ impl<T: Config> Verifier for Plonky2<T> {
 
     type Vk = Vk<T>;
 
+    type VkMetadata = ();
+
     fn hash_context_data() -> &'static [u8] {
         b"plonky2"
     }
@@ -121,32 +123,7 @@ impl<T: Config> Verifier for Plonky2<T> {
         let vk = plonky2_verifier::Vk::from(vk.clone());
         let proof = plonky2_verifier::Proof::from(raw_proof.clone());
 
-        let degree_bits = match vk.config {
-            plonky2_verifier::Plonky2Config::Keccak => {
-                const D: usize = 2;
-                type C = KeccakGoldilocksConfig;
-                type F = <C as GenericConfig<D>>::F;
-
-                deserialize_vk::<F, C, D>(&vk.bytes)
-                    .map_err(|_| VerifyError::InvalidVerificationKey)?
-                    .common
-                    .fri_params
-                    .degree_bits
-            }
-            plonky2_verifier::Plonky2Config::Poseidon => {
-                const D: usize = 2;
-                type C = PoseidonGoldilocksConfig;
-                type F = <C as GenericConfig<D>>::F;
-
-                deserialize_vk::<F, C, D>(&vk.bytes)
-                    .map_err(|_| VerifyError::InvalidVerificationKey)?
-                    .common
-                    .fri_params
-                    .degree_bits
-            }
-        };
-
-        let w = compute_weight::<T>(degree_bits, vk.config);
+        let w = validate_vk_config(&vk)?.weight::<T>(vk.config);
 
         verify(&vk, &proof, raw_pubs)
             .inspect_err(|e| log::debug!("Proof verification failed: {e:?}"))
@@ -157,27 +134,7 @@ impl<T: Config> Verifier for Plonky2<T> {
     fn validate_vk(vk: &Self::Vk) -> Result<(), VerifyError> {
         vk.validate_size()?;
 
-        let vk = plonky2_verifier::Vk::from(vk.clone());
-
-        let vk = match vk.config {
-            plonky2_verifier::Plonky2Config::Keccak => {
-                const D: usize = 2;
-                type C = KeccakGoldilocksConfig;
-                type F = <C as GenericConfig<D>>::F;
-
-                validate_vk_inner::<F, C, D>(&vk.bytes)
-            }
-            plonky2_verifier::Plonky2Config::Poseidon => {
-                const D: usize = 2;
-                type C = PoseidonGoldilocksConfig;
-                type F = <C as GenericConfig<D>>::F;
-
-                validate_vk_inner::<F, C, D>(&vk.bytes)
-            }
-        };
-
-        vk.inspect_err(|e| log::debug!("VK validation failed: {e:?}"))
-            .map_err(|_| VerifyError::InvalidVerificationKey)
+        validate_vk_config(&plonky2_verifier::Vk::from(vk.clone())).map(|_| ())
     }
 
     fn pubs_bytes(pubs: &Self::Pubs) -> Cow<'_, [u8]> {
@@ -185,36 +142,52 @@ impl<T: Config> Verifier for Plonky2<T> {
     }
 }
 
-fn validate_vk_inner<F, C, const D: usize>(vk: &[u8]) -> plonky2_verifier::ValidateResult
+/// Deserialize the vk and check its circuit config, returning its verification cost.
+fn validate_vk_config(vk: &plonky2_verifier::Vk) -> Result<VerificationCost, VerifyError> {
+    match vk.config {
+        plonky2_verifier::Plonky2Config::Keccak => {
+            const D: usize = 2;
+            type C = KeccakGoldilocksConfig;
+            type F = <C as GenericConfig<D>>::F;
+
+            validate_vk_inner::<F, C, D>(&vk.bytes)
+        }
+        plonky2_verifier::Plonky2Config::Poseidon => {
+            const D: usize = 2;
+            type C = PoseidonGoldilocksConfig;
+            type F = <C as GenericConfig<D>>::F;
+
+            validate_vk_inner::<F, C, D>(&vk.bytes)
+        }
+    }
+    .inspect_err(|e| log::debug!("VK validation failed: {e:?}"))
+    .map_err(|_| VerifyError::InvalidVerificationKey)
+}
+
+fn validate_vk_inner<F, C, const D: usize>(vk: &[u8]) -> Result<VerificationCost, ValidateError>
 where
     F: RichField + Extendable<D>,
     C: GenericConfig<D, F = F>,
 {
-    deserialize_vk::<F, C, D>(vk)
+    let vk = deserialize_vk::<F, C, D>(vk)
         .map_err(ValidateError::from)
-        .and_then(|vk| {
-            (vk.common.fri_params.degree_bits <= MAX_DEGREE_BITS
-                && vk.common.config == CircuitConfig::standard_recursion_config())
-            .then_some(())
-            .ok_or(ValidateError::UnsupportedCircuitConfig)
-        })
+        .inspect_err(|e| {
+            log::debug!("Cannot deserialize VK, it may use gates unknown to the verifier: {e:?}")
+        })?;
+    circuit::validate_circuit(&vk.common)?;
+    Ok(VerificationCost::new(&vk.common))
 }
 
+/// Benchmarked weight for the given degree bits, clamped to the benchmarked range.
 fn compute_weight<T: Config>(
     degree_bits: usize,
     config: plonky2_verifier::Plonky2Config,
 ) -> Weight {
     match (degree_bits, config) {
-        (1, plonky2_verifier::Plonky2Config::Poseidon) => {
-            T::WeightInfo::verify_proof_poseidon_uncompressed_2()
-        }
-        (1, plonky2_verifier::Plonky2Config::Keccak) => {
-            T::WeightInfo::verify_proof_keccak_uncompressed_2()
-        }
-        (2, plonky2_verifier::Plonky2Config::Poseidon) => {
+        (..=2, plonky2_verifier::Plonky2Config::Poseidon) => {
             T::WeightInfo::verify_proof_poseidon_uncompressed_2()
         }
-        (2, plonky2_verifier::Plonky2Config::Keccak) => {
+        (..=2, plonky2_verifier::Plonky2Config::Keccak) => {
             T::WeightInfo::verify_proof_keccak_uncompressed_2()
         }
         (3, plonky2_verifier::Plonky2Config::Poseidon) => {
@@ -313,13 +286,12 @@ fn compute_weight<T: Config>(
         (18, plonky2_verifier::Plonky2Config::Keccak) => {
             T::WeightInfo::verify_proof_keccak_uncompressed_18()
         }
-        (19, plonky2_verifier::Plonky2Config::Poseidon) => {
+        (_, plonky2_verifier::Plonky2Config::Poseidon) => {
             T::WeightInfo::verify_proof_poseidon_uncompressed_19()
         }
-        (19, plonky2_verifier::Plonky2Config::Keccak) => {
+        (_, plonky2_verifier::Plonky2Config::Keccak) => {
             T::WeightInfo::verify_proof_keccak_uncompressed_19()
         }
-        _ => panic!("Invalid value given for degree_bits."),
     }
 }
 
@@ -331,7 +303,10 @@ impl<T: Config, W: WeightInfo> pallet_verifiers::WeightInfo<Plonky2<T>> for Plon
         _pubs: &<Plonky2<T> as Verifier>::Pubs,
     ) -> Weight {
         // TODO: Update once compression is supported
-        T::WeightInfo::verify_proof_poseidon_uncompressed_19()
+        let worst_case = VerificationCost::worst_case();
+        worst_case
+            .weight::<T>(plonky2_verifier::Plonky2Config::Poseidon)
+            .max(worst_case.weight::<T>(plonky2_verifier::Plonky2Config::Keccak))
         // W::verify_proof()
     }
 
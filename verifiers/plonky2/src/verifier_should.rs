@@ -16,6 +16,7 @@
 #![cfg(test)]
 
 use super::*;
+use crate::circuit::MAX_DEGREE_BITS;
 use crate::resources::*;
 use frame_support::assert_ok;
 use rstest::*;
@@ -34,6 +35,44 @@ fn worst_case_test_data() -> TestData<MockConfig> {
     get_parameterized_test_data(MAX_DEGREE_BITS, crate::vk::Plonky2Config::Poseidon)
 }
 
+/// Offsets of the circuit config fields in the serialized Poseidon vk of
+/// `worst_case_test_data`.
+mod offset {
+    pub const NUM_WIRES: usize = 0x228;
+    pub const NUM_ROUTED_WIRES: usize = 0x230;
+    pub const ZERO_KNOWLEDGE: usize = 0x259;
+    pub const RATE_BITS: usize = 0x25a;
+    pub const NUM_QUERY_ROUNDS: usize = 0x26a;
+    pub const FRI_PARAMS_RATE_BITS: usize = 0x287;
+    pub const FRI_PARAMS_NUM_QUERY_ROUNDS: usize = 0x297;
+    pub const DEGREE_BITS: usize = 0x2dc;
+    pub const HIDING: usize = 0x2e4;
+    pub const FIRST_GATE_TAG: usize = 0x60d;
+}
+
+fn set_u64(bytes: &mut [u8], offset: usize, value: u64) {
+    bytes[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+}
+
+fn set_fri_config(bytes: &mut [u8], rate_bits: u64, num_query_rounds: u64) {
+    set_u64(bytes, offset::RATE_BITS, rate_bits);
+    set_u64(bytes, offset::FRI_PARAMS_RATE_BITS, rate_bits);
+    set_u64(bytes, offset::NUM_QUERY_ROUNDS, num_query_rounds);
+    set_u64(bytes, offset::FRI_PARAMS_NUM_QUERY_ROUNDS, num_query_rounds);
+}
+
+fn set_zero_knowledge(bytes: &mut [u8], zero_knowledge: bool) {
+    bytes[offset::ZERO_KNOWLEDGE] = zero_knowledge as u8;
+    bytes[offset::HIDING] = zero_knowledge as u8;
+}
+
+fn vk_weight(vk: &Vk<MockConfig>) -> Weight {
+    let vk = plonky2_verifier::Vk::from(vk.clone());
+    validate_vk_config(&vk)
+        .unwrap()
+        .weight::<MockConfig>(vk.config)
+}
+
 #[rstest]
 fn verify_valid_proof(worst_case_test_data: TestData<MockConfig>) {
     assert_ok!(Plonky2::<MockConfig>::verify_proof(
@@ -67,6 +106,101 @@ fn compute_correct_weight_for_proof() {
     );
 }
 
+mod custom_config {
+    use super::*;
+
+    /// A vk with the same serialization layout as `worst_case_test_data`,
+    /// leaving room for one more rate bit.
+    #[fixture]
+    fn headroom_test_data() -> TestData<MockConfig> {
+        get_parameterized_test_data(MAX_DEGREE_BITS - 1, crate::vk::Plonky2Config::Poseidon)
+    }
+
+    fn set_wires(bytes: &mut [u8], num_wires: u64, num_routed_wires: u64) {
+        set_u64(bytes, offset::NUM_WIRES, num_wires);
+        set_u64(bytes, offset::NUM_ROUTED_WIRES, num_routed_wires);
+    }
+
+    #[rstest]
+    #[case::wider_routed_wires(|vk: &mut Vec<u8>| set_wires(vk, 110, 105))]
+    #[case::fewer_wires(|vk: &mut Vec<u8>| set_wires(vk, 100, 60))]
+    #[case::zero_knowledge(|vk: &mut Vec<u8>| {
+        set_wires(vk, 135, 68);
+        set_zero_knowledge(vk, true)
+    })]
+    #[case::fewer_query_rounds(|vk: &mut Vec<u8>| set_fri_config(vk, 4, 21))]
+    fn should_validate_vk(
+        headroom_test_data: TestData<MockConfig>,
+        #[case] patch: impl Fn(&mut Vec<u8>),
+    ) {
+        let mut vk = headroom_test_data.vk;
+        patch(&mut vk.bytes);
+
+        assert_ok!(Plonky2::<MockConfig>::validate_vk(&vk));
+    }
+
+    #[rstest]
+    fn should_use_benchmarked_weight_for_standard_config(
+        #[values(Plonky2Config::Keccak, Plonky2Config::Poseidon)] config: Plonky2Config,
+        #[values(2, 10, MAX_DEGREE_BITS)] degree_bits: usize,
+    ) {
+        let test_data = get_parameterized_test_data::<MockConfig>(degree_bits, config);
+
+        assert_eq!(
+            vk_weight(&test_data.vk),
+            compute_weight::<MockConfig>(degree_bits, config.into())
+        );
+    }
+
+    #[rstest]
+    #[case::wider_routed_wires(|vk: &mut Vec<u8>| set_wires(vk, 110, 105))]
+    #[case::zero_knowledge(|vk: &mut Vec<u8>| {
+        set_wires(vk, 135, 68);
+        set_zero_knowledge(vk, true)
+    })]
+    fn should_weight_as_standard_config(
+        headroom_test_data: TestData<MockConfig>,
+        #[case] patch: impl Fn(&mut Vec<u8>),
+    ) {
+        let mut vk = headroom_test_data.vk;
+        let standard = vk_weight(&vk);
+        patch(&mut vk.bytes);
+
+        assert_eq!(vk_weight(&vk), standard);
+    }
+
+    #[rstest]
+    fn should_weight_higher_rate_bits_as_larger_standard_circuit(
+        headroom_test_data: TestData<MockConfig>,
+    ) {
+        let mut vk = headroom_test_data.vk;
+        set_fri_config(&mut vk.bytes, 4, 21);
+
+        assert_eq!(
+            vk_weight(&vk),
+            compute_weight::<MockConfig>(MAX_DEGREE_BITS, vk.config.into())
+        );
+    }
+
+    #[rstest]
+    fn should_weight_less_than_pre_dispatch_weight(headroom_test_data: TestData<MockConfig>) {
+        let TestData {
+            mut vk,
+            proof,
+            pubs,
+        } = headroom_test_data;
+        set_wires(&mut vk.bytes, 135, 68);
+        set_zero_knowledge(&mut vk.bytes, true);
+        set_fri_config(&mut vk.bytes, 4, 21);
+
+        let pre_dispatch = <Plonky2Weight<()> as pallet_verifiers::WeightInfo<
+            Plonky2<MockConfig>,
+        >>::verify_proof(&proof, &pubs);
+
+        assert!(vk_weight(&vk).all_lte(pre_dispatch));
+    }
+}
+
 mod reject {
     use frame_support::assert_err;
     use pallet_verifiers::traits::VerifyError;
@@ -185,4 +319,58 @@ mod reject {
             VerifyError::InvalidInput
         );
     }
+
+    #[rstest]
+    #[case::routed_wires_over_wires(|vk: &mut Vec<u8>| set_u64(vk, offset::NUM_ROUTED_WIRES, 136))]
+    #[case::too_many_wires(|vk: &mut Vec<u8>| set_u64(vk, offset::NUM_WIRES, 136))]
+    #[case::too_many_opened_columns(|vk: &mut Vec<u8>| set_u64(vk, offset::NUM_ROUTED_WIRES, 81))]
+    #[case::zero_knowledge_with_standard_wires(|vk: &mut Vec<u8>| set_zero_knowledge(vk, true))]
+    #[case::zero_rate_bits(|vk: &mut Vec<u8>| set_fri_config(vk, 0, 28))]
+    #[case::lower_rate_bits(|vk: &mut Vec<u8>| set_fri_config(vk, 1, 84))]
+    #[case::too_large_rate_bits(|vk: &mut Vec<u8>| set_fri_config(vk, 6, 14))]
+    #[case::too_large_lde(|vk: &mut Vec<u8>| set_fri_config(vk, 4, 21))]
+    #[case::too_many_query_rounds(|vk: &mut Vec<u8>| set_fri_config(vk, 3, 29))]
+    #[case::insufficient_security(|vk: &mut Vec<u8>| set_fri_config(vk, 3, 27))]
+    #[case::mismatched_fri_params(|vk: &mut Vec<u8>| set_u64(vk, offset::NUM_QUERY_ROUNDS, 40))]
+    #[case::mismatched_hiding(|vk: &mut Vec<u8>| vk[offset::ZERO_KNOWLEDGE] = 1)]
+    fn should_not_validate_vk_with_unsupported_config(
+        worst_case_test_data: TestData<MockConfig>,
+        #[case] patch: impl Fn(&mut Vec<u8>),
+    ) {
+        let TestData {
+            mut vk,
+            proof,
+            pubs,
+        } = worst_case_test_data;
+        patch(&mut vk.bytes);
+
+        assert_err!(
+            Plonky2::<MockConfig>::validate_vk(&vk),
+            VerifyError::InvalidVerificationKey
+        );
+        assert_err!(
+            Plonky2::<MockConfig>::verify_proof(&vk, &proof, &pubs),
+            VerifyError::InvalidVerificationKey
+        );
+    }
+
+    #[rstest]
+    fn should_not_validate_vk_with_unknown_gate(worst_case_test_data: TestData<MockConfig>) {
+        let TestData {
+            mut vk,
+            proof,
+            pubs,
+        } = worst_case_test_data;
+        vk.bytes[offset::FIRST_GATE_TAG..offset::FIRST_GATE_TAG + 4]
+            .copy_from_slice(&u32::MAX.to_le_bytes());
+
+        assert_err!(
+            Plonky2::<MockConfig>::validate_vk(&vk),
+            VerifyError::InvalidVerificationKey
+        );
+        assert_err!(
+            Plonky2::<MockConfig>::verify_proof(&vk, &proof, &pubs),
+            VerifyError::InvalidVerificationKey
+        );
+    }
 }
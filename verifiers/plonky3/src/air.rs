@@ -0,0 +1,72 @@
+// Copyright 2024-2026, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The AIR interpreting an [`AirDescriptor`]: it evaluates the expression
+//! graph with whatever builder the STARK verifier provides.
+
+use crate::vk::{AirDescriptor, Node, Row};
+use alloc::vec::Vec;
+use p3_air::{Air, AirBuilderWithPublicValues, BaseAir};
+use p3_field::PrimeCharacteristicRing;
+use p3_matrix::Matrix;
+
+/// An AIR backed by a validated [`AirDescriptor`].
+#[derive(Clone, Copy)]
+pub struct DescribedAir<'a>(pub &'a AirDescriptor);
+
+impl<F> BaseAir<F> for DescribedAir<'_> {
+    fn width(&self) -> usize {
+        self.0.width as usize
+    }
+}
+
+impl<AB: AirBuilderWithPublicValues> Air<AB> for DescribedAir<'_> {
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0).expect("The matrix is empty?");
+        let next = main.row_slice(1).expect("The matrix only has 1 row?");
+        let public_values = builder.public_values().to_vec();
+
+        // Operands always precede the node that uses them: the descriptor has
+        // been validated.
+        let mut values: Vec<AB::Expr> = Vec::with_capacity(self.0.nodes.len());
+        for node in &self.0.nodes {
+            let value = match *node {
+                Node::Constant(c) => AB::Expr::from_u32(c),
+                Node::Main {
+                    row: Row::Local,
+                    column,
+                } => local[column as usize].clone().into(),
+                Node::Main {
+                    row: Row::Next,
+                    column,
+                } => next[column as usize].clone().into(),
+                Node::Public(i) => public_values[i as usize].into(),
+                Node::IsFirstRow => builder.is_first_row(),
+                Node::IsLastRow => builder.is_last_row(),
+                Node::IsTransition => builder.is_transition(),
+                Node::Add(a, b) => values[a as usize].clone() + values[b as usize].clone(),
+                Node::Sub(a, b) => values[a as usize].clone() - values[b as usize].clone(),
+                Node::Mul(a, b) => values[a as usize].clone() * values[b as usize].clone(),
+                Node::Neg(a) => -values[a as usize].clone(),
+            };
+            values.push(value);
+        }
+
+        for &constraint in &self.0.constraints {
+            builder.assert_zero(values[constraint as usize].clone());
+        }
+    }
+}
@@ -0,0 +1,250 @@
+// Copyright 2024-2026, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::cost::{MAX_LDE_HEIGHT, MAX_OPENED_COLUMNS};
+use crate::resources::{products_air, worst_case_vk};
+use crate::stark::{baby_bear, koala_bear};
+use crate::Plonky3 as Verifier;
+use crate::{Proof, StarkKind, MAX_AIR_NODES, MAX_DEGREE_BITS};
+use alloc::{vec, vec::Vec};
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+use pallet_verifiers::traits::Verifier as _;
+use pallet_verifiers::{benchmarking_utils, VkOrHash};
+
+pub struct Pallet<T: Config>(crate::Pallet<T>);
+pub trait Config: crate::Config {}
+impl<T: crate::Config> Config for T {}
+pub type Call<T> = pallet_verifiers::Call<T, Verifier<T>>;
+
+// A proof is weighted by `crate::cost` on its AIRs and traces: here we benchmark its
+// building blocks, the out of domain evaluation of an AIR of `n` nodes opening `c`
+// columns and the Merkle opening of `c` columns in a low degree extension of `2^h`
+// rows done by each FRI query.
+#[allow(clippy::multiple_bound_locations)]
+#[benchmarks(where T: pallet_verifiers::Config<Verifier<T>>)]
+mod benchmarks {
+    use super::*;
+
+    benchmarking_utils!(Verifier<T>, crate::Config);
+
+    #[benchmark]
+    fn verify_air_baby_bear(
+        c: Linear<1, MAX_OPENED_COLUMNS>,
+        n: Linear<1, { MAX_AIR_NODES as u32 }>,
+    ) {
+        let air = products_air(c as u16, n as usize);
+
+        #[block]
+        {
+            baby_bear::eval_air(&air, MAX_DEGREE_BITS);
+        }
+    }
+
+    #[benchmark]
+    fn verify_query_baby_bear(h: Linear<1, MAX_LDE_HEIGHT>, c: Linear<1, MAX_OPENED_COLUMNS>) {
+        let r;
+        #[block]
+        {
+            r = baby_bear::open_row(h as usize, c as usize);
+        }
+        assert!(r);
+    }
+
+    #[benchmark]
+    fn verify_air_koala_bear(
+        c: Linear<1, MAX_OPENED_COLUMNS>,
+        n: Linear<1, { MAX_AIR_NODES as u32 }>,
+    ) {
+        let air = products_air(c as u16, n as usize);
+
+        #[block]
+        {
+            koala_bear::eval_air(&air, MAX_DEGREE_BITS);
+        }
+    }
+
+    #[benchmark]
+    fn verify_query_koala_bear(h: Linear<1, MAX_LDE_HEIGHT>, c: Linear<1, MAX_OPENED_COLUMNS>) {
+        let r;
+        #[block]
+        {
+            r = koala_bear::open_row(h as usize, c as usize);
+        }
+        assert!(r);
+    }
+
+    #[benchmark]
+    fn get_vk() {
+        let vk = worst_case_vk::<T>();
+        let hash = sp_core::H256::repeat_byte(2);
+
+        insert_vk_anonymous::<T>(vk, hash);
+
+        let r;
+        #[block]
+        {
+            r = do_get_vk::<T>(&hash)
+        };
+        assert!(r.is_some());
+    }
+
+    #[benchmark]
+    fn validate_vk() {
+        let vk = worst_case_vk::<T>();
+
+        let r;
+        #[block]
+        {
+            r = do_validate_vk::<T>(&vk)
+        };
+        assert!(r.is_ok());
+    }
+
+    #[benchmark]
+    fn compute_statement_hash() {
+        let proof = Proof::new(StarkKind::BatchStark, Vec::new());
+        let pubs = vec![0; T::max_pubs_size() as usize];
+        let vk = VkOrHash::Vk(worst_case_vk::<T>().into());
+
+        #[block]
+        {
+            do_compute_statement_hash::<T>(&vk, &proof, &pubs);
+        }
+    }
+
+    #[benchmark]
+    fn register_vk() {
+        // setup code
+        let caller = funded_account::<T>();
+        let vk = worst_case_vk::<T>();
+
+        #[extrinsic_call]
+        register_vk(RawOrigin::Signed(caller), vk.clone().into());
+
+        // Verify
+        assert!(do_get_vk::<T>(&do_vk_hash::<T>(&vk)).is_some());
+    }
+
+    #[benchmark]
+    fn unregister_vk() {
+        // setup code
+        let caller = funded_account::<T>();
+        let hash = sp_core::H256::repeat_byte(2);
+        let vk = worst_case_vk::<T>();
+
+        insert_vk::<T>(caller.clone(), vk, hash);
+
+        #[extrinsic_call]
+        unregister_vk(RawOrigin::Signed(caller), hash);
+
+        // Verify
+        assert!(do_get_vk::<T>(&hash).is_none());
+    }
+
+    impl_benchmark_test_suite!(Pallet, mock::test_ext(), super::mock::Test);
+}
+
+#[cfg(test)]
+mod mock {
+    use frame_support::{
+        derive_impl, parameter_types,
+        sp_runtime::{traits::IdentityLookup, BuildStorage},
+        traits::{fungible::HoldConsideration, LinearStoragePrice},
+    };
+    use sp_core::{ConstU128, ConstU32};
+
+    type Balance = u128;
+    type AccountId = u64;
+
+    // Configure a mock runtime to test the pallet.
+    frame_support::construct_runtime!(
+        pub enum Test
+        {
+            System: frame_system,
+            Balances: pallet_balances,
+            CommonVerifiersPallet: pallet_verifiers::common,
+            VerifierPallet: crate,
+        }
+    );
+
+    impl crate::Config for Test {
+        type MaxProofSize = ConstU32<1000000>;
+        type MaxPubsSize = ConstU32<1000000>;
+        type MaxVkSize = ConstU32<1000000>;
+        type WeightInfo = ();
+    }
+
+    #[derive_impl(frame_system::config_preludes::SolochainDefaultConfig as frame_system::DefaultConfig)]
+    impl frame_system::Config for Test {
+        type Block = frame_system::mocking::MockBlockU32<Test>;
+        type AccountId = AccountId;
+        type AccountData = pallet_balances::AccountData<Balance>;
+        type Lookup = IdentityLookup<Self::AccountId>;
+    }
+
+    parameter_types! {
+        pub const BaseDeposit: Balance = 1;
+        pub const PerByteDeposit: Balance = 2;
+        pub const HoldReasonVkRegistration: RuntimeHoldReason = RuntimeHoldReason::CommonVerifiersPallet(pallet_verifiers::common::HoldReason::VkRegistration);
+    }
+
+    impl pallet_verifiers::Config<crate::Plonky3<Test>> for Test {
+        type RuntimeEvent = RuntimeEvent;
+        type OnProofVerified = ();
+        type WeightInfo = crate::Plonky3Weight<()>;
+        type Ticket = HoldConsideration<
+            AccountId,
+            Balances,
+            HoldReasonVkRegistration,
+            LinearStoragePrice<BaseDeposit, PerByteDeposit, Balance>,
+        >;
+        type Currency = Balances;
+    }
+
+    impl pallet_balances::Config for Test {
+        type RuntimeEvent = RuntimeEvent;
+        type RuntimeHoldReason = RuntimeHoldReason;
+        type RuntimeFreezeReason = RuntimeFreezeReason;
+        type WeightInfo = ();
+        type Balance = Balance;
+        type DustRemoval = ();
+        type ExistentialDeposit = ConstU128<1>;
+        type AccountStore = System;
+        type ReserveIdentifier = [u8; 8];
+        type FreezeIdentifier = RuntimeFreezeReason;
+        type MaxLocks = ConstU32<10>;
+        type MaxReserves = ConstU32<10>;
+        type MaxFreezes = ConstU32<10>;
+        type DoneSlashHandler = ();
+    }
+
+    impl pallet_verifiers::common::Config for Test {
+        type CommonWeightInfo = Test;
+    }
+
+    /// Build genesis storage according to the mock runtime.
+    pub fn test_ext() -> sp_io::TestExternalities {
+        let mut ext = sp_io::TestExternalities::from(
+            frame_system::GenesisConfig::<Test>::default()
+                .build_storage()
+                .unwrap(),
+        );
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright 2024-2026, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The cost model used to weight the proofs.
+//!
+//! Each proven trace is weighted as the evaluation of its AIR constraints at
+//! the out of domain point plus one FRI query per round. The AIR evaluation
+//! depends on the number of nodes of the expression graph and on the opened
+//! columns: the main trace ones plus the quotient chunks, at most
+//! `2^log_blowup` elements of the challenge field. A query opens the main
+//! trace and the quotient rows in the low degree extension (trace degree bits
+//! plus the FRI blowup) and then a pair of folded values in each FRI commit
+//! phase tree, one level shorter at each round. Every opening walks a Merkle
+//! path as long as its tree height.
+
+use crate::vk::{Field, Vk};
+use crate::{WeightInfo, MAX_AIRS, MAX_AIR_NODES, MAX_AIR_WIDTH, MAX_LOG_BLOWUP, MAX_NUM_QUERIES};
+use alloc::{vec, vec::Vec};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::TypeInfo;
+use frame_support::weights::Weight;
+use frame_support::BoundedVec;
+use sp_core::ConstU32;
+
+/// Degree of the extension field the challenges are drawn from.
+const CHALLENGE_DEGREE: u32 = 4;
+/// Base field elements opened in a FRI commit phase tree: a pair of challenges.
+const FRI_LEAF_COLUMNS: u32 = 2 * CHALLENGE_DEGREE;
+/// Maximum number of columns opened for a trace.
+#[cfg(feature = "runtime-benchmarks")]
+pub const MAX_OPENED_COLUMNS: u32 = MAX_AIR_WIDTH as u32 + (CHALLENGE_DEGREE << MAX_LOG_BLOWUP);
+/// Maximum height (log2) of a low degree extension.
+#[cfg(feature = "runtime-benchmarks")]
+pub const MAX_LDE_HEIGHT: u32 = crate::MAX_DEGREE_BITS as u32 + MAX_LOG_BLOWUP as u32;
+
+/// The shape of an AIR that drives the cost of verifying its traces.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct AirCost {
+    /// Number of main trace columns.
+    pub width: u16,
+    /// Number of nodes of the expression graph.
+    pub nodes: u16,
+}
+
+/// The vk parameters that drive the verification cost of its proofs. It's stored
+/// next to the registered vks to weight the proofs that reference them.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct VerificationCost {
+    pub field: Field,
+    pub log_blowup: u8,
+    pub num_queries: u16,
+    pub airs: BoundedVec<AirCost, ConstU32<{ MAX_AIRS as u32 }>>,
+}
+
+impl VerificationCost {
+    pub fn new<T>(vk: &Vk<T>) -> Self {
+        let airs = vk
+            .airs
+            .iter()
+            .map(|air| AirCost {
+                width: air.width,
+                nodes: air.nodes.len().try_into().unwrap_or(u16::MAX),
+            })
+            .collect::<Vec<_>>();
+        Self {
+            field: vk.field,
+            log_blowup: vk.fri.log_blowup,
+            num_queries: vk.fri.num_queries,
+            airs: BoundedVec::truncate_from(airs),
+        }
+    }
+
+    /// The most expensive vk over `field` with the given number of AIRs accepted by
+    /// the verifier.
+    pub fn worst_case(field: Field, airs: usize) -> Self {
+        let air = AirCost {
+            width: MAX_AIR_WIDTH,
+            nodes: MAX_AIR_NODES as u16,
+        };
+        Self {
+            field,
+            log_blowup: MAX_LOG_BLOWUP,
+            num_queries: MAX_NUM_QUERIES,
+            airs: BoundedVec::truncate_from(vec![air; airs.min(MAX_AIRS)]),
+        }
+    }
+
+    /// The weight of a proof of traces with the given degree bits, one for each AIR.
+    pub fn weight<W: WeightInfo>(&self, degree_bits: &[usize]) -> Weight {
+        self.airs
+            .iter()
+            .zip(degree_bits)
+            .fold(Weight::zero(), |w, (air, &d)| {
+                w.saturating_add(self.instance_weight::<W>(air, d))
+            })
+    }
+
+    fn instance_weight<W: WeightInfo>(&self, air: &AirCost, degree_bits: usize) -> Weight {
+        let quotient_columns = CHALLENGE_DEGREE << self.log_blowup;
+        let columns = air.width as u32 + quotient_columns;
+        let height = degree_bits as u32 + self.log_blowup as u32;
+        let nodes = air.nodes as u32;
+        let (verify_air, verify_query): (_, fn(u32, u32) -> Weight) = match self.field {
+            Field::BabyBear => (
+                W::verify_air_baby_bear(columns, nodes),
+                W::verify_query_baby_bear,
+            ),
+            Field::KoalaBear => (
+                W::verify_air_koala_bear(columns, nodes),
+                W::verify_query_koala_bear,
+            ),
+        };
+        // The commit phase folds down to the blowup: assume no final polynomial.
+        let query = (self.log_blowup as u32..height).fold(
+            verify_query(height, air.width as u32)
+                .saturating_add(verify_query(height, quotient_columns)),
+            |w, h| w.saturating_add(verify_query(h, FRI_LEAF_COLUMNS)),
+        );
+        verify_air.saturating_add(query.saturating_mul(self.num_queries as u64))
+    }
+}
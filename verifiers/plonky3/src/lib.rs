@@ -0,0 +1,257 @@
+// Copyright 2024-2026, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! A [Plonky3](https://github.com/Plonky3/Plonky3) verifier for uni-stark and
+//! batch-stark proofs over BabyBear and KoalaBear, committed with Poseidon2 and
+//! FRI. The AIRs are not compiled in the verifier: the vk describes them (see
+//! [`AirDescriptor`]) along with the FRI parameters used by the prover. Proofs
+//! are the `postcard` serialization of `p3_uni_stark::Proof` or
+//! `p3_batch_stark::BatchProof`.
+
+extern crate alloc;
+
+pub use crate::cost::{AirCost, VerificationCost};
+pub use crate::proof::{Proof, StarkKind};
+pub use crate::vk::{AirDescriptor, Field, FriParams, Node, Row, Vk};
+
+use alloc::{borrow::Cow, vec::Vec};
+use codec::Encode;
+use core::marker::PhantomData;
+use frame_support::ensure;
+use frame_support::pallet_prelude::Get;
+use frame_support::weights::Weight;
+use pallet_verifiers::traits::{Verifier, VerifyError};
+
+mod air;
+pub mod benchmarking;
+mod cost;
+mod proof;
+mod resources;
+mod stark;
+mod verifier_should;
+mod vk;
+mod weight;
+
+pub use weight::WeightInfo;
+
+/// The public values of all the vk AIRs, in order, as little endian `u32`
+/// canonical field elements.
+pub type Pubs = Vec<u8>;
+
+/// Maximum trace degree bits (log2 of the trace height) accepted.
+pub const MAX_DEGREE_BITS: usize = 22;
+/// Maximum number of AIRs in a vk, i.e. of instances in a batch-stark proof.
+pub const MAX_AIRS: usize = 4;
+/// Maximum number of main trace columns of an AIR.
+pub const MAX_AIR_WIDTH: u16 = 1024;
+/// Maximum number of nodes in an AIR expression graph.
+pub const MAX_AIR_NODES: usize = u16::MAX as usize;
+/// Minimum FRI blowup (log2) accepted.
+pub const MIN_LOG_BLOWUP: u8 = 1;
+/// Maximum FRI blowup (log2) accepted.
+pub const MAX_LOG_BLOWUP: u8 = 3;
+/// Maximum FRI final polynomial length (log2) accepted.
+pub const MAX_LOG_FINAL_POLY_LEN: u8 = 8;
+/// Maximum FRI query rounds accepted.
+pub const MAX_NUM_QUERIES: u16 = 128;
+/// Maximum FRI proof of work bits accepted.
+pub const MAX_PROOF_OF_WORK_BITS: u8 = 30;
+/// Minimum conjectured security of the FRI parameters.
+pub const MIN_SECURITY_BITS: u32 = 100;
+
+impl<T: Config> Vk<T> {
+    pub fn validate_size(&self) -> Result<(), VerifyError> {
+        if self.airs.encoded_size() > T::max_vk_size() as usize {
+            return Err(VerifyError::InvalidVerificationKey);
+        }
+        Ok(())
+    }
+}
+
+pub trait Config: 'static {
+    /// Maximum number of bytes contained in the proof (otherwise rejected)
+    type MaxProofSize: Get<u32>;
+    /// Maximum number of bytes contained in the public inputs (otherwise rejected)
+    type MaxPubsSize: Get<u32>;
+    /// Maximum number of bytes of the encoded AIR descriptors (otherwise rejected)
+    type MaxVkSize: Get<u32>;
+    /// Weight info used to compute the verify proof weight
+    type WeightInfo: WeightInfo;
+
+    fn max_proof_size() -> u32 {
+        Self::MaxProofSize::get()
+    }
+
+    fn max_pubs_size() -> u32 {
+        Self::MaxPubsSize::get()
+    }
+
+    fn max_vk_size() -> u32 {
+        Self::MaxVkSize::get()
+    }
+}
+
+#[pallet_verifiers::verifier]
+pub struct Plonky3<T>;
+
+impl<T: Config> Verifier for Plonky3<T> {
+    type Proof = Proof;
+
+    type Pubs = Pubs;
+
+    type Vk = Vk<T>;
+
+    /// The verification cost of the vk proofs: `None` only for the default value, which
+    /// is charged as the worst case.
+    type VkMetadata = Option<VerificationCost>;
+
+    fn hash_context_data() -> &'static [u8] {
+        b"plonky3"
+    }
+
+    fn verify_proof(
+        vk: &Self::Vk,
+        proof: &Self::Proof,
+        pubs: &Self::Pubs,
+    ) -> Result<Option<Weight>, VerifyError> {
+        ensure!(
+            proof.bytes.len() <= T::max_proof_size() as usize,
+            VerifyError::InvalidProofData
+        );
+        ensure!(
+            pubs.len() <= T::max_pubs_size() as usize,
+            VerifyError::InvalidInput
+        );
+        Self::validate_vk(vk)?;
+
+        let pubs = split_pubs(vk, pubs)?;
+        let degree_bits = match vk.field {
+            Field::BabyBear => stark::baby_bear::verify(&vk.fri, proof, &vk.airs, &pubs),
+            Field::KoalaBear => stark::koala_bear::verify(&vk.fri, proof, &vk.airs, &pubs),
+        }?;
+
+        Ok(Some(
+            VerificationCost::new(vk).weight::<T::WeightInfo>(&degree_bits),
+        ))
+    }
+
+    fn validate_vk(vk: &Self::Vk) -> Result<(), VerifyError> {
+        vk.validate_size()?;
+
+        vk.validate()
+            .inspect_err(|e| log::debug!("VK validation failed: {e:?}"))
+            .map_err(|_| VerifyError::InvalidVerificationKey)
+    }
+
+    fn vk_metadata(vk: &Self::Vk) -> Self::VkMetadata {
+        Some(VerificationCost::new(vk))
+    }
+
+    fn pubs_bytes(pubs: &Self::Pubs) -> Cow<'_, [u8]> {
+        Cow::Borrowed(pubs)
+    }
+}
+
+/// Split the public values among the vk AIRs, checking they are canonical
+/// field elements.
+fn split_pubs<T>(vk: &Vk<T>, pubs: &[u8]) -> Result<Vec<Vec<u32>>, VerifyError> {
+    let num_public_values = vk
+        .airs
+        .iter()
+        .map(|air| air.num_public_values as usize)
+        .sum::<usize>();
+    ensure!(
+        pubs.len() == num_public_values * 4,
+        VerifyError::InvalidInput
+    );
+
+    let order = vk.field.order();
+    let mut values = pubs
+        .chunks_exact(4)
+        .map(|c| u32::from_le_bytes(c.try_into().expect("Chunks are 4 bytes long. qed")));
+    vk.airs
+        .iter()
+        .map(|air| {
+            (0..air.num_public_values)
+                .map(|_| {
+                    values
+                        .next()
+                        .filter(|&v| v < order)
+                        .ok_or(VerifyError::InvalidInput)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// The struct to use in runtime pallet configuration to map the weight computed by this crate
+/// benchmarks to the weight needed by the `pallet-verifiers`.
+pub struct Plonky3Weight<W: WeightInfo>(PhantomData<W>);
+
+impl<T: Config, W: WeightInfo> pallet_verifiers::WeightInfo<Plonky3<T>> for Plonky3Weight<W> {
+    fn verify_proof(
+        proof: &<Plonky3<T> as Verifier>::Proof,
+        _pubs: &<Plonky3<T> as Verifier>::Pubs,
+    ) -> Weight {
+        // Without the vk neither the field, the FRI parameters nor the AIRs are known: charge
+        // the worst case for every instance the proof can contain.
+        let instances = match proof.kind {
+            StarkKind::UniStark => 1,
+            StarkKind::BatchStark => MAX_AIRS,
+        };
+        let degree_bits = [MAX_DEGREE_BITS; MAX_AIRS];
+        [Field::BabyBear, Field::KoalaBear]
+            .into_iter()
+            .map(|field| VerificationCost::worst_case(field, instances).weight::<W>(&degree_bits))
+            .fold(Weight::zero(), |w, field_w| w.max(field_w))
+    }
+
+    fn verify_proof_with_vk_metadata(
+        proof: &<Plonky3<T> as Verifier>::Proof,
+        pubs: &<Plonky3<T> as Verifier>::Pubs,
+        vk_metadata: &Option<VerificationCost>,
+    ) -> Weight {
+        match vk_metadata {
+            // The registered vk fixes everything but the trace degrees.
+            Some(cost) => cost.weight::<W>(&[MAX_DEGREE_BITS; MAX_AIRS]),
+            None => <Self as pallet_verifiers::WeightInfo<Plonky3<T>>>::verify_proof(proof, pubs),
+        }
+    }
+
+    fn register_vk(_vk: &<Plonky3<T> as Verifier>::Vk) -> Weight {
+        W::register_vk()
+    }
+
+    fn unregister_vk() -> Weight {
+        W::unregister_vk()
+    }
+
+    fn get_vk() -> Weight {
+        W::get_vk()
+    }
+
+    fn validate_vk(_vk: &<Plonky3<T> as Verifier>::Vk) -> Weight {
+        W::validate_vk()
+    }
+
+    fn compute_statement_hash(
+        _proof: &<Plonky3<T> as Verifier>::Proof,
+        _pubs: &<Plonky3<T> as Verifier>::Pubs,
+    ) -> Weight {
+        W::compute_statement_hash()
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright 2024-2026, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The proof to verify: the postcard serialized plonky3 proof along with the
+//! STARK flavour that produced it.
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::TypeInfo;
+
+/// Important Notes: DO NOT alter the indices of the existing variants.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum StarkKind {
+    /// A `p3_uni_stark::Proof` of a single AIR.
+    #[codec(index = 0)]
+    UniStark,
+    /// A `p3_batch_stark::BatchProof` of one instance per vk AIR.
+    #[codec(index = 1)]
+    BatchStark,
+}
+
+#[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo)]
+pub struct Proof {
+    pub kind: StarkKind,
+    pub bytes: Vec<u8>,
+}
+
+impl Proof {
+    pub fn new(kind: StarkKind, bytes: Vec<u8>) -> Self {
+        Self { kind, bytes }
+    }
+}
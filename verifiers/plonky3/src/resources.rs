@@ -0,0 +1,184 @@
+// Copyright 2024-2026, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(any(test, feature = "runtime-benchmarks"))]
+
+use crate::{
+    AirDescriptor, Config, Field, FriParams, Node, Proof, Pubs, Row, StarkKind, Vk, MAX_AIRS,
+    MAX_AIR_NODES, MAX_AIR_WIDTH,
+};
+use alloc::{vec, vec::Vec};
+use codec::Encode;
+
+/// The FRI parameters of the Plonky3 examples.
+pub fn fri_params() -> FriParams {
+    FriParams {
+        log_blowup: 1,
+        log_final_poly_len: 0,
+        num_queries: 100,
+        commit_proof_of_work_bits: 0,
+        query_proof_of_work_bits: 16,
+    }
+}
+
+/// The Fibonacci AIR of the Plonky3 examples: the public values are the two
+/// starting values and the last one.
+#[allow(dead_code)]
+pub fn fibonacci_air() -> AirDescriptor {
+    use Node::*;
+
+    let local = |column| Main {
+        row: Row::Local,
+        column,
+    };
+    let next = |column| Main {
+        row: Row::Next,
+        column,
+    };
+    let nodes = vec![
+        // 0..=3: local[0], local[1], next[0], next[1]
+        local(0),
+        local(1),
+        next(0),
+        next(1),
+        // 4..=6: a, b, x
+        Public(0),
+        Public(1),
+        Public(2),
+        // 7..=9
+        IsFirstRow,
+        IsTransition,
+        IsLastRow,
+        // 10..=13: on the first row local[0] == a and local[1] == b
+        Sub(0, 4),
+        Mul(7, 10),
+        Sub(1, 5),
+        Mul(7, 12),
+        // 14..=18: on transitions next[0] == local[1] and next[1] == local[0] + local[1]
+        Sub(2, 1),
+        Mul(8, 14),
+        Add(0, 1),
+        Sub(3, 16),
+        Mul(8, 17),
+        // 19..=20: on the last row local[1] == x
+        Sub(1, 6),
+        Mul(9, 19),
+    ];
+
+    AirDescriptor {
+        width: 2,
+        num_public_values: 3,
+        nodes,
+        constraints: vec![11, 13, 15, 18, 20],
+    }
+}
+
+#[allow(dead_code)]
+pub fn fibonacci_vk<T>(field: Field) -> Vk<T> {
+    Vk::new(field, fri_params(), vec![fibonacci_air()])
+}
+
+#[allow(dead_code)]
+pub struct TestData<T> {
+    pub vk: Vk<T>,
+    pub proof: Proof,
+    pub pubs: Pubs,
+}
+
+/// Proofs of the Fibonacci AIR made by the Plonky3 0.4 prover with [`fri_params`]:
+/// the uni-stark one proves 2^8 rows from (0, 1), the batch-stark one proves both
+/// 2^8 rows from (0, 1) and 2^6 rows from (1, 2).
+#[allow(dead_code)]
+pub fn fibonacci_test_data<T>(field: Field, kind: StarkKind) -> TestData<T> {
+    let (proof, pubs): (&[u8], &[u8]) = match (field, kind) {
+        (Field::BabyBear, StarkKind::UniStark) => (
+            include_bytes!("resources/fibonacci/baby_bear/uni_stark/proof.bin"),
+            include_bytes!("resources/fibonacci/baby_bear/uni_stark/pubs.bin"),
+        ),
+        (Field::BabyBear, StarkKind::BatchStark) => (
+            include_bytes!("resources/fibonacci/baby_bear/batch_stark/proof.bin"),
+            include_bytes!("resources/fibonacci/baby_bear/batch_stark/pubs.bin"),
+        ),
+        (Field::KoalaBear, StarkKind::UniStark) => (
+            include_bytes!("resources/fibonacci/koala_bear/uni_stark/proof.bin"),
+            include_bytes!("resources/fibonacci/koala_bear/uni_stark/pubs.bin"),
+        ),
+        (Field::KoalaBear, StarkKind::BatchStark) => (
+            include_bytes!("resources/fibonacci/koala_bear/batch_stark/proof.bin"),
+            include_bytes!("resources/fibonacci/koala_bear/batch_stark/pubs.bin"),
+        ),
+    };
+    let airs = match kind {
+        StarkKind::UniStark => 1,
+        StarkKind::BatchStark => 2,
+    };
+    TestData {
+        vk: Vk::new(field, fri_params(), vec![fibonacci_air(); airs]),
+        proof: Proof::new(kind, proof.to_vec()),
+        pubs: pubs.to_vec(),
+    }
+}
+
+/// An AIR of `width` columns and `nodes` nodes: it reads the local row and then
+/// multiplies it over and over, asserting the last product is zero.
+#[cfg(feature = "runtime-benchmarks")]
+pub fn products_air(width: u16, nodes: usize) -> AirDescriptor {
+    let nodes = (0..nodes)
+        .map(|i| match i as u16 {
+            column if column < width => Node::Main {
+                row: Row::Local,
+                column,
+            },
+            i => Node::Mul(i - 1, i % width),
+        })
+        .collect::<Vec<_>>();
+    AirDescriptor {
+        width,
+        num_public_values: 0,
+        constraints: vec![(nodes.len() - 1) as u16],
+        nodes,
+    }
+}
+
+/// The biggest vk fitting in `T::max_vk_size()`: `MAX_AIRS` AIRs of
+/// `MAX_AIR_WIDTH` columns, each one summing its columns over and over.
+pub fn worst_case_vk<T: Config>() -> Vk<T> {
+    let columns = (0..MAX_AIR_WIDTH)
+        .map(|column| Node::Main {
+            row: Row::Local,
+            column,
+        })
+        .collect::<Vec<_>>();
+    let air = AirDescriptor {
+        width: MAX_AIR_WIDTH,
+        num_public_values: 0,
+        nodes: columns,
+        constraints: vec![0],
+    };
+    let mut vk = Vk::new(Field::BabyBear, fri_params(), vec![air; MAX_AIRS]);
+
+    // Leave some room for the growth of the length prefixes.
+    let budget = (T::max_vk_size() as usize).saturating_sub(vk.airs.encoded_size() + 8 * MAX_AIRS);
+    let sums = (budget / MAX_AIRS / Node::Add(0, 0).encoded_size())
+        .min(MAX_AIR_NODES - MAX_AIR_WIDTH as usize);
+    for air in vk.airs.iter_mut() {
+        for i in 0..sums {
+            let last = (air.nodes.len() - 1) as u16;
+            air.nodes.push(Node::Add(last, i as u16 % MAX_AIR_WIDTH));
+        }
+        air.constraints = vec![(air.nodes.len() - 1) as u16];
+    }
+    vk
+}
@@ -0,0 +1,222 @@
+// Copyright 2024-2026, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The STARK configurations supported by the verifier: Poseidon2 Merkle
+//! commitments and FRI over BabyBear and KoalaBear, with challenges drawn from
+//! their degree 4 extension. They match the ones used by the Plonky3 examples.
+
+use crate::air::DescribedAir;
+use crate::proof::{Proof, StarkKind};
+use crate::vk::{AirDescriptor, FriParams};
+use crate::MAX_DEGREE_BITS;
+use alloc::{vec, vec::Vec};
+use frame_support::ensure;
+use p3_challenger::DuplexChallenger;
+use p3_commit::ExtensionMmcs;
+use p3_dft::Radix2DitParallel;
+use p3_field::extension::BinomialExtensionField;
+use p3_field::{Field, PrimeCharacteristicRing};
+use p3_fri::{FriParameters, TwoAdicFriPcs};
+use p3_merkle_tree::MerkleTreeMmcs;
+use p3_symmetric::{PaddingFreeSponge, TruncatedPermutation};
+use p3_uni_stark::StarkConfig;
+use pallet_verifiers::traits::VerifyError;
+
+fn check_degree_bits(degree_bits: &[usize]) -> Result<(), VerifyError> {
+    degree_bits
+        .iter()
+        .all(|&d| d <= MAX_DEGREE_BITS)
+        .then_some(())
+        .ok_or(VerifyError::InvalidProofData)
+        .inspect_err(|_| log::debug!("Unsupported trace degree bits {degree_bits:?}"))
+}
+
+macro_rules! stark_config {
+    ($module:ident, $val:ty, $perm:ty, $default_perm:path) => {
+        pub mod $module {
+            use super::*;
+
+            type Val = $val;
+            type Challenge = BinomialExtensionField<Val, 4>;
+            type Perm = $perm;
+            type Hash = PaddingFreeSponge<Perm, 16, 8, 8>;
+            type Compress = TruncatedPermutation<Perm, 2, 8, 16>;
+            type ValMmcs =
+                MerkleTreeMmcs<<Val as Field>::Packing, <Val as Field>::Packing, Hash, Compress, 8>;
+            type ChallengeMmcs = ExtensionMmcs<Val, Challenge, ValMmcs>;
+            type Challenger = DuplexChallenger<Val, Perm, 16, 8>;
+            type Dft = Radix2DitParallel<Val>;
+            type Pcs = TwoAdicFriPcs<Val, Dft, ValMmcs, ChallengeMmcs>;
+            type Config = StarkConfig<Pcs, Challenge, Challenger>;
+
+            fn config(fri: &FriParams) -> Config {
+                let perm = $default_perm();
+                let val_mmcs = ValMmcs::new(Hash::new(perm.clone()), Compress::new(perm.clone()));
+                let fri_params = FriParameters {
+                    log_blowup: fri.log_blowup as usize,
+                    log_final_poly_len: fri.log_final_poly_len as usize,
+                    num_queries: fri.num_queries as usize,
+                    commit_proof_of_work_bits: fri.commit_proof_of_work_bits as usize,
+                    query_proof_of_work_bits: fri.query_proof_of_work_bits as usize,
+                    mmcs: ChallengeMmcs::new(val_mmcs.clone()),
+                };
+                let pcs = Pcs::new(Dft::default(), val_mmcs, fri_params);
+                Config::new(pcs, Challenger::new(perm))
+            }
+
+            /// Verify the proof against the vk AIRs and the public values of each
+            /// of them, returning the degree bits of the proven traces.
+            pub fn verify(
+                fri: &FriParams,
+                proof: &Proof,
+                airs: &[AirDescriptor],
+                pubs: &[Vec<u32>],
+            ) -> Result<Vec<usize>, VerifyError> {
+                let config = config(fri);
+                let airs = airs.iter().map(DescribedAir).collect::<Vec<_>>();
+                let pubs = pubs
+                    .iter()
+                    .map(|p| p.iter().map(|&v| Val::from_u32(v)).collect::<Vec<_>>())
+                    .collect::<Vec<_>>();
+
+                match proof.kind {
+                    StarkKind::UniStark => {
+                        let [air] = airs.as_slice() else {
+                            log::debug!("Uni-stark proofs need a single AIR, got {}", airs.len());
+                            return Err(VerifyError::InvalidProofData);
+                        };
+                        let proof: p3_uni_stark::Proof<Config> = postcard::from_bytes(&proof.bytes)
+                            .inspect_err(|e| log::debug!("Cannot deserialize proof: {e:?}"))
+                            .map_err(|_| VerifyError::InvalidProofData)?;
+                        check_degree_bits(&[proof.degree_bits])?;
+
+                        p3_uni_stark::verify(&config, air, &proof, &pubs[0])
+                            .inspect_err(|e| log::debug!("Proof verification failed: {e:?}"))
+                            .map_err(|_| VerifyError::VerifyError)?;
+                        Ok(vec![proof.degree_bits])
+                    }
+                    StarkKind::BatchStark => {
+                        let proof: p3_batch_stark::BatchProof<Config> =
+                            postcard::from_bytes(&proof.bytes)
+                                .inspect_err(|e| log::debug!("Cannot deserialize proof: {e:?}"))
+                                .map_err(|_| VerifyError::InvalidProofData)?;
+                        ensure!(
+                            proof.degree_bits.len() == airs.len(),
+                            VerifyError::InvalidProofData
+                        );
+                        check_degree_bits(&proof.degree_bits)?;
+
+                        // The described AIRs have neither preprocessed columns nor lookups
+                        let common = p3_batch_stark::CommonData::empty(airs.len());
+                        p3_batch_stark::verifier::verify_batch_no_lookups(
+                            &config, &airs, &proof, &pubs, &common,
+                        )
+                        .inspect_err(|e| log::debug!("Proof verification failed: {e:?}"))
+                        .map_err(|_| VerifyError::VerifyError)?;
+                        Ok(proof.degree_bits)
+                    }
+                }
+            }
+
+            /// Evaluate the `air` constraints at an out of domain point, as the verifier
+            /// does for each proven trace of `2^degree_bits` rows, on arbitrary openings.
+            #[cfg(feature = "runtime-benchmarks")]
+            pub fn eval_air(air: &AirDescriptor, degree_bits: usize) {
+                use p3_field::BasedVectorSpace;
+                use p3_uni_stark::StarkGenericConfig;
+
+                let config = config(&crate::resources::fri_params());
+                let point = |seed: u32| {
+                    Challenge::from_basis_coefficients_fn(|i| Val::from_u32(seed + i as u32))
+                };
+                let local = (0..air.width as u32).map(point).collect::<Vec<_>>();
+                let next = (0..air.width as u32)
+                    .map(|i| point(i + 1))
+                    .collect::<Vec<_>>();
+                let pubs = (0..air.num_public_values as u32)
+                    .map(Val::from_u32)
+                    .collect::<Vec<_>>();
+                let domain =
+                    <Pcs as p3_commit::Pcs<Challenge, Challenger>>::natural_domain_for_degree(
+                        config.pcs(),
+                        1 << degree_bits,
+                    );
+
+                // The quotient doesn't match: only the evaluation cost matters.
+                let _ = p3_uni_stark::verify_constraints::<Config, _, ()>(
+                    &DescribedAir(air),
+                    &local,
+                    &next,
+                    None,
+                    None,
+                    &pubs,
+                    domain,
+                    point(7),
+                    point(11),
+                    point(13),
+                );
+            }
+
+            /// Check the Merkle opening of a row of `width` values in a tree of
+            /// `2^log_height` leaves, as each FRI query does.
+            #[cfg(feature = "runtime-benchmarks")]
+            pub fn open_row(log_height: usize, width: usize) -> bool {
+                use p3_commit::{BatchOpeningRef, Mmcs};
+                use p3_matrix::Dimensions;
+                use p3_symmetric::{CryptographicHasher, PseudoCompressionFunction};
+
+                let perm = $default_perm();
+                let hash = Hash::new(perm.clone());
+                let compress = Compress::new(perm.clone());
+                let mmcs = ValMmcs::new(hash.clone(), compress.clone());
+
+                let opened = vec![(0..width as u32).map(Val::from_u32).collect::<Vec<_>>()];
+                let siblings = (0..log_height as u32)
+                    .map(|i| [Val::from_u32(i); 8])
+                    .collect::<Vec<_>>();
+                // The opened row is the first one: it's always on the left.
+                let root = siblings.iter().fold(
+                    hash.hash_iter_slices(opened.iter().map(Vec::as_slice)),
+                    |node, &s| compress.compress([node, s]),
+                );
+                let dimensions = [Dimensions {
+                    width,
+                    height: 1 << log_height,
+                }];
+
+                mmcs.verify_batch(
+                    &root.into(),
+                    &dimensions,
+                    0,
+                    BatchOpeningRef::new(&opened, &siblings),
+                )
+                .is_ok()
+            }
+        }
+    };
+}
+
+stark_config!(
+    baby_bear,
+    p3_baby_bear::BabyBear,
+    p3_baby_bear::Poseidon2BabyBear<16>,
+    p3_baby_bear::default_babybear_poseidon2_16
+);
+stark_config!(
+    koala_bear,
+    p3_koala_bear::KoalaBear,
+    p3_koala_bear::Poseidon2KoalaBear<16>,
+    p3_koala_bear::default_koalabear_poseidon2_16
+);
@@ -0,0 +1,415 @@
+// Copyright 2024-2026, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(test)]
+
+use super::*;
+use crate::resources::*;
+use frame_support::{assert_err, assert_ok};
+use rstest::*;
+
+pub struct MockConfig;
+
+impl crate::Config for MockConfig {
+    type MaxProofSize = frame_support::traits::ConstU32<1000000>;
+    type MaxPubsSize = frame_support::traits::ConstU32<1000000>;
+    type MaxVkSize = frame_support::traits::ConstU32<1000000>;
+    type WeightInfo = ();
+}
+
+type MockVk = Vk<MockConfig>;
+
+fn encode_pubs(values: &[u32]) -> Pubs {
+    values.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+#[rstest]
+fn verify_valid_proof(
+    #[values(Field::BabyBear, Field::KoalaBear)] field: Field,
+    #[values(StarkKind::UniStark, StarkKind::BatchStark)] kind: StarkKind,
+) {
+    let data = fibonacci_test_data::<MockConfig>(field, kind);
+
+    assert_ok!(Plonky3::<MockConfig>::verify_proof(
+        &data.vk,
+        &data.proof,
+        &data.pubs
+    ));
+}
+
+#[rstest]
+#[case::uni_stark(StarkKind::UniStark, &[8])]
+#[case::batch_stark(StarkKind::BatchStark, &[8, 6])]
+fn weight_valid_proof_by_its_trace_degrees(
+    #[values(Field::BabyBear, Field::KoalaBear)] field: Field,
+    #[case] kind: StarkKind,
+    #[case] degree_bits: &[usize],
+) {
+    let data = fibonacci_test_data::<MockConfig>(field, kind);
+
+    assert_eq!(
+        Plonky3::<MockConfig>::verify_proof(&data.vk, &data.proof, &data.pubs),
+        Ok(Some(weight(&data.vk, degree_bits)))
+    );
+}
+
+#[rstest]
+fn validate_vk(#[values(Field::BabyBear, Field::KoalaBear)] field: Field) {
+    assert_ok!(Plonky3::<MockConfig>::validate_vk(&fibonacci_vk(field)));
+}
+
+#[test]
+fn validate_worst_case_vk() {
+    let vk = worst_case_vk::<MockConfig>();
+
+    assert_ok!(Plonky3::<MockConfig>::validate_vk(&vk));
+}
+
+#[test]
+fn validate_batch_vk() {
+    let vk = MockVk::new(
+        Field::KoalaBear,
+        fri_params(),
+        vec![fibonacci_air(); MAX_AIRS],
+    );
+
+    assert_ok!(Plonky3::<MockConfig>::validate_vk(&vk));
+}
+
+#[rstest]
+#[case::max_blowup(FriParams { log_blowup: MAX_LOG_BLOWUP, num_queries: 28, ..fri_params() })]
+#[case::max_queries(FriParams { num_queries: MAX_NUM_QUERIES, ..fri_params() })]
+#[case::no_pow(FriParams { num_queries: 100, query_proof_of_work_bits: 0, ..fri_params() })]
+#[case::final_poly(FriParams { log_final_poly_len: MAX_LOG_FINAL_POLY_LEN, ..fri_params() })]
+fn validate_vk_with_custom_fri_params(#[case] fri: FriParams) {
+    let vk = MockVk::new(Field::BabyBear, fri, vec![fibonacci_air()]);
+
+    assert_ok!(Plonky3::<MockConfig>::validate_vk(&vk));
+}
+
+fn weight(vk: &MockVk, degree_bits: &[usize]) -> Weight {
+    VerificationCost::new(vk).weight::<()>(degree_bits)
+}
+
+fn pre_dispatch(kind: StarkKind) -> Weight {
+    <Plonky3Weight<()> as pallet_verifiers::WeightInfo<Plonky3<MockConfig>>>::verify_proof(
+        &Proof::new(kind, Vec::new()),
+        &Vec::new(),
+    )
+}
+
+fn pre_dispatch_with_vk(vk: &MockVk) -> Weight {
+    <Plonky3Weight<()> as pallet_verifiers::WeightInfo<Plonky3<MockConfig>>>::verify_proof_with_vk_metadata(
+        &Proof::new(StarkKind::BatchStark, Vec::new()),
+        &Vec::new(),
+        &Plonky3::<MockConfig>::vk_metadata(vk),
+    )
+}
+
+#[test]
+fn weight_proofs_by_trace_degree() {
+    let vk = MockVk::new(Field::BabyBear, fri_params(), vec![fibonacci_air(); 2]);
+
+    assert!(weight(&vk, &[10, 10]).ref_time() < weight(&vk, &[10, 20]).ref_time());
+    // Each query opens taller main and quotient trees and 6 more commit phase trees.
+    let open = <() as WeightInfo>::verify_query_baby_bear;
+    let rows = |h| open(h, 2).saturating_add(open(h, 8));
+    let extra = (11..17).fold(rows(17).saturating_sub(rows(11)), |w, h| {
+        w.saturating_add(open(h, 8))
+    });
+    assert_eq!(
+        weight(&vk, &[10, 16]),
+        weight(&vk, &[10, 10]).saturating_add(extra.saturating_mul(100))
+    );
+}
+
+#[test]
+fn weight_proofs_as_air_evaluation_and_fri_queries() {
+    let vk = fibonacci_vk::<MockConfig>(Field::KoalaBear);
+
+    // 2 main columns and 2 quotient chunks of 4 elements, 21 nodes, 100 queries at
+    // the LDE height 2^(16+1): each one opens the main and quotient rows and then a
+    // pair of folded values in the 16 commit phase trees.
+    let open = <() as WeightInfo>::verify_query_koala_bear;
+    let query = (1..17).fold(open(17, 2).saturating_add(open(17, 8)), |w, h| {
+        w.saturating_add(open(h, 8))
+    });
+    assert_eq!(
+        weight(&vk, &[16]),
+        <() as WeightInfo>::verify_air_koala_bear(10, 21).saturating_add(query.saturating_mul(100))
+    );
+}
+
+#[rstest]
+#[case::more_columns(|vk: &mut MockVk| vk.airs[0].width += 1)]
+#[case::more_nodes(|vk: &mut MockVk| vk.airs[0].nodes.push(Node::Neg(20)))]
+#[case::more_airs(|vk: &mut MockVk| vk.airs.push(fibonacci_air()))]
+#[case::more_queries(|vk: &mut MockVk| vk.fri.num_queries += 1)]
+#[case::higher_blowup(|vk: &mut MockVk| vk.fri.log_blowup += 1)]
+fn weight_proofs_by_air_shape_and_fri_params(#[case] patch: impl Fn(&mut MockVk)) {
+    let vk = fibonacci_vk::<MockConfig>(Field::BabyBear);
+    let mut bigger = vk.clone();
+    patch(&mut bigger);
+
+    assert!(weight(&vk, &[16, 16]).ref_time() < weight(&bigger, &[16, 16]).ref_time());
+}
+
+#[rstest]
+fn charge_the_worst_case_before_dispatch(
+    #[values(Field::BabyBear, Field::KoalaBear)] field: Field,
+) {
+    let worst_case =
+        |airs| VerificationCost::worst_case(field, airs).weight::<()>(&[MAX_DEGREE_BITS; MAX_AIRS]);
+
+    assert!(worst_case(1).all_lte(pre_dispatch(StarkKind::UniStark)));
+    assert!(worst_case(MAX_AIRS).all_lte(pre_dispatch(StarkKind::BatchStark)));
+    assert!(weight(&worst_case_vk(), &[MAX_DEGREE_BITS; MAX_AIRS])
+        .all_lte(pre_dispatch(StarkKind::BatchStark)));
+}
+
+#[rstest]
+fn charge_registered_vks_by_their_cost(#[values(Field::BabyBear, Field::KoalaBear)] field: Field) {
+    let vk = MockVk::new(field, fri_params(), vec![fibonacci_air(); 2]);
+
+    assert_eq!(
+        Plonky3::<MockConfig>::vk_metadata(&vk),
+        Some(VerificationCost::new(&vk))
+    );
+    assert_eq!(
+        pre_dispatch_with_vk(&vk),
+        weight(&vk, &[MAX_DEGREE_BITS; 2])
+    );
+    assert!(weight(&vk, &[10, 20]).all_lte(pre_dispatch_with_vk(&vk)));
+    assert!(pre_dispatch_with_vk(&vk).ref_time() < pre_dispatch(StarkKind::BatchStark).ref_time());
+}
+
+#[test]
+fn charge_the_worst_case_without_vk_metadata() {
+    assert_eq!(
+        <Plonky3Weight<()> as pallet_verifiers::WeightInfo<Plonky3<MockConfig>>>::verify_proof_with_vk_metadata(
+            &Proof::new(StarkKind::UniStark, Vec::new()),
+            &Vec::new(),
+            &None,
+        ),
+        pre_dispatch(StarkKind::UniStark)
+    );
+}
+
+mod reject {
+    use super::*;
+    use pallet_verifiers::traits::VerifyError;
+
+    #[rstest]
+    #[case::no_airs(|vk: &mut MockVk| vk.airs.clear())]
+    #[case::too_many_airs(|vk: &mut MockVk| vk.airs = vec![fibonacci_air(); MAX_AIRS + 1])]
+    #[case::too_low_blowup(|vk: &mut MockVk| vk.fri.log_blowup = MIN_LOG_BLOWUP - 1)]
+    #[case::too_high_blowup(|vk: &mut MockVk| vk.fri.log_blowup = MAX_LOG_BLOWUP + 1)]
+    #[case::too_many_queries(|vk: &mut MockVk| vk.fri.num_queries = MAX_NUM_QUERIES + 1)]
+    #[case::too_much_commit_pow(|vk: &mut MockVk| vk.fri.commit_proof_of_work_bits = MAX_PROOF_OF_WORK_BITS + 1)]
+    #[case::too_much_query_pow(|vk: &mut MockVk| vk.fri.query_proof_of_work_bits = MAX_PROOF_OF_WORK_BITS + 1)]
+    #[case::too_long_final_poly(|vk: &mut MockVk| vk.fri.log_final_poly_len = MAX_LOG_FINAL_POLY_LEN + 1)]
+    #[case::insufficient_security(|vk: &mut MockVk| vk.fri.num_queries = 83)]
+    #[case::no_columns(|vk: &mut MockVk| vk.airs[0].width = 0)]
+    #[case::too_many_columns(|vk: &mut MockVk| vk.airs[0].width = MAX_AIR_WIDTH + 1)]
+    #[case::column_out_of_range(|vk: &mut MockVk| vk.airs[0].width = 1)]
+    #[case::public_value_out_of_range(|vk: &mut MockVk| vk.airs[0].num_public_values = 2)]
+    #[case::forward_reference(|vk: &mut MockVk| vk.airs[0].nodes[10] = Node::Sub(0, 11))]
+    #[case::self_reference(|vk: &mut MockVk| vk.airs[0].nodes[10] = Node::Neg(10))]
+    #[case::non_canonical_constant(|vk: &mut MockVk| vk.airs[0].nodes[4] = Node::Constant(Field::BabyBear.order()))]
+    #[case::no_constraints(|vk: &mut MockVk| vk.airs[0].constraints.clear())]
+    #[case::constraint_out_of_range(|vk: &mut MockVk| vk.airs[0].constraints.push(21))]
+    #[case::too_high_degree(|vk: &mut MockVk| {
+        vk.airs[0].nodes.push(Node::Mul(20, 20));
+        vk.airs[0].constraints.push(21);
+    })]
+    fn invalid_vk(#[case] patch: impl Fn(&mut MockVk)) {
+        let mut vk = fibonacci_vk::<MockConfig>(Field::BabyBear);
+        patch(&mut vk);
+
+        assert_err!(
+            Plonky3::<MockConfig>::validate_vk(&vk),
+            VerifyError::InvalidVerificationKey
+        );
+        assert_err!(
+            Plonky3::<MockConfig>::verify_proof(
+                &vk,
+                &Proof::new(StarkKind::UniStark, Vec::new()),
+                &encode_pubs(&[0, 1, 21])
+            ),
+            VerifyError::InvalidVerificationKey
+        );
+    }
+
+    #[rstest]
+    fn valid_proof_with_wrong_pubs(
+        #[values(Field::BabyBear, Field::KoalaBear)] field: Field,
+        #[values(StarkKind::UniStark, StarkKind::BatchStark)] kind: StarkKind,
+    ) {
+        let mut data = fibonacci_test_data::<MockConfig>(field, kind);
+        // The last value of the first AIR
+        data.pubs[8] ^= 1;
+
+        assert_err!(
+            Plonky3::<MockConfig>::verify_proof(&data.vk, &data.proof, &data.pubs),
+            VerifyError::VerifyError
+        );
+    }
+
+    #[rstest]
+    fn valid_proof_with_different_fri_params(
+        #[values(Field::BabyBear, Field::KoalaBear)] field: Field,
+        #[values(StarkKind::UniStark, StarkKind::BatchStark)] kind: StarkKind,
+    ) {
+        let mut data = fibonacci_test_data::<MockConfig>(field, kind);
+        data.vk.fri.num_queries += 1;
+
+        assert!(Plonky3::<MockConfig>::verify_proof(&data.vk, &data.proof, &data.pubs).is_err());
+    }
+
+    #[rstest]
+    fn valid_proof_over_another_field(
+        #[values(StarkKind::UniStark, StarkKind::BatchStark)] kind: StarkKind,
+    ) {
+        let mut data = fibonacci_test_data::<MockConfig>(Field::BabyBear, kind);
+        data.vk.field = Field::KoalaBear;
+
+        assert!(Plonky3::<MockConfig>::verify_proof(&data.vk, &data.proof, &data.pubs).is_err());
+    }
+
+    #[rstest]
+    fn valid_proof_of_another_kind(#[values(Field::BabyBear, Field::KoalaBear)] field: Field) {
+        let uni = fibonacci_test_data::<MockConfig>(field, StarkKind::UniStark);
+        let batch = fibonacci_test_data::<MockConfig>(field, StarkKind::BatchStark);
+
+        assert!(Plonky3::<MockConfig>::verify_proof(
+            &uni.vk,
+            &Proof::new(StarkKind::UniStark, batch.proof.bytes),
+            &uni.pubs
+        )
+        .is_err());
+        assert!(Plonky3::<MockConfig>::verify_proof(
+            &batch.vk,
+            &Proof::new(StarkKind::BatchStark, uni.proof.bytes),
+            &batch.pubs
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn constant_non_canonical_only_for_baby_bear() {
+        let mut vk = fibonacci_vk::<MockConfig>(Field::KoalaBear);
+        vk.airs[0].nodes[4] = Node::Constant(Field::BabyBear.order());
+
+        assert_ok!(Plonky3::<MockConfig>::validate_vk(&vk));
+    }
+
+    #[test]
+    fn oversized_vk() {
+        struct SmallVkConfig;
+
+        impl crate::Config for SmallVkConfig {
+            type MaxProofSize = frame_support::traits::ConstU32<1000000>;
+            type MaxPubsSize = frame_support::traits::ConstU32<1000000>;
+            type MaxVkSize = frame_support::traits::ConstU32<32>;
+            type WeightInfo = ();
+        }
+
+        assert_err!(
+            Plonky3::<SmallVkConfig>::validate_vk(&fibonacci_vk(Field::BabyBear)),
+            VerifyError::InvalidVerificationKey
+        );
+    }
+
+    #[rstest]
+    #[case::too_few(&[0, 1])]
+    #[case::too_many(&[0, 1, 21, 34])]
+    #[case::non_canonical(&[0, Field::BabyBear.order(), 21])]
+    fn invalid_pubs(#[case] pubs: &[u32]) {
+        let vk = fibonacci_vk::<MockConfig>(Field::BabyBear);
+
+        assert_err!(
+            Plonky3::<MockConfig>::verify_proof(
+                &vk,
+                &Proof::new(StarkKind::UniStark, Vec::new()),
+                &encode_pubs(pubs)
+            ),
+            VerifyError::InvalidInput
+        );
+    }
+
+    #[test]
+    fn uni_stark_proof_of_many_airs() {
+        let vk = MockVk::new(
+            Field::BabyBear,
+            fri_params(),
+            vec![fibonacci_air(), fibonacci_air()],
+        );
+
+        assert_err!(
+            Plonky3::<MockConfig>::verify_proof(
+                &vk,
+                &Proof::new(StarkKind::UniStark, Vec::new()),
+                &encode_pubs(&[0, 1, 21, 0, 1, 21])
+            ),
+            VerifyError::InvalidProofData
+        );
+    }
+
+    #[rstest]
+    fn malformed_proof(
+        #[values(Field::BabyBear, Field::KoalaBear)] field: Field,
+        #[values(StarkKind::UniStark, StarkKind::BatchStark)] kind: StarkKind,
+    ) {
+        let vk = fibonacci_vk::<MockConfig>(field);
+
+        assert_err!(
+            Plonky3::<MockConfig>::verify_proof(
+                &vk,
+                &Proof::new(kind, Vec::new()),
+                &encode_pubs(&[0, 1, 21])
+            ),
+            VerifyError::InvalidProofData
+        );
+    }
+
+    #[test]
+    fn oversized_proof() {
+        let vk = fibonacci_vk::<MockConfig>(Field::BabyBear);
+        let proof = Proof::new(
+            StarkKind::UniStark,
+            vec![0; MockConfig::max_proof_size() as usize + 1],
+        );
+
+        assert_err!(
+            Plonky3::<MockConfig>::verify_proof(&vk, &proof, &encode_pubs(&[0, 1, 21])),
+            VerifyError::InvalidProofData
+        );
+    }
+
+    #[test]
+    fn oversized_pubs() {
+        let vk = fibonacci_vk::<MockConfig>(Field::BabyBear);
+        let pubs = vec![0; MockConfig::max_pubs_size() as usize + 1];
+
+        assert_err!(
+            Plonky3::<MockConfig>::verify_proof(
+                &vk,
+                &Proof::new(StarkKind::UniStark, Vec::new()),
+                &pubs
+            ),
+            VerifyError::InvalidInput
+        );
+    }
+}
@@ -0,0 +1,249 @@
+// Copyright 2024-2026, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Verification key: the field, the FRI parameters and the descriptors of the
+//! AIRs proven by the STARK.
+
+use crate::{
+    Config, MAX_AIRS, MAX_AIR_NODES, MAX_AIR_WIDTH, MAX_LOG_BLOWUP, MAX_LOG_FINAL_POLY_LEN,
+    MAX_NUM_QUERIES, MAX_PROOF_OF_WORK_BITS, MIN_LOG_BLOWUP, MIN_SECURITY_BITS,
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use educe::Educe;
+use frame_support::pallet_prelude::TypeInfo;
+use p3_baby_bear::BabyBear;
+use p3_field::PrimeField32;
+use p3_koala_bear::KoalaBear;
+
+/// The base field the STARK is defined over.
+///
+/// Important Notes: DO NOT alter the indices of the existing variants, or the
+/// already registered vks will be decoded as a different field.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum Field {
+    #[codec(index = 0)]
+    BabyBear,
+    #[codec(index = 1)]
+    KoalaBear,
+}
+
+impl Field {
+    /// The field modulus.
+    pub fn order(&self) -> u32 {
+        match self {
+            Field::BabyBear => BabyBear::ORDER_U32,
+            Field::KoalaBear => KoalaBear::ORDER_U32,
+        }
+    }
+}
+
+/// The FRI parameters used by the prover.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct FriParams {
+    pub log_blowup: u8,
+    pub log_final_poly_len: u8,
+    pub num_queries: u16,
+    /// Proof of work bits before sampling each FRI folding challenge.
+    pub commit_proof_of_work_bits: u8,
+    /// Proof of work bits before sampling the FRI queries.
+    pub query_proof_of_work_bits: u8,
+}
+
+impl FriParams {
+    /// Conjectured security level of the FRI low degree test.
+    pub fn conjectured_security_bits(&self) -> u32 {
+        self.log_blowup as u32 * self.num_queries as u32 + self.query_proof_of_work_bits as u32
+    }
+}
+
+/// Which trace row a [`Node::Main`] refers to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum Row {
+    #[codec(index = 0)]
+    Local,
+    #[codec(index = 1)]
+    Next,
+}
+
+/// A node of the constraints expression graph. Operands are indices of the
+/// nodes that precede it in [`AirDescriptor::nodes`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum Node {
+    /// A canonical field element.
+    #[codec(index = 0)]
+    Constant(u32),
+    /// A main trace cell.
+    #[codec(index = 1)]
+    Main { row: Row, column: u16 },
+    /// A public value.
+    #[codec(index = 2)]
+    Public(u16),
+    #[codec(index = 3)]
+    IsFirstRow,
+    #[codec(index = 4)]
+    IsLastRow,
+    #[codec(index = 5)]
+    IsTransition,
+    #[codec(index = 6)]
+    Add(u16, u16),
+    #[codec(index = 7)]
+    Sub(u16, u16),
+    #[codec(index = 8)]
+    Mul(u16, u16),
+    #[codec(index = 9)]
+    Neg(u16),
+}
+
+/// An AIR described as an expression graph over the main trace, the public
+/// values and the row selectors.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct AirDescriptor {
+    /// Number of columns of the main trace.
+    pub width: u16,
+    /// Number of public values.
+    pub num_public_values: u16,
+    /// The expression graph, in topological order.
+    pub nodes: Vec<Node>,
+    /// The nodes constrained to be zero on every row.
+    pub constraints: Vec<u16>,
+}
+
+/// Why a vk was rejected.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum VkError {
+    UnsupportedAirsCount(usize),
+    UnsupportedFriParams,
+    InsufficientSecurity(u32),
+    UnsupportedWidth { air: usize },
+    TooManyNodes { air: usize },
+    InvalidNode { air: usize, node: usize },
+    NoConstraints { air: usize },
+    InvalidConstraint { air: usize, constraint: usize },
+    ConstraintDegreeTooHigh { air: usize, constraint: usize },
+}
+
+// Here educe is used for Clone, Debug, and PartialEq to work around
+// a long-standing compiler bug https://github.com/rust-lang/rust/issues/26925
+#[derive(Educe, Encode, Decode, TypeInfo)]
+#[educe(Clone, Debug, PartialEq)]
+#[scale_info(skip_type_params(T))]
+pub struct Vk<T> {
+    pub field: Field,
+    pub fri: FriParams,
+    /// A single AIR for uni-stark proofs, one per instance for batch-stark ones.
+    pub airs: Vec<AirDescriptor>,
+    _marker: PhantomData<T>,
+}
+
+impl<T> Vk<T> {
+    pub fn new(field: Field, fri: FriParams, airs: Vec<AirDescriptor>) -> Self {
+        Self {
+            field,
+            fri,
+            airs,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Check that the vk describes a STARK the verifier supports.
+    pub fn validate(&self) -> Result<(), VkError> {
+        let fri = &self.fri;
+        (1..=MAX_AIRS)
+            .contains(&self.airs.len())
+            .then_some(())
+            .ok_or(VkError::UnsupportedAirsCount(self.airs.len()))?;
+        ((MIN_LOG_BLOWUP..=MAX_LOG_BLOWUP).contains(&fri.log_blowup)
+            && fri.log_final_poly_len <= MAX_LOG_FINAL_POLY_LEN
+            && fri.num_queries <= MAX_NUM_QUERIES
+            && fri.commit_proof_of_work_bits <= MAX_PROOF_OF_WORK_BITS
+            && fri.query_proof_of_work_bits <= MAX_PROOF_OF_WORK_BITS)
+            .then_some(())
+            .ok_or(VkError::UnsupportedFriParams)?;
+        let security_bits = fri.conjectured_security_bits();
+        (security_bits >= MIN_SECURITY_BITS)
+            .then_some(())
+            .ok_or(VkError::InsufficientSecurity(security_bits))?;
+
+        // Plonky3 requires the quotient degree to fit in the blowup.
+        let max_constraint_degree = (1 << fri.log_blowup) + 1;
+        self.airs
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, air)| air.validate(i, self.field, max_constraint_degree))
+    }
+}
+
+impl AirDescriptor {
+    fn validate(
+        &self,
+        air: usize,
+        field: Field,
+        max_constraint_degree: usize,
+    ) -> Result<(), VkError> {
+        (1..=MAX_AIR_WIDTH)
+            .contains(&self.width)
+            .then_some(())
+            .ok_or(VkError::UnsupportedWidth { air })?;
+        (self.nodes.len() <= MAX_AIR_NODES)
+            .then_some(())
+            .ok_or(VkError::TooManyNodes { air })?;
+
+        let mut degrees: Vec<usize> = Vec::with_capacity(self.nodes.len());
+        for (node, n) in self.nodes.iter().enumerate() {
+            let invalid = VkError::InvalidNode { air, node };
+            let degree_of = |i: u16| degrees.get(i as usize).copied().ok_or(invalid);
+            let degree = match *n {
+                Node::Constant(c) if c < field.order() => 0,
+                Node::Constant(_) => return Err(invalid),
+                Node::Main { column, .. } if column < self.width => 1,
+                Node::Main { .. } => return Err(invalid),
+                Node::Public(i) if i < self.num_public_values => 0,
+                Node::Public(_) => return Err(invalid),
+                Node::IsFirstRow | Node::IsLastRow | Node::IsTransition => 1,
+                Node::Add(a, b) | Node::Sub(a, b) => degree_of(a)?.max(degree_of(b)?),
+                Node::Mul(a, b) => degree_of(a)?.saturating_add(degree_of(b)?),
+                Node::Neg(a) => degree_of(a)?,
+            };
+            degrees.push(degree);
+        }
+
+        (!self.constraints.is_empty())
+            .then_some(())
+            .ok_or(VkError::NoConstraints { air })?;
+        self.constraints
+            .iter()
+            .enumerate()
+            .try_for_each(|(constraint, &node)| {
+                let degree = degrees
+                    .get(node as usize)
+                    .ok_or(VkError::InvalidConstraint { air, constraint })?;
+                (*degree <= max_constraint_degree)
+                    .then_some(())
+                    .ok_or(VkError::ConstraintDegreeTooHigh { air, constraint })
+            })
+    }
+}
+
+impl<T: Config> MaxEncodedLen for Vk<T> {
+    fn max_encoded_len() -> usize {
+        Field::max_encoded_len()
+            + FriParams::max_encoded_len()
+            + codec::Compact(T::max_vk_size()).encoded_size()
+            + T::max_vk_size() as usize
+    }
+}
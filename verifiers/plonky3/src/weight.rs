@@ -0,0 +1,98 @@
+// Copyright 2024-2026, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_plonky3_verifier`
+//!
+//! `verify_air_*` is the out of domain evaluation of an AIR with `n` nodes
+//! opening `c` columns, `verify_query_*` the Merkle opening of `c` columns in
+//! a tree of `2^h` leaves: `crate::cost` weights a proof on top of them. Their
+//! slopes are a linear fit of the `benchmarking.rs` blocks timed on a
+//! development machine in release mode, varying one component at a time with
+//! the other one at its maximum; the other weights are still estimates.
+//! Regenerate this file with the substrate benchmark CLI on the reference
+//! hardware before wiring the pallet in a runtime.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_plonky3_verifier`.
+pub trait WeightInfo {
+    fn get_vk() -> Weight;
+    fn validate_vk() -> Weight;
+    fn compute_statement_hash() -> Weight;
+    fn register_vk() -> Weight;
+    fn unregister_vk() -> Weight;
+    fn verify_air_baby_bear(c: u32, n: u32, ) -> Weight;
+    fn verify_query_baby_bear(h: u32, c: u32, ) -> Weight;
+    fn verify_air_koala_bear(c: u32, n: u32, ) -> Weight;
+    fn verify_query_koala_bear(h: u32, c: u32, ) -> Weight;
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    fn get_vk() -> Weight {
+        Weight::from_parts(10_000_000, 53510)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+    }
+    fn validate_vk() -> Weight {
+        Weight::from_parts(50_000_000, 0)
+    }
+    fn compute_statement_hash() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+    }
+    fn register_vk() -> Weight {
+        Weight::from_parts(150_000_000, 53510)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    fn unregister_vk() -> Weight {
+        Weight::from_parts(60_000_000, 53510)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// The range of component `c` is `[1, 1056]`.
+    /// The range of component `n` is `[1, 65535]`.
+    fn verify_air_baby_bear(c: u32, n: u32, ) -> Weight {
+        Weight::from_parts(0, 0)
+            .saturating_add(Weight::from_parts(122_580, 0).saturating_mul(c.into()))
+            .saturating_add(Weight::from_parts(26_910, 0).saturating_mul(n.into()))
+    }
+    /// The range of component `h` is `[1, 25]`.
+    /// The range of component `c` is `[1, 1056]`.
+    fn verify_query_baby_bear(h: u32, c: u32, ) -> Weight {
+        Weight::from_parts(11_549_000, 0)
+            .saturating_add(Weight::from_parts(2_924_610, 0).saturating_mul(h.into()))
+            .saturating_add(Weight::from_parts(386_670, 0).saturating_mul(c.into()))
+    }
+    /// The range of component `c` is `[1, 1056]`.
+    /// The range of component `n` is `[1, 65535]`.
+    fn verify_air_koala_bear(c: u32, n: u32, ) -> Weight {
+        Weight::from_parts(0, 0)
+            .saturating_add(Weight::from_parts(342_290, 0).saturating_mul(c.into()))
+            .saturating_add(Weight::from_parts(29_960, 0).saturating_mul(n.into()))
+    }
+    /// The range of component `h` is `[1, 25]`.
+    /// The range of component `c` is `[1, 1056]`.
+    fn verify_query_koala_bear(h: u32, c: u32, ) -> Weight {
+        Weight::from_parts(40_159_000, 0)
+            .saturating_add(Weight::from_parts(3_618_260, 0).saturating_mul(h.into()))
+            .saturating_add(Weight::from_parts(501_830, 0).saturating_mul(c.into()))
+    }
+}
@@ -160,13 +160,13 @@ fn impl_risc0_proof(data: EnumData) -> proc_macro2::TokenStream {
         impl #proof {
             fn len(&self) -> usize {
                 match self {
-                    #(#proof::#local_variants(proof_bytes) => proof_bytes.len()),*
+                    #(#proof::#local_variants(proof_bytes) => AsRef::<[u8]>::as_ref(proof_bytes).len()),*
                 }
             }
 
             fn bytes(&self) -> &[u8] {
                 match self {
-                    #(#proof::#local_variants(proof_bytes) => proof_bytes.as_slice()),*
+                    #(#proof::#local_variants(proof_bytes) => AsRef::<[u8]>::as_ref(proof_bytes)),*
                 }
             }
         }
@@ -321,19 +321,19 @@ mod test {
             impl Proof {
                 fn len(&self) -> usize {
                     match self {
-                        Proof::V1_0(proof_bytes) => proof_bytes.len(),
-                        Proof::V1_1(proof_bytes) => proof_bytes.len(),
-                        Proof::V1_2(proof_bytes) => proof_bytes.len(),
-                        Proof::V2_0(proof_bytes) => proof_bytes.len()
+                        Proof::V1_0(proof_bytes) => AsRef::<[u8]>::as_ref(proof_bytes).len(),
+                        Proof::V1_1(proof_bytes) => AsRef::<[u8]>::as_ref(proof_bytes).len(),
+                        Proof::V1_2(proof_bytes) => AsRef::<[u8]>::as_ref(proof_bytes).len(),
+                        Proof::V2_0(proof_bytes) => AsRef::<[u8]>::as_ref(proof_bytes).len()
                     }
                 }
 
                 fn bytes(&self) -> &[u8] {
                     match self {
-                        Proof::V1_0(proof_bytes) => proof_bytes.as_slice(),
-                        Proof::V1_1(proof_bytes) => proof_bytes.as_slice(),
-                        Proof::V1_2(proof_bytes) => proof_bytes.as_slice(),
-                        Proof::V2_0(proof_bytes) => proof_bytes.as_slice()
+                        Proof::V1_0(proof_bytes) => AsRef::<[u8]>::as_ref(proof_bytes),
+                        Proof::V1_1(proof_bytes) => AsRef::<[u8]>::as_ref(proof_bytes),
+                        Proof::V1_2(proof_bytes) => AsRef::<[u8]>::as_ref(proof_bytes),
+                        Proof::V2_0(proof_bytes) => AsRef::<[u8]>::as_ref(proof_bytes)
                     }
                 }
             }
@@ -138,6 +138,7 @@ pub mod mock {
         type Segment20MaxSize = ConstU32<10000>; // Doesn't matter: never verify proof
         type MaxPubsSize = ConstU32<100>;
         type WeightInfo = ();
+        type VerifiedClaims = ();
     }
 
     #[derive_impl(frame_system::config_preludes::SolochainDefaultConfig as frame_system::DefaultConfig)]
@@ -36,6 +36,8 @@ mod benchmarks {
 
     benchmarking_utils!(Verifier<T>, crate::Config);
 
+    // TODO: add the `verify_proof_groth16` benchmark once we have a Groth16 receipt
+    // fixture.
     #[benchmark]
     fn verify_proof_succinct() {
         let vk = VK;
@@ -172,6 +174,7 @@ pub mod mock {
         type Segment20MaxSize = ConstU32<350_000>;
         type MaxPubsSize = ConstU32<2060>;
         type WeightInfo = ();
+        type VerifiedClaims = ();
     }
 
     #[derive_impl(frame_system::config_preludes::SolochainDefaultConfig as frame_system::DefaultConfig)]
@@ -0,0 +1,199 @@
+// Copyright 2024-2026, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The record of the claims proved by the verified risc0 proofs, used to resolve the
+//! assumptions of composed receipts.
+
+pub use pallet::*;
+
+use frame_support::{pallet_prelude::*, weights::Weight};
+use sp_core::H256;
+
+/// What a verified proof attests: the program `image_id` ran and committed a journal
+/// whose SHA-256 is `journal_digest`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct Claim {
+    pub image_id: H256,
+    pub journal_digest: H256,
+}
+
+/// Keeps the claims of the verified proofs indexed by their statement hash.
+pub trait VerifiedClaims {
+    /// The claim proved by the proof with the given statement hash, if any.
+    fn get(statement: &H256) -> Option<Claim>;
+    /// Record the claim proved by the proof with the given statement hash.
+    fn insert(statement: H256, claim: Claim);
+    /// The weight of a single [`VerifiedClaims::get`] call.
+    fn get_weight() -> Weight;
+    /// The weight of a single [`VerifiedClaims::insert`] call.
+    fn insert_weight() -> Weight;
+}
+
+/// No record at all: composed receipts can never be resolved.
+impl VerifiedClaims for () {
+    fn get(_statement: &H256) -> Option<Claim> {
+        None
+    }
+
+    fn insert(_statement: H256, _claim: Claim) {}
+
+    fn get_weight() -> Weight {
+        Weight::zero()
+    }
+
+    fn insert_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::Claim;
+    use frame_support::{pallet_prelude::*, Identity};
+    use sp_core::H256;
+
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+    #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    /// The record of the claims proved by the verified risc0 proofs.
+    pub struct Pallet<T>(_);
+
+    /// Configure the pallet by specifying the parameters and types on which it depends.
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Maximum number of recorded claims: the slots are used as a ring, so a new claim
+        /// expires the oldest one.
+        #[pallet::constant]
+        type MaxClaims: Get<u32>;
+    }
+
+    /// The recorded claims, by the statement hash of the proof that proved them.
+    #[pallet::storage]
+    pub type Claims<T: Config> = StorageMap<_, Identity, H256, Claim>;
+
+    /// The statements of the recorded claims, by slot.
+    #[pallet::storage]
+    pub type ClaimSlots<T: Config> = StorageMap<_, Twox64Concat, u32, H256>;
+
+    /// The slot that the next recorded claim takes.
+    #[pallet::storage]
+    pub type NextClaimSlot<T: Config> = StorageValue<_, u32, ValueQuery>;
+}
+
+/// Recording a claim that is already there doesn't refresh it.
+impl<T: Config> VerifiedClaims for Pallet<T> {
+    fn get(statement: &H256) -> Option<Claim> {
+        Claims::<T>::get(statement)
+    }
+
+    fn insert(statement: H256, claim: Claim) {
+        if Claims::<T>::contains_key(statement) {
+            return;
+        }
+        let slot = NextClaimSlot::<T>::get();
+        if let Some(expired) = ClaimSlots::<T>::get(slot) {
+            Claims::<T>::remove(expired);
+        }
+        ClaimSlots::<T>::insert(slot, statement);
+        Claims::<T>::insert(statement, claim);
+        NextClaimSlot::<T>::put(slot.saturating_add(1) % T::MaxClaims::get().max(1));
+    }
+
+    fn get_weight() -> Weight {
+        T::DbWeight::get().reads(1)
+    }
+
+    fn insert_weight() -> Weight {
+        T::DbWeight::get().reads_writes(3, 4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frame_support::derive_impl;
+    use frame_support::sp_runtime::BuildStorage;
+    use sp_core::ConstU32;
+
+    frame_support::construct_runtime!(
+        pub enum Test
+        {
+            System: frame_system,
+            Risc0Claims: crate::claims,
+        }
+    );
+
+    #[derive_impl(frame_system::config_preludes::SolochainDefaultConfig as frame_system::DefaultConfig)]
+    impl frame_system::Config for Test {
+        type Block = frame_system::mocking::MockBlockU32<Test>;
+    }
+
+    impl Config for Test {
+        type MaxClaims = ConstU32<2>;
+    }
+
+    fn test_ext() -> sp_io::TestExternalities {
+        frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap()
+            .into()
+    }
+
+    fn claim(n: u8) -> Claim {
+        Claim {
+            image_id: H256::repeat_byte(n),
+            journal_digest: H256::repeat_byte(n.wrapping_add(1)),
+        }
+    }
+
+    #[test]
+    fn record_the_claims() {
+        test_ext().execute_with(|| {
+            Risc0Claims::insert(H256::repeat_byte(1), claim(1));
+            Risc0Claims::insert(H256::repeat_byte(2), claim(2));
+
+            assert_eq!(Risc0Claims::get(&H256::repeat_byte(1)), Some(claim(1)));
+            assert_eq!(Risc0Claims::get(&H256::repeat_byte(2)), Some(claim(2)));
+            assert_eq!(Risc0Claims::get(&H256::repeat_byte(3)), None);
+        });
+    }
+
+    #[test]
+    fn expire_the_oldest_claim_when_full() {
+        test_ext().execute_with(|| {
+            for n in 1..=3 {
+                Risc0Claims::insert(H256::repeat_byte(n), claim(n));
+            }
+
+            assert_eq!(Risc0Claims::get(&H256::repeat_byte(1)), None);
+            assert_eq!(Risc0Claims::get(&H256::repeat_byte(2)), Some(claim(2)));
+            assert_eq!(Risc0Claims::get(&H256::repeat_byte(3)), Some(claim(3)));
+        });
+    }
+
+    #[test]
+    fn not_refresh_a_claim_recorded_again() {
+        test_ext().execute_with(|| {
+            Risc0Claims::insert(H256::repeat_byte(1), claim(1));
+            Risc0Claims::insert(H256::repeat_byte(2), claim(2));
+            Risc0Claims::insert(H256::repeat_byte(1), claim(1));
+            Risc0Claims::insert(H256::repeat_byte(3), claim(3));
+
+            assert_eq!(Risc0Claims::get(&H256::repeat_byte(1)), None);
+            assert_eq!(Risc0Claims::get(&H256::repeat_byte(2)), Some(claim(2)));
+        });
+    }
+}
@@ -29,6 +29,7 @@ use sp_core::{Get, H256};
 
 pub mod benchmarking;
 pub mod benchmarking_verify_proof;
+pub mod claims;
 pub mod extend_benchmarking;
 pub mod fake_extend_benchmarking;
 
@@ -40,6 +41,7 @@ mod weight;
 mod weight_verify_proof;
 
 pub use crate::weight_verify_proof::WeightInfo as WeightInfoVerifyProof;
+pub use claims::{Claim, VerifiedClaims};
 pub use weight::WeightInfo;
 
 pub trait Config {
@@ -57,6 +59,10 @@ pub trait Config {
     /// Weight info used to compute the verify proof weight
     type WeightInfo: WeightInfoVerifyProof;
 
+    /// Record of the claims proved by the verified proofs: used to resolve the assumptions
+    /// of composed receipts (see [`claims::Pallet`])
+    type VerifiedClaims: VerifiedClaims;
+
     fn max_proof_size() -> u32 {
         Self::MaxNSegment::get() * Self::Segment20MaxSize::get()
     }
@@ -70,6 +76,9 @@ pub trait Config {
     }
 }
 
+/// Maximum number of assumptions that a composed receipt can resolve.
+pub const MAX_ASSUMPTIONS: u32 = 8;
+
 #[pallet_verifiers::verifier]
 pub struct Risc0<T>;
 
@@ -87,6 +96,42 @@ pub enum Proof {
     V2_2(Vec<u8>),
     V2_3(Vec<u8>),
     V3_0(Vec<u8>),
+    V3_0Composed(ComposedReceipt),
+}
+
+/// A receipt that takes part in a composition: its assumptions (the proofs verified by the
+/// guest through `env::verify`) are resolved by proofs already verified on this chain, and
+/// its own claim can be recorded to resolve the assumptions of later receipts.
+#[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo)]
+pub struct ComposedReceipt {
+    /// The serialized receipt.
+    pub receipt: Vec<u8>,
+    /// The statement hashes of the proofs that resolve the receipt assumptions, in the
+    /// same order of the assumptions.
+    pub assumptions: BoundedVec<H256, ConstU32<MAX_ASSUMPTIONS>>,
+    /// Record the claim of this receipt once it's submitted, so that later receipts can
+    /// cite its statement. Recorded claims expire when newer ones take their slot (see
+    /// [`claims::Config::MaxClaims`]).
+    pub record_claim: bool,
+}
+
+impl AsRef<[u8]> for ComposedReceipt {
+    fn as_ref(&self) -> &[u8] {
+        &self.receipt
+    }
+}
+
+impl Proof {
+    fn assumptions(&self) -> &[H256] {
+        match self {
+            Proof::V3_0Composed(composed) => &composed.assumptions,
+            _ => &[],
+        }
+    }
+
+    fn record_claim(&self) -> bool {
+        matches!(self, Proof::V3_0Composed(composed) if composed.record_claim)
+    }
 }
 
 pub type Pubs = Vec<u8>;
@@ -95,18 +140,47 @@ pub type Vk = H256;
 enum ProofStructure {
     Succinct,
     Composite(Vec<SegmentInfo>),
+    Groth16,
 }
 
 impl R0Proof {
-    fn verify(self, vk: Risc0Vk, journal: Journal) -> Result<(), VerifyError> {
-        self.verifier()
-            .verify(vk.into(), self.take_proof(), journal)
+    fn verify(
+        self,
+        vk: Risc0Vk,
+        journal: Journal,
+        assumptions: &[Claim],
+    ) -> Result<(), VerifyError> {
+        let verifier = self.verifier();
+        let proof = self.take_proof();
+        let result = if assumptions.is_empty() {
+            verifier.verify(vk.into(), proof, journal)
+        } else {
+            let assumptions = assumptions
+                .iter()
+                .map(|c| {
+                    risc0_verifier::Assumption::new(c.image_id.0.into(), c.journal_digest.0.into())
+                })
+                .collect::<Vec<_>>();
+            verifier.verify_conditional(vk.into(), proof, journal, &assumptions)
+        };
+        result
             .inspect_err(|e| debug!("Cannot verify proof: {e:?}"))
             .map_err(|_| VerifyError::VerifyError)
     }
 
     fn proof_structure(&self) -> Result<ProofStructure, ()> {
         let r0_proof = self.proof();
+        if r0_proof.inner.groth16().is_ok() {
+            // Groth16 receipts are checked against the control root of the proof version:
+            // the verifier returned by `verifier()` carries the right one.
+            return match self {
+                R0Proof::V2_1(_)
+                | R0Proof::V2_2(_)
+                | R0Proof::V2_3(_)
+                | R0Proof::V3_0(_)
+                | R0Proof::V3_0Composed(_) => Ok(ProofStructure::Groth16),
+            };
+        }
         let structure = match r0_proof.inner.composite().ok() {
             Some(c) => self
                 .verifier()
@@ -123,7 +197,9 @@ impl R0Proof {
             R0Proof::V2_1(_r0_proof) => v2_1().inject_native_poseidon2_if_needed().boxed(),
             R0Proof::V2_2(_r0_proof) => v2_2().inject_native_poseidon2_if_needed().boxed(),
             R0Proof::V2_3(_r0_proof) => v2_3().inject_native_poseidon2_if_needed().boxed(),
-            R0Proof::V3_0(_r0_proof) => v3_0().inject_native_poseidon2_if_needed().boxed(),
+            R0Proof::V3_0(_r0_proof) | R0Proof::V3_0Composed(_r0_proof) => {
+                v3_0().inject_native_poseidon2_if_needed().boxed()
+            }
         }
     }
 }
@@ -171,6 +247,8 @@ impl<T: Config> Verifier for Risc0<T> {
 
     type Vk = Vk;
 
+    type VkMetadata = ();
+
     fn hash_context_data() -> &'static [u8] {
         b"risc0"
     }
@@ -189,14 +267,29 @@ impl<T: Config> Verifier for Risc0<T> {
             pubs.len() <= T::MaxPubsSize::get() as usize,
             VerifyError::InvalidInput
         );
+        let assumptions = proof
+            .assumptions()
+            .iter()
+            .map(|statement| {
+                T::VerifiedClaims::get(statement).ok_or_else(|| {
+                    debug!("No verified claim for statement {statement:?}");
+                    VerifyError::InvalidProofData
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
         log::trace!("Verifying (native)");
         let journal = Journal::new(pubs.to_vec());
         let proof_len = proof.len();
-        let proof = R0Proof::try_from(proof)?;
-        let w = proof
+        let r0_proof = R0Proof::try_from(proof)?;
+        let structure = r0_proof
             .proof_structure()
-            .and_then(Self::verify_weight)
             .map_err(|_| VerifyError::InvalidProofData)?;
+        // Groth16 receipts are always unconditional: they cannot have assumptions to resolve
+        ensure!(
+            assumptions.is_empty() || !matches!(structure, ProofStructure::Groth16),
+            VerifyError::InvalidProofData
+        );
+        let w = Self::verify_weight(structure).map_err(|_| VerifyError::InvalidProofData)?;
         let max_w = T::max_verify_proof_weight().ref_time();
         if w.ref_time() > max_w {
             debug!(
@@ -205,7 +298,17 @@ impl<T: Config> Verifier for Risc0<T> {
             );
             fail!(VerifyError::InvalidProofData)
         }
-        proof.verify(vk.0.into(), journal).map(|_| Some(w))
+        r0_proof.verify(vk.0.into(), journal, &assumptions)?;
+        Ok(Some(w.saturating_add(Self::claims_weight(
+            assumptions.len(),
+            proof.record_claim(),
+        ))))
+    }
+
+    fn on_proof_verified(vk: &Self::Vk, proof: &Self::Proof, pubs: &Self::Pubs, statement: H256) {
+        if proof.record_claim() {
+            Self::record_claim(vk, pubs, statement);
+        }
     }
 
     fn pubs_bytes(pubs: &Self::Pubs) -> Cow<'_, [u8]> {
@@ -243,7 +346,7 @@ impl<T: Config> Verifier for Risc0<T> {
             Proof::V2_3(_) => hex_literal::hex!(
                 "09c82225a1a8d085090e5169383d874fcca7a340517654659c4eb6fd105e79ec"
             ),
-            Proof::V3_0(_) => hex_literal::hex!(
+            Proof::V3_0(_) | Proof::V3_0Composed(_) => hex_literal::hex!(
                 "aa24368f9ce9025f58596d966209fc3f322c8cac37dae753bb9b0367d273700d"
             ),
         };
@@ -252,9 +355,35 @@ impl<T: Config> Verifier for Risc0<T> {
 }
 
 impl<T: Config> Risc0<T> {
+    /// Record the claim proved by a submitted proof that asked for it, so that later
+    /// composed receipts can cite its statement to resolve their assumptions.
+    fn record_claim(vk: &Vk, pubs: &Pubs, statement: H256) {
+        T::VerifiedClaims::insert(
+            statement,
+            Claim {
+                image_id: *vk,
+                journal_digest: H256(sp_io::hashing::sha2_256(pubs)),
+            },
+        );
+    }
+
+    /// The weight needed to resolve `n_assumptions` claims and, if requested, to record
+    /// the new one.
+    fn claims_weight(n_assumptions: usize, record_claim: bool) -> Weight {
+        let record = if record_claim {
+            T::VerifiedClaims::insert_weight()
+        } else {
+            Weight::zero()
+        };
+        T::VerifiedClaims::get_weight()
+            .saturating_mul(n_assumptions as u64)
+            .saturating_add(record)
+    }
+
     fn verify_weight(structure: ProofStructure) -> Result<Weight, ()> {
         let w = match structure {
             ProofStructure::Succinct => T::WeightInfo::verify_proof_succinct(),
+            ProofStructure::Groth16 => T::WeightInfo::verify_proof_groth16(),
             ProofStructure::Composite(powers) => powers
                 .into_iter()
                 .map(|power| Self::segment_weight(power))
@@ -286,10 +415,13 @@ pub struct Risc0Weight<W: WeightInfo>(PhantomData<W>);
 
 impl<T: Config, W: WeightInfo> pallet_verifiers::WeightInfo<Risc0<T>> for Risc0Weight<W> {
     fn verify_proof(
-        _proof: &<Risc0<T> as Verifier>::Proof,
+        proof: &<Risc0<T> as Verifier>::Proof,
         _pubs: &<Risc0<T> as Verifier>::Pubs,
     ) -> Weight {
-        <T as Config>::max_verify_proof_weight()
+        <T as Config>::max_verify_proof_weight().saturating_add(Risc0::<T>::claims_weight(
+            proof.assumptions().len(),
+            proof.record_claim(),
+        ))
     }
 
     fn register_vk(_vk: &<Risc0<T> as Verifier>::Vk) -> Weight {
@@ -27,6 +27,7 @@ impl Config for Mock {
     type Segment20MaxSize = ConstU32<350_000>;
     type MaxPubsSize = ConstU32<100>;
     type WeightInfo = ();
+    type VerifiedClaims = ();
 }
 include!("resources.rs");
 
@@ -94,6 +95,10 @@ fn reject_unsupported_proof_version(#[case] proof: Proof) {
     Proof::V3_0(Default::default()),
     H256::from(sp_io::hashing::sha2_256(b"risc0:v3.0"))
 )]
+#[case::v3_0_composed(
+    Proof::V3_0Composed(ComposedReceipt { receipt: Default::default(), assumptions: vec![H256::zero()], record_claim: true }),
+    H256::from(sp_io::hashing::sha2_256(b"risc0:v3.0"))
+)]
 #[case::do_not_depend_on_proof_content(
     Proof::V2_1([0xde;16].to_vec()),
     H256::from(sp_io::hashing::sha2_256(b"risc0:v2.1"))
@@ -164,6 +169,7 @@ mod reject {
             type Segment20MaxSize = ConstU32<1_000_000>;
             type MaxPubsSize = ConstU32<100>;
             type WeightInfo = ();
+            type VerifiedClaims = ();
         }
 
         assert_eq!(
@@ -214,6 +220,14 @@ fn compute_correct_weight_for_succinct_proof() {
     );
 }
 
+#[test]
+fn compute_correct_weight_for_groth16_proof() {
+    assert_eq!(
+        Risc0::<Mock>::verify_weight(ProofStructure::Groth16),
+        Ok(<() as crate::WeightInfoVerifyProof>::verify_proof_groth16())
+    );
+}
+
 #[test]
 fn segment_weight_return_error_if_unsupported_size_or_hash() {
     assert!(Risc0::<Mock>::segment_weight(SegmentInfo::new("unknown".to_owned(), 20)).is_err());
@@ -243,3 +257,198 @@ mod weight {
         );
     }
 }
+
+mod composed {
+    use super::*;
+    use core::cell::RefCell;
+    use std::collections::HashMap;
+
+    thread_local! {
+        static CLAIMS: RefCell<HashMap<H256, Claim>> = RefCell::new(HashMap::new());
+    }
+
+    struct MemClaims;
+
+    impl VerifiedClaims for MemClaims {
+        fn get(statement: &H256) -> Option<Claim> {
+            CLAIMS.with(|c| c.borrow().get(statement).copied())
+        }
+
+        fn insert(statement: H256, claim: Claim) {
+            CLAIMS.with(|c| c.borrow_mut().insert(statement, claim));
+        }
+
+        fn get_weight() -> Weight {
+            Weight::from_parts(10, 0)
+        }
+
+        fn insert_weight() -> Weight {
+            Weight::from_parts(100, 0)
+        }
+    }
+
+    struct WithClaims;
+
+    impl Config for WithClaims {
+        type MaxNSegment = ConstU32<4>;
+        type Segment20MaxSize = ConstU32<350_000>;
+        type MaxPubsSize = ConstU32<100>;
+        type WeightInfo = ();
+        type VerifiedClaims = MemClaims;
+    }
+
+    fn composed(assumptions: Vec<H256>) -> Proof {
+        Proof::V3_0Composed(ComposedReceipt {
+            receipt: v3_0::PROOF_SUCCINCT.to_vec(),
+            assumptions: assumptions.try_into().unwrap(),
+            record_claim: false,
+        })
+    }
+
+    fn recorded() -> Proof {
+        Proof::V3_0Composed(ComposedReceipt {
+            receipt: v3_0::PROOF_SUCCINCT.to_vec(),
+            assumptions: Default::default(),
+            record_claim: true,
+        })
+    }
+
+    fn statement(vk: H256, proof: &Proof, pubs: &Pubs) -> H256 {
+        pallet_verifiers::compute_statement_hash::<Risc0<WithClaims>>(
+            &VkOrHash::from_hash(vk),
+            proof,
+            pubs,
+        )
+    }
+
+    #[test]
+    fn record_the_claim_of_submitted_proofs_that_ask_for_it() {
+        let proof = recorded();
+        let pubs = v3_0::PUBS_SUCCINCT.to_vec();
+        let statement = statement(v3_0::VK, &proof, &pubs);
+
+        Risc0::<WithClaims>::verify_proof(&v3_0::VK, &proof, &pubs).unwrap();
+        Risc0::<WithClaims>::on_proof_verified(&v3_0::VK, &proof, &pubs, statement);
+
+        assert_eq!(
+            MemClaims::get(&statement),
+            Some(Claim {
+                image_id: v3_0::VK,
+                journal_digest: H256(sp_io::hashing::sha2_256(&pubs)),
+            })
+        );
+    }
+
+    #[rstest]
+    #[case::plain(Proof::V3_0(v3_0::PROOF_SUCCINCT.to_vec()))]
+    #[case::composed(composed(vec![]))]
+    fn not_record_the_claim_of_submitted_proofs_that_do_not_ask_for_it(#[case] proof: Proof) {
+        let pubs = v3_0::PUBS_SUCCINCT.to_vec();
+        let statement = statement(v3_0::VK, &proof, &pubs);
+
+        Risc0::<WithClaims>::verify_proof(&v3_0::VK, &proof, &pubs).unwrap();
+        Risc0::<WithClaims>::on_proof_verified(&v3_0::VK, &proof, &pubs, statement);
+
+        assert_eq!(MemClaims::get(&statement), None);
+    }
+
+    #[test]
+    fn not_record_the_claim_of_proofs_that_are_just_verified() {
+        let proof = recorded();
+        let pubs = v3_0::PUBS_SUCCINCT.to_vec();
+
+        Risc0::<WithClaims>::verify_proof(&v3_0::VK, &proof, &pubs).unwrap();
+
+        assert_eq!(MemClaims::get(&statement(v3_0::VK, &proof, &pubs)), None);
+    }
+
+    #[test]
+    fn reject_receipt_citing_an_unknown_statement() {
+        assert_eq!(
+            Risc0::<WithClaims>::verify_proof(
+                &v3_0::VK,
+                &composed(vec![H256::repeat_byte(0xaa)]),
+                &v3_0::PUBS_SUCCINCT.to_vec()
+            ),
+            Err(VerifyError::InvalidProofData)
+        );
+    }
+
+    #[test]
+    fn reject_receipt_with_too_many_assumptions() {
+        let encoded = (
+            v3_0::PROOF_SUCCINCT.to_vec(),
+            vec![H256::zero(); MAX_ASSUMPTIONS as usize + 1],
+            false,
+        )
+            .encode();
+
+        assert!(ComposedReceipt::decode(&mut encoded.as_slice()).is_err());
+    }
+
+    #[test]
+    fn reject_receipt_whose_assumptions_do_not_match_the_cited_claims() {
+        let statement = H256::repeat_byte(0xbb);
+        MemClaims::insert(
+            statement,
+            Claim {
+                image_id: H256::repeat_byte(0x01),
+                journal_digest: H256::repeat_byte(0x02),
+            },
+        );
+
+        // The receipt doesn't have any assumption at all.
+        assert_eq!(
+            Risc0::<WithClaims>::verify_proof(
+                &v3_0::VK,
+                &composed(vec![statement]),
+                &v3_0::PUBS_SUCCINCT.to_vec()
+            ),
+            Err(VerifyError::VerifyError)
+        );
+    }
+
+    #[test]
+    fn verify_receipt_without_assumptions() {
+        assert!(Risc0::<WithClaims>::verify_proof(
+            &v3_0::VK,
+            &composed(vec![]),
+            &v3_0::PUBS_SUCCINCT.to_vec()
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn weight_the_assumptions_resolution() {
+        let base =
+            <Risc0Weight<()> as pallet_verifiers::WeightInfo<Risc0<WithClaims>>>::verify_proof(
+                &Proof::V3_0(Vec::new()),
+                &Vec::new(),
+            );
+
+        assert_eq!(
+            <Risc0Weight<()> as pallet_verifiers::WeightInfo<Risc0<WithClaims>>>::verify_proof(
+                &composed(vec![H256::zero(); 2]),
+                &Vec::new(),
+            ),
+            base + Weight::from_parts(2 * 10, 0)
+        );
+    }
+
+    #[test]
+    fn weight_the_claim_recording_only_when_asked() {
+        let base =
+            <Risc0Weight<()> as pallet_verifiers::WeightInfo<Risc0<WithClaims>>>::verify_proof(
+                &composed(vec![]),
+                &Vec::new(),
+            );
+
+        assert_eq!(
+            <Risc0Weight<()> as pallet_verifiers::WeightInfo<Risc0<WithClaims>>>::verify_proof(
+                &recorded(),
+                &Vec::new(),
+            ),
+            base + Weight::from_parts(100, 0)
+        );
+    }
+}
@@ -50,6 +50,7 @@ use core::marker::PhantomData;
 /// Weight functions needed for `pallet_risc0_verifier_tuning`.
 pub trait WeightInfo {
     fn verify_proof_succinct() -> Weight;
+    fn verify_proof_groth16() -> Weight;
     fn verify_proof_segment_poseidon2_16() -> Weight;
     fn verify_proof_segment_poseidon2_17() -> Weight;
     fn verify_proof_segment_poseidon2_18() -> Weight;
@@ -67,6 +68,11 @@ impl WeightInfo for () {
         // Minimum execution time: 25_159_349_000 picoseconds.
         Weight::from_parts(25_393_350_000, 0)
     }
+    fn verify_proof_groth16() -> Weight {
+        // TODO: not benchmarked yet, we need a Groth16 receipt fixture. Use the succinct
+        // receipt weight as an upper bound.
+        Weight::from_parts(25_393_350_000, 0)
+    }
     fn verify_proof_segment_poseidon2_16() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `0`
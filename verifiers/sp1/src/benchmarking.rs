@@ -36,9 +36,11 @@ mod benchmarks {
 
     benchmarking_utils!(Verifier<T>, crate::Config);
 
+    // TODO: add the `verify_proof_groth16` and `verify_proof_plonk` benchmarks once we
+    // have SP1 wrapped proof fixtures.
     #[benchmark]
     fn verify_proof() {
-        let proof = include_bytes!("resources/proof.bin").to_vec();
+        let proof = crate::Proof::V5_0(include_bytes!("resources/proof.bin").to_vec());
         let pubs = VALID_PUBS.to_vec();
         let vk = VALID_VK;
 
@@ -79,7 +81,7 @@ mod benchmarks {
 
     #[benchmark]
     fn compute_statement_hash() {
-        let proof = include_bytes!("resources/proof.bin").to_vec();
+        let proof = crate::Proof::V5_0(include_bytes!("resources/proof.bin").to_vec());
         let pubs = VALID_PUBS.to_vec();
         let vk = VALID_VK;
 
@@ -24,19 +24,142 @@ mod weight;
 use alloc::{borrow::Cow, vec::Vec};
 use core::marker::PhantomData;
 
+use codec::{Decode, Encode};
 use frame_support::weights::Weight;
+use native::bn254::HostHooks as CurveHooksImpl;
 use pallet_verifiers::traits::{Verifier, VerifyError};
-use sp1_zkv_verifier::Proof as SP1Proof;
+use scale_info::TypeInfo;
 use sp_core::{Get, H256};
 pub use weight::WeightInfo;
 
 #[pallet_verifiers::verifier]
 pub struct Sp1<T>;
 
-pub type Proof = Vec<u8>;
+/// An SP1 proof tagged with the SP1 version that produced it. Compressed STARK proofs
+/// are verified against the recursion verification key of their own version, so proofs
+/// generated with different SP1 releases can be submitted side by side. `Groth16` and
+/// `Plonk` variants carry the proofs that SP1 wraps into BN254 SNARKs with a fixed
+/// verification key: they are much smaller and cheaper to verify.
+///
+/// Variants must keep their codec index: retiring a version is done by rejecting it
+/// in [`Proof::verify`], never by removing the variant.
+#[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo)]
+pub enum Proof {
+    #[codec(index = 0)]
+    V5_0(Vec<u8>),
+    #[codec(index = 1)]
+    V5_2(Vec<u8>),
+    #[codec(index = 2)]
+    Groth16V5_2(Vec<u8>),
+    #[codec(index = 3)]
+    PlonkV5_2(Vec<u8>),
+}
+
+impl Proof {
+    fn raw(&self) -> &[u8] {
+        match self {
+            Proof::V5_0(p) | Proof::V5_2(p) | Proof::Groth16V5_2(p) | Proof::PlonkV5_2(p) => p,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.raw().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.raw().is_empty()
+    }
+
+    /// Whether this is a BN254 wrapped proof rather than a compressed STARK one.
+    pub fn is_wrapped(&self) -> bool {
+        matches!(self, Proof::Groth16V5_2(_) | Proof::PlonkV5_2(_))
+    }
+
+    fn max_size(&self) -> usize {
+        if self.is_wrapped() {
+            MAX_WRAPPED_PROOF_SIZE
+        } else {
+            MAX_PROOF_SIZE
+        }
+    }
+
+    fn verify(&self, vk: &H256, pubs: &[u8]) -> Result<(), VerifyError> {
+        match self {
+            Proof::V5_0(raw) => {
+                let proof: sp1_zkv_verifier_v5_0::Proof =
+                    bincode::serde::decode_from_slice(raw, bincode::config::legacy())
+                        .inspect_err(|err| log::debug!("Cannot deserialize proof: {err}"))
+                        .map_err(|_| VerifyError::InvalidProofData)?
+                        .0;
+                sp1_zkv_verifier_v5_0::verify(vk.as_fixed_bytes(), &proof, pubs)
+                    .inspect_err(|err| log::debug!("Verification error: {err}"))
+                    .map_err(|_| VerifyError::VerifyError)
+            }
+            Proof::V5_2(raw) => {
+                let proof: sp1_zkv_verifier_v5_2::Proof =
+                    bincode::serde::decode_from_slice(raw, bincode::config::legacy())
+                        .inspect_err(|err| log::debug!("Cannot deserialize proof: {err}"))
+                        .map_err(|_| VerifyError::InvalidProofData)?
+                        .0;
+                sp1_zkv_verifier_v5_2::verify(vk.as_fixed_bytes(), &proof, pubs)
+                    .inspect_err(|err| log::debug!("Verification error: {err}"))
+                    .map_err(|_| VerifyError::VerifyError)
+            }
+            Proof::Groth16V5_2(raw) => {
+                let vkey_hash = vkey_hash_bn254(vk)?;
+                let digest = committed_values_digest(pubs);
+                sp1_zkv_verifier_v5_2::groth16::verify::<CurveHooksImpl>(raw, &vkey_hash, &digest)
+                    .inspect_err(|err| log::debug!("Groth16 verification error: {err}"))
+                    .map_err(|_| VerifyError::VerifyError)
+            }
+            Proof::PlonkV5_2(raw) => {
+                let vkey_hash = vkey_hash_bn254(vk)?;
+                let digest = committed_values_digest(pubs);
+                sp1_zkv_verifier_v5_2::plonk::verify::<CurveHooksImpl>(raw, &vkey_hash, &digest)
+                    .inspect_err(|err| log::debug!("Plonk verification error: {err}"))
+                    .map_err(|_| VerifyError::VerifyError)
+            }
+        }
+    }
+}
+
+/// BabyBear field modulus: every word of an SP1 program vkey hash must be below it.
+const BABYBEAR_MODULUS: u32 = 0x78000001;
+
+/// Maps the SP1 program vkey hash (eight big endian BabyBear words) to the BN254 field
+/// element that wrapped proofs expose as their first public input. Each word is shifted
+/// in with 31 bits, so the result always fits in the field.
+fn vkey_hash_bn254(vk: &H256) -> Result<[u8; 32], VerifyError> {
+    let mut out = [0u8; 32];
+    for (i, chunk) in vk.as_bytes().chunks_exact(4).enumerate() {
+        let word = u32::from_be_bytes(chunk.try_into().expect("chunks of 4 bytes; qed"));
+        if word >= BABYBEAR_MODULUS {
+            log::debug!("Vkey hash word {i} is not a BabyBear element");
+            Err(VerifyError::InvalidVerificationKey)?;
+        }
+        let offset = 31 * (7 - i);
+        for bit in (0..31).filter(|bit| word >> bit & 1 == 1) {
+            let pos = offset + bit;
+            out[31 - pos / 8] |= 1 << (pos % 8);
+        }
+    }
+    Ok(out)
+}
+
+/// The committed values digest is the SHA-256 of the public values with the top three
+/// bits cleared, so that it fits in the BN254 scalar field.
+fn committed_values_digest(pubs: &[u8]) -> [u8; 32] {
+    let mut digest = sp_io::hashing::sha2_256(pubs);
+    digest[0] &= 0x1f;
+    digest
+}
+
 pub type Pubs = Vec<u8>;
 
 const MAX_PROOF_SIZE: usize = 786432;
+/// Wrapped proofs are 260 bytes for Groth16 and less than 900 bytes for PLONK (both
+/// including the 4 bytes verification key selector).
+const MAX_WRAPPED_PROOF_SIZE: usize = 1024;
 
 pub trait Config {
     /// Maximum number of bytes contained in the public inputs (otherwise rejected)
@@ -54,6 +177,8 @@ impl<T: Config> Verifier for Sp1<T> {
 
     type Vk = H256;
 
+    type VkMetadata = ();
+
     fn hash_context_data() -> &'static [u8] {
         b"sp1"
     }
@@ -63,7 +188,7 @@ impl<T: Config> Verifier for Sp1<T> {
         proof: &Self::Proof,
         pubs: &Self::Pubs,
     ) -> Result<Option<Weight>, VerifyError> {
-        if proof.len() > MAX_PROOF_SIZE {
+        if proof.len() > proof.max_size() {
             log::debug!("Proof exceeds maximum size");
             Err(VerifyError::InvalidProofData)?;
         }
@@ -72,15 +197,7 @@ impl<T: Config> Verifier for Sp1<T> {
             Err(VerifyError::InvalidInput)?;
         }
 
-        let proof: SP1Proof =
-            bincode::serde::decode_from_slice(&proof[..], bincode::config::legacy())
-                .inspect_err(|err| log::debug!("Cannot deserialize proof: {err}"))
-                .map_err(|_| VerifyError::InvalidProofData)?
-                .0;
-
-        sp1_zkv_verifier::verify(vk.as_fixed_bytes(), &proof, pubs)
-            .inspect_err(|err| log::debug!("Verification error: {err}"))
-            .map_err(|_| VerifyError::VerifyError)?;
+        proof.verify(vk, pubs)?;
 
         Ok(None)
     }
@@ -92,16 +209,34 @@ impl<T: Config> Verifier for Sp1<T> {
     fn pubs_bytes(pubs: &Self::Pubs) -> Cow<'_, [u8]> {
         Cow::Borrowed(pubs)
     }
+
+    fn verifier_version_hash(proof: &Self::Proof) -> H256 {
+        // Computed as: SHA2-256("sp1:vx.y")
+        match proof {
+            // V5_0 returns NO_VERSION_HASH to preserve the statement hashes of the proofs
+            // verified before the proofs were versioned.
+            Proof::V5_0(_) => pallet_verifiers::traits::NO_VERSION_HASH,
+            Proof::V5_2(_) | Proof::Groth16V5_2(_) | Proof::PlonkV5_2(_) => {
+                H256(hex_literal::hex!(
+                    "548acf6c8b0816c297c8503885095e9462a76bed9f9f37756894f6a1d787b3b5"
+                ))
+            }
+        }
+    }
 }
 
 pub struct Sp1Weight<W: WeightInfo>(PhantomData<W>);
 
 impl<T: Config, W: WeightInfo> pallet_verifiers::WeightInfo<Sp1<T>> for Sp1Weight<W> {
     fn verify_proof(
-        _proof: &<Sp1<T> as Verifier>::Proof,
+        proof: &<Sp1<T> as Verifier>::Proof,
         _pubs: &<Sp1<T> as Verifier>::Pubs,
     ) -> Weight {
-        W::verify_proof()
+        match proof {
+            Proof::V5_0(_) | Proof::V5_2(_) => W::verify_proof(),
+            Proof::Groth16V5_2(_) => W::verify_proof_groth16(),
+            Proof::PlonkV5_2(_) => W::verify_proof_plonk(),
+        }
     }
 
     fn register_vk(_vk: &<Sp1<T> as Verifier>::Vk) -> Weight {
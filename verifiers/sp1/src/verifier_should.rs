@@ -28,7 +28,7 @@ impl Config for Mock {
 
 #[test]
 fn verify_valid_proof() {
-    let proof = include_bytes!("resources/proof.bin").to_vec();
+    let proof = Proof::V5_0(include_bytes!("resources/proof.bin").to_vec());
     let pubs = VALID_PUBS.to_vec();
     let vk = VALID_VK;
 
@@ -37,7 +37,7 @@ fn verify_valid_proof() {
 
 #[test]
 fn reject_too_long_proof() {
-    let proof = vec![0u8; MAX_PROOF_SIZE + 1];
+    let proof = Proof::V5_0(vec![0u8; MAX_PROOF_SIZE + 1]);
     let pubs = VALID_PUBS.to_vec();
     let vk = VALID_VK;
 
@@ -49,7 +49,7 @@ fn reject_too_long_proof() {
 
 #[test]
 fn reject_too_long_pubs() {
-    let proof = include_bytes!("resources/proof.bin").to_vec();
+    let proof = Proof::V5_0(include_bytes!("resources/proof.bin").to_vec());
     let pubs = vec![0u8; Mock::max_pubs_size() as usize + 1];
     let vk = VALID_VK;
 
@@ -61,11 +61,12 @@ fn reject_too_long_pubs() {
 
 #[test]
 fn reject_invalid_proof() {
-    let mut proof = include_bytes!("resources/proof.bin").to_vec();
+    let mut raw_proof = include_bytes!("resources/proof.bin").to_vec();
     let pubs = VALID_PUBS.to_vec();
     let vk = VALID_VK;
 
-    proof[0] = proof[0].wrapping_add(0x01);
+    raw_proof[0] = raw_proof[0].wrapping_add(0x01);
+    let proof = Proof::V5_0(raw_proof);
 
     assert_eq!(
         Sp1::<Mock>::verify_proof(&vk, &proof, &pubs),
@@ -75,7 +76,7 @@ fn reject_invalid_proof() {
 
 #[test]
 fn reject_invalid_vk() {
-    let proof = include_bytes!("resources/proof.bin").to_vec();
+    let proof = Proof::V5_0(include_bytes!("resources/proof.bin").to_vec());
     let pubs = VALID_PUBS.to_vec();
     let mut vk = VALID_VK;
 
@@ -90,7 +91,7 @@ fn reject_invalid_vk() {
 
 #[test]
 fn reject_invalid_pubs() {
-    let proof = include_bytes!("resources/proof.bin").to_vec();
+    let proof = Proof::V5_0(include_bytes!("resources/proof.bin").to_vec());
     let mut pubs = VALID_PUBS.to_vec();
     let vk = VALID_VK;
 
@@ -101,3 +102,140 @@ fn reject_invalid_pubs() {
         Err(VerifyError::VerifyError)
     )
 }
+
+#[test]
+fn reject_proof_with_wrong_version() {
+    let proof = Proof::V5_2(include_bytes!("resources/proof.bin").to_vec());
+    let pubs = VALID_PUBS.to_vec();
+    let vk = VALID_VK;
+
+    assert!(Sp1::<Mock>::verify_proof(&vk, &proof, &pubs).is_err());
+}
+
+#[test]
+fn reject_too_long_proof_for_any_version() {
+    let proof = Proof::V5_2(vec![0u8; MAX_PROOF_SIZE + 1]);
+    let pubs = VALID_PUBS.to_vec();
+    let vk = VALID_VK;
+
+    assert_eq!(
+        Sp1::<Mock>::verify_proof(&vk, &proof, &pubs),
+        Err(VerifyError::InvalidProofData)
+    )
+}
+
+#[test]
+fn use_a_different_version_hash_for_each_version() {
+    let raw_proof = include_bytes!("resources/proof.bin").to_vec();
+    let v5_0 = Sp1::<Mock>::verifier_version_hash(&Proof::V5_0(raw_proof.clone()));
+    let v5_2 = Sp1::<Mock>::verifier_version_hash(&Proof::V5_2(raw_proof));
+
+    assert_ne!(v5_2, pallet_verifiers::traits::NO_VERSION_HASH);
+    assert_ne!(v5_0, v5_2);
+}
+
+#[test]
+fn preserve_the_statement_hash_of_v5_0_proofs() {
+    let proof = Proof::V5_0(include_bytes!("resources/proof.bin").to_vec());
+
+    assert_eq!(
+        Sp1::<Mock>::verifier_version_hash(&proof),
+        pallet_verifiers::traits::NO_VERSION_HASH
+    );
+}
+
+#[test]
+fn map_vkey_hash_to_bn254() {
+    assert_eq!(
+        vkey_hash_bn254(&VALID_VK),
+        Ok(hex_literal::hex!(
+            "008b28ceb0124dcb1e5e75b5d6526a96636322b4700c4979386affbfec15c0dd"
+        ))
+    );
+}
+
+#[test]
+fn clear_the_top_bits_of_committed_values_digest() {
+    let digest = committed_values_digest(VALID_PUBS);
+
+    assert_eq!(digest[0] & 0xe0, 0);
+    assert_eq!(digest[1..], sp_io::hashing::sha2_256(VALID_PUBS)[1..]);
+}
+
+mod wrapped {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::groth16(Proof::Groth16V5_2(vec![0u8; MAX_WRAPPED_PROOF_SIZE + 1]))]
+    #[case::plonk(Proof::PlonkV5_2(vec![0u8; MAX_WRAPPED_PROOF_SIZE + 1]))]
+    fn reject_too_long_proof(#[case] proof: Proof) {
+        assert_eq!(
+            Sp1::<Mock>::verify_proof(&VALID_VK, &proof, &VALID_PUBS.to_vec()),
+            Err(VerifyError::InvalidProofData)
+        )
+    }
+
+    #[rstest]
+    #[case::groth16(Proof::Groth16V5_2(vec![0u8; 260]))]
+    #[case::plonk(Proof::PlonkV5_2(vec![0u8; 868]))]
+    fn reject_vk_that_is_not_a_babybear_hash(#[case] proof: Proof) {
+        let vk = H256::repeat_byte(0xff);
+
+        assert_eq!(
+            Sp1::<Mock>::verify_proof(&vk, &proof, &VALID_PUBS.to_vec()),
+            Err(VerifyError::InvalidVerificationKey)
+        )
+    }
+
+    #[rstest]
+    #[case::groth16(Proof::Groth16V5_2(vec![0u8; 260]))]
+    #[case::plonk(Proof::PlonkV5_2(vec![0u8; 868]))]
+    fn reject_invalid_proof(#[case] proof: Proof) {
+        assert_eq!(
+            Sp1::<Mock>::verify_proof(&VALID_VK, &proof, &VALID_PUBS.to_vec()),
+            Err(VerifyError::VerifyError)
+        )
+    }
+
+    #[test]
+    fn use_their_own_weight() {
+        struct W;
+        impl WeightInfo for W {
+            fn verify_proof() -> Weight {
+                Weight::from_parts(1, 0)
+            }
+            fn verify_proof_groth16() -> Weight {
+                Weight::from_parts(2, 0)
+            }
+            fn verify_proof_plonk() -> Weight {
+                Weight::from_parts(3, 0)
+            }
+            fn get_vk() -> Weight {
+                Weight::zero()
+            }
+            fn validate_vk() -> Weight {
+                Weight::zero()
+            }
+            fn compute_statement_hash() -> Weight {
+                Weight::zero()
+            }
+            fn register_vk() -> Weight {
+                Weight::zero()
+            }
+            fn unregister_vk() -> Weight {
+                Weight::zero()
+            }
+        }
+        let w = |proof: Proof| {
+            <Sp1Weight<W> as pallet_verifiers::WeightInfo<Sp1<Mock>>>::verify_proof(
+                &proof,
+                &Vec::new(),
+            )
+        };
+
+        assert_eq!(w(Proof::V5_2(Vec::new())), Weight::from_parts(1, 0));
+        assert_eq!(w(Proof::Groth16V5_2(Vec::new())), Weight::from_parts(2, 0));
+        assert_eq!(w(Proof::PlonkV5_2(Vec::new())), Weight::from_parts(3, 0));
+    }
+}
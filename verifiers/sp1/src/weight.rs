@@ -56,6 +56,8 @@ use core::marker::PhantomData;
 /// Weight functions needed for `pallet_sp1_verifier`.
 pub trait WeightInfo {
     fn verify_proof() -> Weight;
+    fn verify_proof_groth16() -> Weight;
+    fn verify_proof_plonk() -> Weight;
     fn get_vk() -> Weight;
     fn validate_vk() -> Weight;
     fn compute_statement_hash() -> Weight;
@@ -72,6 +74,16 @@ impl WeightInfo for () {
         // Minimum execution time: 50_981_989_000 picoseconds.
         Weight::from_parts(54_266_640_000, 0)
     }
+    fn verify_proof_groth16() -> Weight {
+        // TODO: not benchmarked yet, we need wrapped proof fixtures. Use the compressed
+        // proof weight as an upper bound.
+        Weight::from_parts(54_266_640_000, 0)
+    }
+    fn verify_proof_plonk() -> Weight {
+        // TODO: not benchmarked yet, we need wrapped proof fixtures. Use the compressed
+        // proof weight as an upper bound.
+        Weight::from_parts(54_266_640_000, 0)
+    }
     /// Storage: `SettlementSp1Pallet::Vks` (r:1 w:0)
     /// Proof: `SettlementSp1Pallet::Vks` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
     fn get_vk() -> Weight {
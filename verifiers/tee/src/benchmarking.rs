@@ -31,10 +31,19 @@ pub type Call<T> = pallet_verifiers::Call<T, Verifier<T>>;
 const INTEL_PRESENT: u64 = 1769092187000; // Thu, 22 Jan 2026 14:29:47 GMT, in ms
 const NITRO_PRESENT: u64 = 1668034320696; // Wed, 09 Nov 2022 22:52:00 GMT, in ms
 
-/// Insert an empty CRL for the given Vk variant's CA name into pallet_crl storage.
-/// This allows `T::Crl::get_crl()` to succeed during benchmarks without requiring a full
-/// CRL update extrinsic.
+/// Register the CA of the given Vk variant, with no revoked certificates, into pallet_crl
+/// storage. This allows the CRL lookups to succeed during benchmarks without requiring the
+/// CA registration and CRL update extrinsics.
 fn setup_empty_crl<T>(vk: &Vk)
+where
+    T: crate::Config + pallet_crl::Config,
+{
+    setup_empty_crl_with_root::<T>(vk, &[])
+}
+
+/// Like [`setup_empty_crl`], but also register the CA root certificate (DER encoded): it's
+/// needed by the verifiers that check the vk certificate chain against it.
+fn setup_empty_crl_with_root<T>(vk: &Vk, root_cert: &[u8])
 where
     T: crate::Config + pallet_crl::Config,
 {
@@ -43,15 +52,50 @@ where
     let bounded_ca_name: pallet_crl::CaName<T> = ca_name_bytes
         .try_into()
         .expect("CA name fits within MaxCaNameLength");
-    pallet_crl::Revoked::<T>::insert(
+    pallet_crl::CertificateAuthorities::<T>::insert(
         &bounded_ca_name,
-        BoundedVec::<
-            pallet_crl::RevokedInfo,
-            ConstU32<{ pallet_crl::MAX_REVOKED_CERTS_PER_CA }>,
-        >::new(),
+        pallet_crl::CaInfo {
+            root_cert: root_cert
+                .to_vec()
+                .try_into()
+                .expect("Root certificate fits within MAX_ROOT_CERT_LENGTH"),
+            revoked_count: 0,
+            crl_versions: Default::default(),
+            staleness_policy: Default::default(),
+        },
     );
 }
 
+/// The measurements of the trust domain in `valid_quote.dat`: pinning all of them makes
+/// `intel_verify_proof` hit the worst case.
+fn valid_quote_measurements() -> crate::IntelMeasurements {
+    crate::IntelMeasurements::Tdx(crate::TdxMeasurements {
+        mr_td: Some(hex_literal::hex!("91eb2b44d141d4ece09f0c75c2c53d247a3c68edd7fafe8a3520c942a604a407de03ae6dc5f87f27428b2538873118b7")),
+        rtmrs: [
+            Some(hex_literal::hex!("44c0197b39157fdd7a4dcc44767f9d6b0bb3977c7a8e347b8492f827fe9d9e5c48aca29b220b80b6a540cf994b9bc9c0")),
+            Some(hex_literal::hex!("0084452c01668329d4bc06acdf58a7205c26743304509973949e5619bf81a6a7aea8c323c173019b3093d54e579e9378")),
+            Some(hex_literal::hex!("d833feef2cd945148aa38ead2c53e9b7f138190aaaebfc551dccd829fc207aa3ba80b70870d7330733642e01d48c3132")),
+            Some([0; 48]),
+        ],
+    })
+}
+
+/// The PCRs of the enclave in `valid_attestation.bin`: pinning all of them makes
+/// `nitro_verify_proof` hit the worst case.
+fn valid_attestation_pcrs() -> crate::NitroPcrs {
+    crate::NitroPcrs([
+        Some(hex_literal::hex!("22f9e1c949208da55eb01b9b9fc88f87454f77ba133f0d82320b509621c92482152a99d0a12335b97178c02d6f977d01")),
+        Some(hex_literal::hex!("bcdf05fefccaa8e55bf2c8d6dee9e79bbff31e34bf28a99aa19e6b29c37ee80b214a414b7607236edf26fcb78654e63f")),
+        Some(hex_literal::hex!("00943ea859140fed74e1025fe41aedb38780eae5656b3f9ef9b4b0e6111350315544db3efcda05725108f72b2ab141f7")),
+        Some([0; 48]),
+        Some(hex_literal::hex!("4554042339e6a1ddfac89db7da58231d5619073729344333aff055429abeec6b006f81b19d1176001b8291f885283106")),
+        Some([0; 48]),
+        Some([0; 48]),
+        Some([0; 48]),
+        Some([0; 48]),
+    ])
+}
+
 fn set_timestamp<T>(ts: u64)
 where
     T: pallet_babe::Config + pallet_timestamp::Config,
@@ -84,10 +128,42 @@ mod benchmarks {
                 .to_vec()
                 .try_into()
                 .unwrap(),
+            measurements: Some(valid_quote_measurements()),
+        };
+
+        set_timestamp::<T>(INTEL_PRESENT);
+        setup_empty_crl::<T>(&vk);
+
+        let r;
+        #[block]
+        {
+            r = do_verify_proof::<T>(&vk, &proof, &pubs)
+        };
+        assert!(r.is_ok());
+    }
+
+    #[benchmark]
+    fn intel_registry_verify_proof() {
+        let proof = include_bytes!("resources/intel/valid_quote.dat").to_vec();
+        let pubs = vec![];
+        let vk = Vk::IntelTcbRegistry {
+            measurements: Some(valid_quote_measurements()),
         };
 
         set_timestamp::<T>(INTEL_PRESENT);
         setup_empty_crl::<T>(&vk);
+        pallet_crl::TcbInfos::<T>::insert(
+            hex_literal::hex!("b0c06f000000"),
+            pallet_crl::TcbId::Tdx,
+            pallet_crl::TcbInfoEntry {
+                tcb_response: include_bytes!("resources/intel/valid_tcbinfo.json")
+                    .to_vec()
+                    .try_into()
+                    .unwrap(),
+                evaluation_data_number: 18,
+                issue_date: 1768899331,
+            },
+        );
 
         let r;
         #[block]
@@ -101,7 +177,9 @@ mod benchmarks {
     fn nitro_verify_proof() {
         let proof = include_bytes!("resources/nitro/valid_attestation.bin").to_vec();
         let pubs = vec![];
-        let vk = Vk::Nitro;
+        let vk = Vk::Nitro {
+            pcrs: valid_attestation_pcrs(),
+        };
 
         set_timestamp::<T>(NITRO_PRESENT);
         setup_empty_crl::<T>(&vk);
@@ -114,6 +192,38 @@ mod benchmarks {
         assert!(r.is_ok());
     }
 
+    #[benchmark]
+    fn amd_verify_proof() {
+        let proof = include_bytes!("resources/amd/valid_attestation.bin").to_vec();
+        // The report data is bound verbatim: the longest pubs accepted.
+        let pubs = hex_literal::hex!("d447b55d197491bfe15cf298f9de9986b7a7c4be2468b4f6e2d53b71d7c645810b0f2cdfca0040433be063fc1a8293f0f3f8dae7b79fecb3d1cd82bd6a93ebfd").to_vec();
+        let vk = Vk::AmdSnp {
+            certificates: include_bytes!("resources/amd/valid_cert_chain.pem")
+                .to_vec()
+                .try_into()
+                .unwrap(),
+            policy: crate::SnpPolicy {
+                min_tcb: crate::SnpTcb {
+                    bootloader: 3,
+                    tee: 0,
+                    snp: 8,
+                    microcode: 115,
+                },
+                ..Default::default()
+            },
+        };
+
+        set_timestamp::<T>(INTEL_PRESENT);
+        setup_empty_crl_with_root::<T>(&vk, include_bytes!("resources/amd/ark.der"));
+
+        let r;
+        #[block]
+        {
+            r = do_verify_proof::<T>(&vk, &proof, &pubs)
+        };
+        assert!(r.is_ok());
+    }
+
     #[benchmark]
     fn get_vk() {
         let vk = Vk::Intel {
@@ -125,6 +235,7 @@ mod benchmarks {
                 .to_vec()
                 .try_into()
                 .unwrap(),
+            measurements: None,
         };
         let hash = sp_core::H256::repeat_byte(2);
 
@@ -149,6 +260,7 @@ mod benchmarks {
                 .to_vec()
                 .try_into()
                 .unwrap(),
+            measurements: None,
         };
 
         set_timestamp::<T>(INTEL_PRESENT);
@@ -175,6 +287,7 @@ mod benchmarks {
                 .to_vec()
                 .try_into()
                 .unwrap(),
+            measurements: None,
         };
 
         let vk = VkOrHash::Vk(vk.into());
@@ -198,6 +311,7 @@ mod benchmarks {
                 .to_vec()
                 .try_into()
                 .unwrap(),
+            measurements: None,
         };
 
         set_timestamp::<T>(INTEL_PRESENT);
@@ -224,6 +338,7 @@ mod benchmarks {
                 .to_vec()
                 .try_into()
                 .unwrap(),
+            measurements: None,
         };
 
         insert_vk::<T>(caller.clone(), vk, hash);
@@ -252,14 +367,18 @@ mod mock {
 
     parameter_types! {
         pub const MaxCaNameLength: u32 = 64;
+        pub const IntelCaName: &'static str = "Intel_SGX_Processor";
     }
 
     pub struct TeeCaNames;
     impl crate::CaNameProvider for TeeCaNames {
         fn ca_name_for(vk: &crate::Vk) -> &'static str {
             match vk {
-                crate::Vk::Intel { .. } => "Intel_SGX_Processor",
-                crate::Vk::Nitro => "AWS_Nitro",
+                crate::Vk::Intel { .. } => IntelCaName::get(),
+                crate::Vk::IntelTcbRegistry { .. } => IntelCaName::get(),
+                crate::Vk::Tdx { .. } => IntelCaName::get(),
+                crate::Vk::Nitro { .. } => "AWS_Nitro",
+                crate::Vk::AmdSnp { .. } => "AMD_SEV_SNP",
             }
         }
     }
@@ -280,6 +399,7 @@ mod mock {
     impl crate::Config for Test {
         type UnixTime = Timestamp;
         type Crl = pallet_crl::Pallet<Test>;
+        type TcbInfo = pallet_crl::Pallet<Test>;
         type CaName = TeeCaNames;
         type WeightInfo = ();
     }
@@ -317,6 +437,26 @@ mod mock {
         type WeightInfo = ();
         type MaxCaNameLength = MaxCaNameLength;
         type UnixTime = Timestamp;
+        type OffchainWorkerInterval = ConstU32<0>;
+        type UnsignedPriority = ConstU64<100>;
+        type IntelCaName = IntelCaName;
+    }
+
+    impl<C> frame_system::offchain::CreateTransactionBase<C> for Test
+    where
+        RuntimeCall: From<C>,
+    {
+        type Extrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+        type RuntimeCall = RuntimeCall;
+    }
+
+    impl<C> frame_system::offchain::CreateInherent<C> for Test
+    where
+        RuntimeCall: From<C>,
+    {
+        fn create_inherent(call: Self::RuntimeCall) -> Self::Extrinsic {
+            frame_system::mocking::MockUncheckedExtrinsic::<Test>::new_bare(call)
+        }
     }
 
     impl pallet_babe::Config for Test {
@@ -371,10 +511,11 @@ mod mock {
         ext.execute_with(|| {
             System::set_block_number(1);
             Timestamp::set_timestamp(crate::benchmarking::INTEL_PRESENT); // Thu, 22 Jan 2026 14:29:47 GMT
-                                                                          // Set up an empty CRL for the Intel SGX CA so benchmark tests can look up CRL data.
+                                                                          // Register the Intel SGX CA so benchmark tests can look up CRL data.
             crate::benchmarking::setup_empty_crl::<Test>(&crate::Vk::Intel {
                 tcb_response: vec![],
                 certificates: vec![],
+                measurements: None,
             });
         });
         ext
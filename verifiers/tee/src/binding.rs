@@ -0,0 +1,57 @@
+// Copyright 2026, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Binding of the statement public inputs to the data the TEE vouched for.
+//!
+//! Empty pubs bind nothing. Otherwise the pubs must be carried by the attestation
+//! either verbatim or as their SHA-256 digest:
+//! - fixed size fields (SGX/TDX/SEV-SNP `report_data`) hold the value left aligned and
+//!   zero padded;
+//! - variable size fields (Nitro `user_data` and `nonce`) hold exactly the value.
+
+use tee_verifier::ReportBody;
+
+/// The `report_data` field of an Intel quote, for both SGX enclaves and TDX trust domains.
+pub fn intel_report_data(body: &ReportBody) -> &[u8] {
+    match body {
+        ReportBody::Sgx(body) => &body.report_data,
+        ReportBody::Td(body) => &body.report_data,
+    }
+}
+
+/// Check that `pubs` are bound to the fixed size `report_data` field.
+pub fn bound_to_report_data(report_data: &[u8], pubs: &[u8]) -> bool {
+    pubs.is_empty()
+        || zero_padded_eq(report_data, pubs)
+        || zero_padded_eq(report_data, &sp_io::hashing::sha2_256(pubs))
+}
+
+/// Check that `pubs` are bound to at least one of the given variable size `fields`.
+pub fn bound_to_any<'a>(fields: impl IntoIterator<Item = Option<&'a [u8]>>, pubs: &[u8]) -> bool {
+    if pubs.is_empty() {
+        return true;
+    }
+    let digest = sp_io::hashing::sha2_256(pubs);
+    fields
+        .into_iter()
+        .flatten()
+        .any(|field| field == pubs || field == digest)
+}
+
+fn zero_padded_eq(field: &[u8], value: &[u8]) -> bool {
+    field.len() >= value.len()
+        && field[..value.len()] == *value
+        && field[value.len()..].iter().all(|b| *b == 0)
+}
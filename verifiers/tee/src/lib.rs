@@ -18,7 +18,10 @@
 extern crate alloc;
 
 pub mod benchmarking;
+mod binding;
+mod measurements;
 pub mod migrations;
+mod tdx;
 mod verifier_should;
 mod weight;
 
@@ -26,13 +29,18 @@ use alloc::{borrow::Cow, vec::Vec};
 use core::marker::PhantomData;
 
 use frame_support::{ensure, pallet_prelude::StorageVersion, traits::UnixTime, weights::Weight};
+pub use measurements::{
+    IntelMeasurements, NitroPcrs, SgxMeasurements, Sha384, TdxMeasurements, NITRO_PCRS, TDX_RTMRS,
+};
 use pallet_verifiers::traits::Verifier;
+pub use tdx::{TdReport, TdxPolicy, TEE_TCB_SVN_LENGTH};
 pub use weight::WeightInfo;
 
-use pallet_crl::CrlProvider;
+use pallet_crl::{CrlProvider, Revocations, TcbId, TcbInfoProvider};
 use pallet_verifiers::traits::VerifyError;
 use tee_verifier::{
-    intel_parse_quote, intel_parse_tcb_response, nitro_parse_attestation, TcbResponse,
+    amd_parse_attestation, amd_verify_cert_chain, intel_parse_quote, intel_parse_tcb_response,
+    nitro_parse_attestation, ReportBody, TcbResponse,
 };
 
 use codec::{Decode, Encode, MaxEncodedLen};
@@ -48,22 +56,101 @@ pub type Pubs = Vec<u8>;
 pub const MAX_VK_LENGTH: u32 = 65536;
 // Max size in bytes of the quote
 pub const MAX_PROOF_LENGTH: u32 = 65536;
-// Max size in bytes of the pubs; this pallet does not need any pubs
-pub const MAX_PUBS_LENGTH: u32 = 0;
+// Max size in bytes of the pubs: they must be carried by the attestation, verbatim or hashed
+pub const MAX_PUBS_LENGTH: u32 = 1024;
 
 #[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo)]
 pub enum Vk {
+    /// Intel SGX/TDX: `measurements`, if any, pins the quoted enclave or trust domain.
     Intel {
         tcb_response: Vec<u8>,
         certificates: Vec<u8>,
+        measurements: Option<IntelMeasurements>,
+    },
+    /// AWS Nitro: the attestation document is self-contained, so the vk just pins the
+    /// expected PCR values.
+    Nitro { pcrs: NitroPcrs },
+    /// AMD SEV-SNP: `certificates` is the ASK/ARK chain (PEM) that must chain up to the
+    /// ARK registered in `pallet_crl`, while `policy` constrains the accepted reports.
+    AmdSnp {
+        certificates: Vec<u8>,
+        policy: SnpPolicy,
+    },
+    /// Intel SGX/TDX, using the TCB info registered in `pallet_crl` for the quoting platform
+    /// instead of an embedded one.
+    IntelTcbRegistry {
+        measurements: Option<IntelMeasurements>,
+    },
+    /// Intel TDX only: `policy` constrains the attributes, the TCB level and the measurements
+    /// of the quoted trust domain.
+    Tdx {
+        tcb_response: Vec<u8>,
+        certificates: Vec<u8>,
+        policy: TdxPolicy,
     },
-    Nitro,
+}
+
+/// The SEV-SNP TCB version, as reported in the attestation report. Each component
+/// is a security version number of the corresponding firmware.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct SnpTcb {
+    pub bootloader: u8,
+    pub tee: u8,
+    pub snp: u8,
+    pub microcode: u8,
+}
+
+impl SnpTcb {
+    /// True if every component is at least the one of `min`.
+    pub fn satisfies(&self, min: &SnpTcb) -> bool {
+        self.bootloader >= min.bootloader
+            && self.tee >= min.tee
+            && self.snp >= min.snp
+            && self.microcode >= min.microcode
+    }
+}
+
+/// The constraints that a SEV-SNP attestation report should satisfy.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct SnpPolicy {
+    /// Minimum accepted `reported_tcb`.
+    pub min_tcb: SnpTcb,
+    /// Accept guests launched with the debug policy bit set.
+    pub allow_debug: bool,
+    /// Accept guests launched with a migration agent.
+    pub allow_migration_agent: bool,
+}
+
+/// Guest policy bit: a migration agent can be associated to the guest.
+const SNP_POLICY_MIGRATE_MA: u64 = 1 << 18;
+/// Guest policy bit: the guest can be debugged.
+const SNP_POLICY_DEBUG: u64 = 1 << 19;
+
+impl SnpPolicy {
+    /// Check the guest policy bits and the reported TCB of a report against this policy.
+    pub fn check(&self, guest_policy: u64, reported_tcb: &SnpTcb) -> Result<(), VerifyError> {
+        ensure!(
+            self.allow_debug || guest_policy & SNP_POLICY_DEBUG == 0,
+            VerifyError::VerifyError
+        );
+        ensure!(
+            self.allow_migration_agent || guest_policy & SNP_POLICY_MIGRATE_MA == 0,
+            VerifyError::VerifyError
+        );
+        ensure!(
+            reported_tcb.satisfies(&self.min_tcb),
+            VerifyError::VerifyError
+        );
+        Ok(())
+    }
 }
 
 impl MaxEncodedLen for Vk {
     fn max_encoded_len() -> usize {
         // codec::Compact(len).encoded_size() + element_size * len as usize
-        codec::Compact(2 * MAX_VK_LENGTH).encoded_size() + (2 * MAX_VK_LENGTH) as usize
+        codec::Compact(2 * MAX_VK_LENGTH).encoded_size()
+            + (2 * MAX_VK_LENGTH) as usize
+            + Option::<IntelMeasurements>::max_encoded_len().max(TdxPolicy::max_encoded_len())
     }
 }
 
@@ -74,10 +161,73 @@ pub trait CaNameProvider {
 pub trait Config {
     type UnixTime: UnixTime;
     type Crl: CrlProvider;
+    type TcbInfo: TcbInfoProvider;
     type CaName: CaNameProvider;
     type WeightInfo: WeightInfo;
 }
 
+impl<T: Config> Tee<T> {
+    /// Get the certificates revoked by the given CA, refusing to rely on them if the CA
+    /// staleness policy considers its CRLs stale at `now`.
+    fn revocations(ca_name: &str, now: u64) -> Result<Revocations<'_, T::Crl>, VerifyError> {
+        let stale = T::Crl::is_crl_stale(ca_name, now).map_err(|_| VerifyError::VerifyError)?;
+        if stale {
+            log::debug!("Stale CRL for CA {ca_name}");
+            return Err(VerifyError::VerifyError);
+        }
+        Ok(Revocations::new(ca_name))
+    }
+
+    /// Verify an Intel quote against the given signed TCB info response or, if `None`, the
+    /// one registered for the quoting platform. Then check the quoted enclave or trust domain
+    /// with `check_body` and against the pubs.
+    fn verify_intel_quote(
+        proof: &[u8],
+        tcb_response: Option<&[u8]>,
+        crl: &Revocations<'_, T::Crl>,
+        now: u64,
+        pubs: &[u8],
+        check_body: impl FnOnce(&ReportBody) -> Result<(), VerifyError>,
+    ) -> Result<(), VerifyError> {
+        let quote = intel_parse_quote(proof).map_err(|_| VerifyError::InvalidProofData)?;
+        let body = quote.report_body();
+
+        let registered;
+        let tcb_response = match tcb_response {
+            Some(tcb_response) => tcb_response,
+            None => {
+                let fmspc = quote.fmspc().map_err(|_| VerifyError::InvalidProofData)?;
+                let id = match body {
+                    ReportBody::Sgx(_) => TcbId::Sgx,
+                    ReportBody::Td(_) => TcbId::Tdx,
+                };
+                registered = T::TcbInfo::get_tcb_response(&fmspc, id).ok_or_else(|| {
+                    log::debug!("No registered TCB info for FMSPC {fmspc:?} ({id:?})");
+                    VerifyError::VerifyError
+                })?;
+                &registered[..]
+            }
+        };
+        let tcb_response =
+            intel_parse_tcb_response(tcb_response).map_err(|_| VerifyError::InvalidInput)?;
+
+        tcb_response
+            .tcb_info
+            .verify(now)
+            .map_err(|_| VerifyError::VerifyError)?;
+
+        quote
+            .verify(&tcb_response.tcb_info, crl, now)
+            .map_err(|_| VerifyError::VerifyError)?;
+        check_body(&body)?;
+        ensure!(
+            binding::bound_to_report_data(binding::intel_report_data(&body), pubs),
+            VerifyError::VerifyError
+        );
+        Ok(())
+    }
+}
+
 impl<T: Config> Verifier for Tee<T> {
     const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
@@ -87,6 +237,8 @@ impl<T: Config> Verifier for Tee<T> {
 
     type Vk = Vk;
 
+    type VkMetadata = ();
+
     fn hash_context_data() -> &'static [u8] {
         b"tee"
     }
@@ -94,14 +246,14 @@ impl<T: Config> Verifier for Tee<T> {
     fn verify_proof(
         vk: &Self::Vk,
         proof: &Self::Proof,
-        _pubs: &Self::Pubs,
+        pubs: &Self::Pubs,
     ) -> Result<Option<Weight>, VerifyError> {
         ensure!(
             proof.len() <= MAX_PROOF_LENGTH as usize,
             VerifyError::InvalidProofData
         );
         ensure!(
-            _pubs.len() <= MAX_PUBS_LENGTH as usize,
+            pubs.len() <= MAX_PUBS_LENGTH as usize,
             VerifyError::InvalidInput
         );
 
@@ -109,13 +261,13 @@ impl<T: Config> Verifier for Tee<T> {
 
         // Always require the CA to be registered, even if empty, to allow for unpermissioned CRL
         // updates.
-        let crl =
-            T::Crl::get_crl(T::CaName::ca_name_for(vk)).map_err(|_| VerifyError::VerifyError)?;
+        let crl = Self::revocations(T::CaName::ca_name_for(vk), now)?;
 
         match vk {
             Vk::Intel {
                 tcb_response,
                 certificates,
+                measurements,
             } => {
                 ensure!(
                     tcb_response.len() <= MAX_VK_LENGTH as usize
@@ -125,28 +277,84 @@ impl<T: Config> Verifier for Tee<T> {
                     VerifyError::InvalidVerificationKey
                 );
 
-                let quote = intel_parse_quote(proof).map_err(|_| VerifyError::InvalidProofData)?;
-                let tcb_response = intel_parse_tcb_response(&tcb_response[..])
-                    .map_err(|_| VerifyError::InvalidInput)?;
-
-                tcb_response
-                    .tcb_info
-                    .verify(now)
-                    .map_err(|_| VerifyError::VerifyError)?;
+                Self::verify_intel_quote(proof, Some(tcb_response), &crl, now, pubs, |body| {
+                    check_measurements(measurements, body)
+                })
+                .map(|_| Some(T::WeightInfo::intel_verify_proof()))
+            }
+            Vk::IntelTcbRegistry { measurements } => {
+                Self::verify_intel_quote(proof, None, &crl, now, pubs, |body| {
+                    check_measurements(measurements, body)
+                })
+                .map(|_| Some(T::WeightInfo::intel_registry_verify_proof()))
+            }
+            Vk::Tdx {
+                tcb_response,
+                certificates,
+                policy,
+            } => {
+                ensure!(
+                    tcb_response.len() <= MAX_VK_LENGTH as usize
+                        && certificates.len() <= MAX_VK_LENGTH as usize
+                        && !tcb_response.is_empty()
+                        && !certificates.is_empty(),
+                    VerifyError::InvalidVerificationKey
+                );
 
-                quote
-                    .verify(&tcb_response.tcb_info, &crl, now)
-                    .map_err(|_| VerifyError::VerifyError)
-                    .map(|_| Some(T::WeightInfo::intel_verify_proof()))
+                Self::verify_intel_quote(proof, Some(tcb_response), &crl, now, pubs, |body| {
+                    let report = TdReport::from_body(body).ok_or(VerifyError::VerifyError)?;
+                    policy.check(&report)
+                })
+                // Same work as a generic Intel quote: the policy checks are negligible
+                .map(|_| Some(T::WeightInfo::intel_verify_proof()))
             }
-            Vk::Nitro => {
+            Vk::Nitro { pcrs } => {
                 let attestation =
                     nitro_parse_attestation(proof).map_err(|_| VerifyError::InvalidProofData)?;
 
                 attestation
                     .verify(Some(&crl), now)
-                    .map_err(|_| VerifyError::VerifyError)
-                    .map(|_| Some(T::WeightInfo::nitro_verify_proof()))
+                    .map_err(|_| VerifyError::VerifyError)?;
+                pcrs.check(|index| attestation.pcr(index))?;
+                ensure!(
+                    binding::bound_to_any([attestation.user_data(), attestation.nonce()], pubs),
+                    VerifyError::VerifyError
+                );
+                Ok(Some(T::WeightInfo::nitro_verify_proof()))
+            }
+            Vk::AmdSnp {
+                certificates,
+                policy,
+            } => {
+                ensure!(
+                    certificates.len() <= MAX_VK_LENGTH as usize && !certificates.is_empty(),
+                    VerifyError::InvalidVerificationKey
+                );
+                let ark = T::Crl::get_root_cert(T::CaName::ca_name_for(vk))
+                    .map_err(|_| VerifyError::VerifyError)?;
+
+                // The proof is the attestation report followed by the DER encoded VCEK
+                let attestation =
+                    amd_parse_attestation(proof).map_err(|_| VerifyError::InvalidProofData)?;
+
+                attestation
+                    .verify(certificates, &ark, &crl, now)
+                    .map_err(|_| VerifyError::VerifyError)?;
+                let report = &attestation.report;
+                policy.check(
+                    report.policy,
+                    &SnpTcb {
+                        bootloader: report.reported_tcb.bootloader,
+                        tee: report.reported_tcb.tee,
+                        snp: report.reported_tcb.snp,
+                        microcode: report.reported_tcb.microcode,
+                    },
+                )?;
+                ensure!(
+                    binding::bound_to_report_data(&report.report_data, pubs),
+                    VerifyError::VerifyError
+                );
+                Ok(Some(T::WeightInfo::amd_verify_proof()))
             }
         }
     }
@@ -156,6 +364,7 @@ impl<T: Config> Verifier for Tee<T> {
             Vk::Intel {
                 tcb_response,
                 certificates,
+                ..
             } => {
                 if tcb_response.len() > MAX_VK_LENGTH as usize
                     || certificates.len() > MAX_VK_LENGTH as usize
@@ -170,15 +379,57 @@ impl<T: Config> Verifier for Tee<T> {
                         .map_err(|_| VerifyError::InvalidVerificationKey)?;
 
                 let now = T::UnixTime::now().as_secs();
-                let crl = T::Crl::get_crl(T::CaName::ca_name_for(vk))
-                    .map_err(|_| VerifyError::VerifyError)?;
+                let crl = Self::revocations(T::CaName::ca_name_for(vk), now)?;
+                tcb_response
+                    .verify(certificates.to_vec(), &crl, now)
+                    .map_err(|_| VerifyError::VerifyError)
+            }
+            // The TCB info signature has been checked when it was registered
+            Vk::IntelTcbRegistry { .. } => Ok(()),
+            // The attestation document is self-contained: a vk that doesn't pin any PCR
+            // would accept any enclave => no point in registering it
+            Vk::Nitro { pcrs } if pcrs.is_empty() => Err(VerifyError::InvalidVerificationKey),
+            Vk::Nitro { .. } => Ok(()),
+            Vk::AmdSnp { certificates, .. } => {
+                if certificates.len() > MAX_VK_LENGTH as usize || certificates.is_empty() {
+                    return Err(VerifyError::InvalidVerificationKey);
+                }
+
+                let now = T::UnixTime::now().as_secs();
+                let ca_name = T::CaName::ca_name_for(vk);
+                let ark = T::Crl::get_root_cert(ca_name).map_err(|_| VerifyError::VerifyError)?;
+                let crl = Self::revocations(ca_name, now)?;
+                amd_verify_cert_chain(certificates, &ark, &crl, now)
+                    .map_err(|_| VerifyError::VerifyError)
+            }
+            Vk::Tdx {
+                tcb_response,
+                certificates,
+                ..
+            } => {
+                if tcb_response.len() > MAX_VK_LENGTH as usize
+                    || certificates.len() > MAX_VK_LENGTH as usize
+                    || tcb_response.is_empty()
+                    || certificates.is_empty()
+                {
+                    return Err(VerifyError::InvalidVerificationKey);
+                }
+
+                let (tcb_response, _used): (TcbResponse, usize) =
+                    serde_json_core::from_slice(&tcb_response[..])
+                        .map_err(|_| VerifyError::InvalidVerificationKey)?;
+                // An SGX TCB info doesn't define the TDX TCB levels
+                ensure!(
+                    tcb_response.tcb_info.id == "TDX",
+                    VerifyError::InvalidVerificationKey
+                );
+
+                let now = T::UnixTime::now().as_secs();
+                let crl = Self::revocations(T::CaName::ca_name_for(vk), now)?;
                 tcb_response
                     .verify(certificates.to_vec(), &crl, now)
                     .map_err(|_| VerifyError::VerifyError)
             }
-            // Nitro has no VK data to validate, the attestation document is self-contained
-            // => no point in registering a vk
-            Vk::Nitro => Err(VerifyError::InvalidVerificationKey),
         }
     }
 
@@ -187,6 +438,15 @@ impl<T: Config> Verifier for Tee<T> {
     }
 }
 
+fn check_measurements(
+    measurements: &Option<IntelMeasurements>,
+    body: &ReportBody,
+) -> Result<(), VerifyError> {
+    measurements
+        .as_ref()
+        .map_or(Ok(()), |measurements| measurements.check(body))
+}
+
 pub struct TeeWeight<W: WeightInfo>(PhantomData<W>);
 
 impl<T: Config, W: WeightInfo> pallet_verifiers::WeightInfo<Tee<T>> for TeeWeight<W> {
@@ -194,7 +454,10 @@ impl<T: Config, W: WeightInfo> pallet_verifiers::WeightInfo<Tee<T>> for TeeWeigh
         _proof: &<Tee<T> as Verifier>::Proof,
         _pubs: &<Tee<T> as Verifier>::Pubs,
     ) -> Weight {
-        W::intel_verify_proof().max(W::nitro_verify_proof())
+        W::intel_verify_proof()
+            .max(W::intel_registry_verify_proof())
+            .max(W::nitro_verify_proof())
+            .max(W::amd_verify_proof())
     }
 
     fn register_vk(_vk: &<Tee<T> as Verifier>::Vk) -> Weight {
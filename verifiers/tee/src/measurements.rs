@@ -0,0 +1,133 @@
+// Copyright 2026, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Expected-measurement policies that bind a vk to an approved enclave build.
+//!
+//! Every measurement is optional: a `None` value accepts whatever the attestation
+//! reports, while a `Some` value must match exactly.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::ensure;
+use pallet_verifiers::traits::VerifyError;
+use scale_info::TypeInfo;
+use tee_verifier::ReportBody;
+
+/// A SHA-384 measurement (TDX MRTD/RTMRs and Nitro PCRs).
+pub type Sha384 = [u8; 48];
+
+/// Number of TDX runtime measurement registers.
+pub const TDX_RTMRS: usize = 4;
+/// Number of Nitro PCRs that can be pinned (PCR0 to PCR8).
+pub const NITRO_PCRS: usize = 9;
+
+/// Expected measurements of an SGX enclave.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct SgxMeasurements {
+    /// Expected enclave identity.
+    pub mr_enclave: Option<[u8; 32]>,
+    /// Expected enclave signer identity.
+    pub mr_signer: Option<[u8; 32]>,
+    /// Minimum accepted enclave security version.
+    pub min_isv_svn: u16,
+}
+
+impl SgxMeasurements {
+    pub fn check(
+        &self,
+        mr_enclave: &[u8; 32],
+        mr_signer: &[u8; 32],
+        isv_svn: u16,
+    ) -> Result<(), VerifyError> {
+        ensure!(
+            matches(&self.mr_enclave, mr_enclave),
+            VerifyError::VerifyError
+        );
+        ensure!(
+            matches(&self.mr_signer, mr_signer),
+            VerifyError::VerifyError
+        );
+        ensure!(isv_svn >= self.min_isv_svn, VerifyError::VerifyError);
+        Ok(())
+    }
+}
+
+/// Expected measurements of a TDX trust domain.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct TdxMeasurements {
+    /// Expected measurement of the initial TD contents.
+    pub mr_td: Option<Sha384>,
+    /// Expected runtime measurement registers.
+    pub rtmrs: [Option<Sha384>; TDX_RTMRS],
+}
+
+impl TdxMeasurements {
+    pub fn check(&self, mr_td: &Sha384, rtmrs: &[Sha384; TDX_RTMRS]) -> Result<(), VerifyError> {
+        ensure!(matches(&self.mr_td, mr_td), VerifyError::VerifyError);
+        ensure!(
+            self.rtmrs
+                .iter()
+                .zip(rtmrs.iter())
+                .all(|(expected, actual)| matches(expected, actual)),
+            VerifyError::VerifyError
+        );
+        Ok(())
+    }
+}
+
+/// Expected measurements of an Intel quote: the policy kind must match the quoted TEE.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum IntelMeasurements {
+    Sgx(SgxMeasurements),
+    Tdx(TdxMeasurements),
+}
+
+impl IntelMeasurements {
+    pub fn check(&self, body: &ReportBody) -> Result<(), VerifyError> {
+        match (self, body) {
+            (IntelMeasurements::Sgx(expected), ReportBody::Sgx(body)) => {
+                expected.check(&body.mr_enclave, &body.mr_signer, body.isv_svn)
+            }
+            (IntelMeasurements::Tdx(expected), ReportBody::Td(body)) => {
+                expected.check(&body.mr_td, &body.rtmr)
+            }
+            _ => Err(VerifyError::VerifyError),
+        }
+    }
+}
+
+/// Expected PCR values of a Nitro enclave image.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct NitroPcrs(pub [Option<Sha384>; NITRO_PCRS]);
+
+impl NitroPcrs {
+    /// True if no PCR is pinned, i.e. any enclave would be accepted.
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(Option::is_none)
+    }
+
+    /// Check the pinned PCRs against the ones returned by `pcr` for each index.
+    pub fn check<'a>(&self, pcr: impl Fn(usize) -> Option<&'a [u8]>) -> Result<(), VerifyError> {
+        for (index, expected) in self.0.iter().enumerate() {
+            if let Some(expected) = expected {
+                ensure!(pcr(index) == Some(&expected[..]), VerifyError::VerifyError);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn matches<const N: usize>(expected: &Option<[u8; N]>, actual: &[u8; N]) -> bool {
+    expected.as_ref().is_none_or(|expected| expected == actual)
+}
@@ -0,0 +1,103 @@
+// Copyright 2026, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! TDX trust domain policy.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::ensure;
+use pallet_verifiers::traits::VerifyError;
+use scale_info::TypeInfo;
+use tee_verifier::ReportBody;
+
+use crate::measurements::{Sha384, TdxMeasurements, TDX_RTMRS};
+
+/// Size in bytes of the TEE TCB SVN of a TD report.
+pub const TEE_TCB_SVN_LENGTH: usize = 16;
+
+/// TD attribute: the TD is in debug mode (its state can be read by the host).
+const TD_ATTRIBUTES_DEBUG: u64 = 1 << 0;
+
+/// The TD report fields checked by a [`TdxPolicy`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TdReport {
+    /// TDX TCB level: the SVN of the TDX module is the first component.
+    pub tee_tcb_svn: [u8; TEE_TCB_SVN_LENGTH],
+    pub seam_attributes: u64,
+    pub td_attributes: u64,
+    pub xfam: u64,
+    pub mr_td: Sha384,
+    pub rtmrs: [Sha384; TDX_RTMRS],
+}
+
+impl TdReport {
+    /// Extract the TD report from a quote body, if the quote was produced by a trust domain.
+    pub fn from_body(body: &ReportBody) -> Option<Self> {
+        match body {
+            ReportBody::Td(body) => Some(Self {
+                tee_tcb_svn: body.tee_tcb_svn,
+                seam_attributes: u64::from_le_bytes(body.seam_attributes),
+                td_attributes: u64::from_le_bytes(body.td_attributes),
+                xfam: u64::from_le_bytes(body.xfam),
+                mr_td: body.mr_td,
+                rtmrs: body.rtmr,
+            }),
+            ReportBody::Sgx(_) => None,
+        }
+    }
+}
+
+/// The constraints that a TDX trust domain should satisfy.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct TdxPolicy {
+    /// Expected measurements of the trust domain.
+    pub measurements: TdxMeasurements,
+    /// Accept trust domains with the debug attribute set.
+    pub allow_debug: bool,
+    /// Minimum accepted SVN of the TDX (SEAM) module.
+    pub min_seam_svn: u8,
+    /// Minimum accepted TDX TCB level: each component of the TEE TCB SVN should be at least
+    /// the corresponding one.
+    pub min_tee_tcb_svn: [u8; TEE_TCB_SVN_LENGTH],
+    /// Expected XFAM, i.e. the set of extended CPU features available to the trust domain.
+    pub xfam: Option<u64>,
+}
+
+impl TdxPolicy {
+    pub fn check(&self, report: &TdReport) -> Result<(), VerifyError> {
+        // A production TDX module doesn't set any SEAM attribute
+        ensure!(report.seam_attributes == 0, VerifyError::VerifyError);
+        ensure!(
+            self.allow_debug || report.td_attributes & TD_ATTRIBUTES_DEBUG == 0,
+            VerifyError::VerifyError
+        );
+        ensure!(
+            report.tee_tcb_svn[0] >= self.min_seam_svn,
+            VerifyError::VerifyError
+        );
+        ensure!(
+            report
+                .tee_tcb_svn
+                .iter()
+                .zip(self.min_tee_tcb_svn.iter())
+                .all(|(svn, min)| svn >= min),
+            VerifyError::VerifyError
+        );
+        ensure!(
+            self.xfam.is_none_or(|xfam| xfam == report.xfam),
+            VerifyError::VerifyError
+        );
+        self.measurements.check(&report.mr_td, &report.rtmrs)
+    }
+}
@@ -20,7 +20,7 @@ use hex_literal::hex;
 use sp_core::{ConstU64, Get};
 
 use super::*;
-use pallet_crl::{CaNotFoundError, Crl, CrlProvider, RevokedCertId};
+use pallet_crl::{CaNotFoundError, Crl, CrlProvider, CrlValidity, RevokedCertId, StalenessPolicy};
 
 // Mock timestamps used to test the validity period of the tcb info
 const PAST: u64 = 1737556187; // Thu, 22 Jan 2025 14:29:47 GMT
@@ -32,6 +32,8 @@ const NITRO_NOW: u64 = 1668034320;
 
 const MOCK_INTEL_WEIGHT: u64 = 1;
 const MOCK_NITRO_WEIGHT: u64 = 2;
+const MOCK_INTEL_REGISTRY_WEIGHT: u64 = 9;
+const MOCK_AMD_WEIGHT: u64 = 8;
 
 struct MockTime<T: Get<u64>>(PhantomData<T>);
 
@@ -47,6 +49,22 @@ impl CrlProvider for EmptyCrl {
     fn get_crl(_ca_name: &str) -> Result<Crl, CaNotFoundError> {
         Ok(vec![])
     }
+
+    fn get_root_cert(_ca_name: &str) -> Result<Vec<u8>, CaNotFoundError> {
+        Err(CaNotFoundError)
+    }
+
+    fn get_crl_validity(_ca_name: &str) -> Result<Vec<CrlValidity>, CaNotFoundError> {
+        Ok(vec![])
+    }
+
+    fn is_crl_stale(_ca_name: &str, _now: u64) -> Result<bool, CaNotFoundError> {
+        Ok(false)
+    }
+
+    fn is_revoked(_ca_name: &str, _issuer: &[u8], _serial_number: &[u8]) -> bool {
+        false
+    }
 }
 
 /// CRL containing a revoked certificate matching the test quote.
@@ -61,6 +79,108 @@ impl CrlProvider for RevokedCrl {
             serial_number: hex!("00956f5dcdbd1be1e94049c9d4f433ce01570bde54").to_vec(),
         }])
     }
+
+    fn get_root_cert(_ca_name: &str) -> Result<Vec<u8>, CaNotFoundError> {
+        Err(CaNotFoundError)
+    }
+
+    fn get_crl_validity(_ca_name: &str) -> Result<Vec<CrlValidity>, CaNotFoundError> {
+        Ok(vec![])
+    }
+
+    fn is_crl_stale(_ca_name: &str, _now: u64) -> Result<bool, CaNotFoundError> {
+        Ok(false)
+    }
+
+    fn is_revoked(ca_name: &str, issuer: &[u8], serial_number: &[u8]) -> bool {
+        Self::get_crl(ca_name).is_ok_and(|crl| {
+            crl.iter()
+                .any(|r| r.issuer == issuer && r.serial_number == serial_number)
+        })
+    }
+}
+
+/// Empty CRL for the AMD Milan CA, whose ARK is registered as root certificate.
+struct AmdRootCrl;
+impl CrlProvider for AmdRootCrl {
+    fn get_crl(_ca_name: &str) -> Result<Crl, CaNotFoundError> {
+        Ok(vec![])
+    }
+
+    fn get_root_cert(_ca_name: &str) -> Result<Vec<u8>, CaNotFoundError> {
+        Ok(include_bytes!("resources/amd/ark.der").to_vec())
+    }
+
+    fn get_crl_validity(_ca_name: &str) -> Result<Vec<CrlValidity>, CaNotFoundError> {
+        Ok(vec![])
+    }
+
+    fn is_crl_stale(_ca_name: &str, _now: u64) -> Result<bool, CaNotFoundError> {
+        Ok(false)
+    }
+
+    fn is_revoked(_ca_name: &str, _issuer: &[u8], _serial_number: &[u8]) -> bool {
+        false
+    }
+}
+
+/// Empty CRL whose `nextUpdate` is in the past of `PRESENT`, for a CA that enforces its
+/// staleness policy.
+struct StaleCrl;
+impl CrlProvider for StaleCrl {
+    fn get_crl(_ca_name: &str) -> Result<Crl, CaNotFoundError> {
+        Ok(vec![])
+    }
+
+    fn get_root_cert(_ca_name: &str) -> Result<Vec<u8>, CaNotFoundError> {
+        Err(CaNotFoundError)
+    }
+
+    fn get_crl_validity(_ca_name: &str) -> Result<Vec<CrlValidity>, CaNotFoundError> {
+        Ok(vec![CrlValidity {
+            this_update: PAST,
+            next_update: Some(PRESENT - 1),
+        }])
+    }
+
+    fn is_crl_stale(ca_name: &str, now: u64) -> Result<bool, CaNotFoundError> {
+        let policy = StalenessPolicy::Enforce { grace_period: 0 };
+        Ok(Self::get_crl_validity(ca_name)?
+            .iter()
+            .any(|validity| policy.is_stale(validity, now)))
+    }
+
+    fn is_revoked(_ca_name: &str, _issuer: &[u8], _serial_number: &[u8]) -> bool {
+        false
+    }
+}
+
+/// The measurements of the trust domain in `valid_quote.dat`.
+fn valid_quote_measurements() -> TdxMeasurements {
+    TdxMeasurements {
+        mr_td: Some(hex!("91eb2b44d141d4ece09f0c75c2c53d247a3c68edd7fafe8a3520c942a604a407de03ae6dc5f87f27428b2538873118b7")),
+        rtmrs: [
+            Some(hex!("44c0197b39157fdd7a4dcc44767f9d6b0bb3977c7a8e347b8492f827fe9d9e5c48aca29b220b80b6a540cf994b9bc9c0")),
+            Some(hex!("0084452c01668329d4bc06acdf58a7205c26743304509973949e5619bf81a6a7aea8c323c173019b3093d54e579e9378")),
+            Some(hex!("d833feef2cd945148aa38ead2c53e9b7f138190aaaebfc551dccd829fc207aa3ba80b70870d7330733642e01d48c3132")),
+            Some([0; 48]),
+        ],
+    }
+}
+
+/// The PCRs of the enclave in `valid_attestation.bin`.
+fn valid_attestation_pcrs() -> NitroPcrs {
+    NitroPcrs([
+        Some(hex!("22f9e1c949208da55eb01b9b9fc88f87454f77ba133f0d82320b509621c92482152a99d0a12335b97178c02d6f977d01")),
+        Some(hex!("bcdf05fefccaa8e55bf2c8d6dee9e79bbff31e34bf28a99aa19e6b29c37ee80b214a414b7607236edf26fcb78654e63f")),
+        Some(hex!("00943ea859140fed74e1025fe41aedb38780eae5656b3f9ef9b4b0e6111350315544db3efcda05725108f72b2ab141f7")),
+        Some([0; 48]),
+        Some(hex!("4554042339e6a1ddfac89db7da58231d5619073729344333aff055429abeec6b006f81b19d1176001b8291f885283106")),
+        Some([0; 48]),
+        Some([0; 48]),
+        Some([0; 48]),
+        Some([0; 48]),
+    ])
 }
 
 struct MockCaName;
@@ -68,7 +188,10 @@ impl CaNameProvider for MockCaName {
     fn ca_name_for(vk: &Vk) -> &'static str {
         match vk {
             Vk::Intel { .. } => "Intel_SGX_Processor",
-            Vk::Nitro => "AWS_Nitro",
+            Vk::IntelTcbRegistry { .. } => "Intel_SGX_Processor",
+            Vk::Tdx { .. } => "Intel_SGX_Processor",
+            Vk::Nitro { .. } => "AWS_Nitro",
+            Vk::AmdSnp { .. } => "AMD_SEV_SNP",
         }
     }
 }
@@ -80,10 +203,18 @@ impl WeightInfo for MockWeight {
         Weight::from_all(MOCK_INTEL_WEIGHT)
     }
 
+    fn intel_registry_verify_proof() -> Weight {
+        Weight::from_all(MOCK_INTEL_REGISTRY_WEIGHT)
+    }
+
     fn nitro_verify_proof() -> Weight {
         Weight::from_all(MOCK_NITRO_WEIGHT)
     }
 
+    fn amd_verify_proof() -> Weight {
+        Weight::from_all(MOCK_AMD_WEIGHT)
+    }
+
     fn get_vk() -> Weight {
         Weight::from_all(3)
     }
@@ -105,10 +236,30 @@ impl WeightInfo for MockWeight {
     }
 }
 
-struct Mock<T: UnixTime, C: CrlProvider = EmptyCrl>(PhantomData<(T, C)>);
-impl<T: UnixTime, C: CrlProvider> Config for Mock<T, C> {
+/// TCB info registry holding the TDX TCB info of the test quote platform.
+struct MockTcbRegistry;
+impl TcbInfoProvider for MockTcbRegistry {
+    fn get_tcb_response(fmspc: &pallet_crl::Fmspc, id: TcbId) -> Option<Vec<u8>> {
+        (fmspc == &hex!("b0c06f000000") && id == TcbId::Tdx)
+            .then(|| include_bytes!("resources/intel/valid_tcbinfo.json").to_vec())
+    }
+}
+
+/// Empty TCB info registry.
+struct EmptyTcbRegistry;
+impl TcbInfoProvider for EmptyTcbRegistry {
+    fn get_tcb_response(_fmspc: &pallet_crl::Fmspc, _id: TcbId) -> Option<Vec<u8>> {
+        None
+    }
+}
+
+struct Mock<T: UnixTime, C: CrlProvider = EmptyCrl, R: TcbInfoProvider = MockTcbRegistry>(
+    PhantomData<(T, C, R)>,
+);
+impl<T: UnixTime, C: CrlProvider, R: TcbInfoProvider> Config for Mock<T, C, R> {
     type UnixTime = T;
     type Crl = C;
+    type TcbInfo = R;
     type CaName = MockCaName;
     type WeightInfo = MockWeight;
 }
@@ -123,6 +274,7 @@ mod intel {
         let vk = Vk::Intel {
             tcb_response: include_bytes!("resources/intel/valid_tcbinfo.json").to_vec(),
             certificates: include_bytes!("resources/intel/valid_tcbinfo_certs.pem").to_vec(),
+            measurements: None,
         };
 
         let res = Tee::<Mock<MockTime<ConstU64<PRESENT>>>>::verify_proof(&vk, &proof, &pubs);
@@ -138,6 +290,7 @@ mod intel {
         let vk = Vk::Intel {
             tcb_response: include_bytes!("resources/intel/valid_tcbinfo.json").to_vec(),
             certificates: include_bytes!("resources/intel/valid_tcbinfo_certs.pem").to_vec(),
+            measurements: None,
         };
 
         assert_eq!(
@@ -146,6 +299,26 @@ mod intel {
         );
     }
 
+    #[test]
+    fn reject_valid_proof_with_stale_crl() {
+        let proof = include_bytes!("resources/intel/valid_quote.dat").to_vec();
+        let pubs = vec![];
+        let vk = Vk::Intel {
+            tcb_response: include_bytes!("resources/intel/valid_tcbinfo.json").to_vec(),
+            certificates: include_bytes!("resources/intel/valid_tcbinfo_certs.pem").to_vec(),
+            measurements: None,
+        };
+
+        assert_eq!(
+            Tee::<Mock<MockTime<ConstU64<PRESENT>>, StaleCrl>>::verify_proof(&vk, &proof, &pubs),
+            Err(VerifyError::VerifyError)
+        );
+        assert_eq!(
+            Tee::<Mock<MockTime<ConstU64<PRESENT>>, StaleCrl>>::validate_vk(&vk),
+            Err(VerifyError::VerifyError)
+        );
+    }
+
     #[test]
     fn reject_invalid_proof() {
         let proof = include_bytes!("resources/intel/invalid_quote.dat").to_vec();
@@ -153,6 +326,7 @@ mod intel {
         let vk = Vk::Intel {
             tcb_response: include_bytes!("resources/intel/valid_tcbinfo.json").to_vec(),
             certificates: include_bytes!("resources/intel/valid_tcbinfo_certs.pem").to_vec(),
+            measurements: None,
         };
 
         assert_eq!(
@@ -166,6 +340,7 @@ mod intel {
         let vk = Vk::Intel {
             tcb_response: include_bytes!("resources/intel/invalid_tcbinfo.json").to_vec(),
             certificates: include_bytes!("resources/intel/valid_tcbinfo_certs.pem").to_vec(),
+            measurements: None,
         };
 
         assert_eq!(
@@ -181,6 +356,7 @@ mod intel {
         let vk = Vk::Intel {
             tcb_response: include_bytes!("resources/intel/valid_tcbinfo.json").to_vec(),
             certificates: include_bytes!("resources/intel/valid_tcbinfo_certs.pem").to_vec(),
+            measurements: None,
         };
 
         assert_eq!(
@@ -211,6 +387,7 @@ mod intel {
         let vk = Vk::Intel {
             tcb_response: vec![],
             certificates: vec![],
+            measurements: None,
         };
 
         assert_eq!(
@@ -226,6 +403,7 @@ mod intel {
         let vk = Vk::Intel {
             tcb_response: vec![0u8; MAX_VK_LENGTH as usize + 1],
             certificates: vec![0u8; MAX_VK_LENGTH as usize + 1],
+            measurements: None,
         };
 
         assert_eq!(
@@ -234,6 +412,202 @@ mod intel {
         )
     }
 
+    mod measurements {
+        use super::*;
+
+        fn vk(measurements: IntelMeasurements) -> Vk {
+            Vk::Intel {
+                tcb_response: include_bytes!("resources/intel/valid_tcbinfo.json").to_vec(),
+                certificates: include_bytes!("resources/intel/valid_tcbinfo_certs.pem").to_vec(),
+                measurements: Some(measurements),
+            }
+        }
+
+        fn verify(vk: &Vk) -> Result<Option<Weight>, VerifyError> {
+            let proof = include_bytes!("resources/intel/valid_quote.dat").to_vec();
+            Tee::<Mock<MockTime<ConstU64<PRESENT>>>>::verify_proof(vk, &proof, &vec![])
+        }
+
+        #[test]
+        fn verify_valid_proof_with_matching_measurements() {
+            assert_eq!(
+                verify(&vk(IntelMeasurements::Tdx(valid_quote_measurements()))),
+                Ok(Some(Weight::from_all(MOCK_INTEL_WEIGHT)))
+            );
+        }
+
+        #[test]
+        fn verify_valid_proof_pinning_just_mr_td() {
+            let measurements = TdxMeasurements {
+                mr_td: valid_quote_measurements().mr_td,
+                ..Default::default()
+            };
+            assert_eq!(
+                verify(&vk(IntelMeasurements::Tdx(measurements))),
+                Ok(Some(Weight::from_all(MOCK_INTEL_WEIGHT)))
+            );
+        }
+
+        #[test]
+        fn reject_valid_proof_with_mismatching_mr_td() {
+            let measurements = TdxMeasurements {
+                mr_td: Some([0xff; 48]),
+                ..valid_quote_measurements()
+            };
+            assert_eq!(
+                verify(&vk(IntelMeasurements::Tdx(measurements))),
+                Err(VerifyError::VerifyError)
+            );
+        }
+
+        #[test]
+        fn reject_valid_proof_with_mismatching_rtmr() {
+            for index in 0..TDX_RTMRS {
+                let mut measurements = valid_quote_measurements();
+                measurements.rtmrs[index] = Some([0xff; 48]);
+                assert_eq!(
+                    verify(&vk(IntelMeasurements::Tdx(measurements))),
+                    Err(VerifyError::VerifyError),
+                    "RTMR{index}"
+                );
+            }
+        }
+
+        #[test]
+        fn reject_sgx_measurements_for_a_tdx_quote() {
+            assert_eq!(
+                verify(&vk(IntelMeasurements::Sgx(SgxMeasurements::default()))),
+                Err(VerifyError::VerifyError)
+            );
+        }
+
+        mod sgx {
+            use super::*;
+
+            const MR_ENCLAVE: [u8; 32] = [1; 32];
+            const MR_SIGNER: [u8; 32] = [2; 32];
+
+            #[test]
+            fn accept_any_enclave_if_nothing_is_pinned() {
+                assert_eq!(
+                    SgxMeasurements::default().check(&MR_ENCLAVE, &MR_SIGNER, 0),
+                    Ok(())
+                );
+            }
+
+            #[test]
+            fn accept_matching_enclave() {
+                let measurements = SgxMeasurements {
+                    mr_enclave: Some(MR_ENCLAVE),
+                    mr_signer: Some(MR_SIGNER),
+                    min_isv_svn: 3,
+                };
+                assert_eq!(measurements.check(&MR_ENCLAVE, &MR_SIGNER, 3), Ok(()));
+                assert_eq!(measurements.check(&MR_ENCLAVE, &MR_SIGNER, 4), Ok(()));
+            }
+
+            #[test]
+            fn reject_mismatching_mr_enclave() {
+                let measurements = SgxMeasurements {
+                    mr_enclave: Some(MR_ENCLAVE),
+                    ..Default::default()
+                };
+                assert_eq!(
+                    measurements.check(&[0xff; 32], &MR_SIGNER, 0),
+                    Err(VerifyError::VerifyError)
+                );
+            }
+
+            #[test]
+            fn reject_mismatching_mr_signer() {
+                let measurements = SgxMeasurements {
+                    mr_signer: Some(MR_SIGNER),
+                    ..Default::default()
+                };
+                assert_eq!(
+                    measurements.check(&MR_ENCLAVE, &[0xff; 32], 0),
+                    Err(VerifyError::VerifyError)
+                );
+            }
+
+            #[test]
+            fn reject_outdated_isv_svn() {
+                let measurements = SgxMeasurements {
+                    min_isv_svn: 3,
+                    ..Default::default()
+                };
+                assert_eq!(
+                    measurements.check(&MR_ENCLAVE, &MR_SIGNER, 2),
+                    Err(VerifyError::VerifyError)
+                );
+            }
+        }
+    }
+
+    mod tcb_registry {
+        use super::*;
+
+        #[test]
+        fn verify_valid_proof() {
+            let proof = include_bytes!("resources/intel/valid_quote.dat").to_vec();
+            let vk = Vk::IntelTcbRegistry {
+                measurements: Some(IntelMeasurements::Tdx(valid_quote_measurements())),
+            };
+
+            assert_eq!(
+                Tee::<Mock<MockTime<ConstU64<PRESENT>>>>::verify_proof(&vk, &proof, &vec![]),
+                Ok(Some(Weight::from_all(MOCK_INTEL_REGISTRY_WEIGHT)))
+            );
+        }
+
+        #[test]
+        fn reject_valid_proof_if_no_tcb_info_is_registered() {
+            let proof = include_bytes!("resources/intel/valid_quote.dat").to_vec();
+            let vk = Vk::IntelTcbRegistry { measurements: None };
+
+            assert_eq!(
+                Tee::<Mock<MockTime<ConstU64<PRESENT>>, EmptyCrl, EmptyTcbRegistry>>::verify_proof(
+                    &vk,
+                    &proof,
+                    &vec![]
+                ),
+                Err(VerifyError::VerifyError)
+            );
+        }
+
+        #[test]
+        fn reject_valid_proof_if_the_registered_tcb_info_expired() {
+            let proof = include_bytes!("resources/intel/valid_quote.dat").to_vec();
+            let vk = Vk::IntelTcbRegistry { measurements: None };
+
+            assert_eq!(
+                Tee::<Mock<MockTime<ConstU64<FUTURE>>>>::verify_proof(&vk, &proof, &vec![]),
+                Err(VerifyError::VerifyError)
+            );
+        }
+
+        #[test]
+        fn reject_invalid_proof() {
+            let proof = include_bytes!("resources/intel/invalid_quote.dat").to_vec();
+            let vk = Vk::IntelTcbRegistry { measurements: None };
+
+            assert_eq!(
+                Tee::<Mock<MockTime<ConstU64<PRESENT>>>>::verify_proof(&vk, &proof, &vec![]),
+                Err(VerifyError::VerifyError)
+            );
+        }
+
+        #[test]
+        fn validate_vk() {
+            let vk = Vk::IntelTcbRegistry { measurements: None };
+
+            assert_eq!(
+                Tee::<Mock<MockTime<ConstU64<PRESENT>>>>::validate_vk(&vk),
+                Ok(())
+            );
+        }
+    }
+
     #[test]
     fn reject_invalid_pubs() {
         let proof = vec![];
@@ -241,6 +615,7 @@ mod intel {
         let vk = Vk::Intel {
             tcb_response: vec![],
             certificates: vec![],
+            measurements: None,
         };
 
         assert_eq!(
@@ -248,6 +623,37 @@ mod intel {
             Err(VerifyError::InvalidInput)
         )
     }
+
+    #[test]
+    fn verify_valid_proof_with_pubs_bound_to_report_data() {
+        let proof = include_bytes!("resources/intel/valid_quote.dat").to_vec();
+        let pubs = hex!("9a9d48e7f6799642d3d1b34e1e5e1742d4bb02dd6ddd551862c1211d35c304f9eca3efdbb481601c163cf52493d6e44aed55d51ec39b7e518fadb92c2b523f20").to_vec();
+        let vk = Vk::Intel {
+            tcb_response: include_bytes!("resources/intel/valid_tcbinfo.json").to_vec(),
+            certificates: include_bytes!("resources/intel/valid_tcbinfo_certs.pem").to_vec(),
+            measurements: None,
+        };
+
+        assert_eq!(
+            Tee::<Mock<MockTime<ConstU64<PRESENT>>>>::verify_proof(&vk, &proof, &pubs),
+            Ok(Some(Weight::from_all(MOCK_INTEL_WEIGHT)))
+        );
+    }
+
+    #[test]
+    fn reject_valid_proof_with_pubs_not_bound_to_report_data() {
+        let proof = include_bytes!("resources/intel/valid_quote.dat").to_vec();
+        let vk = Vk::Intel {
+            tcb_response: include_bytes!("resources/intel/valid_tcbinfo.json").to_vec(),
+            certificates: include_bytes!("resources/intel/valid_tcbinfo_certs.pem").to_vec(),
+            measurements: None,
+        };
+
+        assert_eq!(
+            Tee::<Mock<MockTime<ConstU64<PRESENT>>>>::verify_proof(&vk, &proof, &vec![0x9a]),
+            Err(VerifyError::VerifyError)
+        );
+    }
 }
 
 // =========================================================================
@@ -261,7 +667,9 @@ mod nitro {
     fn verify_valid_proof() {
         let proof = include_bytes!("resources/nitro/valid_attestation.bin").to_vec();
         let pubs = vec![];
-        let vk = Vk::Nitro;
+        let vk = Vk::Nitro {
+            pcrs: NitroPcrs::default(),
+        };
 
         let res = Tee::<Mock<MockTime<ConstU64<NITRO_NOW>>>>::verify_proof(&vk, &proof, &pubs);
 
@@ -273,7 +681,9 @@ mod nitro {
     fn reject_invalid_proof() {
         let proof = include_bytes!("resources/nitro/invalid_attestation.bin").to_vec();
         let pubs = vec![];
-        let vk = Vk::Nitro;
+        let vk = Vk::Nitro {
+            pcrs: NitroPcrs::default(),
+        };
 
         assert_eq!(
             Tee::<Mock<MockTime<ConstU64<NITRO_NOW>>>>::verify_proof(&vk, &proof, &pubs),
@@ -285,7 +695,9 @@ mod nitro {
     fn reject_with_expired_timestamp() {
         let proof = include_bytes!("resources/nitro/valid_attestation.bin").to_vec();
         let pubs = vec![];
-        let vk = Vk::Nitro;
+        let vk = Vk::Nitro {
+            pcrs: NitroPcrs::default(),
+        };
 
         // One year after: certificates will have expired
         const NITRO_FUTURE: u64 = NITRO_NOW + 365 * 24 * 3600;
@@ -296,8 +708,641 @@ mod nitro {
     }
 
     #[test]
-    fn validate_vk_always_fails() {
-        let vk = Vk::Nitro;
-        assert!(Tee::<Mock<MockTime<ConstU64<NITRO_NOW>>>>::validate_vk(&vk).is_err());
+    fn verify_valid_proof_with_matching_pcrs() {
+        let proof = include_bytes!("resources/nitro/valid_attestation.bin").to_vec();
+        let vk = Vk::Nitro {
+            pcrs: valid_attestation_pcrs(),
+        };
+
+        assert_eq!(
+            Tee::<Mock<MockTime<ConstU64<NITRO_NOW>>>>::verify_proof(&vk, &proof, &vec![]),
+            Ok(Some(Weight::from_all(MOCK_NITRO_WEIGHT)))
+        );
+    }
+
+    #[test]
+    fn reject_valid_proof_with_mismatching_pcr() {
+        let proof = include_bytes!("resources/nitro/valid_attestation.bin").to_vec();
+        for index in 0..NITRO_PCRS {
+            let mut pcrs = valid_attestation_pcrs();
+            pcrs.0[index] = Some([0xff; 48]);
+            let vk = Vk::Nitro { pcrs };
+
+            assert_eq!(
+                Tee::<Mock<MockTime<ConstU64<NITRO_NOW>>>>::verify_proof(&vk, &proof, &vec![]),
+                Err(VerifyError::VerifyError),
+                "PCR{index}"
+            );
+        }
+    }
+
+    #[rstest::rstest]
+    #[case::user_data(hex!("7c37108079b3e8a36d8a7970de1d6d4ff1461e0e35d9557c4d789df5e0576620"))]
+    #[case::nonce(hex!("c678c86135de539d183acff58886d98dfb9823041af9f934bf909ac0f8d96245"))]
+    fn verify_valid_proof_with_bound_pubs(#[case] pubs: [u8; 32]) {
+        let proof = include_bytes!("resources/nitro/valid_attestation.bin").to_vec();
+        let vk = Vk::Nitro {
+            pcrs: NitroPcrs::default(),
+        };
+
+        assert_eq!(
+            Tee::<Mock<MockTime<ConstU64<NITRO_NOW>>>>::verify_proof(&vk, &proof, &pubs.to_vec()),
+            Ok(Some(Weight::from_all(MOCK_NITRO_WEIGHT)))
+        );
+    }
+
+    #[test]
+    fn reject_valid_proof_with_unbound_pubs() {
+        let proof = include_bytes!("resources/nitro/valid_attestation.bin").to_vec();
+        let vk = Vk::Nitro {
+            pcrs: NitroPcrs::default(),
+        };
+
+        assert_eq!(
+            Tee::<Mock<MockTime<ConstU64<NITRO_NOW>>>>::verify_proof(&vk, &proof, &vec![0x7c]),
+            Err(VerifyError::VerifyError)
+        );
+    }
+
+    #[test]
+    fn reject_vk_without_pinned_pcrs() {
+        let vk = Vk::Nitro {
+            pcrs: NitroPcrs::default(),
+        };
+        assert_eq!(
+            Tee::<Mock<MockTime<ConstU64<NITRO_NOW>>>>::validate_vk(&vk),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+    }
+
+    #[test]
+    fn validate_vk_with_pinned_pcrs() {
+        let mut pcrs = NitroPcrs::default();
+        pcrs.0[0] = valid_attestation_pcrs().0[0];
+        let vk = Vk::Nitro { pcrs };
+        assert_eq!(
+            Tee::<Mock<MockTime<ConstU64<NITRO_NOW>>>>::validate_vk(&vk),
+            Ok(())
+        );
+    }
+}
+
+// =========================================================================
+// Pubs binding tests
+// =========================================================================
+
+mod binding {
+    use crate::binding::{bound_to_any, bound_to_report_data};
+
+    const PUBS: &[u8] = b"enclave output";
+
+    fn padded(value: &[u8]) -> [u8; 64] {
+        let mut report_data = [0; 64];
+        report_data[..value.len()].copy_from_slice(value);
+        report_data
+    }
+
+    #[test]
+    fn empty_pubs_are_always_bound() {
+        assert!(bound_to_report_data(&[0xff; 64], &[]));
+        assert!(bound_to_any([None], &[]));
+    }
+
+    #[test]
+    fn accept_report_data_holding_the_pubs() {
+        assert!(bound_to_report_data(&padded(PUBS), PUBS));
+    }
+
+    #[test]
+    fn accept_report_data_holding_the_pubs_digest() {
+        let digest = sp_io::hashing::sha2_256(PUBS);
+        assert!(bound_to_report_data(&padded(&digest), PUBS));
+    }
+
+    #[test]
+    fn reject_report_data_with_trailing_garbage() {
+        let mut report_data = padded(PUBS);
+        report_data[63] = 1;
+        assert!(!bound_to_report_data(&report_data, PUBS));
+    }
+
+    #[test]
+    fn reject_pubs_longer_than_report_data() {
+        let pubs = [0xaa; 65];
+        assert!(!bound_to_report_data(&[0xaa; 64], &pubs));
+    }
+
+    #[test]
+    fn accept_any_field_holding_the_pubs_or_their_digest() {
+        let digest = sp_io::hashing::sha2_256(PUBS);
+        assert!(bound_to_any([None, Some(PUBS)], PUBS));
+        assert!(bound_to_any([Some(&digest[..]), None], PUBS));
+    }
+
+    #[test]
+    fn reject_fields_not_holding_the_pubs() {
+        assert!(!bound_to_any([None, None], PUBS));
+        assert!(!bound_to_any([Some(&padded(PUBS)[..])], PUBS));
+    }
+}
+
+// =========================================================================
+// AMD SEV-SNP tests
+// =========================================================================
+
+mod amd {
+    use super::*;
+
+    const DEBUG: u64 = 1 << 19;
+    const MIGRATE_MA: u64 = 1 << 18;
+    // Default guest policy: ABI 0.0, SMT allowed, reserved bit 17 set
+    const DEFAULT_POLICY: u64 = 0x30000;
+
+    fn tcb(bootloader: u8, tee: u8, snp: u8, microcode: u8) -> SnpTcb {
+        SnpTcb {
+            bootloader,
+            tee,
+            snp,
+            microcode,
+        }
+    }
+
+    fn vk(policy: SnpPolicy) -> Vk {
+        Vk::AmdSnp {
+            certificates: b"-----BEGIN CERTIFICATE-----".to_vec(),
+            policy,
+        }
+    }
+
+    /// A Milan report followed by the VCEK that signed it.
+    const VALID_ATTESTATION: &[u8] = include_bytes!("resources/amd/valid_attestation.bin");
+    /// The `report_data` of the report in `valid_attestation.bin`.
+    const REPORT_DATA: [u8; 64] = hex!("d447b55d197491bfe15cf298f9de9986b7a7c4be2468b4f6e2d53b71d7c645810b0f2cdfca0040433be063fc1a8293f0f3f8dae7b79fecb3d1cd82bd6a93ebfd");
+    /// Offset of the `measurement` field in the report.
+    const MEASUREMENT_OFFSET: usize = 0x90;
+
+    /// The Milan ASK/ARK chain that issued the VCEK in `valid_attestation.bin`.
+    fn valid_vk(policy: SnpPolicy) -> Vk {
+        Vk::AmdSnp {
+            certificates: include_bytes!("resources/amd/valid_cert_chain.pem").to_vec(),
+            policy,
+        }
+    }
+
+    type AmdTee = Tee<Mock<MockTime<ConstU64<PRESENT>>, AmdRootCrl>>;
+
+    #[test]
+    fn verify_valid_report() {
+        assert_eq!(
+            AmdTee::verify_proof(
+                &valid_vk(SnpPolicy::default()),
+                VALID_ATTESTATION,
+                &REPORT_DATA.to_vec()
+            ),
+            Ok(Some(Weight::from_all(MOCK_AMD_WEIGHT)))
+        );
+    }
+
+    #[test]
+    fn verify_valid_report_satisfying_the_min_tcb() {
+        // The report comes from a platform at TCB 3.0.8.115
+        let policy = SnpPolicy {
+            min_tcb: tcb(3, 0, 8, 115),
+            ..Default::default()
+        };
+
+        assert!(AmdTee::verify_proof(&valid_vk(policy), VALID_ATTESTATION, &vec![]).is_ok());
+    }
+
+    #[test]
+    fn validate_valid_vk() {
+        assert_eq!(AmdTee::validate_vk(&valid_vk(SnpPolicy::default())), Ok(()));
+    }
+
+    #[test]
+    fn reject_tampered_report() {
+        let mut proof = VALID_ATTESTATION.to_vec();
+        proof[MEASUREMENT_OFFSET] ^= 0x01;
+
+        assert_eq!(
+            AmdTee::verify_proof(&valid_vk(SnpPolicy::default()), &proof, &vec![]),
+            Err(VerifyError::VerifyError)
+        );
+    }
+
+    #[test]
+    fn reject_report_below_the_min_tcb() {
+        let policy = SnpPolicy {
+            min_tcb: tcb(3, 0, 9, 115),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            AmdTee::verify_proof(&valid_vk(policy), VALID_ATTESTATION, &vec![]),
+            Err(VerifyError::VerifyError)
+        );
+    }
+
+    #[test]
+    fn reject_pubs_not_bound_to_report_data() {
+        assert_eq!(
+            AmdTee::verify_proof(
+                &valid_vk(SnpPolicy::default()),
+                VALID_ATTESTATION,
+                &b"other pubs".to_vec()
+            ),
+            Err(VerifyError::VerifyError)
+        );
+    }
+
+    #[test]
+    fn reject_report_if_the_vk_chain_is_not_rooted_in_the_registered_ark() {
+        assert_eq!(
+            AmdTee::verify_proof(
+                &Vk::AmdSnp {
+                    certificates: include_bytes!("resources/intel/valid_tcbinfo_certs.pem")
+                        .to_vec(),
+                    policy: SnpPolicy::default(),
+                },
+                VALID_ATTESTATION,
+                &vec![]
+            ),
+            Err(VerifyError::VerifyError)
+        );
+    }
+
+    #[test]
+    fn reject_malformed_report() {
+        let proof = vec![0u8; 64];
+        let vk = vk(SnpPolicy::default());
+
+        assert_eq!(
+            Tee::<Mock<MockTime<ConstU64<PRESENT>>, AmdRootCrl>>::verify_proof(
+                &vk,
+                &proof,
+                &vec![]
+            ),
+            Err(VerifyError::InvalidProofData)
+        );
+    }
+
+    #[test]
+    fn reject_if_root_cert_is_not_registered() {
+        let proof = vec![0u8; 64];
+        let vk = vk(SnpPolicy::default());
+
+        assert_eq!(
+            Tee::<Mock<MockTime<ConstU64<PRESENT>>>>::verify_proof(&vk, &proof, &vec![]),
+            Err(VerifyError::VerifyError)
+        );
+    }
+
+    #[test]
+    fn reject_vk_without_certificates() {
+        let vk = Vk::AmdSnp {
+            certificates: vec![],
+            policy: SnpPolicy::default(),
+        };
+
+        assert_eq!(
+            Tee::<Mock<MockTime<ConstU64<PRESENT>>, AmdRootCrl>>::validate_vk(&vk),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+        assert_eq!(
+            Tee::<Mock<MockTime<ConstU64<PRESENT>>, AmdRootCrl>>::verify_proof(
+                &vk,
+                &vec![0u8; 64],
+                &vec![]
+            ),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+    }
+
+    #[test]
+    fn reject_vk_if_root_cert_is_not_registered() {
+        let vk = vk(SnpPolicy::default());
+
+        assert_eq!(
+            Tee::<Mock<MockTime<ConstU64<PRESENT>>>>::validate_vk(&vk),
+            Err(VerifyError::VerifyError)
+        );
+    }
+
+    mod policy {
+        use super::*;
+
+        #[test]
+        fn accept_default_guest_policy() {
+            assert_eq!(
+                SnpPolicy::default().check(DEFAULT_POLICY, &tcb(0, 0, 0, 0)),
+                Ok(())
+            );
+        }
+
+        #[test]
+        fn reject_debug_guest_unless_allowed() {
+            let policy = SnpPolicy::default();
+            assert_eq!(
+                policy.check(DEFAULT_POLICY | DEBUG, &tcb(0, 0, 0, 0)),
+                Err(VerifyError::VerifyError)
+            );
+
+            let policy = SnpPolicy {
+                allow_debug: true,
+                ..policy
+            };
+            assert_eq!(
+                policy.check(DEFAULT_POLICY | DEBUG, &tcb(0, 0, 0, 0)),
+                Ok(())
+            );
+        }
+
+        #[test]
+        fn reject_migration_agent_unless_allowed() {
+            let policy = SnpPolicy::default();
+            assert_eq!(
+                policy.check(DEFAULT_POLICY | MIGRATE_MA, &tcb(0, 0, 0, 0)),
+                Err(VerifyError::VerifyError)
+            );
+
+            let policy = SnpPolicy {
+                allow_migration_agent: true,
+                ..policy
+            };
+            assert_eq!(
+                policy.check(DEFAULT_POLICY | MIGRATE_MA, &tcb(0, 0, 0, 0)),
+                Ok(())
+            );
+        }
+
+        #[test]
+        fn reject_outdated_tcb() {
+            let policy = SnpPolicy {
+                min_tcb: tcb(3, 1, 8, 115),
+                ..Default::default()
+            };
+            for reported in [
+                tcb(2, 1, 8, 115),
+                tcb(3, 0, 8, 115),
+                tcb(3, 1, 7, 115),
+                tcb(3, 1, 8, 114),
+            ] {
+                assert_eq!(
+                    policy.check(DEFAULT_POLICY, &reported),
+                    Err(VerifyError::VerifyError),
+                    "{reported:?}"
+                );
+            }
+        }
+
+        #[test]
+        fn accept_up_to_date_tcb() {
+            let policy = SnpPolicy {
+                min_tcb: tcb(3, 0, 8, 115),
+                ..Default::default()
+            };
+            assert_eq!(policy.check(DEFAULT_POLICY, &tcb(3, 0, 8, 115)), Ok(()));
+            assert_eq!(policy.check(DEFAULT_POLICY, &tcb(4, 1, 9, 210)), Ok(()));
+        }
+    }
+}
+
+// =========================================================================
+// TDX tests
+// =========================================================================
+
+mod tdx {
+    use super::*;
+
+    /// TEE TCB SVN of the TDX module that produced `valid_quote.dat`.
+    const TEE_TCB_SVN: [u8; TEE_TCB_SVN_LENGTH] = hex!("06010300000000000000000000000000");
+    /// XFAM of the trust domain in `valid_quote.dat`.
+    const XFAM: u64 = 0x602e7;
+
+    /// The policy matching the trust domain in `valid_quote.dat`.
+    fn valid_quote_policy() -> TdxPolicy {
+        TdxPolicy {
+            measurements: valid_quote_measurements(),
+            allow_debug: false,
+            min_seam_svn: TEE_TCB_SVN[0],
+            min_tee_tcb_svn: TEE_TCB_SVN,
+            xfam: Some(XFAM),
+        }
+    }
+
+    fn vk(policy: TdxPolicy) -> Vk {
+        Vk::Tdx {
+            tcb_response: include_bytes!("resources/intel/valid_tcbinfo.json").to_vec(),
+            certificates: include_bytes!("resources/intel/valid_tcbinfo_certs.pem").to_vec(),
+            policy,
+        }
+    }
+
+    fn verify(vk: &Vk) -> Result<Option<Weight>, VerifyError> {
+        let proof = include_bytes!("resources/intel/valid_quote.dat").to_vec();
+        Tee::<Mock<MockTime<ConstU64<PRESENT>>>>::verify_proof(vk, &proof, &vec![])
+    }
+
+    #[test]
+    fn verify_valid_proof() {
+        assert_eq!(
+            verify(&vk(valid_quote_policy())),
+            Ok(Some(Weight::from_all(MOCK_INTEL_WEIGHT)))
+        );
+    }
+
+    #[test]
+    fn verify_valid_proof_with_default_policy() {
+        assert_eq!(
+            verify(&vk(TdxPolicy::default())),
+            Ok(Some(Weight::from_all(MOCK_INTEL_WEIGHT)))
+        );
+    }
+
+    #[test]
+    fn reject_valid_proof_with_outdated_seam_module() {
+        let policy = TdxPolicy {
+            min_seam_svn: TEE_TCB_SVN[0] + 1,
+            ..valid_quote_policy()
+        };
+        assert_eq!(verify(&vk(policy)), Err(VerifyError::VerifyError));
+    }
+
+    #[test]
+    fn reject_valid_proof_with_outdated_tcb_level() {
+        for index in 0..TEE_TCB_SVN_LENGTH {
+            let mut policy = valid_quote_policy();
+            policy.min_tee_tcb_svn[index] += 1;
+            assert_eq!(
+                verify(&vk(policy)),
+                Err(VerifyError::VerifyError),
+                "component {index}"
+            );
+        }
+    }
+
+    #[test]
+    fn reject_valid_proof_with_mismatching_xfam() {
+        let policy = TdxPolicy {
+            xfam: Some(XFAM & !(1 << 17)),
+            ..valid_quote_policy()
+        };
+        assert_eq!(verify(&vk(policy)), Err(VerifyError::VerifyError));
+    }
+
+    #[test]
+    fn reject_valid_proof_with_mismatching_measurements() {
+        let mut policy = valid_quote_policy();
+        policy.measurements.mr_td = Some([0xff; 48]);
+        assert_eq!(verify(&vk(policy)), Err(VerifyError::VerifyError));
+    }
+
+    #[test]
+    fn reject_valid_proof_with_pubs_not_bound_to_report_data() {
+        let proof = include_bytes!("resources/intel/valid_quote.dat").to_vec();
+        assert_eq!(
+            Tee::<Mock<MockTime<ConstU64<PRESENT>>>>::verify_proof(
+                &vk(valid_quote_policy()),
+                &proof,
+                &vec![0x9a]
+            ),
+            Err(VerifyError::VerifyError)
+        );
+    }
+
+    #[test]
+    fn reject_invalid_proof() {
+        let proof = include_bytes!("resources/intel/invalid_quote.dat").to_vec();
+        assert_eq!(
+            Tee::<Mock<MockTime<ConstU64<PRESENT>>>>::verify_proof(
+                &vk(valid_quote_policy()),
+                &proof,
+                &vec![]
+            ),
+            Err(VerifyError::VerifyError)
+        );
+    }
+
+    #[test]
+    fn reject_too_long_vk() {
+        let vk = Vk::Tdx {
+            tcb_response: vec![0u8; MAX_VK_LENGTH as usize + 1],
+            certificates: vec![0u8; MAX_VK_LENGTH as usize + 1],
+            policy: TdxPolicy::default(),
+        };
+
+        assert_eq!(verify(&vk), Err(VerifyError::InvalidVerificationKey));
+        assert_eq!(
+            Tee::<Mock<MockTime<ConstU64<PRESENT>>>>::validate_vk(&vk),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+    }
+
+    #[test]
+    fn validate_vk() {
+        assert_eq!(
+            Tee::<Mock<MockTime<ConstU64<PRESENT>>>>::validate_vk(&vk(valid_quote_policy())),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn reject_vk_with_sgx_tcb_info() {
+        let tcb_response =
+            core::str::from_utf8(include_bytes!("resources/intel/valid_tcbinfo.json"))
+                .unwrap()
+                .replacen(r#""id":"TDX""#, r#""id":"SGX""#, 1);
+        let vk = Vk::Tdx {
+            tcb_response: tcb_response.into_bytes(),
+            certificates: include_bytes!("resources/intel/valid_tcbinfo_certs.pem").to_vec(),
+            policy: valid_quote_policy(),
+        };
+
+        assert_eq!(
+            Tee::<Mock<MockTime<ConstU64<PRESENT>>>>::validate_vk(&vk),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+    }
+
+    mod policy {
+        use super::*;
+
+        const DEBUG: u64 = 1 << 0;
+        const SEPT_VE_DISABLE: u64 = 1 << 28;
+
+        /// The TD report of `valid_quote.dat`.
+        fn report() -> TdReport {
+            let measurements = valid_quote_measurements();
+            TdReport {
+                tee_tcb_svn: TEE_TCB_SVN,
+                seam_attributes: 0,
+                td_attributes: SEPT_VE_DISABLE,
+                xfam: XFAM,
+                mr_td: measurements.mr_td.unwrap(),
+                rtmrs: measurements.rtmrs.map(Option::unwrap),
+            }
+        }
+
+        #[test]
+        fn accept_matching_report() {
+            assert_eq!(valid_quote_policy().check(&report()), Ok(()));
+        }
+
+        #[test]
+        fn reject_debug_trust_domain_unless_allowed() {
+            let report = TdReport {
+                td_attributes: SEPT_VE_DISABLE | DEBUG,
+                ..report()
+            };
+            assert_eq!(
+                valid_quote_policy().check(&report),
+                Err(VerifyError::VerifyError)
+            );
+
+            let policy = TdxPolicy {
+                allow_debug: true,
+                ..valid_quote_policy()
+            };
+            assert_eq!(policy.check(&report), Ok(()));
+        }
+
+        #[test]
+        fn reject_non_production_seam_module() {
+            let report = TdReport {
+                seam_attributes: 1,
+                ..report()
+            };
+            assert_eq!(
+                TdxPolicy::default().check(&report),
+                Err(VerifyError::VerifyError)
+            );
+        }
+
+        #[test]
+        fn accept_newer_tcb_level() {
+            let report = TdReport {
+                tee_tcb_svn: hex!("07010400000000000000000000000000"),
+                ..report()
+            };
+            assert_eq!(valid_quote_policy().check(&report), Ok(()));
+        }
+
+        #[test]
+        fn ignore_xfam_if_not_pinned() {
+            let report = TdReport {
+                xfam: 0,
+                ..report()
+            };
+            assert_eq!(
+                valid_quote_policy().check(&report),
+                Err(VerifyError::VerifyError)
+            );
+
+            let policy = TdxPolicy {
+                xfam: None,
+                ..valid_quote_policy()
+            };
+            assert_eq!(policy.check(&report), Ok(()));
+        }
     }
 }
@@ -56,7 +56,9 @@ use core::marker::PhantomData;
 /// Weight functions needed for `pallet_tee_verifier`.
 pub trait WeightInfo {
     fn intel_verify_proof() -> Weight;
+    fn intel_registry_verify_proof() -> Weight;
     fn nitro_verify_proof() -> Weight;
+    fn amd_verify_proof() -> Weight;
     fn get_vk() -> Weight;
     fn validate_vk() -> Weight;
     fn compute_statement_hash() -> Weight;
@@ -68,27 +70,52 @@ pub trait WeightInfo {
 impl WeightInfo for () {
     /// Storage: `Timestamp::Now` (r:1 w:0)
     /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-    /// Storage: `Crl::Revoked` (r:1 w:0)
-    /// Proof: `Crl::Revoked` (`max_values`: None, `max_size`: Some(2910084), added: 2912559, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::CertificateAuthorities` (r:1 w:0)
+    /// Proof: `Crl::CertificateAuthorities` (`max_values`: None, `max_size`: Some(2144), added: 4619, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::RevokedCerts` (r:3 w:0)
+    /// Proof: `Crl::RevokedCerts` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
     fn intel_verify_proof() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `195`
-        //  Estimated: `2913549`
-        // Minimum execution time: 8_635_752_000 picoseconds.
-        Weight::from_parts(8_811_384_000, 2913549)
-            .saturating_add(RocksDbWeight::get().reads(2_u64))
+        // TODO: re-run the benchmark, it predates storing one entry per revoked certificate.
+        // The execution time is the one of the previous run: it's dominated by the signature
+        // checks. The proof size sums the bounds of the storage items above.
+        Weight::from_parts(8_811_384_000, 13033)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+    }
+    /// Storage: `Crl::TcbInfos` (r:1 w:0)
+    /// Proof: `Crl::TcbInfos` (`max_values`: None, `max_size`: Some(16430), added: 18905, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::CertificateAuthorities` (r:1 w:0)
+    /// Proof: `Crl::CertificateAuthorities` (`max_values`: None, `max_size`: Some(2144), added: 4619, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::RevokedCerts` (r:3 w:0)
+    /// Proof: `Crl::RevokedCerts` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+    fn intel_registry_verify_proof() -> Weight {
+        // TODO: re-run the benchmark, it predates storing one entry per revoked certificate.
+        // The execution time is the one of the previous run: it's dominated by the signature
+        // checks. The proof size sums the bounds of the storage items above.
+        Weight::from_parts(8_838_117_000, 31938)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
     }
     /// Storage: `Timestamp::Now` (r:1 w:0)
     /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-    /// Storage: `Crl::Revoked` (r:1 w:0)
-    /// Proof: `Crl::Revoked` (`max_values`: None, `max_size`: Some(2910084), added: 2912559, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::CertificateAuthorities` (r:1 w:0)
+    /// Proof: `Crl::CertificateAuthorities` (`max_values`: None, `max_size`: Some(2144), added: 4619, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::RevokedCerts` (r:4 w:0)
+    /// Proof: `Crl::RevokedCerts` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
     fn nitro_verify_proof() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `185`
-        //  Estimated: `2913549`
-        // Minimum execution time: 33_072_251_000 picoseconds.
-        Weight::from_parts(33_201_071_000, 2913549)
-            .saturating_add(RocksDbWeight::get().reads(2_u64))
+        // TODO: re-run the benchmark, it predates storing one entry per revoked certificate.
+        // The execution time is the one of the previous run: it's dominated by the signature
+        // checks. The proof size sums the bounds of the storage items above.
+        Weight::from_parts(33_201_071_000, 15670)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+    }
+    fn amd_verify_proof() -> Weight {
+        // TODO: generate it. The `amd_verify_proof` benchmark exists, but it hasn't been run
+        // yet. Until then, use the Nitro weight as an upper bound: it checks more P-384
+        // signatures than an SNP report and its chain. The proof size sums the bounds of the `Timestamp::Now`,
+        // `Crl::CertificateAuthorities`, `Crl::RootRotations` and 2 `Crl::RevokedCerts` reads.
+        Weight::from_parts(33_201_071_000, 15010)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
     }
     /// Storage: `SettlementTeePallet::Vks` (r:1 w:0)
     /// Proof: `SettlementTeePallet::Vks` (`max_values`: None, `max_size`: Some(131116), added: 133591, mode: `MaxEncodedLen`)
@@ -102,15 +129,18 @@ impl WeightInfo for () {
     }
     /// Storage: `Timestamp::Now` (r:1 w:0)
     /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-    /// Storage: `Crl::Revoked` (r:1 w:0)
-    /// Proof: `Crl::Revoked` (`max_values`: None, `max_size`: Some(2910084), added: 2912559, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::CertificateAuthorities` (r:1 w:0)
+    /// Proof: `Crl::CertificateAuthorities` (`max_values`: None, `max_size`: Some(2144), added: 4619, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::RevokedCerts` (r:1 w:0)
+    /// Proof: `Crl::RevokedCerts` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::RootRotations` (r:1 w:0)
+    /// Proof: `Crl::RootRotations` (`max_values`: None, `max_size`: Some(2139), added: 4614, mode: `MaxEncodedLen`)
     fn validate_vk() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `195`
-        //  Estimated: `2913549`
-        // Minimum execution time: 5_185_294_000 picoseconds.
-        Weight::from_parts(5_247_302_000, 2913549)
-            .saturating_add(RocksDbWeight::get().reads(2_u64))
+        // TODO: re-run the benchmark, it predates storing one entry per revoked certificate.
+        // The execution time is the one of the previous run: it's dominated by the signature
+        // checks. The proof size sums the bounds of the storage items above.
+        Weight::from_parts(5_247_302_000, 12373)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
     }
     fn compute_statement_hash() -> Weight {
         // Proof Size summary in bytes:
@@ -125,19 +155,22 @@ impl WeightInfo for () {
     /// Proof: `SettlementTeePallet::Tickets` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
     /// Storage: `Timestamp::Now` (r:1 w:0)
     /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-    /// Storage: `Crl::Revoked` (r:1 w:0)
-    /// Proof: `Crl::Revoked` (`max_values`: None, `max_size`: Some(2910084), added: 2912559, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::CertificateAuthorities` (r:1 w:0)
+    /// Proof: `Crl::CertificateAuthorities` (`max_values`: None, `max_size`: Some(2144), added: 4619, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::RevokedCerts` (r:1 w:0)
+    /// Proof: `Crl::RevokedCerts` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
     /// Storage: `Balances::Holds` (r:1 w:1)
     /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `MaxEncodedLen`)
     /// Storage: `SettlementTeePallet::Vks` (r:1 w:1)
     /// Proof: `SettlementTeePallet::Vks` (`max_values`: None, `max_size`: Some(131116), added: 133591, mode: `MaxEncodedLen`)
+    /// Storage: `Crl::RootRotations` (r:1 w:0)
+    /// Proof: `Crl::RootRotations` (`max_values`: None, `max_size`: Some(2139), added: 4614, mode: `MaxEncodedLen`)
     fn register_vk() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `309`
-        //  Estimated: `2913549`
-        // Minimum execution time: 5_249_565_000 picoseconds.
-        Weight::from_parts(5_327_850_000, 2913549)
-            .saturating_add(RocksDbWeight::get().reads(6_u64))
+        // TODO: re-run the benchmark, it predates storing one entry per revoked certificate.
+        // The execution time is the one of the previous run: it's dominated by the signature
+        // checks. The proof size sums the bounds of the storage items above.
+        Weight::from_parts(5_327_850_000, 151663)
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
             .saturating_add(RocksDbWeight::get().writes(3_u64))
     }
     /// Storage: `SettlementTeePallet::Tickets` (r:1 w:1)
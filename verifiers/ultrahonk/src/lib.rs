@@ -136,6 +136,15 @@ pub enum VersionedVk {
     Legacy([u8; VK_SIZE_V0_84]),
 }
 
+/// The metadata stored next to a registered vk.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct VkMetadata {
+    /// The logarithm of the circuit size, for the protocol versions whose verification weight
+    /// depends on it. `None` if the weight doesn't depend on it or the circuit is bigger than
+    /// `MAX_BENCHMARKED_LOG_CIRCUIT_SIZE`.
+    pub log_circuit_size: Option<u64>,
+}
+
 impl Proof {
     pub fn new(proof_type: ProofType, proof_bytes: RawProof) -> Self {
         match proof_type {
@@ -228,7 +237,7 @@ impl IntoVerifyError for ultrahonk_no_std_v3_0::errors::VerifyError {
 pub struct Ultrahonk<T>;
 
 impl<T: Config> Verifier for Ultrahonk<T> {
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
     type Proof = VersionedProof;
 
@@ -236,6 +245,8 @@ impl<T: Config> Verifier for Ultrahonk<T> {
 
     type Vk = VersionedVk;
 
+    type VkMetadata = VkMetadata;
+
     fn hash_context_data() -> &'static [u8] {
         b"ultrahonk"
     }
@@ -308,6 +319,14 @@ impl<T: Config> Verifier for Ultrahonk<T> {
         Ok(())
     }
 
+    fn vk_metadata(vk: &Self::Vk) -> Self::VkMetadata {
+        let log_circuit_size = match vk {
+            VersionedVk::V3_0(vk_bytes) => valid_log_circuit_size(vk_bytes).ok(),
+            VersionedVk::V0_84(_) | VersionedVk::Legacy(_) => None,
+        };
+        VkMetadata { log_circuit_size }
+    }
+
     fn vk_hash(vk: &Self::Vk) -> H256 {
         match vk {
             // Legacy uses SHA2-256 of raw VK bytes to match the pre-versioning hash.
@@ -413,6 +432,20 @@ impl<T: Config, W: WeightInfo> pallet_verifiers::WeightInfo<Ultrahonk<T>> for Ul
         }
     }
 
+    fn verify_proof_with_vk_metadata(
+        proof: &<Ultrahonk<T> as Verifier>::Proof,
+        pubs: &<Ultrahonk<T> as Verifier>::Pubs,
+        vk_metadata: &VkMetadata,
+    ) -> Weight {
+        match (proof, vk_metadata.log_circuit_size) {
+            // For V3.0 with a registered vk we already know the actual log_circuit_size
+            (VersionedProof::V3_0(inner), Some(log_circuit_size)) => {
+                compute_weight::<T>(ProtocolVersion::V3_0, inner.into(), log_circuit_size)
+            }
+            _ => <Self as pallet_verifiers::WeightInfo<Ultrahonk<T>>>::verify_proof(proof, pubs),
+        }
+    }
+
     fn register_vk(_vk: &<Ultrahonk<T> as Verifier>::Vk) -> Weight {
         W::register_vk()
     }
@@ -0,0 +1,262 @@
+// Copyright 2025-2026, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migration for the UltraHonk verifier pallet from V2 to V3.
+//!
+//! In V2, the `Vks` entries contained just the [`VersionedVk`](crate::VersionedVk) and its
+//! reference count.
+//!
+//! In V3, each entry also stores the [`VkMetadata`](crate::VkMetadata) computed when the vk
+//! is registered (the log circuit size for `V3_0` vks), which are used to give a precise
+//! pre-dispatch weight when a proof references a registered vk.
+//!
+//! This migration computes the metadata for all the existing vks. The storage keys don't
+//! change, so the `Tickets` entries remain valid without modification.
+
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+use pallet_verifiers::traits::Verifier;
+use sp_core::Get;
+
+/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the storage from V2 to V3.
+///
+/// Appends to every `Vks` entry the [`VkMetadata`](crate::VkMetadata) computed by
+/// [`Ultrahonk::vk_metadata`](crate::Ultrahonk).
+pub struct InnerMigrateV2ToV3<T>(core::marker::PhantomData<T>);
+
+mod v2 {
+    use crate::VersionedVk;
+    use codec::{Decode, Encode};
+
+    /// Migration-only struct mirroring `VkEntry<VersionedVk>` with accessible fields.
+    #[derive(Decode, Encode)]
+    pub struct OldVkEntry {
+        pub vk: VersionedVk,
+        pub ref_count: u64,
+    }
+}
+
+mod v3 {
+    use crate::{VersionedVk, VkMetadata};
+    use codec::{Decode, Encode};
+    use frame_support::{storage_alias, Identity};
+    use sp_core::H256;
+
+    /// Migration-only struct mirroring `VkEntry<VersionedVk, VkMetadata>` with accessible fields.
+    #[derive(Decode, Encode)]
+    pub struct NewVkEntry {
+        pub vk: VersionedVk,
+        pub ref_count: u64,
+        pub metadata: VkMetadata,
+    }
+
+    #[storage_alias]
+    pub type Vks<T: crate::Config + pallet_verifiers::Config<crate::Ultrahonk<T>>> =
+        StorageMap<crate::Pallet<T>, Identity, H256, NewVkEntry>;
+}
+
+impl<T> UncheckedOnRuntimeUpgrade for InnerMigrateV2ToV3<T>
+where
+    T: pallet_verifiers::Config<crate::Ultrahonk<T>> + crate::Config,
+{
+    fn on_runtime_upgrade() -> frame_support::weights::Weight {
+        let mut count: u64 = 0;
+
+        v3::Vks::<T>::translate::<v2::OldVkEntry, _>(|_, old_entry| {
+            count += 1;
+            Some(v3::NewVkEntry {
+                metadata: crate::Ultrahonk::<T>::vk_metadata(&old_entry.vk),
+                vk: old_entry.vk,
+                ref_count: old_entry.ref_count,
+            })
+        });
+
+        log::info!(
+            target: "runtime::ultrahonk",
+            "UltraHonk migration V2->V3: added metadata to {} VK entries",
+            count,
+        );
+
+        // Per entry: 1 read + 1 write
+        T::DbWeight::get().reads_writes(count, count)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<alloc::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+        let vk_count = v3::Vks::<T>::iter_keys().count() as u64;
+        log::info!(
+            target: "runtime::ultrahonk",
+            "ultrahonk pre_upgrade v2->v3: {vk_count} VKs to migrate"
+        );
+        Ok(vk_count.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: alloc::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        let pre_vk_count =
+            u64::decode(&mut state.as_slice()).map_err(|_| "Failed to decode pre_upgrade state")?;
+
+        let post_vk_count = v3::Vks::<T>::iter()
+            .inspect(|(_, entry)| {
+                assert_eq!(
+                    entry.metadata,
+                    crate::Ultrahonk::<T>::vk_metadata(&entry.vk),
+                    "All migrated VKs should have their metadata"
+                );
+            })
+            .count() as u64;
+
+        frame_support::ensure!(
+            post_vk_count == pre_vk_count,
+            "ultrahonk post_upgrade v2->v3: expected {pre_vk_count} VKs, got {post_vk_count}"
+        );
+
+        log::info!(
+            target: "runtime::ultrahonk",
+            "ultrahonk post_upgrade v2->v3: OK, migrated {post_vk_count} VKs"
+        );
+        Ok(())
+    }
+}
+
+/// [`UncheckedOnRuntimeUpgrade`] implementation [`InnerMigrateV2ToV3`] wrapped in a
+/// [`VersionedMigration`], which ensures that:
+/// - The migration only runs once when the on-chain storage version is 2
+/// - The on-chain storage version is updated to `3` after the migration executes
+/// - Reads/Writes from checking/setting the on-chain storage version are accounted for
+pub type MigrateV2ToV3<T> = VersionedMigration<
+    2, // The migration will only execute when the on-chain storage version is 2
+    3, // The on-chain storage version will be set to 3 after the migration is complete
+    InnerMigrateV2ToV3<T>,
+    crate::Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::migrations::mock::*;
+    use crate::resources::{get_parameterized_test_data, TestParams};
+    use crate::{
+        ProofType, ProtocolVersion, Ultrahonk, VersionedVk, VkMetadata,
+        MAX_BENCHMARKED_LOG_CIRCUIT_SIZE, MIN_BENCHMARKED_LOG_CIRCUIT_SIZE,
+    };
+    use frame_support::{storage_alias, traits::UncheckedOnRuntimeUpgrade, Identity};
+    use sp_core::H256;
+
+    #[storage_alias]
+    type OldVks<T: crate::Config + pallet_verifiers::Config<crate::Ultrahonk<T>>> =
+        StorageMap<crate::Pallet<T>, Identity, H256, v2::OldVkEntry>;
+
+    fn insert_old_vk(vk: VersionedVk, ref_count: u64) -> H256 {
+        let hash = Ultrahonk::<Test>::vk_hash(&vk);
+        OldVks::<Test>::insert(hash, v2::OldVkEntry { vk, ref_count });
+        hash
+    }
+
+    fn v3_0_vk(log_circuit_size: u64) -> VersionedVk {
+        get_parameterized_test_data(TestParams::new(
+            log_circuit_size,
+            ProofType::Plain,
+            ProtocolVersion::V3_0,
+        ))
+        .expect("test data should be available")
+        .versioned_vk
+    }
+
+    fn legacy_vk() -> VersionedVk {
+        get_parameterized_test_data(TestParams::new_legacy(ProofType::Plain))
+            .expect("test data should be available")
+            .versioned_vk
+    }
+
+    #[test]
+    fn adds_the_log_circuit_size_to_v3_0_vks() {
+        test_ext().execute_with(|| {
+            let min = insert_old_vk(v3_0_vk(MIN_BENCHMARKED_LOG_CIRCUIT_SIZE), 2);
+            let max = insert_old_vk(v3_0_vk(MAX_BENCHMARKED_LOG_CIRCUIT_SIZE), 1);
+
+            InnerMigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+            let entry = v3::Vks::<Test>::get(min).expect("VK should be present after migration");
+            assert_eq!(entry.vk, v3_0_vk(MIN_BENCHMARKED_LOG_CIRCUIT_SIZE));
+            assert_eq!(entry.ref_count, 2);
+            assert_eq!(
+                entry.metadata,
+                VkMetadata {
+                    log_circuit_size: Some(MIN_BENCHMARKED_LOG_CIRCUIT_SIZE)
+                }
+            );
+            let entry = v3::Vks::<Test>::get(max).expect("VK should be present after migration");
+            assert_eq!(entry.ref_count, 1);
+            assert_eq!(
+                entry.metadata,
+                VkMetadata {
+                    log_circuit_size: Some(MAX_BENCHMARKED_LOG_CIRCUIT_SIZE)
+                }
+            );
+        });
+    }
+
+    #[test]
+    fn adds_empty_metadata_to_legacy_vks() {
+        test_ext().execute_with(|| {
+            let hash = insert_old_vk(legacy_vk(), 3);
+
+            InnerMigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+            let entry = v3::Vks::<Test>::get(hash).expect("VK should be present after migration");
+            assert_eq!(entry.vk, legacy_vk());
+            assert_eq!(entry.ref_count, 3);
+            assert_eq!(entry.metadata, VkMetadata::default());
+        });
+    }
+
+    #[test]
+    fn migrated_vks_are_readable_by_the_pallet() {
+        test_ext().execute_with(|| {
+            let hash = insert_old_vk(v3_0_vk(MIN_BENCHMARKED_LOG_CIRCUIT_SIZE), 1);
+
+            InnerMigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+            let entry = pallet_verifiers::Vks::<Test, Ultrahonk<Test>>::get(hash)
+                .expect("VK should be decoded by pallet_verifiers::Vks");
+            assert_eq!(
+                entry.metadata().log_circuit_size,
+                Some(MIN_BENCHMARKED_LOG_CIRCUIT_SIZE)
+            );
+        });
+    }
+
+    #[test]
+    fn migrates_multiple_vks_with_correct_weight() {
+        test_ext().execute_with(|| {
+            insert_old_vk(v3_0_vk(MIN_BENCHMARKED_LOG_CIRCUIT_SIZE), 1);
+            insert_old_vk(legacy_vk(), 1);
+
+            let weight = InnerMigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+            assert_eq!(v3::Vks::<Test>::iter().count(), 2);
+            assert_eq!(
+                weight,
+                <<Test as frame_system::Config>::DbWeight as Get<
+                    frame_support::weights::RuntimeDbWeight,
+                >>::get()
+                .reads_writes(2, 2)
+            );
+        });
+    }
+}
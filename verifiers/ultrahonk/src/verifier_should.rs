@@ -159,6 +159,124 @@ fn verify_vk_hash(#[case] version: ProtocolVersion, #[case] expected: [u8; 32])
     assert_eq!(vk_hash.as_bytes(), expected);
 }
 
+#[rstest]
+fn store_the_log_circuit_size_in_v3_0_vk_metadata(
+    #[values(MIN_BENCHMARKED_LOG_CIRCUIT_SIZE, 16, MAX_BENCHMARKED_LOG_CIRCUIT_SIZE)]
+    log_circuit_size: u64,
+) {
+    let TestData { versioned_vk, .. } = get_parameterized_test_data(TestParams::new(
+        log_circuit_size,
+        ProofType::Plain,
+        ProtocolVersion::V3_0,
+    ))
+    .unwrap();
+
+    assert_eq!(
+        Ultrahonk::<MockRuntime>::vk_metadata(&versioned_vk),
+        VkMetadata {
+            log_circuit_size: Some(log_circuit_size)
+        }
+    );
+}
+
+#[rstest]
+fn store_no_log_circuit_size_in_v0_84_vk_metadata(
+    #[values(ProtocolVersion::V0_84, ProtocolVersion::Legacy)] version: ProtocolVersion,
+) {
+    let TestData { versioned_vk, .. } = load_test_data(ProofType::Plain, version);
+
+    assert_eq!(
+        Ultrahonk::<MockRuntime>::vk_metadata(&versioned_vk),
+        VkMetadata::default()
+    );
+}
+
+mod weight_with_vk_metadata {
+    use super::*;
+
+    fn estimated_weight(proof: &VersionedProof, pubs: &Pubs) -> Weight {
+        <UltrahonkWeight<()> as pallet_verifiers::WeightInfo<Ultrahonk<MockRuntime>>>::verify_proof(
+            proof, pubs,
+        )
+    }
+
+    fn weight_with_vk_metadata(
+        proof: &VersionedProof,
+        pubs: &Pubs,
+        vk_metadata: &VkMetadata,
+    ) -> Weight {
+        <UltrahonkWeight<()> as pallet_verifiers::WeightInfo<Ultrahonk<MockRuntime>>>::verify_proof_with_vk_metadata(
+            proof,
+            pubs,
+            vk_metadata,
+        )
+    }
+
+    #[rstest]
+    fn should_use_the_log_circuit_size_for_v3_0_proofs(
+        #[values(ProofType::ZK, ProofType::Plain)] proof_type: ProofType,
+        #[values(MIN_BENCHMARKED_LOG_CIRCUIT_SIZE, 16)] log_circuit_size: u64,
+    ) {
+        let TestData {
+            versioned_vk,
+            versioned_proof,
+            pubs,
+        } = get_parameterized_test_data(TestParams::new(
+            log_circuit_size,
+            proof_type,
+            ProtocolVersion::V3_0,
+        ))
+        .unwrap();
+        let vk_metadata = Ultrahonk::<MockRuntime>::vk_metadata(&versioned_vk);
+
+        let weight = weight_with_vk_metadata(&versioned_proof, &pubs, &vk_metadata);
+
+        assert_eq!(
+            weight,
+            compute_weight::<MockRuntime>(ProtocolVersion::V3_0, proof_type, log_circuit_size)
+        );
+        assert!(weight.ref_time() < estimated_weight(&versioned_proof, &pubs).ref_time());
+    }
+
+    #[rstest]
+    fn should_be_the_estimated_one_without_log_circuit_size(
+        #[values(ProofType::ZK, ProofType::Plain)] proof_type: ProofType,
+        #[values(ProtocolVersion::V3_0, ProtocolVersion::V0_84, ProtocolVersion::Legacy)]
+        version: ProtocolVersion,
+    ) {
+        let TestData {
+            versioned_proof,
+            pubs,
+            ..
+        } = load_test_data(proof_type, version);
+
+        assert_eq!(
+            weight_with_vk_metadata(&versioned_proof, &pubs, &VkMetadata::default()),
+            estimated_weight(&versioned_proof, &pubs)
+        );
+    }
+
+    #[test]
+    fn should_ignore_the_log_circuit_size_for_v0_84_proofs() {
+        let TestData {
+            versioned_proof,
+            pubs,
+            ..
+        } = load_test_data(ProofType::Plain, ProtocolVersion::V0_84);
+
+        assert_eq!(
+            weight_with_vk_metadata(
+                &versioned_proof,
+                &pubs,
+                &VkMetadata {
+                    log_circuit_size: Some(MIN_BENCHMARKED_LOG_CIRCUIT_SIZE)
+                }
+            ),
+            estimated_weight(&versioned_proof, &pubs)
+        );
+    }
+}
+
 mod reject {
     use super::*;
 
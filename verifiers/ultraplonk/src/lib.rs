@@ -53,6 +53,8 @@ impl<T: Config> Verifier for Ultraplonk<T> {
 
     type Vk = Vk;
 
+    type VkMetadata = ();
+
     fn hash_context_data() -> &'static [u8] {
         b"ultraplonk"
     }
@@ -113,7 +113,7 @@ async function run(nodeName, networkInfo, _args) {
         {
             name: "Tee",
             pallet: api.tx.settlementTeePallet,
-            args: [{ 'Vk': { 'Intel': { tcb_response: TEE_VK_TCB_RESP, certificates: TEE_VK_TCB_CERT } } }, TEE_PROOF, TEE_PUBS],
+            args: [{ 'Vk': { 'Intel': { tcb_response: TEE_VK_TCB_RESP, certificates: TEE_VK_TCB_CERT, measurements: null } } }, TEE_PROOF, TEE_PUBS],
         }
     ];
 
@@ -14,6 +14,7 @@ const ReturnCode = {
 const MNEMONIC = 'poverty popular note inform state innocent grant crumble manage tornado primary list';
 const CLAIM_AMOUNT = '1000000000000000000'
 const INITIAL_BALANCE = '10000000000000000000'
+const CAMPAIGN_ID = 0;
 
 async function run(nodeName, networkInfo, _args) {
 
@@ -24,15 +25,6 @@ async function run(nodeName, networkInfo, _args) {
     // Dest address: xpkQVjJtgK2jwbAkNLEPnWxxuxmBkaLtie7oug3WuRBSZXHz8
     const eth_signature = '0x3485f6726eb2efee5356f3b5bdd4df6287c70a73e2b3b9b82e54d8df5395421543456d948bff2109db8fc2342bf4cdc424f70c0161091ff83cb68227a32713e81c';
 
-  // Check token claim pallet account exists
-  const palletAddressOption = await api.query.tokenClaim.palletAccountId();
-  const palletAddress = palletAddressOption.unwrap();
-  const account_balance = (await api.query.system.account(palletAddress))["data"]["free"];
-  if (account_balance <= 0) {
-    console.log(`Token Claim pallet account doesn't exist`);
-    return ReturnCode.ErrInitClaim;
-  }
-
   // Build a keyring and import Alice's credential
   let keyring = new zombie.Keyring({ type: 'sr25519' });
   const alice = keyring.addFromUri('//Alice');
@@ -62,20 +54,20 @@ async function run(nodeName, networkInfo, _args) {
 
   // Begin claim with beneficiary
   const message = "I'm claiming my funds !";
-  let events = await sudoInitClaim(alice, beneficiaries_map, INITIAL_BALANCE, message);
+  let events = await sudoInitClaim(alice, CAMPAIGN_ID, beneficiaries_map, INITIAL_BALANCE, message);
   if (!receivedEvents(events)) {
       console.log(`Failed to initialize claim`);
       return ReturnCode.ErrInitClaim;
   }
   console.log("Claim initialized");
 
-  const keys = await api.query.tokenClaim.beneficiaries.keys();
-  const beneficiaries = keys.map(({ args: [beneficiaryId] }) => beneficiaryId);
+  const keys = await api.query.tokenClaim.beneficiaries.keys(CAMPAIGN_ID);
+  const beneficiaries = keys.map(({ args: [_, beneficiaryId] }) => beneficiaryId);
   console.log('all beneficiaries:', beneficiaries.join(', ')); 
 
   // Claim SR
   let signature = beneficiary_sr.sign(message);
-  events = await claim({Sr25519: beneficiary_sr.publicKey}, {Sr25519: signature});
+  events = await claim(CAMPAIGN_ID, {Sr25519: beneficiary_sr.publicKey}, {Sr25519: signature});
   if (!receivedEvents(events)) {
       console.log(`Failed to claim`);
       return ReturnCode.ErrClaim;
@@ -90,7 +82,7 @@ async function run(nodeName, networkInfo, _args) {
 
   // Claim SR Prefixed (generated via PolkadotJS)
   signature = "0xc89703e8763b08dfedd8e78959d1c1b28138628e58c7e4bc2c0eefd87d796c10e3fdb7bd35ef713d02c49959d3bfb2c559673a70e54156bc71bff5e7541f1e86";;
-  events = await claim({Sr25519: beneficiary_polkadot_js.publicKey}, {Sr25519: hexToU8a(signature)});
+  events = await claim(CAMPAIGN_ID, {Sr25519: beneficiary_polkadot_js.publicKey}, {Sr25519: hexToU8a(signature)});
   if (!receivedEvents(events)) {
       console.log(`Failed to claim`);
       return ReturnCode.ErrClaim;
@@ -105,7 +97,7 @@ async function run(nodeName, networkInfo, _args) {
 
   // Claim Ed25519
   signature = beneficiary_ed.sign(message);
-  events = await claim({Ed25519: beneficiary_ed.publicKey}, {Ed25519: signature});
+  events = await claim(CAMPAIGN_ID, {Ed25519: beneficiary_ed.publicKey}, {Ed25519: signature});
   if (!receivedEvents(events)) {
       console.log(`Failed to claim`);
       return ReturnCode.ErrClaim;
@@ -120,7 +112,7 @@ async function run(nodeName, networkInfo, _args) {
 
   // Claim ECDSA
   signature = beneficiary_ecdsa.sign(message);
-  events = await claim({Ecdsa: beneficiary_ecdsa.publicKey}, {Ecdsa: signature});
+  events = await claim(CAMPAIGN_ID, {Ecdsa: beneficiary_ecdsa.publicKey}, {Ecdsa: signature});
   if (!receivedEvents(events)) {
       console.log(`Failed to claim`);
       return ReturnCode.ErrClaim;
@@ -134,7 +126,7 @@ async function run(nodeName, networkInfo, _args) {
   }
 
   // Claim Ethereum
-  events = await claimEthereum(beneficiary_eth, eth_signature, beneficiary_sr.address);
+  events = await claimEthereum(CAMPAIGN_ID, beneficiary_eth, eth_signature, beneficiary_sr.address);
   if (!receivedEvents(events)) {
       console.log(`Failed to claim`);
       return ReturnCode.ErrClaim;
@@ -16,11 +16,11 @@ const PUBS = [
     "0x21172c52fdd27c8e301ccee69b2ec6145ab5a2fb06dc69e2985eddd1be3f59a5",
     "0x06978cb225e58fd5d5ec05b2cd3a73f002c78ecd86f8dbb9bfe439f061cfdf46"
 ]
-const STATEMENT_HASH = "0xfb165f626e13d17cd9d1f96a730ca835270a39f0ead0535b819b9fc9ba18c07e";
-const VKEY_HASH = "0x27833207b215f9d57bcf0adedaa5353bacd21e90252110f46b60943b54bd9518";
+const STATEMENT_HASH = "0x9a5fcc8ccd998afe6baa9433ee4e4268d690f433a990989eba087c67160c19a4";
+const VKEY_HASH = "0x74cd8176302353fc12996fcada63bed2924af9d403794975c7792d1d519eea90";
 
-exports.PROOF = PROOF;
+exports.PROOF = { 'V22_0': PROOF };
 exports.PUBS = PUBS;
-exports.VK = JSON.parse(VKA);
+exports.VK = { 'V22_0': JSON.parse(VKA) };
 exports.STATEMENT_HASH = STATEMENT_HASH;
 exports.VKEY_HASH = VKEY_HASH;
\ No newline at end of file
@@ -2,6 +2,6 @@ const PROOF = "0xd9003c473dcf824a5622d117b649e6258353ec6ab98ba256d11ec85cdab1a42
 const PUBS = "0xb94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
 const VK = "0x45946758049372c74bceb6ba6526a9661b1915a3403125e470d5ff7f6c15c0dd";
 
-exports.PROOF = PROOF;
+exports.PROOF = { 'V5_0': PROOF };
 exports.PUBS = PUBS;
 exports.VK = VK;
\ No newline at end of file
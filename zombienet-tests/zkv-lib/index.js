@@ -26,9 +26,15 @@ zkvTypes = {
     config: "Plonky2Config",
     bytes: "Bytes"
   },
-  EzklVK: {
+  EzklVkData: {
     vkBytes: "Bytes"
   },
+  EzklVK: {
+    _enum: {
+      V22_0: 'EzklVkData',
+      Legacy: 'EzklVkData'
+    }
+  },
   UltrahonkVk: {
     _enum: {
       V0_84: 'Bytes',
@@ -47,6 +53,7 @@ zkvTypes = {
     wr: "U256",
     x2: "[[U256; 2]; 3]",
     c0: "[U256; 3]",
+    n_public: "u32",
   },
 };
 
@@ -227,26 +234,37 @@ exports.aggregate = async (signer, domain_id, aggregation_id) => {
   return await submitExtrinsic(api, extrinsic, signer, BlockUntil.InBlock, (event) => event.section == "aggregate");
 }
 
-exports.sudoInitClaim = async (signer, beneficiaries, initial_balance, message) => {
-  // Fund claim pallet account
-  const palletAddressOption = await api.query.tokenClaim.palletAccountId();
-  const palletAddress = palletAddressOption.unwrap();
-  const transfer = api.tx.balances.transferAllowDeath(palletAddress, initial_balance);
+exports.tokenClaimCampaignAccount = (campaign_id) => {
+  // Same derivation as `PalletId::into_sub_account_truncating(campaign_id)`
+  const { bnToU8a, stringToU8a, u8aConcat, u8aFixLength } = require('@polkadot/util');
+  const raw = u8aConcat(
+    stringToU8a('modl'),
+    api.consts.tokenClaim.palletId.toU8a(),
+    bnToU8a(campaign_id, { bitLength: 64, isLe: true })
+  );
+  return api.createType('AccountId', u8aFixLength(raw, 256, true)).toString();
+}
+
+exports.sudoInitClaim = async (signer, campaign_id, beneficiaries, initial_balance, message) => {
+  // Fund campaign account
+  const campaignAddress = exports.tokenClaimCampaignAccount(campaign_id);
+  const transfer = api.tx.balances.transferAllowDeath(campaignAddress, initial_balance);
   await submitExtrinsic(api, transfer, signer, BlockUntil.InBlock);
-  console.log(`Claim pallet funded with ${initial_balance} tokens`);
+  console.log(`Claim campaign ${campaign_id} funded with ${initial_balance} tokens`);
 
   // Begin claim and provide beneficiaries
-  let extrinsic = api.tx.sudo.sudo(api.tx.tokenClaim.beginClaim(beneficiaries, message));
+  const config = { manager: null, unclaimedDestination: null, start: null, end: null, vesting: null };
+  let extrinsic = api.tx.sudo.sudo(api.tx.tokenClaim.beginClaim(campaign_id, beneficiaries, message, config));
   return await submitExtrinsic(api, extrinsic, signer, BlockUntil.InBlock, (event) => event.section == "tokenClaim");
 }
 
-exports.claim = async (signer, signature) => {
-  let extrinsic = api.tx.tokenClaim.claim(signer, signature);
+exports.claim = async (campaign_id, signer, signature) => {
+  let extrinsic = api.tx.tokenClaim.claim(campaign_id, signer, signature);
   return await submitExtrinsicUnsigned(api, extrinsic, BlockUntil.InBlock, (event) => event.section == "tokenClaim");
 }
 
-exports.claimEthereum = async (signer, signature, dest) => {
-  let extrinsic = api.tx.tokenClaim.claimEthereum(signer, signature, dest);
+exports.claimEthereum = async (campaign_id, signer, signature, dest) => {
+  let extrinsic = api.tx.tokenClaim.claimEthereum(campaign_id, signer, signature, dest);
   return await submitExtrinsicUnsigned(api, extrinsic, BlockUntil.InBlock, (event) => event.section == "tokenClaim");
 }
 